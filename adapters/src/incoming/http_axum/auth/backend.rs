@@ -1,14 +1,19 @@
 use axum_login::{AuthUser, AuthnBackend, UserId as AxumUserId};
-use domain::auth::{Role, RoleType, UserId, UserPublic};
+use domain::api_token::ApiToken;
+use domain::auth::{Permission, Role, RoleType, UserId, UserPublic};
+use fedi_wplace_application::ports::incoming::auth::{ApiTokenUseCase, LoginProtectionUseCase};
 use fedi_wplace_application::ports::outgoing::{
     ban_store::DynBanStorePort, password_hasher::DynPasswordHasherPort,
     user_store::DynUserStorePort,
 };
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use uuid::Uuid;
 
 use fedi_wplace_application::error::AppError;
 
+const DUMMY_PASSWORD: &str = "fediplace-dummy-password";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
     pub id: Uuid,
@@ -16,16 +21,24 @@ pub struct User {
     pub username: String,
     pub email_verified_at: Option<time::OffsetDateTime>,
     pub roles: Vec<Role>,
+    pub session_version: i32,
+    // Bumping `session_version` changes this hash, which ends every existing session.
+    #[serde(skip)]
+    session_auth_hash: Vec<u8>,
 }
 
 impl From<UserPublic> for User {
     fn from(user_public: UserPublic) -> Self {
+        let session_auth_hash =
+            format!("{}:{}", user_public.email, user_public.session_version).into_bytes();
         Self {
             id: *user_public.id.as_uuid(),
             email: user_public.email,
             username: user_public.username,
             email_verified_at: user_public.email_verified_at,
             roles: user_public.roles,
+            session_version: user_public.session_version,
+            session_auth_hash,
         }
     }
 }
@@ -39,6 +52,7 @@ impl From<User> for UserPublic {
             email_verified_at: user.email_verified_at,
             available_charges: 0,
             charges_updated_at: time::OffsetDateTime::now_utc(),
+            session_version: user.session_version,
             roles: user.roles,
         }
     }
@@ -58,6 +72,17 @@ impl User {
     pub fn is_admin(&self) -> bool {
         self.has_role_type(RoleType::Admin)
     }
+
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.roles
+            .iter()
+            .any(|role| role.has_permission(permission))
+    }
+
+    // Any role that grants a permission counts, not only the built-in admin role.
+    pub fn is_privileged(&self) -> bool {
+        self.is_admin() || self.roles.iter().any(|role| !role.permissions.is_empty())
+    }
 }
 
 impl AuthUser for User {
@@ -68,7 +93,7 @@ impl AuthUser for User {
     }
 
     fn session_auth_hash(&self) -> &[u8] {
-        self.email.as_bytes()
+        &self.session_auth_hash
     }
 }
 
@@ -77,6 +102,10 @@ pub struct AuthBackend {
     user_store: DynUserStorePort,
     password_hasher: DynPasswordHasherPort,
     ban_store: DynBanStorePort,
+    api_token_use_case: Arc<dyn ApiTokenUseCase + Send + Sync>,
+    login_protection_use_case: Arc<dyn LoginProtectionUseCase + Send + Sync>,
+    // Checked instead of a real hash when there is none to check, with the same parameters.
+    dummy_password_hash: Arc<str>,
 }
 
 impl AuthBackend {
@@ -84,12 +113,32 @@ impl AuthBackend {
         user_store: DynUserStorePort,
         password_hasher: DynPasswordHasherPort,
         ban_store: DynBanStorePort,
-    ) -> Self {
-        Self {
+        api_token_use_case: Arc<dyn ApiTokenUseCase + Send + Sync>,
+        login_protection_use_case: Arc<dyn LoginProtectionUseCase + Send + Sync>,
+    ) -> Result<Self, AppError> {
+        let dummy_password_hash = password_hasher.hash(DUMMY_PASSWORD)?.into();
+
+        Ok(Self {
             user_store,
             password_hasher,
             ban_store,
-        }
+            api_token_use_case,
+            login_protection_use_case,
+            dummy_password_hash,
+        })
+    }
+
+    // Resolves a bearer secret to its owner, applying the same ban check as sessions.
+    pub async fn authenticate_api_token(
+        &self,
+        secret: &str,
+    ) -> Result<Option<(User, ApiToken)>, AppError> {
+        let Some(token) = self.api_token_use_case.authenticate(secret).await? else {
+            return Ok(None);
+        };
+
+        let user = self.get_user(&token.user_id).await?;
+        Ok(user.map(|user| (user, token)))
     }
 
     async fn check_user_ban_status(&self, user_id: Uuid) -> Result<Option<String>, AppError> {
@@ -97,7 +146,7 @@ impl AuthBackend {
         let ban = self.ban_store.get_active_ban_by_user_id(&user_id).await?;
 
         match ban {
-            Some(ban) if ban.is_active() => Ok(Some(ban.reason)),
+            Some(ban) if ban.blocks_login() => Ok(Some(ban.reason)),
             _ => Ok(None),
         }
     }
@@ -124,20 +173,39 @@ impl AuthnBackend for AuthBackend {
             .await
             .map_err(|_| AppError::InternalServerError)?;
 
-        let Some((user_id, _email, _username, password_hash, _email_verified_at)) = user_data
-        else {
-            return Ok(None);
+        let account = user_data.and_then(
+            |(user_id, _email, _username, password_hash, _email_verified_at)| {
+                password_hash.map(|password_hash| (user_id, password_hash))
+            },
+        );
+        let login_allowed = match &account {
+            Some((user_id, _)) => {
+                self.login_protection_use_case
+                    .is_login_allowed(*user_id)
+                    .await?
+            }
+            None => false,
         };
 
-        let Some(ref stored_hash) = password_hash else {
-            return Ok(None);
+        // Unknown emails, accounts without a password and blocked accounts still pay for a
+        // hash check, so neither the answer nor its timing sets them apart from a wrong password.
+        let stored_hash = match &account {
+            Some((_, password_hash)) if login_allowed => password_hash.as_str(),
+            _ => &*self.dummy_password_hash,
         };
-
         let password_valid = self
             .password_hasher
             .verify(&creds.password, stored_hash)
             .map_err(|_| AppError::InternalServerError)?;
 
+        let Some((user_id, _)) = account else {
+            return Ok(None);
+        };
+        if !login_allowed {
+            return Ok(None);
+        }
+
+        // Failures are only cleared once the second factor passed as well, see `login_handler`.
         if password_valid {
             if let Some(ban_reason) = self.check_user_ban_status(user_id).await? {
                 tracing::warn!(
@@ -160,6 +228,9 @@ impl AuthnBackend for AuthBackend {
                 Ok(None)
             }
         } else {
+            self.login_protection_use_case
+                .record_failed_login(user_id)
+                .await?;
             Ok(None)
         }
     }
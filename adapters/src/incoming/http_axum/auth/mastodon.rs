@@ -0,0 +1,334 @@
+use std::time::Duration;
+
+use axum::{
+    extract::{Query, State},
+    response::{IntoResponse, Redirect, Response},
+};
+use axum_login::AuthSession;
+use oauth2::{
+    AuthUrl, AuthorizationCode, ClientId, ClientSecret, CsrfToken, EndpointNotSet, EndpointSet,
+    PkceCodeChallenge, PkceCodeVerifier, RedirectUrl, Scope, TokenResponse, TokenUrl,
+    basic::BasicClient,
+};
+use reqwest::redirect::Policy;
+use serde::{Deserialize, Serialize};
+use tower_sessions::Session;
+use tracing::{error, info};
+#[cfg(feature = "docs")]
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::incoming::http_axum::auth::backend::AuthBackend;
+use crate::incoming::http_axum::auth::oidc::{
+    AuthRequest, OidcClaims, complete_social_auth, redirect_to_error,
+};
+use crate::incoming::http_axum::error_mapper::HttpError;
+use crate::shared::app_state::AppState;
+use domain::fediverse::FediverseApp;
+use fedi_wplace_application::error::AppError;
+
+const PROVIDER_NAME: &str = "mastodon";
+const FEDIVERSE_OAUTH_STATE_KEY: &str = "fediverse_oauth_state";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+type ConfiguredClient =
+    BasicClient<EndpointSet, EndpointNotSet, EndpointNotSet, EndpointNotSet, EndpointSet>;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct FediverseOAuthState {
+    instance: String,
+    csrf_state: CsrfToken,
+    pkce_verifier: PkceCodeVerifier,
+    #[serde(default)]
+    link_user_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+pub struct MastodonStartRequest {
+    // Instance domain such as `mastodon.social`, or a full `user@instance` handle.
+    instance: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct VerifiedAccount {
+    id: String,
+    username: String,
+}
+
+// Instances are user supplied, so requests to them never follow redirects.
+pub fn http_client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .redirect(Policy::none())
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .unwrap_or_default()
+}
+
+fn oauth_client(app: &FediverseApp) -> Result<ConfiguredClient, String> {
+    Ok(BasicClient::new(ClientId::new(app.client_id.clone()))
+        .set_client_secret(ClientSecret::new(app.client_secret.clone()))
+        .set_auth_uri(
+            AuthUrl::new(format!("https://{}/oauth/authorize", app.domain))
+                .map_err(|e| format!("Invalid authorization endpoint: {}", e))?,
+        )
+        .set_token_uri(
+            TokenUrl::new(format!("https://{}/oauth/token", app.domain))
+                .map_err(|e| format!("Invalid token endpoint: {}", e))?,
+        )
+        .set_redirect_uri(
+            RedirectUrl::new(app.redirect_url.clone())
+                .map_err(|e| format!("Invalid redirect URL: {}", e))?,
+        ))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/auth/mastodon/start",
+    tag = "auth",
+    summary = "Start fediverse authentication",
+    description = "Registers an OAuth app with the given Mastodon-compatible instance if needed and redirects to its authorization page. Instances can be restricted with allow and deny lists.",
+    params(
+        ("instance" = String, Query, description = "Instance domain, for example mastodon.social")
+    ),
+    responses(
+        (status = 302, description = "Redirect to the instance's authorization page"),
+        (status = 422, description = "Invalid, disallowed instance or fediverse login disabled"),
+        (status = 500, description = "The instance could not be reached")
+    )
+))]
+pub async fn mastodon_auth_start(
+    State(state): State<AppState>,
+    session: Session,
+    Query(params): Query<MastodonStartRequest>,
+) -> Result<Response, HttpError> {
+    start_mastodon_flow(&state, &session, &params.instance, None).await
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/auth/mastodon/link",
+    tag = "auth",
+    summary = "Link a fediverse account",
+    description = "Starts the fediverse login flow for the logged-in user. When it completes, the fediverse account is linked to the current user instead of logging in.",
+    params(
+        ("instance" = String, Query, description = "Instance domain, for example mastodon.social")
+    ),
+    responses(
+        (status = 302, description = "Redirect to the instance's authorization page"),
+        (status = 401, description = "Not logged in"),
+        (status = 422, description = "Invalid, disallowed instance or fediverse login disabled"),
+        (status = 500, description = "The instance could not be reached")
+    )
+))]
+pub async fn mastodon_link_start(
+    State(state): State<AppState>,
+    session: Session,
+    auth_session: AuthSession<AuthBackend>,
+    Query(params): Query<MastodonStartRequest>,
+) -> Result<Response, HttpError> {
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    start_mastodon_flow(&state, &session, &params.instance, Some(user.id)).await
+}
+
+async fn start_mastodon_flow(
+    state: &AppState,
+    session: &Session,
+    instance: &str,
+    link_user_id: Option<Uuid>,
+) -> Result<Response, HttpError> {
+    let app = state
+        .fediverse_login_use_case
+        .resolve_instance_app(instance)
+        .await?;
+    let client = oauth_client(&app).map_err(|e| {
+        error!("Failed to configure OAuth client for {}: {}", app.domain, e);
+        HttpError(AppError::ExternalServiceError { message: e })
+    })?;
+
+    let (pkce_challenge, pkce_verifier) = PkceCodeChallenge::new_random_sha256();
+    let csrf_token = CsrfToken::new_random();
+
+    let mut authorize_request = client
+        .authorize_url(|| csrf_token.clone())
+        .set_pkce_challenge(pkce_challenge);
+    for scope in state.config.auth.mastodon.scopes.split_whitespace() {
+        authorize_request = authorize_request.add_scope(Scope::new(scope.to_string()));
+    }
+    let (auth_url, _) = authorize_request.url();
+
+    info!("Redirecting to {} OAuth: {}", app.domain, auth_url);
+
+    let oauth_state = FediverseOAuthState {
+        instance: app.domain.to_string(),
+        csrf_state: csrf_token,
+        pkce_verifier,
+        link_user_id,
+    };
+
+    session
+        .insert(FEDIVERSE_OAUTH_STATE_KEY, &oauth_state)
+        .await
+        .map_err(|e| {
+            error!("Failed to store fediverse OAuth state in session: {}", e);
+            HttpError(AppError::InternalServerError)
+        })?;
+
+    Ok(Redirect::to(auth_url.as_ref()).into_response())
+}
+
+async fn take_oauth_state(
+    session: &Session,
+    state_param: &str,
+    state: &AppState,
+) -> Result<FediverseOAuthState, Response> {
+    let auth_config = &state.config.auth;
+
+    let oauth_state: FediverseOAuthState = match session.get(FEDIVERSE_OAUTH_STATE_KEY).await {
+        Ok(Some(oauth_state)) => oauth_state,
+        Ok(None) => {
+            error!("Fediverse OAuth state not found in session");
+            return Err(redirect_to_error(auth_config, "invalid_state"));
+        }
+        Err(e) => {
+            error!(
+                "Failed to retrieve fediverse OAuth state from session: {}",
+                e
+            );
+            return Err(redirect_to_error(auth_config, "session_error"));
+        }
+    };
+
+    if oauth_state.csrf_state.secret() != state_param {
+        error!("CSRF state validation failed");
+        return Err(redirect_to_error(auth_config, "invalid_state"));
+    }
+
+    if let Err(e) = session
+        .remove::<FediverseOAuthState>(FEDIVERSE_OAUTH_STATE_KEY)
+        .await
+    {
+        error!("Failed to remove fediverse OAuth state from session: {}", e);
+        return Err(redirect_to_error(auth_config, "session_error"));
+    }
+
+    Ok(oauth_state)
+}
+
+async fn verify_credentials(
+    http_client: &reqwest::Client,
+    app: &FediverseApp,
+    access_token: &oauth2::AccessToken,
+) -> Result<VerifiedAccount, String> {
+    let response = http_client
+        .get(format!(
+            "https://{}/api/v1/accounts/verify_credentials",
+            app.domain
+        ))
+        .bearer_auth(access_token.secret())
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch account: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(format!("verify_credentials returned {}", response.status()));
+    }
+
+    response
+        .json::<VerifiedAccount>()
+        .await
+        .map_err(|e| format!("Failed to parse account: {}", e))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/auth/mastodon/callback",
+    tag = "auth",
+    summary = "Handle fediverse callback",
+    description = "Processes the OAuth callback from a fediverse instance, exchanges the authorization code, reads the account from verify_credentials and logs in, creates or links the account identified by its account id on the instance. Accounts with two-factor authentication are redirected to the frontend error URL with `two_factor_required` and finish through /auth/2fa/login. On success, redirects to frontend success URL. On error, redirects to frontend error URL with error parameter.",
+    params(
+        ("code" = String, Query, description = "Authorization code from the instance"),
+        ("state" = String, Query, description = "CSRF state token for validation")
+    ),
+    responses(
+        (status = 302, description = "Redirect to frontend success URL on successful authentication, or error URL on failure")
+    )
+))]
+#[allow(clippy::cognitive_complexity)]
+pub async fn mastodon_auth_callback(
+    State(state): State<AppState>,
+    session: Session,
+    mut auth_session: AuthSession<AuthBackend>,
+    Query(params): Query<AuthRequest>,
+) -> Response {
+    let auth_config = &state.config.auth;
+
+    let oauth_state = match take_oauth_state(&session, &params.state, &state).await {
+        Ok(oauth_state) => oauth_state,
+        Err(response) => return response,
+    };
+
+    let app = match state
+        .fediverse_login_use_case
+        .resolve_instance_app(&oauth_state.instance)
+        .await
+    {
+        Ok(app) => app,
+        Err(e) => {
+            error!("Failed to resolve app for {}: {}", oauth_state.instance, e);
+            return redirect_to_error(auth_config, "instance_error");
+        }
+    };
+    let client = match oauth_client(&app) {
+        Ok(client) => client,
+        Err(e) => {
+            error!("Failed to configure OAuth client for {}: {}", app.domain, e);
+            return redirect_to_error(auth_config, "config_error");
+        }
+    };
+
+    let http_client = &state.fediverse_http_client;
+    let token_result = match client
+        .exchange_code(AuthorizationCode::new(params.code))
+        .set_pkce_verifier(oauth_state.pkce_verifier)
+        .request_async(http_client)
+        .await
+    {
+        Ok(result) => result,
+        Err(e) => {
+            error!("Failed to exchange authorization code: {}", e);
+            return redirect_to_error(auth_config, "token_exchange_failed");
+        }
+    };
+
+    let account = match verify_credentials(http_client, &app, token_result.access_token()).await {
+        Ok(account) => account,
+        Err(e) => {
+            error!("Failed to verify {} credentials: {}", app.domain, e);
+            return redirect_to_error(auth_config, "userinfo_fetch_failed");
+        }
+    };
+
+    // Usernames can be changed and reused, so the identity is keyed on the account id,
+    // which the instance makes unique.
+    let claims = OidcClaims {
+        subject: format!("{}@{}", account.id, app.domain),
+        email: None,
+        email_verified: false,
+        username: Some(account.username),
+    };
+
+    info!("{} user claims: {:?}", PROVIDER_NAME, claims);
+
+    complete_social_auth(
+        &state,
+        &mut auth_session,
+        PROVIDER_NAME,
+        oauth_state.link_user_id,
+        claims,
+    )
+    .await
+}
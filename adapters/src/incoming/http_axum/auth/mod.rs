@@ -1,3 +1,4 @@
 pub mod backend;
-pub mod oauth_google;
+pub mod mastodon;
+pub mod oidc;
 pub mod session;
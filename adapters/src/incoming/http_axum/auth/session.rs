@@ -1,8 +1,100 @@
-use tower_sessions::{SessionManagerLayer, cookie::SameSite};
+use serde::{Deserialize, Serialize};
+use time::{Duration, OffsetDateTime};
+use tower_sessions::{Session, SessionManagerLayer, cookie::SameSite};
 use tower_sessions_redis_store::{RedisStore, fred::prelude::*};
+use tracing::error;
+use uuid::Uuid;
 
 use fedi_wplace_application::error::AppError;
 
+const SESSION_RECORD_KEY: &str = "session_record";
+const PENDING_TWO_FACTOR_KEY: &str = "pending_two_factor";
+const PENDING_TWO_FACTOR_TTL: Duration = Duration::minutes(5);
+
+// Links a login session to its row in the per-user session index.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SessionRecord {
+    pub id: Uuid,
+    pub user_id: Uuid,
+}
+
+pub async fn load_session_record(session: &Session) -> Result<Option<SessionRecord>, AppError> {
+    session.get(SESSION_RECORD_KEY).await.map_err(|e| {
+        error!("Failed to read session record: {}", e);
+        AppError::InternalServerError
+    })
+}
+
+pub async fn store_session_record(
+    session: &Session,
+    record: SessionRecord,
+) -> Result<(), AppError> {
+    session
+        .insert(SESSION_RECORD_KEY, record)
+        .await
+        .map_err(|e| {
+            error!("Failed to store session record: {}", e);
+            AppError::InternalServerError
+        })
+}
+
+// A social login that passed the provider but still owes a two-factor code.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct PendingTwoFactor {
+    user_id: Uuid,
+    expires_at: i64,
+}
+
+pub async fn store_pending_two_factor(session: &Session, user_id: Uuid) -> Result<(), AppError> {
+    let pending = PendingTwoFactor {
+        user_id,
+        expires_at: (OffsetDateTime::now_utc() + PENDING_TWO_FACTOR_TTL).unix_timestamp(),
+    };
+
+    session
+        .insert(PENDING_TWO_FACTOR_KEY, pending)
+        .await
+        .map_err(|e| {
+            error!("Failed to store pending two-factor login: {}", e);
+            AppError::InternalServerError
+        })
+}
+
+// The user waiting for their second factor, unless the pending login has expired.
+pub async fn load_pending_two_factor(session: &Session) -> Result<Option<Uuid>, AppError> {
+    let pending: Option<PendingTwoFactor> =
+        session.get(PENDING_TWO_FACTOR_KEY).await.map_err(|e| {
+            error!("Failed to read pending two-factor login: {}", e);
+            AppError::InternalServerError
+        })?;
+
+    Ok(pending
+        .filter(|pending| pending.expires_at > OffsetDateTime::now_utc().unix_timestamp())
+        .map(|pending| pending.user_id))
+}
+
+pub async fn clear_pending_two_factor(session: &Session) -> Result<(), AppError> {
+    session
+        .remove::<PendingTwoFactor>(PENDING_TWO_FACTOR_KEY)
+        .await
+        .map(|_| ())
+        .map_err(|e| {
+            error!("Failed to clear pending two-factor login: {}", e);
+            AppError::InternalServerError
+        })
+}
+
+// The current user's session id in the index, if this session has been registered.
+pub async fn current_session_id(
+    session: &Session,
+    user_id: Uuid,
+) -> Result<Option<Uuid>, AppError> {
+    Ok(load_session_record(session)
+        .await?
+        .filter(|record| record.user_id == user_id)
+        .map(|record| record.id))
+}
+
 #[derive(Debug, Clone)]
 pub struct SessionConfig {
     pub cookie_name: String,
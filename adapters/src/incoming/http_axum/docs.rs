@@ -3,10 +3,10 @@ use crate::incoming::ws_axum::{
     endpoint,
     protocol::{ClientMessage, RejectedTile, WSMessage},
 };
-use auth::oauth_google::AuthRequest;
+use auth::{mastodon::MastodonStartRequest, oidc::AuthRequest};
 use domain::{
     color::RgbColor,
-    coords::{PixelCoord, TileCoord},
+    coords::{GlobalCoord, GlobalRegion, PixelCoord, TileCoord},
     tile::TileVersion,
 };
 use dto::common_responses::{
@@ -14,15 +14,26 @@ use dto::common_responses::{
     NotModifiedResponse, RateLimitExceededResponse, UnauthorizedResponse, ValidationErrorResponse,
 };
 use dto::requests::{
-    BanUserRequest, BatchPaintPixelsRequest, BatchPixelPaint, LoginRequest, PaintRequest,
-    RegisterRequest, UpdateUsernameRequest,
+    BanIpRequest, BanUserRequest, BatchPaintPixelsRequest, BatchPixelPaint, ChangeEmailRequest,
+    CreateApiTokenRequest, CreateReportRequest, CreateRoleRequest, DeleteAccountRequest,
+    DismissReportRequest, LoginRequest, PaintRequest, PaintRestrictionRequest,
+    ProtectedAreaRequest, ProtectedRegionRequest, RegisterRequest, ReportTargetRequest,
+    RequestPasswordResetRequest, ResetPasswordRequest, ResolveReportRequest, TwoFactorCodeRequest,
+    UpdateRoleRequest, UpdateUsernameRequest,
 };
-#[cfg(feature = "docs")]
-use dto::responses::{ApiResponseUser, ApiResponseValue};
 use dto::responses::{
-    BanResponse, PaintOkEnvelope, PaintPixelResponse, PixelHistoryEntry, PixelInfoResponse,
-    TileImageResponse, UserResponse,
+    AccountDeletionResponse, AccountExportResponse, ApiTokenResponse, AuditEntryResponse,
+    BanResponse, CreatedApiTokenResponse, ExportedPaintResponse, IpBanResponse,
+    LockedAccountResponse, LoginProviderResponse, PaintOkEnvelope, PaintPixelResponse,
+    PaintRuleResponse, PixelHistoryEntry, PixelInfoResponse, ProtectedRegionResponse,
+    QuarantineReviewResponse, QuarantinedPixelResponse, RecoveryCodesResponse, ReportResponse,
+    RevokedSessionsResponse, RoleMemberResponse, RoleResponse, TileImageResponse,
+    TotpEnrollmentResponse, TwoFactorStatusResponse, UserDetailsResponse,
+    UserDirectoryPageResponse, UserIdentityResponse, UserResponse, UserSessionResponse,
+    UserSummaryResponse,
 };
+#[cfg(feature = "docs")]
+use dto::responses::{ApiResponseUser, ApiResponseValue};
 use handlers::palette::{PaletteEntry, PaletteResponse, SpecialColorEntry};
 use utoipa::OpenApi;
 
@@ -40,13 +51,70 @@ use utoipa::OpenApi;
         handlers::auth::logout_handler,
         handlers::auth::me_handler,
         handlers::auth::update_username_handler,
+        handlers::auth::resend_verification_handler,
+        handlers::auth::change_email_handler,
+        handlers::auth::confirm_email_change_handler,
+        handlers::auth::list_identities_handler,
+        handlers::auth::unlink_identity_handler,
+        handlers::auth::request_password_reset_handler,
+        handlers::auth::reset_password_handler,
         handlers::admin::assign_role_to_user,
+        handlers::admin::revoke_role_from_user,
+        handlers::admin::list_roles,
+        handlers::admin::create_role,
+        handlers::admin::update_role,
+        handlers::admin::delete_role,
+        handlers::admin::list_role_members,
+        handlers::admin::list_users,
+        handlers::admin::get_user_details,
+        handlers::admin::list_locked_accounts,
+        handlers::admin::unlock_account,
+        handlers::audit::list_audit_log,
+        handlers::audit::export_audit_log,
         handlers::ban::ban_user,
         handlers::ban::unban_user,
         handlers::ban::list_active_bans,
         handlers::ban::get_user_ban_status,
-        auth::oauth_google::google_auth_start,
-        auth::oauth_google::google_auth_callback,
+        handlers::ban::get_user_ban_history,
+        handlers::ban::ban_ip,
+        handlers::ban::unban_ip,
+        handlers::ban::list_active_ip_bans,
+        handlers::ban::list_quarantined_pixels,
+        handlers::ban::discard_quarantined_pixels,
+        handlers::ban::promote_quarantined_pixels,
+        handlers::reports::create_report,
+        handlers::reports::list_reports,
+        handlers::reports::claim_report,
+        handlers::reports::resolve_report,
+        handlers::reports::dismiss_report,
+        handlers::protected_regions::list_protected_regions,
+        handlers::protected_regions::create_protected_region,
+        handlers::protected_regions::update_protected_region,
+        handlers::protected_regions::delete_protected_region,
+        handlers::rules::list_paint_rules,
+        auth::oidc::list_login_providers,
+        auth::oidc::oidc_auth_start,
+        auth::oidc::oidc_auth_callback,
+        auth::oidc::oidc_link_start,
+        auth::mastodon::mastodon_auth_start,
+        auth::mastodon::mastodon_link_start,
+        auth::mastodon::mastodon_auth_callback,
+        handlers::two_factor::two_factor_status_handler,
+        handlers::two_factor::complete_two_factor_login_handler,
+        handlers::two_factor::begin_totp_enrollment_handler,
+        handlers::two_factor::confirm_totp_enrollment_handler,
+        handlers::two_factor::disable_totp_handler,
+        handlers::two_factor::regenerate_recovery_codes_handler,
+        handlers::sessions::list_sessions_handler,
+        handlers::sessions::revoke_session_handler,
+        handlers::sessions::revoke_other_sessions_handler,
+        handlers::api_tokens::list_api_tokens_handler,
+        handlers::api_tokens::create_api_token_handler,
+        handlers::api_tokens::revoke_api_token_handler,
+        handlers::account::export_account_handler,
+        handlers::account::account_deletion_status_handler,
+        handlers::account::schedule_account_deletion_handler,
+        handlers::account::cancel_account_deletion_handler,
         endpoint::websocket_handler,
     ),
     components(
@@ -63,11 +131,55 @@ use utoipa::OpenApi;
             SpecialColorEntry,
             RegisterRequest,
             LoginRequest,
+            RequestPasswordResetRequest,
+            ResetPasswordRequest,
+            ChangeEmailRequest,
             UpdateUsernameRequest,
             BanUserRequest,
+            BanIpRequest,
+            CreateReportRequest,
+            ReportTargetRequest,
+            ResolveReportRequest,
+            DismissReportRequest,
+            ProtectedRegionRequest,
+            ProtectedAreaRequest,
+            PaintRestrictionRequest,
+            CreateRoleRequest,
+            UpdateRoleRequest,
             AuthRequest,
+            MastodonStartRequest,
+            TwoFactorCodeRequest,
+            TwoFactorStatusResponse,
+            TotpEnrollmentResponse,
+            RecoveryCodesResponse,
+            UserSessionResponse,
+            RevokedSessionsResponse,
+            CreateApiTokenRequest,
+            ApiTokenResponse,
+            CreatedApiTokenResponse,
+            DeleteAccountRequest,
+            AccountDeletionResponse,
+            AccountExportResponse,
+            ExportedPaintResponse,
+            LoginProviderResponse,
             UserResponse,
             BanResponse,
+            IpBanResponse,
+            QuarantinedPixelResponse,
+            QuarantineReviewResponse,
+            ReportResponse,
+            RoleResponse,
+            RoleMemberResponse,
+            UserSummaryResponse,
+            UserDirectoryPageResponse,
+            LockedAccountResponse,
+            UserIdentityResponse,
+            UserDetailsResponse,
+            AuditEntryResponse,
+            ProtectedRegionResponse,
+            PaintRuleResponse,
+            GlobalCoord,
+            GlobalRegion,
             PixelHistoryEntry,
             PixelInfoResponse,
             RgbColor,
@@ -96,6 +208,9 @@ use utoipa::OpenApi;
         (name = "palette", description = "Color palette management - retrieve available colors for pixel painting"),
         (name = "pixel", description = "Pixel information operations - retrieve metadata about individual pixels"),
         (name = "auth", description = "Authentication and user management - register, login, logout, and user profile operations"),
+        (name = "reports", description = "User reports - flag pixels, regions or users for moderator review"),
+        (name = "regions", description = "Protected regions - areas of the canvas that are locked, reserved for certain roles or only open during a time window"),
+        (name = "rules", description = "Paint rules - instance-wide conditions every paint must meet, such as account age, batch size, allowed colors and pixel cooldowns"),
         (name = "admin", description = "Admin operations - role management and user administration (requires admin privileges)"),
         (name = "system", description = "System health and status monitoring"),
         (name = "websocket", description = "Real-time WebSocket protocol for collaborative pixel painting. Supports tile subscriptions, live updates, configurable IP-based limits, FIFO eviction policy, and rate limiting for both connection upgrades and individual messages.")
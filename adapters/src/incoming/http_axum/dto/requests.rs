@@ -1,11 +1,12 @@
 use domain::{
     color::ColorId,
-    coords::{PixelCoord, TileCoord},
+    coords::{GlobalCoord, PixelCoord, TileCoord},
 };
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 #[cfg(feature = "docs")]
 use utoipa::ToSchema;
+use uuid::Uuid;
 use validator::{Validate, ValidationError};
 
 #[cfg_attr(feature = "docs", derive(ToSchema))]
@@ -164,6 +165,80 @@ pub struct LoginRequest {
     #[cfg_attr(feature = "docs", schema(example = "secure_password"))]
     #[validate(length(min = 1, message = "Password cannot be empty"))]
     pub password: String,
+
+    // Authenticator or recovery code, required once the account has two-factor enabled.
+    #[cfg_attr(feature = "docs", schema(example = "123456"))]
+    #[serde(default)]
+    pub two_factor_code: Option<String>,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Request a password reset link for the account registered with this email",
+    example = json!({
+        "email": "user@example.com"
+    })
+))]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct RequestPasswordResetRequest {
+    #[cfg_attr(feature = "docs", schema(example = "user@example.com"))]
+    #[validate(email(message = "Invalid email format"))]
+    pub email: String,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Request to set a new password using the token from a password reset email. The new password must pass the same strength check as registration.",
+    example = json!({
+        "token": "3f2b8c1e9d4a4f7b8e6c5d2a1b0f9e8d",
+        "new_password": "MyVerySecure!Password123"
+    })
+))]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ResetPasswordRequest {
+    #[cfg_attr(feature = "docs", schema(example = "3f2b8c1e9d4a4f7b8e6c5d2a1b0f9e8d"))]
+    #[validate(length(min = 1, message = "Token cannot be empty"))]
+    pub token: String,
+
+    #[cfg_attr(feature = "docs", schema(example = "MyVerySecure!Password123"))]
+    #[validate(length(min = 1, message = "Password cannot be empty"))]
+    pub new_password: String,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Request to change the account email address. The current password is required for accounts that have one; social-only accounts may omit it.",
+    example = json!({
+        "new_email": "new@example.com",
+        "current_password": "MyVerySecure!Password123"
+    })
+))]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct ChangeEmailRequest {
+    #[cfg_attr(feature = "docs", schema(example = "new@example.com"))]
+    #[validate(email(message = "Invalid email format"))]
+    pub new_email: String,
+
+    #[cfg_attr(feature = "docs", schema(example = "MyVerySecure!Password123"))]
+    pub current_password: Option<String>,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "A code from the authenticator app, or an unused recovery code where allowed",
+    example = json!({
+        "code": "123456"
+    })
+))]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct TwoFactorCodeRequest {
+    #[cfg_attr(feature = "docs", schema(example = "123456"))]
+    #[validate(length(
+        min = 1,
+        max = 32,
+        message = "Code must be between 1 and 32 characters"
+    ))]
+    pub code: String,
 }
 
 #[cfg_attr(feature = "docs", derive(ToSchema))]
@@ -186,10 +261,11 @@ pub struct UpdateUsernameRequest {
 
 #[cfg_attr(feature = "docs", derive(ToSchema))]
 #[cfg_attr(feature = "docs", schema(
-    description = "Request to ban a user with reason and optional expiration date",
+    description = "Request to ban a user with reason and optional expiration date. A shadow ban lets the user keep painting, but their pixels are quarantined for review instead of reaching the canvas.",
     example = json!({
         "reason": "Rule violation",
-        "expires_at": "2024-12-31T23:59:59Z"
+        "expires_at": "2024-12-31T23:59:59Z",
+        "shadow": false
     })
 ))]
 #[derive(Debug, Clone, Serialize, Deserialize, Validate)]
@@ -199,4 +275,254 @@ pub struct BanUserRequest {
 
     #[cfg_attr(feature = "docs", schema(example = "2024-12-31T23:59:59Z"))]
     pub expires_at: Option<String>,
+
+    #[serde(default)]
+    pub shadow: bool,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Request to ban an IP address or CIDR network range with reason and optional expiration date",
+    example = json!({
+        "network": "203.0.113.0/24",
+        "reason": "Ban evasion",
+        "expires_at": "2024-12-31T23:59:59Z"
+    })
+))]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
+pub struct BanIpRequest {
+    #[cfg_attr(feature = "docs", schema(example = "203.0.113.0/24"))]
+    pub network: String,
+
+    #[cfg_attr(feature = "docs", schema(example = "Ban evasion"))]
+    pub reason: String,
+
+    #[cfg_attr(feature = "docs", schema(example = "2024-12-31T23:59:59Z"))]
+    pub expires_at: Option<String>,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(
+    feature = "docs",
+    schema(
+        description = "What is being reported: a single pixel, a rectangular region given by its top-left corner and size, or a user"
+    )
+)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReportTargetRequest {
+    Pixel {
+        x: i32,
+        y: i32,
+    },
+    Region {
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+    },
+    User {
+        user_id: Uuid,
+    },
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Request to report a pixel, region or user to the moderators. Category is one of griefing, offensive, spam, botting or other.",
+    example = json!({
+        "target": {"type": "region", "x": 1024, "y": 768, "width": 64, "height": 64},
+        "category": "griefing",
+        "comment": "Someone painted over our flag"
+    })
+))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateReportRequest {
+    pub target: ReportTargetRequest,
+
+    #[cfg_attr(feature = "docs", schema(example = "griefing"))]
+    pub category: String,
+
+    #[cfg_attr(feature = "docs", schema(example = "Someone painted over our flag"))]
+    #[serde(default)]
+    pub comment: String,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Request to resolve a report. Action is one of none, ban_user or rollback_region. ban_user bans the reported user (or the painter of a reported pixel); rollback_region clears the reported pixels, optionally only those painted since rollback_since or by the reported user.",
+    example = json!({
+        "action": "rollback_region",
+        "note": "Griefing confirmed",
+        "rollback_since": "2024-12-31T12:00:00Z",
+        "rollback_only_target_user": false
+    })
+))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolveReportRequest {
+    #[cfg_attr(feature = "docs", schema(example = "rollback_region"))]
+    pub action: String,
+
+    #[cfg_attr(feature = "docs", schema(example = "Griefing confirmed"))]
+    pub note: Option<String>,
+
+    #[cfg_attr(feature = "docs", schema(example = "Griefing"))]
+    pub ban_reason: Option<String>,
+
+    #[cfg_attr(feature = "docs", schema(example = "2024-12-31T23:59:59Z"))]
+    pub ban_expires_at: Option<String>,
+
+    #[cfg_attr(feature = "docs", schema(example = "2024-12-31T12:00:00Z"))]
+    pub rollback_since: Option<String>,
+
+    #[serde(default)]
+    pub rollback_only_target_user: bool,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Request to dismiss a report without taking action",
+    example = json!({"note": "Not a rule violation"})
+))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DismissReportRequest {
+    #[cfg_attr(feature = "docs", schema(example = "Not a rule violation"))]
+    pub note: Option<String>,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Request to create a role. Permissions are any of ban:create, pixels:rollback, roles:assign, reports:resolve, audit:read, users:read, regions:manage and users:manage.",
+    example = json!({
+        "name": "helper",
+        "description": "Can review reports",
+        "permissions": ["reports:resolve"]
+    })
+))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateRoleRequest {
+    #[cfg_attr(feature = "docs", schema(example = "helper"))]
+    pub name: String,
+
+    #[cfg_attr(feature = "docs", schema(example = "Can review reports"))]
+    pub description: Option<String>,
+
+    #[cfg_attr(feature = "docs", schema(example = json!(["reports:resolve"])))]
+    #[serde(default)]
+    pub permissions: Vec<String>,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Request to update a role. Omitted fields are left unchanged; permissions replace the current set.",
+    example = json!({
+        "description": "Can review reports and roll back griefing",
+        "permissions": ["reports:resolve", "pixels:rollback"]
+    })
+))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateRoleRequest {
+    #[cfg_attr(
+        feature = "docs",
+        schema(example = "Can review reports and roll back griefing")
+    )]
+    pub description: Option<String>,
+
+    #[cfg_attr(feature = "docs", schema(example = json!(["reports:resolve", "pixels:rollback"])))]
+    pub permissions: Option<Vec<String>>,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(
+    feature = "docs",
+    schema(
+        description = "Area covered by a protected region: a rectangle given by its top-left corner and size, or a polygon whose vertices lie on pixel corners"
+    )
+)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ProtectedAreaRequest {
+    Rectangle {
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+    },
+    Polygon {
+        vertices: Vec<GlobalCoord>,
+    },
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(
+    feature = "docs",
+    schema(
+        description = "Who may paint inside a protected region: nobody (locked), only holders of the listed roles (roles_only), or anyone between opens_at and closes_at (time_window)"
+    )
+)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PaintRestrictionRequest {
+    Locked,
+    RolesOnly { roles: Vec<String> },
+    TimeWindow { opens_at: String, closes_at: String },
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Request to create or replace a protected region of the canvas",
+    example = json!({
+        "name": "Instance logo",
+        "description": "Official artwork, please do not paint over it",
+        "area": {"type": "rectangle", "x": 0, "y": 0, "width": 128, "height": 64},
+        "restriction": {"type": "roles_only", "roles": ["moderator"]}
+    })
+))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProtectedRegionRequest {
+    #[cfg_attr(feature = "docs", schema(example = "Instance logo"))]
+    pub name: String,
+
+    #[cfg_attr(
+        feature = "docs",
+        schema(example = "Official artwork, please do not paint over it")
+    )]
+    pub description: Option<String>,
+
+    pub area: ProtectedAreaRequest,
+
+    pub restriction: PaintRestrictionRequest,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Request to create a personal API token. Scopes are read, paint and admin; every scope includes read access. expires_in_days may be omitted for a token without expiry unless the instance caps token lifetimes.",
+    example = json!({
+        "name": "timelapse bot",
+        "scopes": ["paint"],
+        "expires_in_days": 90
+    })
+))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateApiTokenRequest {
+    #[cfg_attr(feature = "docs", schema(example = "timelapse bot"))]
+    pub name: String,
+
+    #[cfg_attr(feature = "docs", schema(example = json!(["paint"])))]
+    pub scopes: Vec<String>,
+
+    #[cfg_attr(feature = "docs", schema(example = 90))]
+    pub expires_in_days: Option<u32>,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Request to schedule deletion of the current account. The current password is required for accounts that have one; social-only accounts may omit it.",
+    example = json!({
+        "current_password": "MyVerySecure!Password123"
+    })
+))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteAccountRequest {
+    #[cfg_attr(feature = "docs", schema(example = "MyVerySecure!Password123"))]
+    pub current_password: Option<String>,
 }
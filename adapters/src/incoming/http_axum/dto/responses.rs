@@ -5,6 +5,7 @@ use axum::{
     },
     response::{IntoResponse, Response},
 };
+use domain::coords::{GlobalCoord, GlobalRegion};
 use serde::Serialize;
 #[cfg(feature = "docs")]
 use utoipa::{ToResponse, ToSchema};
@@ -153,8 +154,12 @@ pub struct ApiResponseUser {
     description = "Role information",
     example = json!({
         "id": "550e8400-e29b-41d4-a716-446655440000",
-        "name": "admin",
-        "description": "Full access to all features and endpoints"
+        "name": "moderator",
+        "description": "Can ban users and resolve reports",
+        "permissions": ["ban:create", "pixels:rollback", "reports:resolve"],
+        "built_in": true,
+        "created_at": "2023-01-01T12:00:00Z",
+        "updated_at": "2023-01-01T12:00:00Z"
     })
 ))]
 #[derive(Debug, Clone, Serialize)]
@@ -164,13 +169,203 @@ pub struct RoleResponse {
         schema(example = "550e8400-e29b-41d4-a716-446655440000")
     )]
     pub id: Uuid,
-    #[cfg_attr(feature = "docs", schema(example = "admin"))]
+    #[cfg_attr(feature = "docs", schema(example = "moderator"))]
     pub name: String,
     #[cfg_attr(
         feature = "docs",
-        schema(example = "Full access to all features and endpoints")
+        schema(example = "Can ban users and resolve reports")
     )]
     pub description: Option<String>,
+    #[cfg_attr(feature = "docs", schema(example = json!(["ban:create", "reports:resolve"])))]
+    pub permissions: Vec<String>,
+    #[cfg_attr(feature = "docs", schema(example = true))]
+    pub built_in: bool,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub created_at: String,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub updated_at: String,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "A user holding a role, with who assigned it and when",
+    example = json!({
+        "user_id": "550e8400-e29b-41d4-a716-446655440001",
+        "username": "johndoe",
+        "assigned_at": "2023-01-01T12:00:00Z",
+        "assigned_by_user_id": "550e8400-e29b-41d4-a716-446655440002"
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct RoleMemberResponse {
+    #[cfg_attr(
+        feature = "docs",
+        schema(example = "550e8400-e29b-41d4-a716-446655440001")
+    )]
+    pub user_id: Uuid,
+    #[cfg_attr(feature = "docs", schema(example = "johndoe"))]
+    pub username: String,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub assigned_at: String,
+    #[cfg_attr(
+        feature = "docs",
+        schema(example = "550e8400-e29b-41d4-a716-446655440002")
+    )]
+    pub assigned_by_user_id: Option<Uuid>,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "A user as listed in the admin user directory",
+    example = json!({
+        "id": "550e8400-e29b-41d4-a716-446655440001",
+        "email": "john@example.com",
+        "username": "johndoe",
+        "email_verified": true,
+        "created_at": "2023-01-01T12:00:00Z",
+        "roles": ["moderator"],
+        "banned": false
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct UserSummaryResponse {
+    #[cfg_attr(
+        feature = "docs",
+        schema(example = "550e8400-e29b-41d4-a716-446655440001")
+    )]
+    pub id: Uuid,
+    #[cfg_attr(feature = "docs", schema(example = "john@example.com"))]
+    pub email: String,
+    #[cfg_attr(feature = "docs", schema(example = "johndoe"))]
+    pub username: String,
+    #[cfg_attr(feature = "docs", schema(example = true))]
+    pub email_verified: bool,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub created_at: String,
+    #[cfg_attr(feature = "docs", schema(example = json!(["moderator"])))]
+    pub roles: Vec<String>,
+    #[cfg_attr(feature = "docs", schema(example = false))]
+    pub banned: bool,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "A page of the admin user directory. Pass next_cursor as cursor to fetch the following page; it is null on the last page.",
+    example = json!({
+        "users": [],
+        "next_cursor": "550e8400-e29b-41d4-a716-446655440001"
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct UserDirectoryPageResponse {
+    pub users: Vec<UserSummaryResponse>,
+    #[cfg_attr(
+        feature = "docs",
+        schema(example = "550e8400-e29b-41d4-a716-446655440001")
+    )]
+    pub next_cursor: Option<Uuid>,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "An account whose password login is locked after too many failed attempts",
+    example = json!({
+        "user_id": "550e8400-e29b-41d4-a716-446655440001",
+        "username": "johndoe",
+        "failed_attempts": 20,
+        "last_failed_at": "2023-01-01T12:00:00Z",
+        "locked_until": "2023-01-01T12:30:00Z"
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct LockedAccountResponse {
+    #[cfg_attr(
+        feature = "docs",
+        schema(example = "550e8400-e29b-41d4-a716-446655440001")
+    )]
+    pub user_id: Uuid,
+    #[cfg_attr(feature = "docs", schema(example = "johndoe"))]
+    pub username: String,
+    #[cfg_attr(feature = "docs", schema(example = 20))]
+    pub failed_attempts: u32,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub last_failed_at: String,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:30:00Z"))]
+    pub locked_until: String,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "An external login linked to a user account",
+    example = json!({
+        "id": "7c9e6679-7425-40de-944b-e07fc1f90ae7",
+        "provider": "google",
+        "provider_user_id": "108234567890123456789",
+        "created_at": "2023-01-01T12:00:00Z"
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct UserIdentityResponse {
+    #[cfg_attr(
+        feature = "docs",
+        schema(example = "7c9e6679-7425-40de-944b-e07fc1f90ae7")
+    )]
+    pub id: String,
+    #[cfg_attr(feature = "docs", schema(example = "google"))]
+    pub provider: String,
+    #[cfg_attr(feature = "docs", schema(example = "108234567890123456789"))]
+    pub provider_user_id: String,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub created_at: String,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Everything moderators need to know about a user: linked logins, roles, active ban, credit balance and paint counts. pixels_on_canvas counts pixels the user currently owns on the canvas.",
+    example = json!({
+        "id": "550e8400-e29b-41d4-a716-446655440001",
+        "email": "john@example.com",
+        "username": "johndoe",
+        "email_verified": true,
+        "created_at": "2023-01-01T12:00:00Z",
+        "identities": [],
+        "roles": [],
+        "ban": null,
+        "available_charges": 25,
+        "max_charges": 30,
+        "seconds_until_next_charge": 12,
+        "pixels_on_canvas": 1024,
+        "pixels_quarantined": 0
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct UserDetailsResponse {
+    #[cfg_attr(
+        feature = "docs",
+        schema(example = "550e8400-e29b-41d4-a716-446655440001")
+    )]
+    pub id: Uuid,
+    #[cfg_attr(feature = "docs", schema(example = "john@example.com"))]
+    pub email: String,
+    #[cfg_attr(feature = "docs", schema(example = "johndoe"))]
+    pub username: String,
+    #[cfg_attr(feature = "docs", schema(example = true))]
+    pub email_verified: bool,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub created_at: String,
+    pub identities: Vec<UserIdentityResponse>,
+    pub roles: Vec<RoleResponse>,
+    pub ban: Option<BanResponse>,
+    #[cfg_attr(feature = "docs", schema(example = 25))]
+    pub available_charges: i32,
+    #[cfg_attr(feature = "docs", schema(example = 30))]
+    pub max_charges: i32,
+    #[cfg_attr(feature = "docs", schema(example = 12))]
+    pub seconds_until_next_charge: i64,
+    #[cfg_attr(feature = "docs", schema(example = 1024))]
+    pub pixels_on_canvas: u64,
+    #[cfg_attr(feature = "docs", schema(example = 0))]
+    pub pixels_quarantined: u64,
 }
 
 #[cfg_attr(feature = "docs", derive(ToSchema))]
@@ -187,6 +382,7 @@ pub struct RoleResponse {
         "seconds_until_next_charge": 30,
         "max_charges": 30,
         "roles": ["admin"],
+        "permissions": ["ban:create", "pixels:rollback", "roles:assign", "reports:resolve", "audit:read", "users:read", "regions:manage", "users:manage"],
         "banned": false,
         "ban_reason": null
     })
@@ -216,6 +412,8 @@ pub struct UserResponse {
     pub max_charges: i32,
     #[cfg_attr(feature = "docs", schema(example = json!(["admin"])))]
     pub roles: Vec<String>,
+    #[cfg_attr(feature = "docs", schema(example = json!(["ban:create", "reports:resolve"])))]
+    pub permissions: Vec<String>,
     #[cfg_attr(feature = "docs", schema(example = false))]
     pub banned: bool,
     #[cfg_attr(feature = "docs", schema(example = "null"))]
@@ -231,7 +429,8 @@ pub struct UserResponse {
         "px": 128,
         "py": 64,
         "color_id": 5,
-        "timestamp": "2023-01-01T12:00:00Z"
+        "timestamp": "2023-01-01T12:00:00Z",
+        "automated": false
     })
 ))]
 #[derive(Debug, Clone, Serialize)]
@@ -240,9 +439,9 @@ pub struct PixelHistoryEntry {
         feature = "docs",
         schema(example = "550e8400-e29b-41d4-a716-446655440000")
     )]
-    pub user_id: Uuid,
+    pub user_id: Option<Uuid>,
     #[cfg_attr(feature = "docs", schema(example = "johndoe"))]
-    pub username: String,
+    pub username: Option<String>,
     #[cfg_attr(feature = "docs", schema(example = 128))]
     pub px: usize,
     #[cfg_attr(feature = "docs", schema(example = 64))]
@@ -251,16 +450,20 @@ pub struct PixelHistoryEntry {
     pub color_id: u8,
     #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
     pub timestamp: String,
+    #[cfg_attr(feature = "docs", schema(example = false))]
+    pub automated: bool,
 }
 
 #[cfg_attr(feature = "docs", derive(ToSchema))]
 #[cfg_attr(feature = "docs", schema(
-    description = "Information about a specific pixel",
+    description = "Information about a specific pixel. automated is true when it was painted through a personal API token. protected_for_seconds is how long other users still cannot paint over it, or null when it is not protected.",
     example = json!({
         "user_id": "550e8400-e29b-41d4-a716-446655440000",
         "username": "johndoe",
         "color_id": 5,
-        "timestamp": "2023-01-01T12:00:00Z"
+        "timestamp": "2023-01-01T12:00:00Z",
+        "automated": false,
+        "protected_for_seconds": 42
     })
 ))]
 #[derive(Debug, Clone, Serialize)]
@@ -269,13 +472,17 @@ pub struct PixelInfoResponse {
         feature = "docs",
         schema(example = "550e8400-e29b-41d4-a716-446655440000")
     )]
-    pub user_id: Uuid,
+    pub user_id: Option<Uuid>,
     #[cfg_attr(feature = "docs", schema(example = "johndoe"))]
-    pub username: String,
+    pub username: Option<String>,
     #[cfg_attr(feature = "docs", schema(example = 5))]
     pub color_id: u8,
     #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
     pub timestamp: String,
+    #[cfg_attr(feature = "docs", schema(example = false))]
+    pub automated: bool,
+    #[cfg_attr(feature = "docs", schema(example = 42))]
+    pub protected_for_seconds: Option<i64>,
 }
 
 #[cfg_attr(feature = "docs", derive(ToSchema))]
@@ -288,7 +495,11 @@ pub struct PixelInfoResponse {
         "reason": "Rule violation",
         "banned_at": "2023-01-01T12:00:00Z",
         "expires_at": "2024-12-31T23:59:59Z",
-        "created_at": "2023-01-01T12:00:00Z"
+        "created_at": "2023-01-01T12:00:00Z",
+        "revoked_at": null,
+        "revoked_by_user_id": null,
+        "revoke_reason": null,
+        "shadow": false
     })
 ))]
 #[derive(Debug, Clone, Serialize)]
@@ -316,4 +527,484 @@ pub struct BanResponse {
     pub expires_at: Option<String>,
     #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
     pub created_at: String,
+    #[cfg_attr(feature = "docs", schema(example = "2023-06-01T12:00:00Z"))]
+    pub revoked_at: Option<String>,
+    #[cfg_attr(
+        feature = "docs",
+        schema(example = "550e8400-e29b-41d4-a716-446655440002")
+    )]
+    pub revoked_by_user_id: Option<Uuid>,
+    #[cfg_attr(feature = "docs", schema(example = "Appeal accepted"))]
+    pub revoke_reason: Option<String>,
+    pub shadow: bool,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Information about a banned IP address or network range",
+    example = json!({
+        "id": "550e8400-e29b-41d4-a716-446655440000",
+        "network": "203.0.113.0/24",
+        "banned_by_user_id": "550e8400-e29b-41d4-a716-446655440002",
+        "reason": "Ban evasion",
+        "banned_at": "2023-01-01T12:00:00Z",
+        "expires_at": "2024-12-31T23:59:59Z",
+        "created_at": "2023-01-01T12:00:00Z"
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct IpBanResponse {
+    #[cfg_attr(
+        feature = "docs",
+        schema(example = "550e8400-e29b-41d4-a716-446655440000")
+    )]
+    pub id: Uuid,
+    #[cfg_attr(feature = "docs", schema(example = "203.0.113.0/24"))]
+    pub network: String,
+    #[cfg_attr(
+        feature = "docs",
+        schema(example = "550e8400-e29b-41d4-a716-446655440002")
+    )]
+    pub banned_by_user_id: Option<Uuid>,
+    #[cfg_attr(feature = "docs", schema(example = "Ban evasion"))]
+    pub reason: String,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub banned_at: String,
+    #[cfg_attr(feature = "docs", schema(example = "2024-12-31T23:59:59Z"))]
+    pub expires_at: Option<String>,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub created_at: String,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "A user report in the moderation queue. Pixel and region reports carry the reported region, user reports the reported user. target_user_id is the user held responsible: the reported user, or the last painter of a reported pixel.",
+    example = json!({
+        "id": "550e8400-e29b-41d4-a716-446655440000",
+        "reporter_user_id": "550e8400-e29b-41d4-a716-446655440001",
+        "target_type": "region",
+        "region": {"min_x": 1024, "min_y": 768, "max_x": 1087, "max_y": 831},
+        "reported_user_id": null,
+        "target_user_id": null,
+        "category": "griefing",
+        "comment": "Someone painted over our flag",
+        "status": "claimed",
+        "claimed_by_user_id": "550e8400-e29b-41d4-a716-446655440002",
+        "claimed_at": "2023-01-01T12:05:00Z",
+        "resolved_by_user_id": null,
+        "resolved_at": null,
+        "resolution_action": null,
+        "resolution_note": null,
+        "created_at": "2023-01-01T12:00:00Z"
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportResponse {
+    pub id: Uuid,
+    pub reporter_user_id: Uuid,
+    #[cfg_attr(feature = "docs", schema(example = "region"))]
+    pub target_type: String,
+    pub region: Option<GlobalRegion>,
+    pub reported_user_id: Option<Uuid>,
+    pub target_user_id: Option<Uuid>,
+    #[cfg_attr(feature = "docs", schema(example = "griefing"))]
+    pub category: String,
+    pub comment: String,
+    #[cfg_attr(feature = "docs", schema(example = "open"))]
+    pub status: String,
+    pub claimed_by_user_id: Option<Uuid>,
+    pub claimed_at: Option<String>,
+    pub resolved_by_user_id: Option<Uuid>,
+    pub resolved_at: Option<String>,
+    #[cfg_attr(feature = "docs", schema(example = "rollback_region"))]
+    pub resolution_action: Option<String>,
+    pub resolution_note: Option<String>,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub created_at: String,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "A privileged operation recorded in the audit log. A null actor_user_id marks an action taken by the server itself, such as a ban expiring.",
+    example = json!({
+        "id": "550e8400-e29b-41d4-a716-446655440000",
+        "actor_user_id": "550e8400-e29b-41d4-a716-446655440002",
+        "action": "ban.created",
+        "target_type": "ban",
+        "target_id": "550e8400-e29b-41d4-a716-446655440003",
+        "parameters": {"user_id": "550e8400-e29b-41d4-a716-446655440001", "reason": "Griefing"},
+        "ip_address": "203.0.113.7",
+        "created_at": "2023-01-01T12:00:00Z"
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditEntryResponse {
+    #[cfg_attr(
+        feature = "docs",
+        schema(example = "550e8400-e29b-41d4-a716-446655440000")
+    )]
+    pub id: Uuid,
+    #[cfg_attr(
+        feature = "docs",
+        schema(example = "550e8400-e29b-41d4-a716-446655440002")
+    )]
+    pub actor_user_id: Option<Uuid>,
+    #[cfg_attr(feature = "docs", schema(example = "ban.created"))]
+    pub action: String,
+    #[cfg_attr(feature = "docs", schema(example = "ban"))]
+    pub target_type: String,
+    #[cfg_attr(
+        feature = "docs",
+        schema(example = "550e8400-e29b-41d4-a716-446655440003")
+    )]
+    pub target_id: Option<String>,
+    pub parameters: serde_json::Value,
+    #[cfg_attr(feature = "docs", schema(example = "203.0.113.7"))]
+    pub ip_address: Option<String>,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub created_at: String,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "A pixel painted by a shadow-banned user, held back from the canvas until reviewed",
+    example = json!({
+        "id": 42,
+        "ban_id": "550e8400-e29b-41d4-a716-446655440000",
+        "x": 1024,
+        "y": 768,
+        "color_id": 5,
+        "painted_at": "2023-01-01T12:00:00Z"
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct QuarantinedPixelResponse {
+    #[cfg_attr(feature = "docs", schema(example = 42))]
+    pub id: i64,
+    #[cfg_attr(
+        feature = "docs",
+        schema(example = "550e8400-e29b-41d4-a716-446655440000")
+    )]
+    pub ban_id: Uuid,
+    #[cfg_attr(feature = "docs", schema(example = 1024))]
+    pub x: i32,
+    #[cfg_attr(feature = "docs", schema(example = 768))]
+    pub y: i32,
+    #[cfg_attr(feature = "docs", schema(example = 5))]
+    pub color_id: u8,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub painted_at: String,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Outcome of reviewing a user's quarantined pixels",
+    example = json!({
+        "pixels_discarded": 0,
+        "pixels_applied": 120,
+        "tiles_affected": 2
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct QuarantineReviewResponse {
+    #[cfg_attr(feature = "docs", schema(example = 0))]
+    pub pixels_discarded: u64,
+    #[cfg_attr(feature = "docs", schema(example = 120))]
+    pub pixels_applied: usize,
+    #[cfg_attr(feature = "docs", schema(example = 2))]
+    pub tiles_affected: usize,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "A protected region of the canvas. Shape is rectangle or polygon; polygons list their vertices, which lie on pixel corners. Restriction is locked, roles_only (only allowed_roles may paint) or time_window (painting is only allowed between opens_at and closes_at).",
+    example = json!({
+        "id": "550e8400-e29b-41d4-a716-446655440000",
+        "name": "Instance logo",
+        "description": "Official artwork, please do not paint over it",
+        "shape": "rectangle",
+        "bounds": {"min_x": 0, "min_y": 0, "max_x": 127, "max_y": 63},
+        "vertices": null,
+        "restriction": "roles_only",
+        "allowed_roles": ["moderator"],
+        "opens_at": null,
+        "closes_at": null,
+        "created_at": "2023-01-01T12:00:00Z",
+        "updated_at": "2023-01-01T12:00:00Z"
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct ProtectedRegionResponse {
+    #[cfg_attr(
+        feature = "docs",
+        schema(example = "550e8400-e29b-41d4-a716-446655440000")
+    )]
+    pub id: Uuid,
+    #[cfg_attr(feature = "docs", schema(example = "Instance logo"))]
+    pub name: String,
+    pub description: Option<String>,
+    #[cfg_attr(feature = "docs", schema(example = "rectangle"))]
+    pub shape: String,
+    pub bounds: GlobalRegion,
+    pub vertices: Option<Vec<GlobalCoord>>,
+    #[cfg_attr(feature = "docs", schema(example = "roles_only"))]
+    pub restriction: String,
+    pub allowed_roles: Vec<String>,
+    pub opens_at: Option<String>,
+    pub closes_at: Option<String>,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub created_at: String,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub updated_at: String,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "An active paint rule. The rule id is also returned in the `rule` field of the 403 error when a paint breaks it.",
+    example = json!({
+        "rule": "max_pixels_per_batch",
+        "description": "At most 50 pixels may be painted per request (moderator: 500)"
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct PaintRuleResponse {
+    #[cfg_attr(feature = "docs", schema(example = "max_pixels_per_batch"))]
+    pub rule: String,
+    pub description: String,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "A configured external login provider. Start logging in at /auth/{name}/start.",
+    example = json!({
+        "name": "google",
+        "display_name": "Google"
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct LoginProviderResponse {
+    #[cfg_attr(feature = "docs", schema(example = "google"))]
+    pub name: String,
+    #[cfg_attr(feature = "docs", schema(example = "Google"))]
+    pub display_name: String,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Two-factor authentication state of the current user",
+    example = json!({
+        "enabled": true,
+        "enabled_at": "2023-01-01T12:00:00Z",
+        "recovery_codes_remaining": 8
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct TwoFactorStatusResponse {
+    #[cfg_attr(feature = "docs", schema(example = true))]
+    pub enabled: bool,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub enabled_at: Option<String>,
+    #[cfg_attr(feature = "docs", schema(example = 8))]
+    pub recovery_codes_remaining: i64,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "A new TOTP secret. Add it to an authenticator app, then confirm with a generated code.",
+    example = json!({
+        "secret": "JBSWY3DPEHPK3PXPJBSWY3DPEHPK3PXP",
+        "otpauth_url": "otpauth://totp/FediPlace:user%40example.com?secret=JBSWY3DPEHPK3PXPJBSWY3DPEHPK3PXP&issuer=FediPlace"
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct TotpEnrollmentResponse {
+    #[cfg_attr(feature = "docs", schema(example = "JBSWY3DPEHPK3PXPJBSWY3DPEHPK3PXP"))]
+    pub secret: String,
+    pub otpauth_url: String,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Single-use recovery codes. They are only shown once.",
+    example = json!({
+        "recovery_codes": ["abcde-fghjk", "mnpqr-stuvw"]
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct RecoveryCodesResponse {
+    pub recovery_codes: Vec<String>,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "A signed-in session of the current user",
+    example = json!({
+        "id": "550e8400-e29b-41d4-a716-446655440000",
+        "user_agent": "Mozilla/5.0 (X11; Linux x86_64; rv:131.0) Gecko/20100101 Firefox/131.0",
+        "ip_address": "203.0.113.7",
+        "created_at": "2023-01-01T12:00:00Z",
+        "last_seen_at": "2023-01-02T08:30:00Z",
+        "current": true
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct UserSessionResponse {
+    pub id: Uuid,
+    pub user_agent: Option<String>,
+    #[cfg_attr(feature = "docs", schema(example = "203.0.113.7"))]
+    pub ip_address: Option<String>,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub created_at: String,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-02T08:30:00Z"))]
+    pub last_seen_at: String,
+    // True for the session that made the request.
+    #[cfg_attr(feature = "docs", schema(example = true))]
+    pub current: bool,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Number of sessions that were signed out",
+    example = json!({
+        "revoked_sessions": 3
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct RevokedSessionsResponse {
+    #[cfg_attr(feature = "docs", schema(example = 3))]
+    pub revoked_sessions: u64,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "A personal API token of the current user. The secret is never returned after creation.",
+    example = json!({
+        "id": "550e8400-e29b-41d4-a716-446655440000",
+        "name": "timelapse bot",
+        "scopes": ["paint"],
+        "created_at": "2023-01-01T12:00:00Z",
+        "last_used_at": "2023-01-02T08:30:00Z",
+        "expires_at": "2023-04-01T12:00:00Z",
+        "expired": false
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiTokenResponse {
+    pub id: Uuid,
+    #[cfg_attr(feature = "docs", schema(example = "timelapse bot"))]
+    pub name: String,
+    #[cfg_attr(feature = "docs", schema(example = json!(["paint"])))]
+    pub scopes: Vec<String>,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub created_at: String,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-02T08:30:00Z"))]
+    pub last_used_at: Option<String>,
+    #[cfg_attr(feature = "docs", schema(example = "2023-04-01T12:00:00Z"))]
+    pub expires_at: Option<String>,
+    #[cfg_attr(feature = "docs", schema(example = false))]
+    pub expired: bool,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "A newly created personal API token. The token is only shown once; send it as \"Authorization: Bearer <token>\".",
+    example = json!({
+        "token": "fpat_3f0c9b7e4a1d4c2e8b6f5a9d0e1c2b3a7d6e5f4c3b2a19088f7e6d5c4b3a2918",
+        "api_token": {
+            "id": "550e8400-e29b-41d4-a716-446655440000",
+            "name": "timelapse bot",
+            "scopes": ["paint"],
+            "created_at": "2023-01-01T12:00:00Z",
+            "last_used_at": null,
+            "expires_at": "2023-04-01T12:00:00Z",
+            "expired": false
+        }
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct CreatedApiTokenResponse {
+    pub token: String,
+    pub api_token: ApiTokenResponse,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "When the current account will be deleted, or null when no deletion is scheduled",
+    example = json!({
+        "deletion_scheduled_at": "2023-01-15T12:00:00Z"
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountDeletionResponse {
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-15T12:00:00Z"))]
+    pub deletion_scheduled_at: Option<String>,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "A pixel on the canvas that is attributed to the exported account",
+    example = json!({
+        "x": 1024,
+        "y": 768,
+        "color_id": 5,
+        "painted_at": "2023-01-01T12:00:00Z",
+        "automated": false
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedPaintResponse {
+    #[cfg_attr(feature = "docs", schema(example = 1024))]
+    pub x: i32,
+    #[cfg_attr(feature = "docs", schema(example = 768))]
+    pub y: i32,
+    #[cfg_attr(feature = "docs", schema(example = 5))]
+    pub color_id: u8,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub painted_at: String,
+    #[cfg_attr(feature = "docs", schema(example = false))]
+    pub automated: bool,
+}
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Archive of the personal data stored about the current account: profile, roles, linked logins, bans, pixels still attributed to it and signed-in sessions",
+    example = json!({
+        "exported_at": "2023-02-01T12:00:00Z",
+        "id": "550e8400-e29b-41d4-a716-446655440000",
+        "email": "user@example.com",
+        "username": "johndoe",
+        "email_verified": true,
+        "created_at": "2023-01-01T12:00:00Z",
+        "deletion_scheduled_at": null,
+        "roles": [],
+        "identities": [],
+        "bans": [],
+        "paints": [],
+        "sessions": []
+    })
+))]
+#[derive(Debug, Clone, Serialize)]
+pub struct AccountExportResponse {
+    #[cfg_attr(feature = "docs", schema(example = "2023-02-01T12:00:00Z"))]
+    pub exported_at: String,
+    #[cfg_attr(
+        feature = "docs",
+        schema(example = "550e8400-e29b-41d4-a716-446655440000")
+    )]
+    pub id: Uuid,
+    #[cfg_attr(feature = "docs", schema(example = "user@example.com"))]
+    pub email: String,
+    #[cfg_attr(feature = "docs", schema(example = "johndoe"))]
+    pub username: String,
+    #[cfg_attr(feature = "docs", schema(example = true))]
+    pub email_verified: bool,
+    #[cfg_attr(feature = "docs", schema(example = "2023-01-01T12:00:00Z"))]
+    pub created_at: Option<String>,
+    #[cfg_attr(feature = "docs", schema(example = "null"))]
+    pub deletion_scheduled_at: Option<String>,
+    pub roles: Vec<RoleResponse>,
+    pub identities: Vec<UserIdentityResponse>,
+    pub bans: Vec<BanResponse>,
+    pub paints: Vec<ExportedPaintResponse>,
+    pub sessions: Vec<UserSessionResponse>,
 }
@@ -1,6 +1,6 @@
 use axum::{
     Json,
-    http::StatusCode,
+    http::{HeaderValue, StatusCode, header::RETRY_AFTER},
     response::{IntoResponse, Response},
 };
 use serde_json::json;
@@ -21,6 +21,13 @@ impl IntoResponse for HttpError {
             | AppError::InvalidPixelCoordinates { .. }
             | AppError::InvalidColorFormat { .. }
             | AppError::ValidationError { .. }
+            | AppError::ProtectedRegion { .. }
+            | AppError::PixelProtected { .. }
+            | AppError::RuleViolation { .. }
+            | AppError::TooManyRequests { .. }
+            | AppError::AccountLinkRequired { .. }
+            | AppError::TwoFactorRequired
+            | AppError::TwoFactorEnrollmentRequired
             | AppError::JsonError(_)
             | AppError::WebSocketError { .. } => {
                 debug!("Client error response generated: {}", app_error);
@@ -96,16 +103,49 @@ impl IntoResponse for HttpError {
                 "Verification token has expired".to_string(),
             ),
 
+            AppError::AccountLinkRequired { .. } => (StatusCode::CONFLICT, app_error.to_string()),
+
+            AppError::TwoFactorRequired => (StatusCode::UNAUTHORIZED, app_error.to_string()),
+
+            AppError::TwoFactorEnrollmentRequired => (StatusCode::FORBIDDEN, app_error.to_string()),
+
+            AppError::TooManyRequests { .. } => {
+                (StatusCode::TOO_MANY_REQUESTS, app_error.to_string())
+            }
+
             AppError::InsufficientCredits { message } => (StatusCode::FORBIDDEN, message.clone()),
+
+            AppError::ProtectedRegion { message }
+            | AppError::PixelProtected { message }
+            | AppError::RuleViolation { message, .. } => (StatusCode::FORBIDDEN, message.clone()),
+        };
+
+        // Rule violations name the rule so clients can react without parsing the message.
+        let error_response = match app_error {
+            AppError::RuleViolation { rule, .. } => json!({
+                "ok": false,
+                "error": message,
+                "rule": rule,
+                "status": status_code.as_u16()
+            }),
+            _ => json!({
+                "ok": false,
+                "error": message,
+                "status": status_code.as_u16()
+            }),
         };
 
-        let error_response = json!({
-            "ok": false,
-            "error": message,
-            "status": status_code.as_u16()
-        });
+        let mut response = (status_code, Json(error_response)).into_response();
+        if let AppError::TooManyRequests {
+            retry_after_seconds,
+        } = app_error
+        {
+            response
+                .headers_mut()
+                .insert(RETRY_AFTER, HeaderValue::from(*retry_after_seconds));
+        }
 
-        (status_code, Json(error_response)).into_response()
+        response
     }
 }
 
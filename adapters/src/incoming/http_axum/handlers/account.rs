@@ -0,0 +1,220 @@
+#[cfg(feature = "docs")]
+use crate::incoming::http_axum::dto::common_responses::{
+    InternalServerErrorResponse, RateLimitExceededResponse, UnauthorizedResponse,
+    ValidationErrorResponse,
+};
+use axum::{
+    Json,
+    extract::State,
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use axum_login::AuthSession;
+use domain::action::PaintAction;
+use fedi_wplace_application::error::AppError;
+use time::OffsetDateTime;
+use tower_sessions::Session;
+#[cfg(feature = "docs")]
+use utoipa;
+
+use crate::{
+    incoming::http_axum::{
+        auth::{backend::AuthBackend, session::current_session_id},
+        dto::{
+            requests::DeleteAccountRequest,
+            responses::{
+                AccountDeletionResponse, AccountExportResponse, ApiResponse, BanResponse,
+                ExportedPaintResponse, RoleResponse, UserIdentityResponse, UserSessionResponse,
+            },
+        },
+        error_mapper::HttpError,
+        handlers::ban::format_datetime,
+    },
+    shared::app_state::AppState,
+};
+
+impl From<PaintAction> for ExportedPaintResponse {
+    fn from(action: PaintAction) -> Self {
+        Self {
+            x: action.global_coord.x,
+            y: action.global_coord.y,
+            color_id: action.color_id.id(),
+            painted_at: format_datetime(action.timestamp),
+            automated: action.automated,
+        }
+    }
+}
+
+fn deletion_response(scheduled_at: Option<OffsetDateTime>) -> AccountDeletionResponse {
+    AccountDeletionResponse {
+        deletion_scheduled_at: scheduled_at.map(format_datetime),
+    }
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/auth/account/export",
+    responses(
+        (status = 200, description = "Personal data archive, sent as a JSON file download", body = AccountExportResponse),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Export account data",
+    description = "Downloads everything stored about the current account as a JSON archive: profile, roles, linked logins, bans, pixels still attributed to the account and signed-in sessions."
+))]
+pub async fn export_account_handler(
+    auth_session: AuthSession<AuthBackend>,
+    session: Session,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, HttpError> {
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    let current_id = current_session_id(&session, user.id).await?;
+    let export = state.account_use_case.export_account_data(user.id).await?;
+
+    let response = AccountExportResponse {
+        exported_at: format_datetime(export.exported_at),
+        id: *export.user.id.as_uuid(),
+        email: export.user.email,
+        username: export.user.username,
+        email_verified: export.user.email_verified_at.is_some(),
+        created_at: export.created_at.map(format_datetime),
+        deletion_scheduled_at: export.deletion_scheduled_at.map(format_datetime),
+        roles: export
+            .user
+            .roles
+            .into_iter()
+            .map(RoleResponse::from)
+            .collect(),
+        identities: export
+            .identities
+            .into_iter()
+            .map(UserIdentityResponse::from)
+            .collect(),
+        bans: export.bans.into_iter().map(BanResponse::from).collect(),
+        paints: export
+            .paints
+            .into_iter()
+            .map(ExportedPaintResponse::from)
+            .collect(),
+        sessions: export
+            .sessions
+            .into_iter()
+            .map(|user_session| UserSessionResponse {
+                id: user_session.id,
+                user_agent: user_session.user_agent,
+                ip_address: user_session.ip_address.map(|ip| ip.to_string()),
+                created_at: format_datetime(user_session.created_at),
+                last_seen_at: format_datetime(user_session.last_seen_at),
+                current: current_id == Some(user_session.id),
+            })
+            .collect(),
+    };
+
+    Ok((
+        [(
+            header::CONTENT_DISPOSITION,
+            "attachment; filename=\"account-export.json\"",
+        )],
+        Json(response),
+    ))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/auth/account/deletion",
+    responses(
+        (status = 200, description = "Deletion status of the current account", body = AccountDeletionResponse),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Get account deletion status",
+    description = "Returns when the current account is scheduled to be deleted, or null when no deletion is pending."
+))]
+pub async fn account_deletion_status_handler(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, HttpError> {
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    let scheduled_at = state
+        .account_use_case
+        .deletion_scheduled_at(user.id)
+        .await?;
+
+    Ok(Json(ApiResponse::success_with_data(Some(
+        deletion_response(scheduled_at),
+    ))))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/auth/account/deletion",
+    request_body = DeleteAccountRequest,
+    responses(
+        (status = 202, description = "Deletion scheduled", body = AccountDeletionResponse),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 422, response = ValidationErrorResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Schedule account deletion",
+    description = "Schedules the current account for deletion after the instance's grace period and emails a notice. The account keeps working until then and the deletion can be cancelled. Once deleted, the account and its personal data are removed; pixels it painted stay on the canvas without attribution."
+))]
+pub async fn schedule_account_deletion_handler(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Json(request): Json<DeleteAccountRequest>,
+) -> Result<impl IntoResponse, HttpError> {
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    let scheduled_at = state
+        .account_use_case
+        .schedule_deletion(user.id, request.current_password)
+        .await?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(ApiResponse::success_with_data(Some(deletion_response(
+            Some(scheduled_at),
+        )))),
+    ))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    delete,
+    path = "/auth/account/deletion",
+    responses(
+        (status = 204, description = "Deletion cancelled"),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 422, response = ValidationErrorResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Cancel account deletion",
+    description = "Cancels the pending deletion of the current account."
+))]
+pub async fn cancel_account_deletion_handler(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, HttpError> {
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    state.account_use_case.cancel_deletion(user.id).await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -1,17 +1,192 @@
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
 };
 use axum_login::AuthSession;
+use serde::Deserialize;
+use time::OffsetDateTime;
 use tracing::instrument;
 use uuid::Uuid;
 
 use crate::incoming::http_axum::{
-    auth::backend::AuthBackend, dto::responses::UserResponse, error_mapper::HttpError,
-    handlers::auth_user_response::build_user_response,
+    auth::backend::AuthBackend,
+    dto::{
+        requests::{CreateRoleRequest, UpdateRoleRequest},
+        responses::{
+            ApiResponse, BanResponse, LockedAccountResponse, RoleMemberResponse, RoleResponse,
+            UserDetailsResponse, UserDirectoryPageResponse, UserIdentityResponse, UserResponse,
+            UserSummaryResponse,
+        },
+    },
+    error_mapper::HttpError,
+    handlers::{
+        auth_user_response::build_user_response,
+        ban::{format_datetime, parse_datetime_string},
+    },
+    middleware::client_ip::ClientIp,
 };
 use crate::shared::app_state::AppState;
-use fedi_wplace_application::error::AppError;
+use domain::{
+    auth::{Identity, Permission, Role, RoleError, RoleMember, UserId, UserSummary},
+    credits::{CreditBalance, CreditConfig},
+    login_protection::LockedAccount,
+};
+use fedi_wplace_application::{
+    error::AppError,
+    ports::outgoing::user_directory::{UserDirectoryQuery, UserSortField},
+};
+
+const DEFAULT_USER_PAGE_SIZE: u32 = 50;
+const MAX_USER_PAGE_SIZE: u32 = 200;
+
+impl From<Role> for RoleResponse {
+    fn from(role: Role) -> Self {
+        let built_in = role.is_built_in();
+        Self {
+            id: *role.id.as_uuid(),
+            name: role.name,
+            description: role.description,
+            permissions: role
+                .permissions
+                .iter()
+                .map(|permission| permission.as_str().to_string())
+                .collect(),
+            built_in,
+            created_at: format_datetime(role.created_at),
+            updated_at: format_datetime(role.updated_at),
+        }
+    }
+}
+
+impl From<RoleMember> for RoleMemberResponse {
+    fn from(member: RoleMember) -> Self {
+        Self {
+            user_id: *member.user_id.as_uuid(),
+            username: member.username,
+            assigned_at: format_datetime(member.assigned_at),
+            assigned_by_user_id: member.assigned_by.map(|id| *id.as_uuid()),
+        }
+    }
+}
+
+impl From<UserSummary> for UserSummaryResponse {
+    fn from(user: UserSummary) -> Self {
+        Self {
+            id: *user.id.as_uuid(),
+            email: user.email,
+            username: user.username,
+            email_verified: user.email_verified_at.is_some(),
+            created_at: format_datetime(user.created_at),
+            roles: user.role_names,
+            banned: user.banned,
+        }
+    }
+}
+
+impl From<LockedAccount> for LockedAccountResponse {
+    fn from(account: LockedAccount) -> Self {
+        Self {
+            user_id: *account.user_id.as_uuid(),
+            username: account.username,
+            failed_attempts: account.failed_attempts,
+            last_failed_at: format_datetime(account.last_failed_at),
+            locked_until: format_datetime(account.locked_until),
+        }
+    }
+}
+
+impl From<Identity> for UserIdentityResponse {
+    fn from(identity: Identity) -> Self {
+        Self {
+            id: identity.id.to_string(),
+            provider: identity.provider,
+            provider_user_id: identity.provider_user_id,
+            created_at: format_datetime(identity.created_at),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UserSortParam {
+    #[default]
+    CreatedAt,
+    Username,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserDirectoryParams {
+    pub search: Option<String>,
+    pub verified: Option<bool>,
+    pub banned: Option<bool>,
+    pub role: Option<String>,
+    pub created_since: Option<String>,
+    pub created_until: Option<String>,
+    #[serde(default)]
+    pub sort: UserSortParam,
+    #[serde(default)]
+    pub order: SortOrder,
+    pub cursor: Option<Uuid>,
+    pub limit: Option<u32>,
+}
+
+impl UserDirectoryParams {
+    fn into_query(self) -> Result<UserDirectoryQuery, AppError> {
+        Ok(UserDirectoryQuery {
+            search: self
+                .search
+                .map(|search| search.trim().to_string())
+                .filter(|search| !search.is_empty()),
+            email_verified: self.verified,
+            banned: self.banned,
+            role_name: self.role,
+            created_since: self
+                .created_since
+                .as_deref()
+                .map(parse_datetime_string)
+                .transpose()?,
+            created_until: self
+                .created_until
+                .as_deref()
+                .map(parse_datetime_string)
+                .transpose()?,
+            sort: match self.sort {
+                UserSortParam::CreatedAt => UserSortField::CreatedAt,
+                UserSortParam::Username => UserSortField::Username,
+            },
+            descending: matches!(self.order, SortOrder::Desc),
+            after: self.cursor.map(UserId::from_uuid),
+            limit: self
+                .limit
+                .unwrap_or(DEFAULT_USER_PAGE_SIZE)
+                .clamp(1, MAX_USER_PAGE_SIZE),
+        })
+    }
+}
+
+fn parse_permissions(permissions: &[String]) -> Result<Vec<Permission>, AppError> {
+    let mut parsed = Vec::with_capacity(permissions.len());
+    for permission in permissions {
+        let permission =
+            permission
+                .parse::<Permission>()
+                .map_err(|()| AppError::ValidationError {
+                    message: format!("{}: {}", RoleError::InvalidPermission, permission),
+                })?;
+        if !parsed.contains(&permission) {
+            parsed.push(permission);
+        }
+    }
+    Ok(parsed)
+}
 
 #[cfg_attr(feature = "docs", utoipa::path(
     put,
@@ -21,7 +196,7 @@ use fedi_wplace_application::error::AppError;
         (status = 200, description = "Role assigned successfully", body = UserResponse),
         (status = 400, description = "Invalid request data"),
         (status = 401, description = "Not authenticated"),
-        (status = 403, description = "Not authorized (admin role required)"),
+        (status = 403, description = "Not authorized (roles:assign permission required)"),
         (status = 404, description = "User or role not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -33,20 +208,473 @@ use fedi_wplace_application::error::AppError;
 pub async fn assign_role_to_user(
     auth_session: AuthSession<AuthBackend>,
     State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Path((user_id, role_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<UserResponse>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::RolesAssign) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let updated_user = state
+        .admin_use_case
+        .assign_role_to_user(user_id, role_id, current_user.id, Some(client_ip))
+        .await?;
+
+    let now = time::OffsetDateTime::now_utc();
+    let response = build_user_response(updated_user, &state, now).await?;
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    delete,
+    path = "/admin/users/{user_id}/roles/{role_id}",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Role revoked successfully", body = UserResponse),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (roles:assign permission required, only admins may revoke the admin role)"),
+        (status = 422, description = "Role not found, user does not have the role, or the user is the last admin"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn revoke_role_from_user(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
     Path((user_id, role_id)): Path<(Uuid, Uuid)>,
 ) -> Result<Json<UserResponse>, HttpError> {
     let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
 
-    if !current_user.is_admin() {
+    if !current_user.has_permission(Permission::RolesAssign) {
         return Err(HttpError(AppError::Forbidden));
     }
 
     let updated_user = state
         .admin_use_case
-        .assign_role_to_user(user_id, role_id, current_user.id)
+        .revoke_role_from_user(user_id, role_id, current_user.id, Some(client_ip))
         .await?;
 
     let now = time::OffsetDateTime::now_utc();
     let response = build_user_response(updated_user, &state, now).await?;
     Ok(Json(response))
 }
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/admin/roles",
+    tag = "admin",
+    responses(
+        (status = 200, description = "All roles, ordered by name", body = Vec<RoleResponse>),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (roles:assign permission required)"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn list_roles(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<Vec<RoleResponse>>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::RolesAssign) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let roles = state.admin_use_case.list_roles(current_user.id).await?;
+
+    let role_responses: Vec<RoleResponse> = roles.into_iter().map(RoleResponse::from).collect();
+    let response = ApiResponse::success_with_data(Some(role_responses));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/admin/roles",
+    tag = "admin",
+    request_body = CreateRoleRequest,
+    responses(
+        (status = 200, description = "Role created", body = RoleResponse),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (roles:assign permission required)"),
+        (status = 422, description = "Invalid role name or permission, or a role with this name already exists"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn create_role(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Json(request): Json<CreateRoleRequest>,
+) -> Result<Json<ApiResponse<RoleResponse>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::RolesAssign) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let permissions = parse_permissions(&request.permissions)?;
+
+    let role = state
+        .admin_use_case
+        .create_role(
+            request.name,
+            request.description,
+            permissions,
+            current_user.id,
+            Some(client_ip),
+        )
+        .await?;
+
+    let response = ApiResponse::success_with_data(Some(RoleResponse::from(role)));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    patch,
+    path = "/admin/roles/{role_id}",
+    tag = "admin",
+    request_body = UpdateRoleRequest,
+    responses(
+        (status = 200, description = "Role updated", body = RoleResponse),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (roles:assign permission required)"),
+        (status = 422, description = "Role not found, invalid permission, or the role is the built-in admin role"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn update_role(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Path(role_id): Path<Uuid>,
+    Json(request): Json<UpdateRoleRequest>,
+) -> Result<Json<ApiResponse<RoleResponse>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::RolesAssign) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let permissions = request
+        .permissions
+        .as_deref()
+        .map(parse_permissions)
+        .transpose()?;
+
+    let role = state
+        .admin_use_case
+        .update_role(
+            role_id,
+            request.description,
+            permissions,
+            current_user.id,
+            Some(client_ip),
+        )
+        .await?;
+
+    let response = ApiResponse::success_with_data(Some(RoleResponse::from(role)));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    delete,
+    path = "/admin/roles/{role_id}",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Role deleted and revoked from all its members"),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (roles:assign permission required)"),
+        (status = 422, description = "Role not found or built in"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn delete_role(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Path(role_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::RolesAssign) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    state
+        .admin_use_case
+        .delete_role(role_id, current_user.id, Some(client_ip))
+        .await?;
+
+    let response = ApiResponse::success();
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/admin/roles/{role_id}/users",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Users holding the role, earliest assignment first", body = Vec<RoleMemberResponse>),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (roles:assign permission required)"),
+        (status = 422, description = "Role not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn list_role_members(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Path(role_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<RoleMemberResponse>>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::RolesAssign) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let members = state
+        .admin_use_case
+        .list_users_by_role(role_id, current_user.id)
+        .await?;
+
+    let member_responses: Vec<RoleMemberResponse> =
+        members.into_iter().map(RoleMemberResponse::from).collect();
+    let response = ApiResponse::success_with_data(Some(member_responses));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/admin/users",
+    tag = "admin",
+    params(
+        ("search" = Option<String>, Query, description = "Case-insensitive prefix of the username or email address"),
+        ("verified" = Option<bool>, Query, description = "Only list users whose email is (true) or is not (false) verified"),
+        ("banned" = Option<bool>, Query, description = "Only list users with (true) or without (false) an active ban, shadow bans included"),
+        ("role" = Option<String>, Query, description = "Only list holders of this role"),
+        ("created_since" = Option<String>, Query, description = "Only list users created at or after this RFC 3339 timestamp"),
+        ("created_until" = Option<String>, Query, description = "Only list users created before this RFC 3339 timestamp"),
+        ("sort" = Option<String>, Query, description = "created_at (default) or username"),
+        ("order" = Option<String>, Query, description = "asc (default) or desc"),
+        ("cursor" = Option<Uuid>, Query, description = "next_cursor from the previous page"),
+        ("limit" = Option<u32>, Query, description = "Page size, at most 200 (default 50)")
+    ),
+    responses(
+        (status = 200, description = "A page of matching users", body = UserDirectoryPageResponse),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (users:read permission required)"),
+        (status = 422, description = "Invalid filter"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn list_users(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Query(params): Query<UserDirectoryParams>,
+) -> Result<Json<ApiResponse<UserDirectoryPageResponse>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::UsersRead) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let query = params.into_query()?;
+    let limit = query.limit;
+
+    let users = state
+        .admin_use_case
+        .list_users(query, current_user.id)
+        .await?;
+
+    let next_cursor = if users.len() == limit as usize {
+        users.last().map(|user| *user.id.as_uuid())
+    } else {
+        None
+    };
+    let page = UserDirectoryPageResponse {
+        users: users.into_iter().map(UserSummaryResponse::from).collect(),
+        next_cursor,
+    };
+    let response = ApiResponse::success_with_data(Some(page));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/admin/users/{user_id}",
+    tag = "admin",
+    responses(
+        (status = 200, description = "User details", body = UserDetailsResponse),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (users:read permission required)"),
+        (status = 422, description = "User not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn get_user_details(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<UserDetailsResponse>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::UsersRead) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let details = state
+        .admin_use_case
+        .get_user_details(user_id, current_user.id)
+        .await?;
+
+    let now = OffsetDateTime::now_utc();
+    let credit_config = CreditConfig::new(
+        state.config.credits.max_charges,
+        state.config.credits.charge_cooldown_seconds,
+    );
+    let credit_balance = CreditBalance::new(
+        details.user.available_charges,
+        details.user.charges_updated_at,
+    );
+
+    let user_details = UserDetailsResponse {
+        id: *details.user.id.as_uuid(),
+        email: details.user.email,
+        username: details.user.username,
+        email_verified: details.user.email_verified_at.is_some(),
+        created_at: format_datetime(details.created_at),
+        identities: details
+            .identities
+            .into_iter()
+            .map(UserIdentityResponse::from)
+            .collect(),
+        roles: details
+            .user
+            .roles
+            .into_iter()
+            .map(RoleResponse::from)
+            .collect(),
+        ban: details.active_ban.map(BanResponse::from),
+        available_charges: credit_balance.calculate_current_balance(now, &credit_config),
+        max_charges: credit_config.max_charges,
+        seconds_until_next_charge: credit_balance.seconds_until_next_charge(now, &credit_config),
+        pixels_on_canvas: details.pixels_on_canvas,
+        pixels_quarantined: details.pixels_quarantined,
+    };
+    let response = ApiResponse::success_with_data(Some(user_details));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/admin/users/locked",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Accounts whose password login is currently locked, latest lockout first", body = Vec<LockedAccountResponse>),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (users:read permission required)"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn list_locked_accounts(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<Vec<LockedAccountResponse>>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::UsersRead) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let accounts = state
+        .login_protection_use_case
+        .list_locked_accounts(UserId::from_uuid(current_user.id))
+        .await?;
+
+    let response = ApiResponse::success_with_data(Some(
+        accounts
+            .into_iter()
+            .map(LockedAccountResponse::from)
+            .collect(),
+    ));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    delete,
+    path = "/admin/users/{user_id}/lockout",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Lockout lifted and failed login attempts reset"),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (users:manage permission required)"),
+        (status = 422, description = "Account is not locked"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn unlock_account(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::UsersManage) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    state
+        .login_protection_use_case
+        .unlock_account(
+            UserId::from_uuid(user_id),
+            UserId::from_uuid(current_user.id),
+            Some(client_ip),
+        )
+        .await?;
+
+    let response = ApiResponse::success();
+    Ok(Json(response))
+}
@@ -0,0 +1,169 @@
+#[cfg(feature = "docs")]
+use crate::incoming::http_axum::dto::common_responses::{
+    ForbiddenResponse, InternalServerErrorResponse, RateLimitExceededResponse,
+    UnauthorizedResponse, ValidationErrorResponse,
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use axum_login::AuthSession;
+use domain::api_token::{ApiToken, ApiTokenScope};
+use fedi_wplace_application::error::AppError;
+use time::OffsetDateTime;
+#[cfg(feature = "docs")]
+use utoipa;
+use uuid::Uuid;
+
+use crate::{
+    incoming::http_axum::{
+        auth::backend::AuthBackend,
+        dto::{
+            requests::CreateApiTokenRequest,
+            responses::{ApiResponse, ApiTokenResponse, CreatedApiTokenResponse},
+        },
+        error_mapper::HttpError,
+        handlers::ban::format_datetime,
+    },
+    shared::app_state::AppState,
+};
+
+impl ApiTokenResponse {
+    fn from_token(token: ApiToken, now: OffsetDateTime) -> Self {
+        Self {
+            id: token.id,
+            expired: !token.is_active(now),
+            name: token.name,
+            scopes: token
+                .scopes
+                .iter()
+                .map(|scope| scope.as_str().to_string())
+                .collect(),
+            created_at: format_datetime(token.created_at),
+            last_used_at: token.last_used_at.map(format_datetime),
+            expires_at: token.expires_at.map(format_datetime),
+        }
+    }
+}
+
+fn parse_scopes(scopes: &[String]) -> Result<Vec<ApiTokenScope>, AppError> {
+    scopes
+        .iter()
+        .map(|scope| {
+            scope
+                .parse::<ApiTokenScope>()
+                .map_err(|e| AppError::ValidationError {
+                    message: e.to_string(),
+                })
+        })
+        .collect()
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/auth/tokens",
+    responses(
+        (status = 200, description = "Personal API tokens of the current user", body = [ApiTokenResponse]),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "List API tokens",
+    description = "Lists the current user's personal API tokens that have not been revoked, newest first. Expired tokens are included and marked as such."
+))]
+pub async fn list_api_tokens_handler(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, HttpError> {
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    let now = OffsetDateTime::now_utc();
+    let tokens = state.api_token_use_case.list_tokens(user.id).await?;
+    let token_responses: Vec<ApiTokenResponse> = tokens
+        .into_iter()
+        .map(|token| ApiTokenResponse::from_token(token, now))
+        .collect();
+
+    Ok(Json(ApiResponse::success_with_data(Some(token_responses))))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/auth/tokens",
+    request_body = CreateApiTokenRequest,
+    responses(
+        (status = 201, description = "Token created", body = CreatedApiTokenResponse),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 403, response = ForbiddenResponse),
+        (status = 422, response = ValidationErrorResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Create an API token",
+    description = "Creates a personal API token for scripts and bots. The token is returned once and is accepted as \"Authorization: Bearer <token>\" on /auth/me (read), the paint endpoint (paint) and the admin routes (admin). The admin scope is only available to users holding a role with permissions, and tokens never grant more than the user's own roles. Requests made with a token have their own rate limit and the pixels they paint are flagged as automated. Only a browser session can manage tokens."
+))]
+pub async fn create_api_token_handler(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Json(request): Json<CreateApiTokenRequest>,
+) -> Result<impl IntoResponse, HttpError> {
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    let scopes = parse_scopes(&request.scopes)?;
+    let created = state
+        .api_token_use_case
+        .create_token(user.id, request.name, scopes, request.expires_in_days)
+        .await?;
+
+    let response = CreatedApiTokenResponse {
+        token: created.secret,
+        api_token: ApiTokenResponse::from_token(created.token, OffsetDateTime::now_utc()),
+    };
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_with_data(Some(response))),
+    ))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    delete,
+    path = "/auth/tokens/{token_id}",
+    params(
+        ("token_id" = Uuid, Path, description = "Token to revoke")
+    ),
+    responses(
+        (status = 204, description = "Token revoked"),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 422, response = ValidationErrorResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Revoke an API token",
+    description = "Revokes one of the current user's personal API tokens. Requests using it are rejected from then on."
+))]
+pub async fn revoke_api_token_handler(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Path(token_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HttpError> {
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    state
+        .api_token_use_case
+        .revoke_token(user.id, token_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
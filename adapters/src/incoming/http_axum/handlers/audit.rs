@@ -0,0 +1,284 @@
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use axum_login::AuthSession;
+use serde::Deserialize;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::incoming::http_axum::{
+    auth::backend::AuthBackend,
+    dto::responses::{ApiResponse, AuditEntryResponse},
+    error_mapper::HttpError,
+    handlers::ban::{format_datetime, parse_datetime_string},
+};
+use crate::shared::app_state::AppState;
+use domain::{
+    audit::{AuditAction, AuditEntry, AuditTargetType},
+    auth::{Permission, UserId},
+};
+use fedi_wplace_application::{error::AppError, ports::outgoing::audit_log::AuditLogQuery};
+
+const DEFAULT_AUDIT_PAGE_SIZE: u32 = 50;
+const MAX_AUDIT_PAGE_SIZE: u32 = 200;
+const MAX_AUDIT_EXPORT_ROWS: u32 = 10_000;
+
+const CSV_HEADER: &str =
+    "id,created_at,actor_user_id,action,target_type,target_id,ip_address,parameters";
+
+impl From<AuditEntry> for AuditEntryResponse {
+    fn from(entry: AuditEntry) -> Self {
+        Self {
+            id: *entry.id.as_uuid(),
+            actor_user_id: entry.actor_user_id.map(|id| *id.as_uuid()),
+            action: entry.action.as_str().to_string(),
+            target_type: entry.target_type.as_str().to_string(),
+            target_id: entry.target_id,
+            parameters: entry.parameters,
+            ip_address: entry.ip_address.map(|ip| ip.to_string()),
+            created_at: format_datetime(entry.created_at),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AuditLogFilter {
+    pub actor_user_id: Option<Uuid>,
+    pub action: Option<String>,
+    pub target_type: Option<String>,
+    pub target_id: Option<String>,
+    pub since: Option<String>,
+    pub until: Option<String>,
+}
+
+impl AuditLogFilter {
+    fn into_query(self, limit: u32, offset: u32) -> Result<AuditLogQuery, AppError> {
+        let validation_error = |message: String| AppError::ValidationError { message };
+
+        Ok(AuditLogQuery {
+            actor_user_id: self.actor_user_id.map(UserId::from_uuid),
+            action: self
+                .action
+                .as_deref()
+                .map(str::parse::<AuditAction>)
+                .transpose()
+                .map_err(|e| validation_error(e.to_string()))?,
+            target_type: self
+                .target_type
+                .as_deref()
+                .map(str::parse::<AuditTargetType>)
+                .transpose()
+                .map_err(|e| validation_error(e.to_string()))?,
+            target_id: self.target_id,
+            since: self
+                .since
+                .as_deref()
+                .map(parse_datetime_string)
+                .transpose()?,
+            until: self
+                .until
+                .as_deref()
+                .map(parse_datetime_string)
+                .transpose()?,
+            limit,
+            offset,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AuditLogPageQuery {
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditExportFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AuditExportQuery {
+    #[serde(default)]
+    pub format: AuditExportFormat,
+}
+
+// Spreadsheet applications evaluate cells starting with these characters as formulas.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@']) {
+        format!("'{value}")
+    } else {
+        value.to_string()
+    };
+
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+fn entries_to_csv(entries: &[AuditEntry]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push_str("\r\n");
+
+    for entry in entries {
+        let fields = [
+            entry.id.as_uuid().to_string(),
+            format_datetime(entry.created_at),
+            entry
+                .actor_user_id
+                .as_ref()
+                .map(|id| id.as_uuid().to_string())
+                .unwrap_or_default(),
+            entry.action.as_str().to_string(),
+            entry.target_type.as_str().to_string(),
+            entry.target_id.clone().unwrap_or_default(),
+            entry
+                .ip_address
+                .map(|ip| ip.to_string())
+                .unwrap_or_default(),
+            entry.parameters.to_string(),
+        ];
+        let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+        csv.push_str(&row.join(","));
+        csv.push_str("\r\n");
+    }
+
+    csv
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/admin/audit-log",
+    tag = "admin",
+    params(
+        ("actor_user_id" = Option<Uuid>, Query, description = "Only list actions taken by this user"),
+        ("action" = Option<String>, Query, description = "Only list this action, e.g. ban.created or role.assigned"),
+        ("target_type" = Option<String>, Query, description = "Only list actions on this kind of target (user, ban, ip_ban, region, report, role or protected_region)"),
+        ("target_id" = Option<String>, Query, description = "Only list actions on this target"),
+        ("since" = Option<String>, Query, description = "Only list actions at or after this RFC 3339 timestamp"),
+        ("until" = Option<String>, Query, description = "Only list actions before this RFC 3339 timestamp"),
+        ("limit" = Option<u32>, Query, description = "Page size, at most 200 (default 50)"),
+        ("offset" = Option<u32>, Query, description = "Number of entries to skip")
+    ),
+    responses(
+        (status = 200, description = "Audit log entries, newest first", body = Vec<AuditEntryResponse>),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (audit:read permission required)"),
+        (status = 422, description = "Invalid filter"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn list_audit_log(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Query(filter): Query<AuditLogFilter>,
+    Query(page): Query<AuditLogPageQuery>,
+) -> Result<Json<ApiResponse<Vec<AuditEntryResponse>>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::AuditRead) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let audit_query = filter.into_query(
+        page.limit
+            .unwrap_or(DEFAULT_AUDIT_PAGE_SIZE)
+            .clamp(1, MAX_AUDIT_PAGE_SIZE),
+        page.offset.unwrap_or(0),
+    )?;
+
+    let entries = state
+        .audit_log_use_case
+        .list_entries(UserId::from_uuid(current_user.id), audit_query)
+        .await?;
+
+    let entry_responses: Vec<AuditEntryResponse> =
+        entries.into_iter().map(AuditEntryResponse::from).collect();
+    let response = ApiResponse::success_with_data(Some(entry_responses));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/admin/audit-log/export",
+    tag = "admin",
+    params(
+        ("actor_user_id" = Option<Uuid>, Query, description = "Only export actions taken by this user"),
+        ("action" = Option<String>, Query, description = "Only export this action, e.g. ban.created or role.assigned"),
+        ("target_type" = Option<String>, Query, description = "Only export actions on this kind of target (user, ban, ip_ban, region, report, role or protected_region)"),
+        ("target_id" = Option<String>, Query, description = "Only export actions on this target"),
+        ("since" = Option<String>, Query, description = "Only export actions at or after this RFC 3339 timestamp"),
+        ("until" = Option<String>, Query, description = "Only export actions before this RFC 3339 timestamp"),
+        ("format" = Option<String>, Query, description = "json (default) or csv")
+    ),
+    responses(
+        (status = 200, description = "Up to 10000 matching audit log entries, newest first, as a JSON or CSV attachment"),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (audit:read permission required)"),
+        (status = 422, description = "Invalid filter"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn export_audit_log(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Query(filter): Query<AuditLogFilter>,
+    Query(export): Query<AuditExportQuery>,
+) -> Result<Response, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::AuditRead) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let audit_query = filter.into_query(MAX_AUDIT_EXPORT_ROWS, 0)?;
+
+    let entries = state
+        .audit_log_use_case
+        .list_entries(UserId::from_uuid(current_user.id), audit_query)
+        .await?;
+
+    let response = match export.format {
+        AuditExportFormat::Json => {
+            let entry_responses: Vec<AuditEntryResponse> =
+                entries.into_iter().map(AuditEntryResponse::from).collect();
+            (
+                [(
+                    header::CONTENT_DISPOSITION,
+                    "attachment; filename=\"audit-log.json\"",
+                )],
+                Json(entry_responses),
+            )
+                .into_response()
+        }
+        AuditExportFormat::Csv => (
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+                (
+                    header::CONTENT_DISPOSITION,
+                    "attachment; filename=\"audit-log.csv\"",
+                ),
+            ],
+            entries_to_csv(&entries),
+        )
+            .into_response(),
+    };
+
+    Ok(response)
+}
@@ -5,7 +5,7 @@ use crate::incoming::http_axum::dto::common_responses::{
 };
 use axum::{
     Json,
-    extract::{Query, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
 };
@@ -13,21 +13,29 @@ use axum_login::AuthSession;
 use fedi_wplace_application::error::AppError;
 use serde::Deserialize;
 use serde_json::json;
+use tower_sessions::Session;
 #[cfg(feature = "docs")]
 use utoipa;
+use uuid::Uuid;
 use validator::Validate;
 
 #[cfg(feature = "docs")]
 use crate::incoming::http_axum::dto::responses::{ApiResponseUser, ApiResponseValue};
 use crate::{
     incoming::http_axum::{
-        auth::backend::{AuthBackend, Credentials, User},
+        auth::{
+            backend::{AuthBackend, Credentials, User},
+            session::current_session_id,
+        },
         dto::{
-            requests::{LoginRequest, RegisterRequest, UpdateUsernameRequest},
-            responses::{ApiResponse, UserResponse},
+            requests::{
+                ChangeEmailRequest, LoginRequest, RegisterRequest, RequestPasswordResetRequest,
+                ResetPasswordRequest, UpdateUsernameRequest,
+            },
+            responses::{ApiResponse, UserIdentityResponse, UserResponse},
         },
         error_mapper::HttpError,
-        handlers::auth_user_response::build_user_response,
+        handlers::{auth_user_response::build_user_response, two_factor::verify_second_factor},
     },
     shared::app_state::AppState,
 };
@@ -130,7 +138,7 @@ pub async fn register_handler(
     ),
     tag = "auth",
     summary = "Login with email and password",
-    description = "Authenticates user credentials and creates a session cookie if successful."
+    description = "Authenticates user credentials and creates a session cookie if successful. Accounts with two-factor authentication enabled must also send `two_factor_code`; without it the response is 401 with a two-factor error."
 ))]
 pub async fn login_handler(
     State(state): State<AppState>,
@@ -157,6 +165,9 @@ pub async fn login_handler(
         return Err(HttpError(AppError::Unauthorized));
     };
 
+    // The password was right; accounts with TOTP also need a code before a session exists.
+    verify_second_factor(&state, user.id, request.two_factor_code).await?;
+
     auth_session
         .login(&user)
         .await
@@ -184,11 +195,24 @@ pub async fn login_handler(
     ),
     tag = "auth",
     summary = "Logout current user",
-    description = "Clears the user session and logs out the current user."
+    description = "Clears the user session, removes it from the session list and logs out the current user."
 ))]
 pub async fn logout_handler(
     mut auth_session: AuthSession<AuthBackend>,
+    session: Session,
+    State(state): State<AppState>,
 ) -> Result<impl IntoResponse, HttpError> {
+    // A failure here must not keep the user from logging out.
+    if let Some(user_id) = auth_session.user.as_ref().map(|user| user.id)
+        && let Some(session_id) = current_session_id(&session, user_id).await?
+        && let Err(e) = state
+            .session_management_use_case
+            .revoke_session(user_id, session_id)
+            .await
+    {
+        tracing::warn!(user_id = %user_id, "Failed to revoke session on logout: {}", e);
+    }
+
     auth_session
         .logout()
         .await
@@ -226,7 +250,7 @@ pub async fn logout_handler(
     ),
     tag = "auth",
     summary = "Get current user information",
-    description = "Returns information about the currently authenticated user."
+    description = "Returns information about the currently authenticated user. Also accepts a personal API token with any scope."
 ))]
 pub async fn me_handler(
     State(state): State<AppState>,
@@ -296,6 +320,325 @@ pub async fn verify_email_handler(
     }))
 }
 
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/auth/verify/resend",
+    responses(
+        (status = 202, description = "Verification email sent", body = ApiResponseValue,
+         example = json!({
+             "ok": true,
+             "data": {
+                 "message": "Verification email sent"
+             }
+         })
+        ),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 422, response = ValidationErrorResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Resend verification email",
+    description = "Sends a new email verification link to the current user and invalidates earlier links. A new link can be requested at most every two minutes."
+))]
+pub async fn resend_verification_handler(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, HttpError> {
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    state
+        .auth_use_case
+        .resend_verification_email(user.id)
+        .await?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(ApiResponse::<serde_json::Value> {
+            ok: true,
+            error: None,
+            data: Some(json!({
+                "message": "Verification email sent"
+            })),
+        }),
+    ))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/auth/email",
+    request_body = ChangeEmailRequest,
+    responses(
+        (status = 202, description = "Confirmation email sent to the new address", body = ApiResponseValue,
+         example = json!({
+             "ok": true,
+             "data": {
+                 "message": "Check the new address for a confirmation link"
+             }
+         })
+        ),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 422, response = ValidationErrorResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Change email address",
+    description = "Starts an email change by sending a confirmation link to the new address. The account keeps its current email until the link is opened."
+))]
+pub async fn change_email_handler(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Json(request): Json<ChangeEmailRequest>,
+) -> Result<impl IntoResponse, HttpError> {
+    if let Err(e) = request.validate() {
+        return Err(HttpError(AppError::ValidationError {
+            message: format!("Validation failed: {}", e),
+        }));
+    }
+
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    state
+        .auth_use_case
+        .request_email_change(user.id, request.new_email, request.current_password)
+        .await?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(ApiResponse::<serde_json::Value> {
+            ok: true,
+            error: None,
+            data: Some(json!({
+                "message": "Check the new address for a confirmation link"
+            })),
+        }),
+    ))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ConfirmEmailChangeQuery {
+    pub token: String,
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/auth/email/confirm",
+    params(
+        ("token" = String, Query, description = "Email change confirmation token")
+    ),
+    responses(
+        (status = 200, description = "Email address changed", body = ApiResponseValue,
+         example = json!({
+             "ok": true,
+             "data": {
+                 "message": "Email address changed, please log in again"
+             }
+         })
+        ),
+        (status = 422, response = ValidationErrorResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Confirm email address change",
+    description = "Swaps in the new email address using the link sent to it, marks it verified and notifies the previous address. Every existing session of the account is signed out."
+))]
+pub async fn confirm_email_change_handler(
+    Query(query): Query<ConfirmEmailChangeQuery>,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, HttpError> {
+    let _user_public = state
+        .auth_use_case
+        .confirm_email_change(query.token)
+        .await?;
+
+    Ok(Json(ApiResponse::<serde_json::Value> {
+        ok: true,
+        error: None,
+        data: Some(json!({
+            "message": "Email address changed, please log in again"
+        })),
+    }))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/auth/identities",
+    responses(
+        (status = 200, description = "External accounts linked to the current user", body = Vec<UserIdentityResponse>),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "List linked accounts",
+    description = "Lists the external login providers linked to the current user."
+))]
+pub async fn list_identities_handler(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, HttpError> {
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    let identities = state.auth_use_case.list_social_identities(user.id).await?;
+
+    let identity_responses: Vec<UserIdentityResponse> = identities
+        .into_iter()
+        .map(UserIdentityResponse::from)
+        .collect();
+    Ok(Json(ApiResponse::success_with_data(Some(
+        identity_responses,
+    ))))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    delete,
+    path = "/auth/identities/{identity_id}",
+    params(
+        ("identity_id" = Uuid, Path, description = "Linked account to remove")
+    ),
+    responses(
+        (status = 204, description = "Linked account removed"),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 422, response = ValidationErrorResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Unlink an external account",
+    description = "Removes a linked login provider from the current user. The last sign-in method of an account without a password cannot be removed."
+))]
+pub async fn unlink_identity_handler(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Path(identity_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HttpError> {
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    state
+        .auth_use_case
+        .unlink_social_identity(user.id, identity_id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/auth/password/reset-request",
+    request_body = RequestPasswordResetRequest,
+    responses(
+        (status = 202, description = "Reset email sent if the account exists", body = ApiResponseValue,
+         example = json!({
+             "ok": true,
+             "data": {
+                 "message": "If an account with that email exists, a password reset link has been sent"
+             }
+         })
+        ),
+        (status = 422, response = ValidationErrorResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Request a password reset",
+    description = "Emails a single-use password reset link that expires after one hour. Requesting a new link invalidates any earlier one. The response is the same whether or not the email belongs to an account."
+))]
+pub async fn request_password_reset_handler(
+    State(state): State<AppState>,
+    Json(request): Json<RequestPasswordResetRequest>,
+) -> Result<impl IntoResponse, HttpError> {
+    if let Err(e) = request.validate() {
+        return Err(HttpError(AppError::ValidationError {
+            message: format!("Validation failed: {}", e),
+        }));
+    }
+
+    state
+        .auth_use_case
+        .request_password_reset(request.email)
+        .await?;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(ApiResponse::<serde_json::Value> {
+            ok: true,
+            error: None,
+            data: Some(json!({
+                "message": "If an account with that email exists, a password reset link has been sent"
+            })),
+        }),
+    ))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/auth/password/reset",
+    request_body = ResetPasswordRequest,
+    responses(
+        (status = 200, description = "Password reset successfully", body = ApiResponseValue,
+         example = json!({
+             "ok": true,
+             "data": {
+                 "message": "Password reset successfully, please log in again"
+             }
+         })
+        ),
+        (status = 422, response = ValidationErrorResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Reset password",
+    description = "Sets a new password using a token from a password reset email. The token can only be used once, and every existing session of the account is signed out."
+))]
+pub async fn reset_password_handler(
+    mut auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Json(request): Json<ResetPasswordRequest>,
+) -> Result<impl IntoResponse, HttpError> {
+    if let Err(e) = request.validate() {
+        return Err(HttpError(AppError::ValidationError {
+            message: format!("Validation failed: {}", e),
+        }));
+    }
+
+    let user_public = state
+        .auth_use_case
+        .reset_password(request.token, request.new_password)
+        .await?;
+
+    // Other sessions are rejected through the bumped session version; the caller's own
+    // session is dropped right away if it belongs to the same account.
+    if auth_session
+        .user
+        .as_ref()
+        .is_some_and(|user| user.id == *user_public.id.as_uuid())
+    {
+        auth_session
+            .logout()
+            .await
+            .map_err(|_| HttpError(AppError::InternalServerError))?;
+    }
+
+    Ok(Json(ApiResponse::<serde_json::Value> {
+        ok: true,
+        error: None,
+        data: Some(json!({
+            "message": "Password reset successfully, please log in again"
+        })),
+    }))
+}
+
 #[cfg_attr(feature = "docs", utoipa::path(
     put,
     path = "/auth/username",
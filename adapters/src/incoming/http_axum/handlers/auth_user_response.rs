@@ -1,6 +1,6 @@
 use time::{OffsetDateTime, format_description::well_known::Rfc3339};
 
-use domain::auth::UserPublic;
+use domain::auth::{Permission, UserPublic};
 use domain::credits::{CreditBalance, CreditConfig};
 use fedi_wplace_application::error::AppError;
 
@@ -28,13 +28,19 @@ pub async fn build_user_response(
         .map(|role| role.name.clone())
         .collect();
 
+    let permissions = Permission::ALL
+        .into_iter()
+        .filter(|permission| user_public.has_permission(*permission))
+        .map(|permission| permission.as_str().to_string())
+        .collect();
+
     let ban_status = state
         .ban_use_case
         .check_user_ban_status(&user_public.id)
         .await?;
     let (banned, ban_reason) = match ban_status {
-        Some(ban) => (true, Some(ban.reason)),
-        None => (false, None),
+        Some(ban) if !ban.shadow => (true, Some(ban.reason)),
+        _ => (false, None),
     };
 
     Ok(UserResponse {
@@ -48,6 +54,7 @@ pub async fn build_user_response(
         seconds_until_next_charge,
         max_charges: state.config.credits.max_charges,
         roles,
+        permissions,
         banned,
         ban_reason,
     })
@@ -1,8 +1,11 @@
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
 };
 use axum_login::AuthSession;
+use ipnet::IpNet;
+use serde::Deserialize;
+use std::net::IpAddr;
 use time::format_description::well_known::Rfc3339;
 use tracing::instrument;
 use uuid::Uuid;
@@ -10,26 +13,43 @@ use uuid::Uuid;
 use crate::incoming::http_axum::{
     auth::backend::AuthBackend,
     dto::{
-        requests::BanUserRequest,
-        responses::{ApiResponse, BanResponse},
+        requests::{BanIpRequest, BanUserRequest},
+        responses::{
+            ApiResponse, BanResponse, IpBanResponse, QuarantineReviewResponse,
+            QuarantinedPixelResponse,
+        },
     },
     error_mapper::HttpError,
+    middleware::client_ip::ClientIp,
 };
 use crate::shared::app_state::AppState;
-use domain::{auth::UserId, ban::Ban};
+use domain::{
+    auth::{Permission, UserId},
+    ban::{Ban, BanError, IpBan, IpBanId, QuarantinedPixel},
+};
 use fedi_wplace_application::error::AppError;
 
-fn format_datetime(dt: time::OffsetDateTime) -> String {
+pub(crate) fn format_datetime(dt: time::OffsetDateTime) -> String {
     dt.format(&Rfc3339).unwrap_or_else(|_| dt.to_string())
 }
 
-fn parse_datetime_string(datetime_str: &str) -> Result<time::OffsetDateTime, AppError> {
+pub(crate) fn parse_datetime_string(datetime_str: &str) -> Result<time::OffsetDateTime, AppError> {
     time::OffsetDateTime::parse(datetime_str, &Rfc3339).map_err(|_| AppError::ValidationError {
         message: "Invalid datetime format. Expected RFC3339 format (e.g., 2024-12-31T23:59:59Z)"
             .to_string(),
     })
 }
 
+fn parse_network(network_str: &str) -> Result<IpNet, AppError> {
+    let network_str = network_str.trim();
+    network_str
+        .parse::<IpNet>()
+        .or_else(|_| network_str.parse::<IpAddr>().map(IpNet::from))
+        .map_err(|_| AppError::ValidationError {
+            message: BanError::InvalidNetwork.to_string(),
+        })
+}
+
 impl From<Ban> for BanResponse {
     fn from(ban: Ban) -> Self {
         Self {
@@ -40,6 +60,42 @@ impl From<Ban> for BanResponse {
             banned_at: format_datetime(ban.banned_at),
             expires_at: ban.expires_at.map(format_datetime),
             created_at: format_datetime(ban.created_at),
+            revoked_at: ban.revoked_at.map(format_datetime),
+            revoked_by_user_id: ban.revoked_by_user_id.map(|id| *id.as_uuid()),
+            revoke_reason: ban.revoke_reason,
+            shadow: ban.shadow,
+        }
+    }
+}
+
+impl From<QuarantinedPixel> for QuarantinedPixelResponse {
+    fn from(pixel: QuarantinedPixel) -> Self {
+        Self {
+            id: pixel.id,
+            ban_id: *pixel.ban_id.as_uuid(),
+            x: pixel.action.global_coord.x,
+            y: pixel.action.global_coord.y,
+            color_id: pixel.action.color_id.id(),
+            painted_at: format_datetime(pixel.action.timestamp),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UnbanUserQuery {
+    pub reason: Option<String>,
+}
+
+impl From<IpBan> for IpBanResponse {
+    fn from(ip_ban: IpBan) -> Self {
+        Self {
+            id: *ip_ban.id.as_uuid(),
+            network: ip_ban.network.to_string(),
+            banned_by_user_id: ip_ban.banned_by_user_id.map(|id| *id.as_uuid()),
+            reason: ip_ban.reason,
+            banned_at: format_datetime(ip_ban.banned_at),
+            expires_at: ip_ban.expires_at.map(format_datetime),
+            created_at: format_datetime(ip_ban.created_at),
         }
     }
 }
@@ -53,7 +109,7 @@ impl From<Ban> for BanResponse {
         (status = 200, description = "User banned successfully", body = BanResponse),
         (status = 400, description = "Invalid request data"),
         (status = 401, description = "Not authenticated"),
-        (status = 403, description = "Not authorized (admin role required)"),
+        (status = 403, description = "Not authorized (ban:create permission required)"),
         (status = 404, description = "User not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -65,12 +121,13 @@ impl From<Ban> for BanResponse {
 pub async fn ban_user(
     auth_session: AuthSession<AuthBackend>,
     State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
     Path(user_id): Path<Uuid>,
     Json(request): Json<BanUserRequest>,
 ) -> Result<Json<ApiResponse<BanResponse>>, HttpError> {
     let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
 
-    if !current_user.is_admin() {
+    if !current_user.has_permission(Permission::BanCreate) {
         return Err(HttpError(AppError::Forbidden));
     }
 
@@ -89,6 +146,8 @@ pub async fn ban_user(
             banned_by_user_id,
             request.reason,
             expires_at,
+            request.shadow,
+            Some(client_ip),
         )
         .await?;
 
@@ -106,11 +165,14 @@ pub async fn ban_user(
     delete,
     path = "/admin/users/{user_id}/ban",
     tag = "admin",
+    params(
+        ("reason" = Option<String>, Query, description = "Reason for revoking the ban, kept in the ban history")
+    ),
     responses(
         (status = 200, description = "User unbanned successfully"),
         (status = 400, description = "User is not banned"),
         (status = 401, description = "Not authenticated"),
-        (status = 403, description = "Not authorized (admin role required)"),
+        (status = 403, description = "Not authorized (ban:create permission required)"),
         (status = 404, description = "User not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -122,11 +184,13 @@ pub async fn ban_user(
 pub async fn unban_user(
     auth_session: AuthSession<AuthBackend>,
     State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
     Path(user_id): Path<Uuid>,
+    Query(query): Query<UnbanUserQuery>,
 ) -> Result<Json<ApiResponse<()>>, HttpError> {
     let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
 
-    if !current_user.is_admin() {
+    if !current_user.has_permission(Permission::BanCreate) {
         return Err(HttpError(AppError::Forbidden));
     }
 
@@ -135,7 +199,7 @@ pub async fn unban_user(
 
     state
         .ban_use_case
-        .unban_user(target_user_id, unbanned_by)
+        .unban_user(target_user_id, unbanned_by, query.reason, Some(client_ip))
         .await?;
 
     let response = ApiResponse::success();
@@ -149,7 +213,7 @@ pub async fn unban_user(
     responses(
         (status = 200, description = "List of active bans", body = Vec<BanResponse>),
         (status = 401, description = "Not authenticated"),
-        (status = 403, description = "Not authorized (admin role required)"),
+        (status = 403, description = "Not authorized (ban:create permission required)"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -163,7 +227,7 @@ pub async fn list_active_bans(
 ) -> Result<Json<ApiResponse<Vec<BanResponse>>>, HttpError> {
     let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
 
-    if !current_user.is_admin() {
+    if !current_user.has_permission(Permission::BanCreate) {
         return Err(HttpError(AppError::Forbidden));
     }
 
@@ -185,7 +249,7 @@ pub async fn list_active_bans(
     responses(
         (status = 200, description = "User ban status", body = BanResponse),
         (status = 401, description = "Not authenticated"),
-        (status = 403, description = "Not authorized (admin role required)"),
+        (status = 403, description = "Not authorized (ban:create permission required)"),
         (status = 404, description = "User not banned or not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -201,7 +265,7 @@ pub async fn get_user_ban_status(
 ) -> Result<Json<ApiResponse<BanResponse>>, HttpError> {
     let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
 
-    if !current_user.is_admin() {
+    if !current_user.has_permission(Permission::BanCreate) {
         return Err(HttpError(AppError::Forbidden));
     }
 
@@ -221,3 +285,296 @@ pub async fn get_user_ban_status(
         })),
     }
 }
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/admin/users/{user_id}/bans",
+    tag = "admin",
+    responses(
+        (status = 200, description = "All bans of the user, newest first, including expired and revoked ones", body = Vec<BanResponse>),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (ban:create permission required)"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn get_user_ban_history(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<BanResponse>>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::BanCreate) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let target_user_id = UserId::from_uuid(user_id);
+    let requesting_user_id = UserId::from_uuid(current_user.id);
+    let bans = state
+        .ban_use_case
+        .get_ban_history(target_user_id, requesting_user_id)
+        .await?;
+
+    let ban_responses: Vec<BanResponse> = bans.into_iter().map(BanResponse::from).collect();
+    let response = ApiResponse::success_with_data(Some(ban_responses));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/admin/ip-bans",
+    tag = "admin",
+    request_body = BanIpRequest,
+    responses(
+        (status = 200, description = "IP address or network banned successfully", body = IpBanResponse),
+        (status = 400, description = "Invalid request data or network already banned"),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (ban:create permission required)"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn ban_ip(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Json(request): Json<BanIpRequest>,
+) -> Result<Json<ApiResponse<IpBanResponse>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::BanCreate) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let network = parse_network(&request.network)?;
+    let banned_by_user_id = UserId::from_uuid(current_user.id);
+
+    let expires_at = match request.expires_at {
+        Some(expires_str) => Some(parse_datetime_string(&expires_str)?),
+        None => None,
+    };
+
+    let ip_ban = state
+        .ip_ban_use_case
+        .ban_network(
+            network,
+            banned_by_user_id,
+            request.reason,
+            expires_at,
+            Some(client_ip),
+        )
+        .await?;
+
+    let response = ApiResponse::success_with_data(Some(IpBanResponse::from(ip_ban)));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    delete,
+    path = "/admin/ip-bans/{ban_id}",
+    tag = "admin",
+    responses(
+        (status = 200, description = "IP ban removed successfully"),
+        (status = 400, description = "IP ban not found or already expired"),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (ban:create permission required)"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn unban_ip(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Path(ban_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::BanCreate) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let unbanned_by = UserId::from_uuid(current_user.id);
+
+    state
+        .ip_ban_use_case
+        .unban_network(IpBanId::from_uuid(ban_id), unbanned_by, Some(client_ip))
+        .await?;
+
+    let response = ApiResponse::success();
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/admin/ip-bans",
+    tag = "admin",
+    responses(
+        (status = 200, description = "List of active IP and network bans", body = Vec<IpBanResponse>),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (ban:create permission required)"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn list_active_ip_bans(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<Vec<IpBanResponse>>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::BanCreate) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let requesting_user_id = UserId::from_uuid(current_user.id);
+    let ip_bans = state
+        .ip_ban_use_case
+        .get_active_ip_bans(requesting_user_id)
+        .await?;
+
+    let ip_ban_responses: Vec<IpBanResponse> =
+        ip_bans.into_iter().map(IpBanResponse::from).collect();
+    let response = ApiResponse::success_with_data(Some(ip_ban_responses));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/admin/users/{user_id}/quarantine",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Pixels painted by the user while shadow-banned, oldest first", body = Vec<QuarantinedPixelResponse>),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (ban:create permission required)"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn list_quarantined_pixels(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<QuarantinedPixelResponse>>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::BanCreate) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let target_user_id = UserId::from_uuid(user_id);
+    let requesting_user_id = UserId::from_uuid(current_user.id);
+    let pixels = state
+        .quarantine_use_case
+        .list_quarantined_pixels(target_user_id, requesting_user_id)
+        .await?;
+
+    let pixel_responses: Vec<QuarantinedPixelResponse> = pixels
+        .into_iter()
+        .map(QuarantinedPixelResponse::from)
+        .collect();
+    let response = ApiResponse::success_with_data(Some(pixel_responses));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    delete,
+    path = "/admin/users/{user_id}/quarantine",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Quarantined pixels discarded", body = QuarantineReviewResponse),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (ban:create permission required)"),
+        (status = 422, description = "User has no quarantined pixels"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn discard_quarantined_pixels(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<QuarantineReviewResponse>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::BanCreate) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let target_user_id = UserId::from_uuid(user_id);
+    let moderator_id = UserId::from_uuid(current_user.id);
+    let pixels_discarded = state
+        .quarantine_use_case
+        .discard_quarantined_pixels(target_user_id, moderator_id, Some(client_ip))
+        .await?;
+
+    let response = ApiResponse::success_with_data(Some(QuarantineReviewResponse {
+        pixels_discarded,
+        pixels_applied: 0,
+        tiles_affected: 0,
+    }));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/admin/users/{user_id}/quarantine/promote",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Quarantined pixels applied to the canvas", body = QuarantineReviewResponse),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (ban:create permission required)"),
+        (status = 422, description = "User has no quarantined pixels"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn promote_quarantined_pixels(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<QuarantineReviewResponse>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::BanCreate) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let target_user_id = UserId::from_uuid(user_id);
+    let moderator_id = UserId::from_uuid(current_user.id);
+    let result = state
+        .quarantine_use_case
+        .promote_quarantined_pixels(target_user_id, moderator_id, Some(client_ip))
+        .await?;
+
+    let response = ApiResponse::success_with_data(Some(QuarantineReviewResponse {
+        pixels_discarded: 0,
+        pixels_applied: result.pixels_applied,
+        tiles_affected: result.tiles_affected,
+    }));
+    Ok(Json(response))
+}
@@ -2,9 +2,17 @@ pub(crate) mod auth_user_response;
 pub(crate) mod palette;
 
 // keep public for OpenAPI docs
+pub mod account;
 pub mod admin;
+pub mod api_tokens;
+pub mod audit;
 pub mod auth;
 pub mod ban;
 pub mod health;
 pub mod pixel_info;
+pub mod protected_regions;
+pub mod reports;
+pub mod rules;
+pub mod sessions;
 pub mod tiles;
+pub mod two_factor;
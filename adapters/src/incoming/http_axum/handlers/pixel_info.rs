@@ -18,14 +18,14 @@ use crate::incoming::http_axum::dto::common_responses::{
     get,
     path = "/pixel/{x}/{y}",
     responses(
-        (status = 200, body = Option<PixelInfoResponse>, description = "Pixel information found", example = json!({"user_id": "12345", "username": "alice", "color_id": 15, "timestamp": "2025-09-10T12:34:56.789Z"})),
+        (status = 200, body = Option<PixelInfoResponse>, description = "Pixel information found", example = json!({"user_id": "12345", "username": "alice", "color_id": 15, "timestamp": "2025-09-10T12:34:56.789Z", "protected_for_seconds": 42})),
         (status = 400, response = BadRequestResponse),
         (status = 429, response = RateLimitExceededResponse),
         (status = 500, response = InternalServerErrorResponse)
     ),
     tag = "pixel",
     summary = "Get pixel information",
-    description = "Retrieve information about a specific pixel at the given global coordinates. Returns the user who last painted it, the color, and timestamp, and other info, or null if the pixel has never been painted.",
+    description = "Retrieve information about a specific pixel at the given global coordinates. Returns the user who last painted it (null once their account has been deleted), the color, and timestamp, how many seconds the pixel is still protected from being painted over by others, and other info, or null if the pixel has never been painted.",
     operation_id = "get_pixel_info"
 ))]
 pub async fn get_pixel_info(
@@ -46,6 +46,10 @@ pub async fn get_pixel_info(
         username: info.username,
         color_id: info.color_id,
         timestamp: info.timestamp.to_string(),
+        automated: info.automated,
+        protected_for_seconds: info
+            .protection_remaining
+            .map(|remaining| remaining.whole_seconds().max(1)),
     });
 
     Ok(Json(pixel_info_response))
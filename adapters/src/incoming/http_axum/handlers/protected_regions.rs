@@ -0,0 +1,258 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use axum_login::AuthSession;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::incoming::http_axum::{
+    auth::backend::AuthBackend,
+    dto::{
+        requests::{PaintRestrictionRequest, ProtectedAreaRequest, ProtectedRegionRequest},
+        responses::{ApiResponse, ProtectedRegionResponse},
+    },
+    error_mapper::HttpError,
+    handlers::ban::{format_datetime, parse_datetime_string},
+    middleware::client_ip::ClientIp,
+};
+use crate::shared::app_state::AppState;
+use domain::{
+    auth::{Permission, UserId},
+    coords::GlobalRegion,
+    protected_region::{PaintRestriction, ProtectedArea, ProtectedRegion, ProtectedRegionId},
+};
+use fedi_wplace_application::{
+    error::AppError, ports::incoming::protected_regions::ProtectedRegionDraft,
+};
+
+impl From<ProtectedRegion> for ProtectedRegionResponse {
+    fn from(region: ProtectedRegion) -> Self {
+        let bounds = region.area.bounding_box();
+        let shape = region.area.shape_str().to_string();
+        let restriction = region.restriction.kind().as_str().to_string();
+        let vertices = match region.area {
+            ProtectedArea::Rectangle(_) => None,
+            ProtectedArea::Polygon(vertices) => Some(vertices),
+        };
+        let (allowed_roles, opens_at, closes_at) = match region.restriction {
+            PaintRestriction::Locked => (Vec::new(), None, None),
+            PaintRestriction::RolesOnly { role_names } => (role_names, None, None),
+            PaintRestriction::TimeWindow {
+                opens_at,
+                closes_at,
+            } => (
+                Vec::new(),
+                Some(format_datetime(opens_at)),
+                Some(format_datetime(closes_at)),
+            ),
+        };
+
+        Self {
+            id: *region.id.as_uuid(),
+            name: region.name,
+            description: region.description,
+            shape,
+            bounds,
+            vertices,
+            restriction,
+            allowed_roles,
+            opens_at,
+            closes_at,
+            created_at: format_datetime(region.created_at),
+            updated_at: format_datetime(region.updated_at),
+        }
+    }
+}
+
+impl TryFrom<ProtectedRegionRequest> for ProtectedRegionDraft {
+    type Error = AppError;
+
+    fn try_from(request: ProtectedRegionRequest) -> Result<Self, Self::Error> {
+        let area = match request.area {
+            ProtectedAreaRequest::Rectangle {
+                x,
+                y,
+                width,
+                height,
+            } => ProtectedArea::Rectangle(GlobalRegion::from_origin_and_size(x, y, width, height)?),
+            ProtectedAreaRequest::Polygon { vertices } => ProtectedArea::Polygon(vertices),
+        };
+
+        let restriction = match request.restriction {
+            PaintRestrictionRequest::Locked => PaintRestriction::Locked,
+            PaintRestrictionRequest::RolesOnly { roles } => {
+                PaintRestriction::RolesOnly { role_names: roles }
+            }
+            PaintRestrictionRequest::TimeWindow {
+                opens_at,
+                closes_at,
+            } => PaintRestriction::TimeWindow {
+                opens_at: parse_datetime_string(&opens_at)?,
+                closes_at: parse_datetime_string(&closes_at)?,
+            },
+        };
+
+        Ok(Self {
+            name: request.name,
+            description: request.description,
+            area,
+            restriction,
+        })
+    }
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/regions",
+    tag = "regions",
+    responses(
+        (status = 200, description = "All protected regions of the canvas, oldest first", body = Vec<ProtectedRegionResponse>),
+        (status = 500, description = "Internal server error")
+    )
+))]
+#[instrument(skip(state))]
+pub async fn list_protected_regions(
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<Vec<ProtectedRegionResponse>>>, HttpError> {
+    let regions = state
+        .protected_region_use_case
+        .list_protected_regions()
+        .await?;
+
+    let region_responses: Vec<ProtectedRegionResponse> = regions
+        .into_iter()
+        .map(ProtectedRegionResponse::from)
+        .collect();
+    let response = ApiResponse::success_with_data(Some(region_responses));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/admin/regions",
+    tag = "admin",
+    request_body = ProtectedRegionRequest,
+    responses(
+        (status = 200, description = "Protected region created", body = ProtectedRegionResponse),
+        (status = 400, description = "Region exceeds the canvas coordinate range"),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (regions:manage permission required)"),
+        (status = 422, description = "Invalid name, shape, time window or unknown role"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn create_protected_region(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Json(request): Json<ProtectedRegionRequest>,
+) -> Result<Json<ApiResponse<ProtectedRegionResponse>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::RegionsManage) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let draft = ProtectedRegionDraft::try_from(request)?;
+
+    let region = state
+        .protected_region_use_case
+        .create_protected_region(draft, UserId::from_uuid(current_user.id), Some(client_ip))
+        .await?;
+
+    let response = ApiResponse::success_with_data(Some(ProtectedRegionResponse::from(region)));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    put,
+    path = "/admin/regions/{region_id}",
+    tag = "admin",
+    request_body = ProtectedRegionRequest,
+    responses(
+        (status = 200, description = "Protected region replaced", body = ProtectedRegionResponse),
+        (status = 400, description = "Region exceeds the canvas coordinate range"),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (regions:manage permission required)"),
+        (status = 422, description = "Region not found, or invalid name, shape, time window or unknown role"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn update_protected_region(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Path(region_id): Path<Uuid>,
+    Json(request): Json<ProtectedRegionRequest>,
+) -> Result<Json<ApiResponse<ProtectedRegionResponse>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::RegionsManage) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let draft = ProtectedRegionDraft::try_from(request)?;
+
+    let region = state
+        .protected_region_use_case
+        .update_protected_region(
+            ProtectedRegionId::from_uuid(region_id),
+            draft,
+            UserId::from_uuid(current_user.id),
+            Some(client_ip),
+        )
+        .await?;
+
+    let response = ApiResponse::success_with_data(Some(ProtectedRegionResponse::from(region)));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    delete,
+    path = "/admin/regions/{region_id}",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Protected region deleted"),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (regions:manage permission required)"),
+        (status = 422, description = "Region not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn delete_protected_region(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Path(region_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<()>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::RegionsManage) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    state
+        .protected_region_use_case
+        .delete_protected_region(
+            ProtectedRegionId::from_uuid(region_id),
+            UserId::from_uuid(current_user.id),
+            Some(client_ip),
+        )
+        .await?;
+
+    let response = ApiResponse::success();
+    Ok(Json(response))
+}
@@ -0,0 +1,364 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use axum_login::AuthSession;
+use serde::Deserialize;
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::incoming::http_axum::{
+    auth::backend::AuthBackend,
+    dto::{
+        requests::{
+            CreateReportRequest, DismissReportRequest, ReportTargetRequest, ResolveReportRequest,
+        },
+        responses::{ApiResponse, ReportResponse},
+    },
+    error_mapper::HttpError,
+    handlers::ban::{format_datetime, parse_datetime_string},
+    middleware::client_ip::ClientIp,
+};
+use crate::shared::app_state::AppState;
+use domain::{
+    auth::{Permission, UserId},
+    coords::{GlobalCoord, GlobalRegion},
+    report::{Report, ReportError, ReportId, ReportResolutionAction, ReportStatus, ReportTarget},
+};
+use fedi_wplace_application::{
+    error::AppError,
+    ports::{incoming::reports::ReportResolution, outgoing::report_store::ReportQuery},
+};
+
+const DEFAULT_REPORT_PAGE_SIZE: u32 = 50;
+const MAX_REPORT_PAGE_SIZE: u32 = 200;
+
+impl From<Report> for ReportResponse {
+    fn from(report: Report) -> Self {
+        let (region, reported_user_id) = match &report.target {
+            ReportTarget::User(user_id) => (None, Some(*user_id.as_uuid())),
+            target => (target.region(), None),
+        };
+
+        Self {
+            id: *report.id.as_uuid(),
+            reporter_user_id: *report.reporter_user_id.as_uuid(),
+            target_type: report.target.type_str().to_string(),
+            region,
+            reported_user_id,
+            target_user_id: report.target_user_id.map(|id| *id.as_uuid()),
+            category: report.category.as_str().to_string(),
+            comment: report.comment,
+            status: report.status.as_str().to_string(),
+            claimed_by_user_id: report.claimed_by_user_id.map(|id| *id.as_uuid()),
+            claimed_at: report.claimed_at.map(format_datetime),
+            resolved_by_user_id: report.resolved_by_user_id.map(|id| *id.as_uuid()),
+            resolved_at: report.resolved_at.map(format_datetime),
+            resolution_action: report
+                .resolution_action
+                .map(|action| action.as_str().to_string()),
+            resolution_note: report.resolution_note,
+            created_at: format_datetime(report.created_at),
+        }
+    }
+}
+
+impl TryFrom<ReportTargetRequest> for ReportTarget {
+    type Error = AppError;
+
+    fn try_from(target: ReportTargetRequest) -> Result<Self, Self::Error> {
+        match target {
+            ReportTargetRequest::Pixel { x, y } => Ok(ReportTarget::Pixel(GlobalCoord::new(x, y))),
+            ReportTargetRequest::Region {
+                x,
+                y,
+                width,
+                height,
+            } => Ok(ReportTarget::Region(GlobalRegion::from_origin_and_size(
+                x, y, width, height,
+            )?)),
+            ReportTargetRequest::User { user_id } => {
+                Ok(ReportTarget::User(UserId::from_uuid(user_id)))
+            }
+        }
+    }
+}
+
+fn parse_optional_datetime(value: Option<&str>) -> Result<Option<time::OffsetDateTime>, AppError> {
+    value.map(parse_datetime_string).transpose()
+}
+
+fn parse_resolution(request: &ResolveReportRequest) -> Result<ReportResolution, AppError> {
+    let action = request
+        .action
+        .parse::<ReportResolutionAction>()
+        .map_err(|e| AppError::ValidationError {
+            message: e.to_string(),
+        })?;
+
+    match action {
+        ReportResolutionAction::NoAction => Ok(ReportResolution::NoAction),
+        ReportResolutionAction::BanUser => {
+            let reason = request
+                .ban_reason
+                .clone()
+                .ok_or_else(|| AppError::ValidationError {
+                    message: "ban_reason is required for the ban_user action".to_string(),
+                })?;
+            Ok(ReportResolution::BanUser {
+                reason,
+                expires_at: parse_optional_datetime(request.ban_expires_at.as_deref())?,
+            })
+        }
+        ReportResolutionAction::RollbackRegion => Ok(ReportResolution::RollbackRegion {
+            painted_since: parse_optional_datetime(request.rollback_since.as_deref())?,
+            only_target_user: request.rollback_only_target_user,
+        }),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListReportsQuery {
+    pub status: Option<String>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/reports",
+    tag = "reports",
+    request_body = CreateReportRequest,
+    responses(
+        (status = 200, description = "Report submitted", body = ReportResponse),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "IP address is banned"),
+        (status = 422, description = "Invalid target, category or comment, duplicate report or too many open reports"),
+        (status = 429, description = "Rate limit exceeded"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn create_report(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Json(request): Json<CreateReportRequest>,
+) -> Result<Json<ApiResponse<ReportResponse>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    let target = ReportTarget::try_from(request.target)?;
+    let category =
+        request
+            .category
+            .parse()
+            .map_err(|e: ReportError| AppError::ValidationError {
+                message: e.to_string(),
+            })?;
+
+    let report = state
+        .report_use_case
+        .submit_report(
+            UserId::from_uuid(current_user.id),
+            target,
+            category,
+            request.comment,
+        )
+        .await?;
+
+    let response = ApiResponse::success_with_data(Some(ReportResponse::from(report)));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/admin/reports",
+    tag = "admin",
+    params(
+        ("status" = Option<String>, Query, description = "Only list reports in this status (open, claimed, resolved or dismissed)"),
+        ("limit" = Option<u32>, Query, description = "Page size, at most 200 (default 50)"),
+        ("offset" = Option<u32>, Query, description = "Number of reports to skip")
+    ),
+    responses(
+        (status = 200, description = "Reports, oldest first", body = Vec<ReportResponse>),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (reports:resolve permission required)"),
+        (status = 422, description = "Invalid status filter"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn list_reports(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Query(query): Query<ListReportsQuery>,
+) -> Result<Json<ApiResponse<Vec<ReportResponse>>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::ReportsResolve) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let status = query
+        .status
+        .as_deref()
+        .map(str::parse::<ReportStatus>)
+        .transpose()
+        .map_err(|e| AppError::ValidationError {
+            message: e.to_string(),
+        })?;
+
+    let report_query = ReportQuery {
+        status,
+        limit: query
+            .limit
+            .unwrap_or(DEFAULT_REPORT_PAGE_SIZE)
+            .clamp(1, MAX_REPORT_PAGE_SIZE),
+        offset: query.offset.unwrap_or(0),
+    };
+
+    let reports = state
+        .report_use_case
+        .list_reports(UserId::from_uuid(current_user.id), report_query)
+        .await?;
+
+    let report_responses: Vec<ReportResponse> =
+        reports.into_iter().map(ReportResponse::from).collect();
+    let response = ApiResponse::success_with_data(Some(report_responses));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/admin/reports/{report_id}/claim",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Report claimed", body = ReportResponse),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (reports:resolve permission required)"),
+        (status = 422, description = "Report not found, already closed or claimed by another moderator"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn claim_report(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Path(report_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ReportResponse>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::ReportsResolve) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let report = state
+        .report_use_case
+        .claim_report(
+            ReportId::from_uuid(report_id),
+            UserId::from_uuid(current_user.id),
+        )
+        .await?;
+
+    let response = ApiResponse::success_with_data(Some(ReportResponse::from(report)));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/admin/reports/{report_id}/resolve",
+    tag = "admin",
+    request_body = ResolveReportRequest,
+    responses(
+        (status = 200, description = "Report resolved and the resolution action applied", body = ReportResponse),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (reports:resolve permission required)"),
+        (status = 422, description = "Invalid action, report already closed or claimed by another moderator, or the action does not apply to the report"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn resolve_report(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Path(report_id): Path<Uuid>,
+    Json(request): Json<ResolveReportRequest>,
+) -> Result<Json<ApiResponse<ReportResponse>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::ReportsResolve) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let resolution = parse_resolution(&request)?;
+
+    let report = state
+        .report_use_case
+        .resolve_report(
+            ReportId::from_uuid(report_id),
+            UserId::from_uuid(current_user.id),
+            resolution,
+            request.note,
+            Some(client_ip),
+        )
+        .await?;
+
+    let response = ApiResponse::success_with_data(Some(ReportResponse::from(report)));
+    Ok(Json(response))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/admin/reports/{report_id}/dismiss",
+    tag = "admin",
+    request_body = DismissReportRequest,
+    responses(
+        (status = 200, description = "Report dismissed", body = ReportResponse),
+        (status = 401, description = "Not authenticated"),
+        (status = 403, description = "Not authorized (reports:resolve permission required)"),
+        (status = 422, description = "Report not found, already closed or claimed by another moderator"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("session" = [])
+    )
+))]
+#[instrument(skip(auth_session, state))]
+pub async fn dismiss_report(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    Path(report_id): Path<Uuid>,
+    Json(request): Json<DismissReportRequest>,
+) -> Result<Json<ApiResponse<ReportResponse>>, HttpError> {
+    let current_user = auth_session.user.ok_or(HttpError(AppError::Unauthorized))?;
+
+    if !current_user.has_permission(Permission::ReportsResolve) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    let report = state
+        .report_use_case
+        .dismiss_report(
+            ReportId::from_uuid(report_id),
+            UserId::from_uuid(current_user.id),
+            request.note,
+            Some(client_ip),
+        )
+        .await?;
+
+    let response = ApiResponse::success_with_data(Some(ReportResponse::from(report)));
+    Ok(Json(response))
+}
@@ -0,0 +1,39 @@
+use axum::{Json, extract::State};
+use tracing::instrument;
+
+use crate::incoming::http_axum::{
+    dto::responses::{ApiResponse, PaintRuleResponse},
+    error_mapper::HttpError,
+};
+use crate::shared::app_state::AppState;
+use domain::rules::PaintRule;
+
+impl From<PaintRule> for PaintRuleResponse {
+    fn from(rule: PaintRule) -> Self {
+        Self {
+            rule: rule.kind().as_str().to_string(),
+            description: rule.describe(),
+        }
+    }
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/rules",
+    tag = "rules",
+    responses(
+        (status = 200, description = "Paint rules in the order they are checked", body = Vec<PaintRuleResponse>),
+        (status = 500, description = "Internal server error")
+    )
+))]
+#[instrument(skip(state))]
+pub async fn list_paint_rules(
+    State(state): State<AppState>,
+) -> Result<Json<ApiResponse<Vec<PaintRuleResponse>>>, HttpError> {
+    let rules = state.paint_rules_query_service.list_paint_rules().await?;
+
+    let rule_responses: Vec<PaintRuleResponse> =
+        rules.into_iter().map(PaintRuleResponse::from).collect();
+    let response = ApiResponse::success_with_data(Some(rule_responses));
+    Ok(Json(response))
+}
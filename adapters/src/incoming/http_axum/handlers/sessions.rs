@@ -0,0 +1,148 @@
+#[cfg(feature = "docs")]
+use crate::incoming::http_axum::dto::common_responses::{
+    InternalServerErrorResponse, RateLimitExceededResponse, UnauthorizedResponse,
+    ValidationErrorResponse,
+};
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use axum_login::AuthSession;
+use fedi_wplace_application::error::AppError;
+use tower_sessions::Session;
+#[cfg(feature = "docs")]
+use utoipa;
+use uuid::Uuid;
+
+use crate::{
+    incoming::http_axum::{
+        auth::{backend::AuthBackend, session::current_session_id},
+        dto::responses::{ApiResponse, RevokedSessionsResponse, UserSessionResponse},
+        error_mapper::HttpError,
+        handlers::ban::format_datetime,
+    },
+    shared::app_state::AppState,
+};
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/auth/sessions",
+    responses(
+        (status = 200, description = "Signed-in sessions of the current user", body = [UserSessionResponse]),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "List sessions",
+    description = "Lists the current user's signed-in sessions with their device, address and last activity, most recently used first."
+))]
+pub async fn list_sessions_handler(
+    auth_session: AuthSession<AuthBackend>,
+    session: Session,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, HttpError> {
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    let current_id = current_session_id(&session, user.id).await?;
+    let sessions = state
+        .session_management_use_case
+        .list_sessions(user.id)
+        .await?;
+
+    let session_responses: Vec<UserSessionResponse> = sessions
+        .into_iter()
+        .map(|user_session| UserSessionResponse {
+            id: user_session.id,
+            user_agent: user_session.user_agent,
+            ip_address: user_session.ip_address.map(|ip| ip.to_string()),
+            created_at: format_datetime(user_session.created_at),
+            last_seen_at: format_datetime(user_session.last_seen_at),
+            current: current_id == Some(user_session.id),
+        })
+        .collect();
+
+    Ok(Json(ApiResponse::success_with_data(Some(
+        session_responses,
+    ))))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    delete,
+    path = "/auth/sessions/{session_id}",
+    params(
+        ("session_id" = Uuid, Path, description = "Session to sign out")
+    ),
+    responses(
+        (status = 204, description = "Session signed out"),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 422, response = ValidationErrorResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Revoke a session",
+    description = "Signs out one of the current user's sessions. Revoking the session that makes the request also logs it out."
+))]
+pub async fn revoke_session_handler(
+    mut auth_session: AuthSession<AuthBackend>,
+    session: Session,
+    State(state): State<AppState>,
+    Path(session_id): Path<Uuid>,
+) -> Result<impl IntoResponse, HttpError> {
+    let Some(user) = auth_session.user.as_ref() else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+    let user_id = user.id;
+
+    state
+        .session_management_use_case
+        .revoke_session(user_id, session_id)
+        .await?;
+
+    if current_session_id(&session, user_id).await? == Some(session_id) {
+        auth_session
+            .logout()
+            .await
+            .map_err(|_| HttpError(AppError::InternalServerError))?;
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    delete,
+    path = "/auth/sessions",
+    responses(
+        (status = 200, description = "Other sessions signed out", body = RevokedSessionsResponse),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Log out everywhere else",
+    description = "Signs out every session of the current user except the one making the request. Use /auth/logout to end that one as well."
+))]
+pub async fn revoke_other_sessions_handler(
+    auth_session: AuthSession<AuthBackend>,
+    session: Session,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, HttpError> {
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    let current_id = current_session_id(&session, user.id).await?;
+    let revoked_sessions = state
+        .session_management_use_case
+        .revoke_all_sessions(user.id, current_id)
+        .await?;
+
+    Ok(Json(ApiResponse::success_with_data(Some(
+        RevokedSessionsResponse { revoked_sessions },
+    ))))
+}
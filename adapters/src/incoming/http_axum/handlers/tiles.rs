@@ -1,5 +1,5 @@
 use axum::{
-    Json,
+    Extension, Json,
     extract::State,
     http::{HeaderMap, StatusCode},
     response::{IntoResponse, Response},
@@ -21,6 +21,7 @@ use crate::incoming::http_axum::{
         responses::{PaintPixelResponse, TileHeadersResponse, TileImageResponse},
     },
     error_mapper::HttpError,
+    middleware::api_token::ApiTokenAuth,
 };
 use crate::shared::app_state::AppState;
 use fedi_wplace_application::ports::incoming::tiles::{PaintPixelsUseCase, TilesQueryUseCase};
@@ -153,13 +154,14 @@ pub async fn serve_tile_head(
     ),
     tag = "painting",
     summary = "Paint multiple pixels",
-    description = "Paint multiple pixels at once within a single tile. All pixels are painted atomically with a single version increment. Supports up to 1000 pixels per batch. Emits WebSocket tile-version for all painted pixels. Requires authentication.",
+    description = "Paint multiple pixels at once within a single tile. All pixels are painted atomically with a single version increment. Supports up to 1000 pixels per batch. Emits WebSocket tile-version for all painted pixels. Batches touching a protected region the user may not paint are rejected with 403 before any credits are spent, as are batches breaking one of the paint rules listed at /rules (the error then names the rule) and batches painting over pixels another user painted within the fresh pixel protection window, unless the instance charges extra credits for such overwrites instead. Requires authentication, either a session or a personal API token with the paint scope; pixels painted with a token are flagged as automated in pixel history.",
     operation_id = "paint_pixels_batch"
 ))]
 pub async fn paint_pixels_batch(
     tile_path: TilePath,
     State(state): State<AppState>,
     auth_session: AuthSession<AuthBackend>,
+    api_token: Option<Extension<ApiTokenAuth>>,
     Valid(Json(paint_req)): Valid<Json<BatchPaintPixelsRequest>>,
 ) -> Result<Json<PaintPixelResponse>, HttpError> {
     let Some(user) = auth_session.user else {
@@ -176,7 +178,12 @@ pub async fn paint_pixels_batch(
 
     let paint_uc: &dyn PaintPixelsUseCase = &*state.paint_pixels_service;
     let painting_result = paint_uc
-        .paint_pixels_batch(UserId::from_uuid(user.id), tile_coord, &pixels)
+        .paint_pixels_batch(
+            UserId::from_uuid(user.id),
+            tile_coord,
+            &pixels,
+            api_token.is_some(),
+        )
         .await
         .map_err(HttpError)?;
 
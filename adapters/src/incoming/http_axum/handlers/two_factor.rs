@@ -0,0 +1,304 @@
+#[cfg(feature = "docs")]
+use crate::incoming::http_axum::dto::common_responses::{
+    InternalServerErrorResponse, RateLimitExceededResponse, UnauthorizedResponse,
+    ValidationErrorResponse,
+};
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse};
+use axum_login::{AuthSession, AuthnBackend};
+use fedi_wplace_application::error::AppError;
+#[cfg(feature = "docs")]
+use utoipa;
+use uuid::Uuid;
+use validator::Validate;
+
+#[cfg(feature = "docs")]
+use crate::incoming::http_axum::dto::responses::ApiResponseUser;
+use crate::{
+    incoming::http_axum::{
+        auth::{
+            backend::AuthBackend,
+            session::{clear_pending_two_factor, load_pending_two_factor},
+        },
+        dto::{
+            requests::TwoFactorCodeRequest,
+            responses::{
+                ApiResponse, RecoveryCodesResponse, TotpEnrollmentResponse,
+                TwoFactorStatusResponse, UserResponse,
+            },
+        },
+        error_mapper::HttpError,
+        handlers::{auth_user_response::build_user_response, ban::format_datetime},
+    },
+    shared::app_state::AppState,
+};
+
+fn validate_code_request(request: &TwoFactorCodeRequest) -> Result<(), HttpError> {
+    request.validate().map_err(|e| {
+        HttpError(AppError::ValidationError {
+            message: format!("Validation failed: {}", e),
+        })
+    })
+}
+
+// Checks the second factor of a login whose password or provider step passed. A wrong code
+// counts as a failed login, and the failure streak only ends once both factors passed.
+pub(crate) async fn verify_second_factor(
+    state: &AppState,
+    user_id: Uuid,
+    code: Option<String>,
+) -> Result<(), HttpError> {
+    if let Err(e) = state
+        .two_factor_use_case
+        .verify_login_code(user_id, code)
+        .await
+    {
+        if matches!(e, AppError::Unauthorized) {
+            state
+                .login_protection_use_case
+                .record_failed_login(user_id)
+                .await?;
+        }
+        return Err(HttpError(e));
+    }
+
+    state
+        .login_protection_use_case
+        .clear_failed_logins(user_id)
+        .await?;
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    get,
+    path = "/auth/2fa",
+    responses(
+        (status = 200, description = "Two-factor state of the current user", body = TwoFactorStatusResponse),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Get two-factor status",
+    description = "Shows whether TOTP is enabled for the current user and how many recovery codes are left."
+))]
+pub async fn two_factor_status_handler(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, HttpError> {
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    let status = state.two_factor_use_case.status(user.id).await?;
+
+    Ok(Json(ApiResponse::success_with_data(Some(
+        TwoFactorStatusResponse {
+            enabled: status.enabled_at.is_some(),
+            enabled_at: status.enabled_at.map(format_datetime),
+            recovery_codes_remaining: status.recovery_codes_remaining,
+        },
+    ))))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/auth/2fa/totp",
+    responses(
+        (status = 200, description = "New TOTP secret to add to an authenticator app", body = TotpEnrollmentResponse),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 422, response = ValidationErrorResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Start TOTP enrollment",
+    description = "Generates a TOTP secret for the current user. Two-factor authentication stays off until the secret is confirmed with a generated code."
+))]
+pub async fn begin_totp_enrollment_handler(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+) -> Result<impl IntoResponse, HttpError> {
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    let enrollment = state
+        .two_factor_use_case
+        .begin_totp_enrollment(user.id)
+        .await?;
+
+    Ok(Json(ApiResponse::success_with_data(Some(
+        TotpEnrollmentResponse {
+            secret: enrollment.secret,
+            otpauth_url: enrollment.otpauth_url,
+        },
+    ))))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/auth/2fa/totp/confirm",
+    request_body = TwoFactorCodeRequest,
+    responses(
+        (status = 200, description = "TOTP enabled, recovery codes issued", body = RecoveryCodesResponse),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 422, response = ValidationErrorResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Confirm TOTP enrollment",
+    description = "Enables two-factor authentication once a code from the new authenticator entry is valid, and returns single-use recovery codes."
+))]
+pub async fn confirm_totp_enrollment_handler(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Json(request): Json<TwoFactorCodeRequest>,
+) -> Result<impl IntoResponse, HttpError> {
+    validate_code_request(&request)?;
+
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    let recovery_codes = state
+        .two_factor_use_case
+        .confirm_totp_enrollment(user.id, request.code)
+        .await?;
+
+    Ok(Json(ApiResponse::success_with_data(Some(
+        RecoveryCodesResponse { recovery_codes },
+    ))))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/auth/2fa/totp/disable",
+    request_body = TwoFactorCodeRequest,
+    responses(
+        (status = 204, description = "Two-factor authentication disabled"),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 422, response = ValidationErrorResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Disable TOTP",
+    description = "Turns off two-factor authentication and deletes the recovery codes. Requires a current authenticator code or an unused recovery code."
+))]
+pub async fn disable_totp_handler(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Json(request): Json<TwoFactorCodeRequest>,
+) -> Result<impl IntoResponse, HttpError> {
+    validate_code_request(&request)?;
+
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    state
+        .two_factor_use_case
+        .disable_totp(user.id, request.code)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/auth/2fa/recovery-codes",
+    request_body = TwoFactorCodeRequest,
+    responses(
+        (status = 200, description = "New recovery codes, replacing all previous ones", body = RecoveryCodesResponse),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 422, response = ValidationErrorResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Regenerate recovery codes",
+    description = "Issues a new set of recovery codes and invalidates the old ones. Requires a current authenticator code or an unused recovery code."
+))]
+pub async fn regenerate_recovery_codes_handler(
+    auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Json(request): Json<TwoFactorCodeRequest>,
+) -> Result<impl IntoResponse, HttpError> {
+    validate_code_request(&request)?;
+
+    let Some(user) = auth_session.user else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    let recovery_codes = state
+        .two_factor_use_case
+        .regenerate_recovery_codes(user.id, request.code)
+        .await?;
+
+    Ok(Json(ApiResponse::success_with_data(Some(
+        RecoveryCodesResponse { recovery_codes },
+    ))))
+}
+
+#[cfg_attr(feature = "docs", utoipa::path(
+    post,
+    path = "/auth/2fa/login",
+    request_body = TwoFactorCodeRequest,
+    responses(
+        (status = 200, description = "Second factor accepted, user logged in", body = ApiResponseUser),
+        (status = 401, response = UnauthorizedResponse),
+        (status = 422, response = ValidationErrorResponse),
+        (status = 429, response = RateLimitExceededResponse),
+        (status = 500, response = InternalServerErrorResponse)
+    ),
+    tag = "auth",
+    summary = "Finish a login with a two-factor code",
+    description = "Completes an external provider login that was redirected with `two_factor_required`. Accepts an authenticator code or an unused recovery code within five minutes of the provider login."
+))]
+pub async fn complete_two_factor_login_handler(
+    mut auth_session: AuthSession<AuthBackend>,
+    State(state): State<AppState>,
+    Json(request): Json<TwoFactorCodeRequest>,
+) -> Result<impl IntoResponse, HttpError> {
+    validate_code_request(&request)?;
+
+    let Some(user_id) = load_pending_two_factor(&auth_session.session).await? else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    // Codes are guessed against the same lockout as passwords.
+    if !state
+        .login_protection_use_case
+        .is_login_allowed(user_id)
+        .await?
+    {
+        return Err(HttpError(AppError::Unauthorized));
+    }
+
+    verify_second_factor(&state, user_id, Some(request.code)).await?;
+
+    clear_pending_two_factor(&auth_session.session).await?;
+
+    // Loading through the backend repeats the ban check a provider login went through.
+    let Some(user) = auth_session.backend.get_user(&user_id).await? else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+
+    auth_session
+        .login(&user)
+        .await
+        .map_err(|_| HttpError(AppError::InternalServerError))?;
+
+    let user_public = state.auth_use_case.me(user_id).await?;
+
+    let now = time::OffsetDateTime::now_utc();
+    let user_response = build_user_response(user_public, &state, now).await?;
+
+    Ok(Json(ApiResponse::<UserResponse> {
+        ok: true,
+        error: None,
+        data: Some(user_response),
+    }))
+}
@@ -0,0 +1,73 @@
+use axum::{
+    extract::{Request, State},
+    http::{HeaderMap, header::AUTHORIZATION},
+    middleware::Next,
+    response::Response,
+};
+use axum_login::AuthSession;
+use uuid::Uuid;
+
+use crate::incoming::http_axum::{
+    auth::backend::AuthBackend, error_mapper::HttpError,
+    middleware::rate_limit::respond_to_rate_limit,
+};
+use crate::shared::app_state::AppState;
+use domain::api_token::ApiTokenScope;
+use fedi_wplace_application::error::AppError;
+
+// Present on requests authenticated with a personal API token rather than a session.
+#[derive(Debug, Clone, Copy)]
+pub struct ApiTokenAuth {
+    pub token_id: Uuid,
+}
+
+fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    let value = headers.get(AUTHORIZATION)?.to_str().ok()?;
+    let (scheme, token) = value.split_once(' ')?;
+
+    scheme
+        .eq_ignore_ascii_case("bearer")
+        .then(|| token.trim())
+        .filter(|token| !token.is_empty())
+}
+
+// Signs the request in as the token owner when it carries `Authorization: Bearer`, so
+// the auth checks further in treat it like a session. Requests without the header pass
+// through untouched.
+pub async fn authenticate_api_token(
+    scope: ApiTokenScope,
+    State(state): State<AppState>,
+    mut auth_session: AuthSession<AuthBackend>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, HttpError> {
+    let Some(secret) = bearer_token(request.headers()).map(str::to_string) else {
+        return Ok(next.run(request).await);
+    };
+
+    let Some((user, token)) = auth_session.backend.authenticate_api_token(&secret).await? else {
+        return Err(HttpError(AppError::Unauthorized));
+    };
+    if !token.grants(scope) {
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    auth_session.user = Some(user);
+    request.extensions_mut().insert(auth_session);
+    request
+        .extensions_mut()
+        .insert(ApiTokenAuth { token_id: token.id });
+
+    if let Some(limiter) = state.rate_limiters.api_token.clone() {
+        let result = limiter.check_api_token_rate_limit(token.id).await;
+        return Ok(respond_to_rate_limit(
+            result,
+            format!("API token: {}", token.id),
+            request,
+            next,
+        )
+        .await);
+    }
+
+    Ok(next.run(request).await)
+}
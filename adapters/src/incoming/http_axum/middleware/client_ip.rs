@@ -0,0 +1,142 @@
+use axum::{
+    extract::{ConnectInfo, FromRequestParts, Request},
+    http::{HeaderMap, request::Parts},
+    middleware::Next,
+    response::Response,
+};
+use ipnet::IpNet;
+use std::{
+    convert::Infallible,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
+
+const FORWARDED_HEADER: &str = "forwarded";
+const X_FORWARDED_FOR_HEADER: &str = "x-forwarded-for";
+const X_REAL_IP_HEADER: &str = "x-real-ip";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientIp(pub IpAddr);
+
+impl<S> FromRequestParts<S> for ClientIp
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        if let Some(client_ip) = parts.extensions.get::<ClientIp>() {
+            return Ok(*client_ip);
+        }
+
+        let socket_ip = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map_or_else(|| IpAddr::from([127, 0, 0, 1]), |info| info.0.ip());
+        Ok(ClientIp(socket_ip))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ClientIpResolver {
+    trusted_proxies: Arc<[IpNet]>,
+}
+
+impl ClientIpResolver {
+    pub fn new(trusted_proxies: Vec<IpNet>) -> Self {
+        Self {
+            trusted_proxies: trusted_proxies.into(),
+        }
+    }
+
+    fn is_trusted(&self, ip: IpAddr) -> bool {
+        self.trusted_proxies
+            .iter()
+            .any(|network| network.contains(&ip))
+    }
+
+    // Headers are only honoured when the direct peer is a trusted proxy.
+    pub fn resolve(&self, headers: &HeaderMap, peer_ip: IpAddr) -> IpAddr {
+        if !self.is_trusted(peer_ip) {
+            return peer_ip;
+        }
+
+        let forwarded_chain = forwarded_chain(headers);
+        if forwarded_chain.is_empty() {
+            return real_ip(headers).unwrap_or(peer_ip);
+        }
+
+        forwarded_chain
+            .iter()
+            .rev()
+            .find(|hop| !self.is_trusted(**hop))
+            .or_else(|| forwarded_chain.first())
+            .copied()
+            .unwrap_or(peer_ip)
+    }
+}
+
+fn forwarded_chain(headers: &HeaderMap) -> Vec<IpAddr> {
+    let forwarded: Vec<IpAddr> = headers
+        .get_all(FORWARDED_HEADER)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|element| {
+            element.split(';').find_map(|pair| {
+                let (name, value) = pair.trim().split_once('=')?;
+                name.eq_ignore_ascii_case("for")
+                    .then(|| parse_forwarded_node(value))
+                    .flatten()
+            })
+        })
+        .collect();
+
+    if !forwarded.is_empty() {
+        return forwarded;
+    }
+
+    headers
+        .get_all(X_FORWARDED_FOR_HEADER)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .filter_map(|hop| hop.trim().parse::<IpAddr>().ok())
+        .collect()
+}
+
+// RFC 7239 node, e.g. `192.0.2.43`, `"192.0.2.43:4711"` or `"[2001:db8::1]:4711"`.
+fn parse_forwarded_node(node: &str) -> Option<IpAddr> {
+    let node = node.trim().trim_matches('"');
+
+    if let Some(bracketed) = node.strip_prefix('[') {
+        let (address, _port) = bracketed.split_once(']')?;
+        return address.parse().ok();
+    }
+
+    node.parse().ok().or_else(|| {
+        let (address, _port) = node.rsplit_once(':')?;
+        address.parse().ok()
+    })
+}
+
+fn real_ip(headers: &HeaderMap) -> Option<IpAddr> {
+    headers
+        .get(X_REAL_IP_HEADER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+pub async fn client_ip_middleware(
+    resolver: ClientIpResolver,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let client_ip = resolver.resolve(request.headers(), addr.ip());
+    request.extensions_mut().insert(ClientIp(client_ip));
+    next.run(request).await
+}
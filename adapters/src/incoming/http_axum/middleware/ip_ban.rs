@@ -0,0 +1,23 @@
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::incoming::http_axum::{error_mapper::HttpError, middleware::client_ip::ClientIp};
+use crate::shared::app_state::AppState;
+use fedi_wplace_application::error::AppError;
+
+pub async fn reject_banned_ip(
+    State(state): State<AppState>,
+    ClientIp(client_ip): ClientIp,
+    request: Request,
+    next: Next,
+) -> Result<Response, HttpError> {
+    if state.ip_ban_use_case.is_ip_banned(client_ip).await? {
+        tracing::warn!("Rejected request from banned IP {}", client_ip);
+        return Err(HttpError(AppError::Forbidden));
+    }
+
+    Ok(next.run(request).await)
+}
@@ -1,4 +1,10 @@
 pub mod admin_auth;
+pub mod api_token;
+pub mod client_ip;
+pub mod ip_ban;
+pub mod permission;
 pub mod rate_limit;
 pub mod request_id;
+pub mod session_tracking;
+pub mod two_factor;
 pub mod verification;
@@ -1,5 +1,5 @@
 use axum::{
-    extract::{ConnectInfo, Request},
+    extract::Request,
     http::{
         HeaderMap, HeaderValue, StatusCode,
         header::{CONTENT_TYPE, RETRY_AFTER},
@@ -7,21 +7,24 @@ use axum::{
     middleware::Next,
     response::{IntoResponse, Response},
 };
-use dashmap::DashMap;
+use axum_login::AuthSession;
 use std::{
-    net::{IpAddr, SocketAddr},
+    collections::HashMap,
+    fmt::Display,
+    net::IpAddr,
     sync::Arc,
     time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
-use tokio::time::{MissedTickBehavior, interval};
 
-use fedi_wplace_application::infrastructure_config::RateLimitConfig;
-
-#[derive(Debug, Clone)]
-pub struct RateLimitEntry {
-    pub requests: u32,
-    pub window_start: Instant,
-}
+use crate::incoming::http_axum::{
+    auth::backend::{AuthBackend, User},
+    middleware::client_ip::ClientIp,
+};
+use domain::auth::UserId;
+use fedi_wplace_application::ports::outgoing::rate_limit::{
+    DynRateLimitPort, RateLimitKey, RateLimitQuota,
+};
+use uuid::Uuid;
 
 #[derive(Debug, Clone)]
 pub struct RateLimitInfo {
@@ -78,85 +81,129 @@ impl RateLimitInfo {
 pub enum RateLimitResult {
     Allowed(RateLimitInfo),
     Denied(RateLimitInfo),
+    Unavailable,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct RateLimiter {
-    pub store: Arc<DashMap<IpAddr, RateLimitEntry>>,
-    pub requests_per_minute: u32,
-    pub burst_size: u32,
+    backend: DynRateLimitPort,
+    quota: RateLimitQuota,
+    role_quotas: HashMap<String, RateLimitQuota>,
+    shared_ip_multiplier: u32,
 }
 
 impl RateLimiter {
-    pub fn new(requests_per_minute: u32, burst_size_multiplier: u32) -> Self {
-        let burst_size = requests_per_minute * burst_size_multiplier;
-        let store = Arc::new(DashMap::new());
-
-        let store_clone = Arc::clone(&store);
-        tokio::spawn(async move {
-            let mut cleanup_interval = interval(Duration::from_secs(60));
-            cleanup_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
-
-            loop {
-                cleanup_interval.tick().await;
-                let now = Instant::now();
-                store_clone.retain(|_, entry: &mut RateLimitEntry| {
-                    now.duration_since(entry.window_start) < Duration::from_secs(60)
-                });
-            }
-        });
-
+    pub fn new(backend: DynRateLimitPort, quota: RateLimitQuota) -> Self {
         Self {
-            store,
-            requests_per_minute,
-            burst_size,
+            backend,
+            quota,
+            role_quotas: HashMap::new(),
+            shared_ip_multiplier: 1,
         }
     }
 
-    pub fn check_rate_limit(&self, ip: IpAddr) -> RateLimitResult {
-        let now = Instant::now();
+    #[must_use]
+    pub fn with_role_quotas(mut self, role_quotas: HashMap<String, RateLimitQuota>) -> Self {
+        self.role_quotas = role_quotas;
+        self
+    }
 
-        let mut entry = self.store.entry(ip).or_insert_with(|| RateLimitEntry {
-            requests: 0,
-            window_start: now,
-        });
+    #[must_use]
+    pub fn with_shared_ip_multiplier(mut self, shared_ip_multiplier: u32) -> Self {
+        self.shared_ip_multiplier = shared_ip_multiplier;
+        self
+    }
 
-        let window_expired = now.duration_since(entry.window_start) >= Duration::from_secs(60);
-        if window_expired {
-            entry.window_start = now;
-            entry.requests = 0;
-        }
+    pub async fn check_rate_limit(&self, ip: IpAddr) -> RateLimitResult {
+        self.check_key(RateLimitKey::Ip(ip), self.quota).await
+    }
 
-        let remaining = self.burst_size.saturating_sub(entry.requests);
-        let reset_time = entry.window_start + Duration::from_secs(60);
+    pub async fn check_api_token_rate_limit(&self, token_id: Uuid) -> RateLimitResult {
+        self.check_key(RateLimitKey::ApiToken(token_id), self.quota)
+            .await
+    }
 
-        let within_burst_limit = entry.requests < self.burst_size;
-        if within_burst_limit {
-            entry.requests += 1;
+    async fn check_user_rate_limit(&self, user: &User) -> RateLimitResult {
+        let key = RateLimitKey::User(UserId::from_uuid(user.id));
+        self.check_key(key, self.quota_for_user(user)).await
+    }
 
-            let rate_limit_info = RateLimitInfo {
-                limit: self.burst_size,
-                remaining: remaining.saturating_sub(1), // -1 because we just consumed one
-                reset_time,
-                retry_after_seconds: None,
-            };
+    // The user key is reported in the headers; the shared IP key only ever adds a denial.
+    // It is checked first so a request the address budget turns away costs the user nothing.
+    pub async fn check_authenticated_rate_limit(&self, user: &User, ip: IpAddr) -> RateLimitResult {
+        let shared_ip_quota = RateLimitQuota {
+            requests_per_minute: self
+                .quota
+                .requests_per_minute
+                .saturating_mul(self.shared_ip_multiplier),
+            burst_size: self
+                .quota
+                .burst_size
+                .saturating_mul(self.shared_ip_multiplier),
+        };
+        let ip_result = self
+            .check_key(RateLimitKey::SharedIp(ip), shared_ip_quota)
+            .await;
+        if matches!(ip_result, RateLimitResult::Denied(_)) {
+            return ip_result;
+        }
 
-            RateLimitResult::Allowed(rate_limit_info)
-        } else {
-            let retry_after_seconds = reset_time.saturating_duration_since(now).as_secs();
+        self.check_user_rate_limit(user).await
+    }
 
-            let rate_limit_info = RateLimitInfo {
-                limit: self.burst_size,
-                remaining: 0,
-                reset_time,
-                retry_after_seconds: Some(retry_after_seconds),
-            };
+    fn quota_for_user(&self, user: &User) -> RateLimitQuota {
+        user.roles
+            .iter()
+            .filter_map(|role| self.role_quotas.get(&role.name))
+            .copied()
+            .fold(self.quota, |highest, quota| {
+                if quota.burst_size > highest.burst_size {
+                    quota
+                } else {
+                    highest
+                }
+            })
+    }
 
-            RateLimitResult::Denied(rate_limit_info)
+    async fn check_key(&self, key: RateLimitKey, quota: RateLimitQuota) -> RateLimitResult {
+        match self.backend.check(&key, quota).await {
+            Ok(decision) => {
+                let rate_limit_info = RateLimitInfo {
+                    limit: decision.limit,
+                    remaining: decision.remaining,
+                    reset_time: Instant::now() + decision.reset_after,
+                    retry_after_seconds: decision
+                        .retry_after
+                        .map(|retry_after| retry_after.as_secs().max(1)),
+                };
+
+                if decision.allowed {
+                    RateLimitResult::Allowed(rate_limit_info)
+                } else {
+                    RateLimitResult::Denied(rate_limit_info)
+                }
+            }
+            Err(e) => {
+                // Fail open: an unavailable backend must not take the whole API down.
+                tracing::warn!("Rate limit backend error for {}: {}", key, e);
+                RateLimitResult::Unavailable
+            }
         }
     }
 }
 
+#[derive(Clone, Default)]
+pub struct RateLimiters {
+    pub paint: Option<Arc<RateLimiter>>,
+    pub tile: Option<Arc<RateLimiter>>,
+    pub general: Option<Arc<RateLimiter>>,
+    pub websocket: Option<Arc<RateLimiter>>,
+    pub auth: Option<Arc<RateLimiter>>,
+    pub account: Option<Arc<RateLimiter>>,
+    pub report: Option<Arc<RateLimiter>>,
+    pub api_token: Option<Arc<RateLimiter>>,
+}
+
 fn merge_headers_safe(target: &mut HeaderMap, source: &HeaderMap) {
     for (key, value) in source {
         if !target.contains_key(key) {
@@ -167,13 +214,45 @@ fn merge_headers_safe(target: &mut HeaderMap, source: &HeaderMap) {
 
 pub async fn rate_limit_middleware(
     rate_limiter: Arc<RateLimiter>,
-    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    ClientIp(client_ip): ClientIp,
     request: Request,
     next: Next,
 ) -> Response {
-    let client_ip = addr.ip();
+    let result = rate_limiter.check_rate_limit(client_ip).await;
+    respond_to_rate_limit(result, format!("IP: {client_ip}"), request, next).await
+}
 
-    match rate_limiter.check_rate_limit(client_ip) {
+pub async fn user_rate_limit_middleware(
+    rate_limiter: Arc<RateLimiter>,
+    auth_session: AuthSession<AuthBackend>,
+    ClientIp(client_ip): ClientIp,
+    request: Request,
+    next: Next,
+) -> Response {
+    if let Some(user) = auth_session.user {
+        let result = rate_limiter
+            .check_authenticated_rate_limit(&user, client_ip)
+            .await;
+        respond_to_rate_limit(
+            result,
+            format!("user: {} (IP: {client_ip})", user.id),
+            request,
+            next,
+        )
+        .await
+    } else {
+        let result = rate_limiter.check_rate_limit(client_ip).await;
+        respond_to_rate_limit(result, format!("IP: {client_ip}"), request, next).await
+    }
+}
+
+pub(crate) async fn respond_to_rate_limit(
+    result: RateLimitResult,
+    subject: impl Display,
+    request: Request,
+    next: Next,
+) -> Response {
+    match result {
         RateLimitResult::Allowed(rate_info) => {
             let mut response = next.run(request).await;
 
@@ -182,10 +261,11 @@ pub async fn rate_limit_middleware(
 
             response
         }
+        RateLimitResult::Unavailable => next.run(request).await,
         RateLimitResult::Denied(rate_info) => {
             tracing::warn!(
-                "Rate limit exceeded for IP: {} on {} {}",
-                client_ip,
+                "Rate limit exceeded for {} on {} {}",
+                subject,
                 request.method(),
                 request.uri()
             );
@@ -202,38 +282,3 @@ pub async fn rate_limit_middleware(
         }
     }
 }
-
-pub fn create_paint_rate_limiter(config: &RateLimitConfig) -> Arc<RateLimiter> {
-    Arc::new(RateLimiter::new(
-        config.paint_requests_per_minute,
-        config.burst_size_multiplier,
-    ))
-}
-
-pub fn create_tile_rate_limiter(config: &RateLimitConfig) -> Arc<RateLimiter> {
-    Arc::new(RateLimiter::new(
-        config.tile_requests_per_minute,
-        config.burst_size_multiplier,
-    ))
-}
-
-pub fn create_general_rate_limiter(config: &RateLimitConfig) -> Arc<RateLimiter> {
-    Arc::new(RateLimiter::new(
-        config.global_requests_per_minute,
-        config.burst_size_multiplier,
-    ))
-}
-
-pub fn create_websocket_rate_limiter(config: &RateLimitConfig) -> Arc<RateLimiter> {
-    Arc::new(RateLimiter::new(
-        config.websocket_messages_per_minute,
-        config.burst_size_multiplier,
-    ))
-}
-
-pub fn create_auth_rate_limiter(config: &RateLimitConfig) -> Arc<RateLimiter> {
-    Arc::new(RateLimiter::new(
-        config.auth_requests_per_minute,
-        config.burst_size_multiplier,
-    ))
-}
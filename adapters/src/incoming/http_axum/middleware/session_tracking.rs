@@ -0,0 +1,72 @@
+use axum::{
+    extract::{Request, State},
+    http::header::USER_AGENT,
+    middleware::Next,
+    response::Response,
+};
+use axum_login::AuthSession;
+use tower_sessions::Session;
+
+use crate::incoming::http_axum::{
+    auth::{
+        backend::AuthBackend,
+        session::{SessionRecord, load_session_record, store_session_record},
+    },
+    error_mapper::HttpError,
+    middleware::client_ip::ClientIp,
+};
+use crate::shared::app_state::AppState;
+use fedi_wplace_application::error::AppError;
+
+// Registers signed-in sessions in the per-user index on first use and ends sessions
+// whose index entry was revoked.
+pub async fn track_session(
+    State(state): State<AppState>,
+    mut auth_session: AuthSession<AuthBackend>,
+    session: Session,
+    ClientIp(client_ip): ClientIp,
+    request: Request,
+    next: Next,
+) -> Result<Response, HttpError> {
+    let Some(user) = auth_session.user.as_ref() else {
+        return Ok(next.run(request).await);
+    };
+    let user_id = user.id;
+
+    match load_session_record(&session).await? {
+        Some(record) if record.user_id == user_id => {
+            let active = state
+                .session_management_use_case
+                .touch_session(user_id, record.id, Some(client_ip))
+                .await?;
+            if !active {
+                auth_session
+                    .logout()
+                    .await
+                    .map_err(|_| HttpError(AppError::InternalServerError))?;
+                return Err(HttpError(AppError::Unauthorized));
+            }
+        }
+        _ => {
+            let user_agent = request
+                .headers()
+                .get(USER_AGENT)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string);
+            let user_session = state
+                .session_management_use_case
+                .register_session(user_id, user_agent, Some(client_ip))
+                .await?;
+            store_session_record(
+                &session,
+                SessionRecord {
+                    id: user_session.id,
+                    user_id,
+                },
+            )
+            .await?;
+        }
+    }
+
+    Ok(next.run(request).await)
+}
@@ -0,0 +1,31 @@
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+use axum_login::AuthSession;
+
+use crate::incoming::http_axum::{auth::backend::AuthBackend, error_mapper::HttpError};
+use crate::shared::app_state::AppState;
+use fedi_wplace_application::error::AppError;
+
+pub async fn require_two_factor_for_privileged(
+    State(state): State<AppState>,
+    auth_session: AuthSession<AuthBackend>,
+    request: Request,
+    next: Next,
+) -> Result<Response, HttpError> {
+    if !state.config.auth.two_factor.require_for_privileged_roles {
+        return Ok(next.run(request).await);
+    }
+
+    // Anonymous requests are rejected by the role and permission checks further in.
+    if let Some(user) = auth_session.user
+        && user.is_privileged()
+        && !state.two_factor_use_case.is_enabled(user.id).await?
+    {
+        return Err(HttpError(AppError::TwoFactorEnrollmentRequired));
+    }
+
+    Ok(next.run(request).await)
+}
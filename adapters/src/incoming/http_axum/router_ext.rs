@@ -3,11 +3,19 @@ use axum_login::AuthManagerLayer;
 use std::sync::Arc;
 use tower_sessions_redis_store::{RedisStore, fred::prelude::Client};
 
+use crate::shared::app_state::AppState;
+use domain::{api_token::ApiTokenScope, auth::Permission};
+
 use crate::incoming::http_axum::{
     auth::backend::AuthBackend,
     middleware::{
-        rate_limit::{RateLimiter, rate_limit_middleware},
+        api_token::authenticate_api_token,
+        ip_ban::reject_banned_ip,
+        permission::require_permission,
+        rate_limit::{RateLimiter, rate_limit_middleware, user_rate_limit_middleware},
         request_id::request_id_middleware,
+        session_tracking::track_session,
+        two_factor::require_two_factor_for_privileged,
     },
 };
 
@@ -15,6 +23,12 @@ pub trait RouterExt<State> {
     fn with_request_id(self) -> Self;
     fn with_auth(self, layer: AuthManagerLayer<AuthBackend, RedisStore<Client>>) -> Self;
     fn with_rate_limit(self, limiter: Arc<RateLimiter>) -> Self;
+    fn with_user_rate_limit(self, limiter: Arc<RateLimiter>) -> Self;
+    fn with_ip_ban_check(self, state: AppState) -> Self;
+    fn with_permission(self, permission: Permission) -> Self;
+    fn with_privileged_two_factor(self, state: AppState) -> Self;
+    fn with_session_tracking(self, state: AppState) -> Self;
+    fn with_api_token(self, state: AppState, scope: ApiTokenScope) -> Self;
 }
 
 impl<State> RouterExt<State> for Router<State>
@@ -30,9 +44,48 @@ where
     }
 
     fn with_rate_limit(self, limiter: Arc<RateLimiter>) -> Self {
-        self.layer(middleware::from_fn(move |conn_info, req, next| {
+        self.layer(middleware::from_fn(move |client_ip, req, next| {
             let limiter_clone = Arc::clone(&limiter);
-            rate_limit_middleware(limiter_clone, conn_info, req, next)
+            rate_limit_middleware(limiter_clone, client_ip, req, next)
+        }))
+    }
+
+    fn with_user_rate_limit(self, limiter: Arc<RateLimiter>) -> Self {
+        self.layer(middleware::from_fn(
+            move |auth_session, client_ip, req, next| {
+                let limiter_clone = Arc::clone(&limiter);
+                user_rate_limit_middleware(limiter_clone, auth_session, client_ip, req, next)
+            },
+        ))
+    }
+
+    fn with_ip_ban_check(self, state: AppState) -> Self {
+        self.layer(middleware::from_fn_with_state(state, reject_banned_ip))
+    }
+
+    fn with_permission(self, permission: Permission) -> Self {
+        self.layer(middleware::from_fn(move |auth_session, req, next| {
+            require_permission(permission, auth_session, req, next)
         }))
     }
+
+    fn with_privileged_two_factor(self, state: AppState) -> Self {
+        self.layer(middleware::from_fn_with_state(
+            state,
+            require_two_factor_for_privileged,
+        ))
+    }
+
+    fn with_session_tracking(self, state: AppState) -> Self {
+        self.layer(middleware::from_fn_with_state(state, track_session))
+    }
+
+    fn with_api_token(self, state: AppState, scope: ApiTokenScope) -> Self {
+        self.layer(middleware::from_fn_with_state(
+            state,
+            move |state, auth_session, req, next| {
+                authenticate_api_token(scope, state, auth_session, req, next)
+            },
+        ))
+    }
 }
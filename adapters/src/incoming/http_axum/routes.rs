@@ -1,8 +1,9 @@
 use axum::{
     Router, middleware,
-    routing::{delete, get, post, put},
+    routing::{delete, get, patch, post, put},
 };
 use axum_login::{AuthManagerLayer, AuthManagerLayerBuilder};
+use domain::{api_token::ApiTokenScope, auth::Permission};
 use fedi_wplace_application::error::AppError;
 use tower_sessions_redis_store::{RedisStore, fred::prelude::Client};
 #[cfg(feature = "docs")]
@@ -15,28 +16,56 @@ use crate::{
     incoming::http_axum::{
         auth::{
             backend::AuthBackend,
-            oauth_google::{google_auth_callback, google_auth_start},
+            mastodon::{mastodon_auth_callback, mastodon_auth_start, mastodon_link_start},
+            oidc::{list_login_providers, oidc_auth_callback, oidc_auth_start, oidc_link_start},
             session::{SessionConfig, create_session_layer},
         },
         handlers::{
-            admin::assign_role_to_user,
+            account::{
+                account_deletion_status_handler, cancel_account_deletion_handler,
+                export_account_handler, schedule_account_deletion_handler,
+            },
+            admin::{
+                assign_role_to_user, create_role, delete_role, get_user_details,
+                list_locked_accounts, list_role_members, list_roles, list_users,
+                revoke_role_from_user, unlock_account, update_role,
+            },
+            api_tokens::{
+                create_api_token_handler, list_api_tokens_handler, revoke_api_token_handler,
+            },
+            audit::{export_audit_log, list_audit_log},
             auth::{
+                change_email_handler, confirm_email_change_handler, list_identities_handler,
                 login_handler, logout_handler, me_handler, register_handler,
-                update_username_handler, verify_email_handler,
+                request_password_reset_handler, resend_verification_handler,
+                reset_password_handler, unlink_identity_handler, update_username_handler,
+                verify_email_handler,
+            },
+            ban::{
+                ban_ip, ban_user, discard_quarantined_pixels, get_user_ban_history,
+                get_user_ban_status, list_active_bans, list_active_ip_bans,
+                list_quarantined_pixels, promote_quarantined_pixels, unban_ip, unban_user,
             },
-            ban::{ban_user, get_user_ban_status, list_active_bans, unban_user},
             health::health_check,
             palette::get_palette,
             pixel_info::get_pixel_info,
+            protected_regions::{
+                create_protected_region, delete_protected_region, list_protected_regions,
+                update_protected_region,
+            },
+            reports::{claim_report, create_report, dismiss_report, list_reports, resolve_report},
+            rules::list_paint_rules,
+            sessions::{
+                list_sessions_handler, revoke_other_sessions_handler, revoke_session_handler,
+            },
             tiles::{paint_pixels_batch, serve_tile, serve_tile_head},
-        },
-        middleware::{
-            admin_auth::require_admin_role,
-            rate_limit::{
-                create_auth_rate_limiter, create_paint_rate_limiter, create_tile_rate_limiter,
+            two_factor::{
+                begin_totp_enrollment_handler, complete_two_factor_login_handler,
+                confirm_totp_enrollment_handler, disable_totp_handler,
+                regenerate_recovery_codes_handler, two_factor_status_handler,
             },
-            verification::require_email_verification,
         },
+        middleware::{admin_auth::require_admin_role, verification::require_email_verification},
         router_ext::RouterExt,
     },
     incoming::ws_axum::endpoint::websocket_handler,
@@ -59,10 +88,12 @@ pub async fn build_application_router(
     let (auth_routes, auth_layer) =
         build_auth_routes(state, user_store, password_hasher, ban_store).await?;
     let tile_routes = build_tile_routes_with_auth(state, auth_layer.clone());
-    let admin_routes = build_admin_routes_with_auth(auth_layer);
+    let report_routes = build_report_routes_with_auth(state, auth_layer.clone());
+    let admin_routes = build_admin_routes_with_auth(state, auth_layer);
 
     Ok(core_routes
         .merge(tile_routes)
+        .merge(report_routes)
         .merge(auth_routes)
         .merge(admin_routes))
 }
@@ -71,6 +102,8 @@ fn build_core_routes() -> Router<AppState> {
     let router = Router::new()
         .route("/palette", get(get_palette))
         .route("/pixel/{x}/{y}", get(get_pixel_info))
+        .route("/regions", get(list_protected_regions))
+        .route("/rules", get(list_paint_rules))
         .route("/live", get(websocket_handler));
 
     #[cfg(feature = "docs")]
@@ -89,41 +122,140 @@ fn build_tile_routes_with_auth(
     auth_layer: AuthManagerLayer<AuthBackend, RedisStore<Client>>,
 ) -> Router<AppState> {
     let tile_routes = Router::new().route("/tiles/{x}/{y}", get(serve_tile).head(serve_tile_head));
-    let paint_routes = Router::new().route("/tiles/{x}/{y}/pixels", post(paint_pixels_batch));
+    let paint_routes = Router::new()
+        .route("/tiles/{x}/{y}/pixels", post(paint_pixels_batch))
+        .with_ip_ban_check(state.clone());
 
-    let tile_routes_final = if state.config.rate_limit.enabled {
-        let tile_limiter = create_tile_rate_limiter(&state.config.rate_limit);
+    let tile_routes_final = if let Some(tile_limiter) = state.rate_limiters.tile.clone() {
         tile_routes.with_rate_limit(tile_limiter)
     } else {
         tile_routes
     };
 
-    let paint_routes_final = if state.config.rate_limit.enabled {
-        let paint_limiter = create_paint_rate_limiter(&state.config.rate_limit);
+    let paint_routes_final = if let Some(paint_limiter) = state.rate_limiters.paint.clone() {
         paint_routes
             .layer(middleware::from_fn(require_email_verification))
+            .with_user_rate_limit(paint_limiter)
+            .with_api_token(state.clone(), ApiTokenScope::Paint)
+            .with_session_tracking(state.clone())
             .with_auth(auth_layer)
-            .with_rate_limit(paint_limiter)
     } else {
         paint_routes
             .layer(middleware::from_fn(require_email_verification))
+            .with_api_token(state.clone(), ApiTokenScope::Paint)
+            .with_session_tracking(state.clone())
             .with_auth(auth_layer)
     };
 
     tile_routes_final.merge(paint_routes_final)
 }
 
+fn build_report_routes_with_auth(
+    state: &AppState,
+    auth_layer: AuthManagerLayer<AuthBackend, RedisStore<Client>>,
+) -> Router<AppState> {
+    let lockout_routes = Router::new()
+        .route("/users/{user_id}/lockout", delete(unlock_account))
+        .with_permission(Permission::UsersManage);
+
+    let report_routes = Router::new()
+        .route("/reports", post(create_report))
+        .with_ip_ban_check(state.clone())
+        .layer(middleware::from_fn(require_email_verification));
+
+    let report_routes_final = if let Some(report_limiter) = state.rate_limiters.report.clone() {
+        report_routes.with_user_rate_limit(report_limiter)
+    } else {
+        report_routes
+    };
+
+    report_routes_final
+        .with_session_tracking(state.clone())
+        .with_auth(auth_layer)
+}
+
 fn build_admin_routes_with_auth(
+    state: &AppState,
     auth_layer: AuthManagerLayer<AuthBackend, RedisStore<Client>>,
 ) -> Router<AppState> {
-    Router::new()
-        .route("/health", get(health_check))
-        .route("/users/{user_id}/roles/{role_id}", put(assign_role_to_user))
+    let ban_routes = Router::new()
         .route("/users/{user_id}/ban", post(ban_user))
         .route("/users/{user_id}/ban", delete(unban_user))
         .route("/users/{user_id}/ban", get(get_user_ban_status))
+        .route("/users/{user_id}/bans", get(get_user_ban_history))
+        .route("/users/{user_id}/quarantine", get(list_quarantined_pixels))
+        .route(
+            "/users/{user_id}/quarantine",
+            delete(discard_quarantined_pixels),
+        )
+        .route(
+            "/users/{user_id}/quarantine/promote",
+            post(promote_quarantined_pixels),
+        )
         .route("/bans", get(list_active_bans))
-        .layer(middleware::from_fn(require_admin_role))
+        .route("/ip-bans", post(ban_ip))
+        .route("/ip-bans", get(list_active_ip_bans))
+        .route("/ip-bans/{ban_id}", delete(unban_ip))
+        .with_permission(Permission::BanCreate);
+
+    let report_routes = Router::new()
+        .route("/reports", get(list_reports))
+        .route("/reports/{report_id}/claim", post(claim_report))
+        .route("/reports/{report_id}/resolve", post(resolve_report))
+        .route("/reports/{report_id}/dismiss", post(dismiss_report))
+        .with_permission(Permission::ReportsResolve);
+
+    let role_routes = Router::new()
+        .route("/users/{user_id}/roles/{role_id}", put(assign_role_to_user))
+        .route(
+            "/users/{user_id}/roles/{role_id}",
+            delete(revoke_role_from_user),
+        )
+        .route("/roles", get(list_roles))
+        .route("/roles", post(create_role))
+        .route("/roles/{role_id}", patch(update_role))
+        .route("/roles/{role_id}", delete(delete_role))
+        .route("/roles/{role_id}/users", get(list_role_members))
+        .with_permission(Permission::RolesAssign);
+
+    let user_routes = Router::new()
+        .route("/users", get(list_users))
+        .route("/users/locked", get(list_locked_accounts))
+        .route("/users/{user_id}", get(get_user_details))
+        .with_permission(Permission::UsersRead);
+
+    let region_routes = Router::new()
+        .route("/regions", post(create_protected_region))
+        .route("/regions/{region_id}", put(update_protected_region))
+        .route("/regions/{region_id}", delete(delete_protected_region))
+        .with_permission(Permission::RegionsManage);
+
+    let audit_routes = Router::new()
+        .route("/audit-log", get(list_audit_log))
+        .route("/audit-log/export", get(export_audit_log))
+        .with_permission(Permission::AuditRead);
+
+    let moderation_routes = ban_routes.merge(lockout_routes).merge(report_routes);
+    let moderation_routes_final = if let Some(account_limiter) = state.rate_limiters.account.clone()
+    {
+        moderation_routes.with_user_rate_limit(account_limiter)
+    } else {
+        moderation_routes
+    };
+
+    let health_routes = Router::new()
+        .route("/health", get(health_check))
+        .layer(middleware::from_fn(require_admin_role));
+
+    health_routes
+        .merge(role_routes)
+        .merge(user_routes)
+        .merge(region_routes)
+        .merge(audit_routes)
+        .merge(moderation_routes_final)
+        .with_privileged_two_factor(state.clone())
+        .with_api_token(state.clone(), ApiTokenScope::Admin)
+        .with_session_tracking(state.clone())
         .with_auth(auth_layer)
 }
 
@@ -153,22 +285,99 @@ async fn build_auth_routes(
     let session_layer =
         create_session_layer(&state.config.redis.redis_url, &session_config).await?;
 
-    let auth_backend = AuthBackend::new(user_store, password_hasher, ban_store);
+    let auth_backend = AuthBackend::new(
+        user_store,
+        password_hasher,
+        ban_store,
+        state.api_token_use_case.clone(),
+        state.login_protection_use_case.clone(),
+    )?;
 
     let rate_limited_routes = Router::new()
         .route("/auth/register", post(register_handler))
-        .route("/auth/login", post(login_handler));
+        .route("/auth/login", post(login_handler))
+        .route("/auth/2fa/login", post(complete_two_factor_login_handler))
+        .route(
+            "/auth/password/reset-request",
+            post(request_password_reset_handler),
+        )
+        .route("/auth/password/reset", post(reset_password_handler))
+        // Starting a fediverse login may register an app with a remote instance.
+        .route("/auth/mastodon/start", get(mastodon_auth_start))
+        .route("/auth/mastodon/link", get(mastodon_link_start))
+        .with_ip_ban_check(state.clone());
+
+    let account_routes = Router::new()
+        .route("/auth/username", put(update_username_handler))
+        .route("/auth/email", post(change_email_handler))
+        .route("/auth/verify/resend", post(resend_verification_handler))
+        .route("/auth/identities", get(list_identities_handler))
+        .route(
+            "/auth/identities/{identity_id}",
+            delete(unlink_identity_handler),
+        )
+        .route("/auth/2fa", get(two_factor_status_handler))
+        .route("/auth/2fa/totp", post(begin_totp_enrollment_handler))
+        .route(
+            "/auth/2fa/totp/confirm",
+            post(confirm_totp_enrollment_handler),
+        )
+        .route("/auth/2fa/totp/disable", post(disable_totp_handler))
+        .route(
+            "/auth/2fa/recovery-codes",
+            post(regenerate_recovery_codes_handler),
+        )
+        .route("/auth/sessions", get(list_sessions_handler))
+        .route("/auth/sessions", delete(revoke_other_sessions_handler))
+        .route(
+            "/auth/sessions/{session_id}",
+            delete(revoke_session_handler),
+        )
+        .route("/auth/tokens", get(list_api_tokens_handler))
+        .route("/auth/tokens", post(create_api_token_handler))
+        .route("/auth/tokens/{token_id}", delete(revoke_api_token_handler))
+        .route("/auth/account/export", get(export_account_handler))
+        .route(
+            "/auth/account/deletion",
+            get(account_deletion_status_handler),
+        )
+        .route(
+            "/auth/account/deletion",
+            post(schedule_account_deletion_handler),
+        )
+        .route(
+            "/auth/account/deletion",
+            delete(cancel_account_deletion_handler),
+        );
+
+    let account_routes_final = if let Some(account_limiter) = state.rate_limiters.account.clone() {
+        account_routes.with_user_rate_limit(account_limiter)
+    } else {
+        account_routes
+    };
+
+    let oauth_routes = Router::new()
+        .route("/auth/providers", get(list_login_providers))
+        .route("/auth/{provider}/start", get(oidc_auth_start))
+        .route("/auth/{provider}/callback", get(oidc_auth_callback))
+        .route("/auth/{provider}/link", get(oidc_link_start))
+        .route("/auth/mastodon/callback", get(mastodon_auth_callback))
+        .with_ip_ban_check(state.clone());
+
+    // Routes personal API tokens may call. Token management itself stays session-only.
+    let token_routes = Router::new()
+        .route("/auth/me", get(me_handler))
+        .with_api_token(state.clone(), ApiTokenScope::Read);
 
     let other_routes = Router::new()
         .route("/auth/logout", post(logout_handler))
-        .route("/auth/me", get(me_handler))
-        .route("/auth/username", put(update_username_handler))
+        .merge(token_routes)
+        .merge(account_routes_final)
         .route("/auth/verify", get(verify_email_handler))
-        .route("/auth/google/start", get(google_auth_start))
-        .route("/auth/google/callback", get(google_auth_callback));
+        .route("/auth/email/confirm", get(confirm_email_change_handler))
+        .merge(oauth_routes);
 
-    let final_routes = if state.config.rate_limit.enabled {
-        let auth_limiter = create_auth_rate_limiter(&state.config.rate_limit);
+    let final_routes = if let Some(auth_limiter) = state.rate_limiters.auth.clone() {
         rate_limited_routes
             .with_rate_limit(auth_limiter)
             .merge(other_routes)
@@ -177,7 +386,9 @@ async fn build_auth_routes(
     };
 
     let auth_manager_layer = AuthManagerLayerBuilder::new(auth_backend, session_layer).build();
-    let routes_with_session = final_routes.layer(auth_manager_layer.clone());
+    let routes_with_session = final_routes
+        .with_session_tracking(state.clone())
+        .layer(auth_manager_layer.clone());
 
     let routes_with_request_id = routes_with_session.with_request_id();
 
@@ -1,2 +1,3 @@
 pub mod http_axum;
+pub mod scheduler;
 pub mod ws_axum;
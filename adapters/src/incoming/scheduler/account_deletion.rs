@@ -0,0 +1,29 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::{
+    task::JoinHandle,
+    time::{MissedTickBehavior, interval},
+};
+use tracing::{debug, error};
+
+use fedi_wplace_application::ports::incoming::account::AccountDeletionUseCase;
+
+pub fn spawn_account_deletion_scheduler(
+    account_deletion_use_case: Arc<dyn AccountDeletionUseCase>,
+    check_interval: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut check_interval = interval(check_interval);
+        check_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        loop {
+            check_interval.tick().await;
+
+            match account_deletion_use_case.process_due_deletions().await {
+                Ok(0) => {}
+                Ok(deleted) => debug!("Deleted {} accounts past their grace period", deleted),
+                Err(e) => error!("Failed to process account deletions: {}", e),
+            }
+        }
+    })
+}
@@ -0,0 +1,29 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::{
+    task::JoinHandle,
+    time::{MissedTickBehavior, interval},
+};
+use tracing::{debug, error};
+
+use fedi_wplace_application::ports::incoming::ban::BanExpiryUseCase;
+
+pub fn spawn_ban_expiry_scheduler(
+    ban_expiry_use_case: Arc<dyn BanExpiryUseCase>,
+    check_interval: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut check_interval = interval(check_interval);
+        check_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        loop {
+            check_interval.tick().await;
+
+            match ban_expiry_use_case.process_expired_bans().await {
+                Ok(0) => {}
+                Ok(processed) => debug!("Processed {} expired bans", processed),
+                Err(e) => error!("Failed to process expired bans: {}", e),
+            }
+        }
+    })
+}
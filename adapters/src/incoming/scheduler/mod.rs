@@ -0,0 +1,3 @@
+pub mod account_deletion;
+pub mod ban_expiry;
+pub mod token_cleanup;
@@ -0,0 +1,29 @@
+use std::{sync::Arc, time::Duration};
+
+use tokio::{
+    task::JoinHandle,
+    time::{MissedTickBehavior, interval},
+};
+use tracing::{debug, error};
+
+use fedi_wplace_application::ports::incoming::auth::TokenCleanupUseCase;
+
+pub fn spawn_token_cleanup_scheduler(
+    token_cleanup_use_case: Arc<dyn TokenCleanupUseCase>,
+    cleanup_interval: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut cleanup_interval = interval(cleanup_interval);
+        cleanup_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        loop {
+            cleanup_interval.tick().await;
+
+            match token_cleanup_use_case.purge_expired_tokens().await {
+                Ok(0) => {}
+                Ok(purged) => debug!("Purged {} expired auth tokens and sessions", purged),
+                Err(e) => error!("Failed to purge expired auth tokens and sessions: {}", e),
+            }
+        }
+    })
+}
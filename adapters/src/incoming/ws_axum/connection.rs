@@ -12,6 +12,7 @@ use thiserror::Error;
 use tokio::time::{Interval, interval};
 use tracing::{debug, error, info, warn};
 
+use crate::incoming::http_axum::middleware::rate_limit::RateLimitResult;
 use crate::incoming::ws_axum::WsAdapterPolicy;
 use crate::incoming::ws_axum::protocol::{ClientMessage, RejectedTile, WSMessage};
 use crate::shared::app_state::AppState;
@@ -76,12 +77,10 @@ impl Connection {
         msg: Message,
         state: &AppState,
     ) -> ConnectionResult<()> {
-        if let Some(ref rate_limiter) = state.websocket_rate_limiter {
-            match rate_limiter.check_rate_limit(self.client_ip) {
-                super::super::http_axum::middleware::rate_limit::RateLimitResult::Allowed(_) => {}
-                super::super::http_axum::middleware::rate_limit::RateLimitResult::Denied(
-                    rate_info,
-                ) => {
+        if let Some(ref rate_limiter) = state.rate_limiters.websocket {
+            match rate_limiter.check_rate_limit(self.client_ip).await {
+                RateLimitResult::Allowed(_) | RateLimitResult::Unavailable => {}
+                RateLimitResult::Denied(rate_info) => {
                     warn!("WebSocket rate limit exceeded for IP: {}", self.client_ip);
 
                     let now_instant = Instant::now();
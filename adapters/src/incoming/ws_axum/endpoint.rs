@@ -1,15 +1,18 @@
 use axum::{
-    body::Body,
-    extract::{ConnectInfo, State, WebSocketUpgrade},
-    http::{HeaderValue, Request, StatusCode},
+    extract::{State, WebSocketUpgrade},
+    http::{HeaderValue, StatusCode},
     response::{IntoResponse, Response},
 };
-use std::net::SocketAddr;
 
-use crate::incoming::http_axum::middleware::rate_limit::RateLimitResult;
+use tracing::warn;
+
+use crate::incoming::http_axum::{
+    error_mapper::HttpError,
+    middleware::{client_ip::ClientIp, rate_limit::RateLimitResult},
+};
 use crate::shared::app_state::AppState;
 
-use super::{handler::ConnectionHandler, ip_utils::extract_client_ip};
+use super::handler::ConnectionHandler;
 
 #[cfg_attr(feature = "docs", utoipa::path(
     get,
@@ -17,6 +20,7 @@ use super::{handler::ConnectionHandler, ip_utils::extract_client_ip};
     responses(
         (status = 101, description = "WebSocket connection established for real-time updates"),
         (status = 400, description = "Bad Request - WebSocket upgrade failed"),
+        (status = 403, description = "Forbidden - client IP address is banned"),
         (status = 429, description = "Rate limit exceeded - WebSocket upgrade denied",
          headers(
              ("RateLimit-Limit" = u32, description = "Maximum WebSocket connections allowed per time window"),
@@ -41,6 +45,9 @@ The WebSocket connection enables bidirectional communication between client and
 - **429 Responses**: Failed upgrades return rate limit headers (RateLimit-Limit, RateLimit-Remaining, RateLimit-Reset, Retry-After)
 - **Error Messages**: Rate limit violations within WebSocket connections receive structured error messages with rate limit details
 
+## IP Bans
+- **Banned Networks**: Upgrade requests from IP addresses covered by an active IP or network-range ban are rejected with 403
+
 ## Subscription System (Configurable Policy)
 - **Per-IP Limits**: Each IP address can subscribe to a configurable maximum number of tiles (default: 64 tiles)
 - **FIFO Eviction**: When limit is exceeded, oldest subscriptions are automatically removed
@@ -75,14 +82,20 @@ See the WebSocket message schemas for detailed message formats and examples.
 pub async fn websocket_handler(
     ws: WebSocketUpgrade,
     State(state): State<AppState>,
-    ConnectInfo(addr): ConnectInfo<SocketAddr>,
-    request: Request<Body>,
+    ClientIp(client_ip): ClientIp,
 ) -> Response {
-    let client_ip = extract_client_ip(&request, Some(addr), false);
+    match state.ip_ban_use_case.is_ip_banned(client_ip).await {
+        Ok(false) => {}
+        Ok(true) => {
+            warn!("Rejected WebSocket upgrade from banned IP {}", client_ip);
+            return (StatusCode::FORBIDDEN, "Forbidden").into_response();
+        }
+        Err(e) => return HttpError(e).into_response(),
+    }
 
-    if let Some(ref rate_limiter) = state.websocket_rate_limiter {
-        match rate_limiter.check_rate_limit(client_ip) {
-            RateLimitResult::Allowed(_) => {}
+    if let Some(ref rate_limiter) = state.rate_limiters.websocket {
+        match rate_limiter.check_rate_limit(client_ip).await {
+            RateLimitResult::Allowed(_) | RateLimitResult::Unavailable => {}
             RateLimitResult::Denied(rate_info) => {
                 let mut headers = rate_info.to_headers();
                 headers.insert("Content-Type", HeaderValue::from_static("text/plain"));
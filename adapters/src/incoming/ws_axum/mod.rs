@@ -1,7 +1,6 @@
 pub(crate) mod buffer;
 pub(crate) mod connection;
 pub(crate) mod handler;
-pub(crate) mod ip_utils;
 pub(crate) mod subscriptions;
 
 pub mod endpoint; // Keep public for router access
@@ -41,4 +41,142 @@ impl EmailSenderPort for ConsoleEmailSender {
 
         Ok(())
     }
+
+    #[instrument(skip(self, reset_token))]
+    async fn send_password_reset_email(
+        &self,
+        recipient_email: &str,
+        username: &str,
+        reset_token: &str,
+    ) -> AppResult<()> {
+        let reset_link = format!(
+            "{}/auth/reset-password?token={}",
+            self.base_url, reset_token
+        );
+
+        let email_content =
+            EmailTemplate::password_reset_email_console(recipient_email, username, &reset_link);
+
+        info!(
+            recipient = recipient_email,
+            username = username,
+            link = reset_link,
+            "📧 PASSWORD RESET LINK (Console Email Sender)"
+        );
+
+        info!("{}", email_content);
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, confirmation_token))]
+    async fn send_email_change_confirmation(
+        &self,
+        recipient_email: &str,
+        username: &str,
+        confirmation_token: &str,
+    ) -> AppResult<()> {
+        let confirmation_link = format!(
+            "{}/auth/email/confirm?token={}",
+            self.base_url, confirmation_token
+        );
+
+        let email_content = EmailTemplate::email_change_confirmation_console(
+            recipient_email,
+            username,
+            &confirmation_link,
+        );
+
+        info!(
+            recipient = recipient_email,
+            username = username,
+            link = confirmation_link,
+            "📧 EMAIL CHANGE CONFIRMATION LINK (Console Email Sender)"
+        );
+
+        info!("{}", email_content);
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn send_email_changed_notice(
+        &self,
+        recipient_email: &str,
+        username: &str,
+        new_email: &str,
+    ) -> AppResult<()> {
+        let email_content =
+            EmailTemplate::email_changed_notice_console(recipient_email, username, new_email);
+
+        info!(
+            recipient = recipient_email,
+            username = username,
+            "📧 EMAIL CHANGED NOTICE (Console Email Sender)"
+        );
+
+        info!("{}", email_content);
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn send_ban_expired_email(&self, recipient_email: &str, username: &str) -> AppResult<()> {
+        let email_content = EmailTemplate::ban_expired_email_console(recipient_email, username);
+
+        info!(
+            recipient = recipient_email,
+            username = username,
+            "📧 BAN EXPIRED NOTIFICATION (Console Email Sender)"
+        );
+
+        info!("{}", email_content);
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn send_account_deletion_scheduled_email(
+        &self,
+        recipient_email: &str,
+        username: &str,
+        deletion_date: &str,
+    ) -> AppResult<()> {
+        let email_content = EmailTemplate::account_deletion_scheduled_console(
+            recipient_email,
+            username,
+            deletion_date,
+        );
+
+        info!(
+            recipient = recipient_email,
+            username = username,
+            "📧 ACCOUNT DELETION SCHEDULED (Console Email Sender)"
+        );
+
+        info!("{}", email_content);
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn send_account_locked_email(
+        &self,
+        recipient_email: &str,
+        username: &str,
+        locked_until: &str,
+    ) -> AppResult<()> {
+        let email_content =
+            EmailTemplate::account_locked_console(recipient_email, username, locked_until);
+
+        info!(
+            recipient = recipient_email,
+            username = username,
+            "📧 ACCOUNT LOCKED NOTIFICATION (Console Email Sender)"
+        );
+
+        info!("{}", email_content);
+
+        Ok(())
+    }
 }
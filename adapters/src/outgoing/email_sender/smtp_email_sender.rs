@@ -63,22 +63,13 @@ impl SmtpEmailSender {
             base_url: config.base_url,
         })
     }
-}
 
-#[async_trait::async_trait]
-impl EmailSenderPort for SmtpEmailSender {
-    #[instrument(skip(self, verification_token))]
-    async fn send_verification_email(
+    async fn send_html_email(
         &self,
         recipient_email: &str,
-        username: &str,
-        verification_token: &str,
+        subject: &str,
+        email_body: String,
     ) -> AppResult<()> {
-        let verification_link = format!(
-            "{}/auth/verify-email?token={}",
-            self.base_url, verification_token
-        );
-
         let from_mailbox = Mailbox::from_str(&format!("{} <{}>", self.from_name, self.from_email))
             .map_err(|e| AppError::ExternalServiceError {
                 message: format!("Invalid from email address: {}", e),
@@ -89,12 +80,10 @@ impl EmailSenderPort for SmtpEmailSender {
                 message: format!("Invalid recipient email address: {}", e),
             })?;
 
-        let email_body = EmailTemplate::verification_email_html(username, &verification_link);
-
         let email = Message::builder()
             .from(from_mailbox)
             .to(to_mailbox)
-            .subject("Welcome to FediPlace - Verify Your Email")
+            .subject(subject)
             .header(ContentType::TEXT_HTML)
             .body(email_body)
             .map_err(|e| AppError::ExternalServiceError {
@@ -105,13 +94,41 @@ impl EmailSenderPort for SmtpEmailSender {
             error!(
                 error = %e,
                 recipient = recipient_email,
-                "Failed to send verification email"
+                subject = subject,
+                "Failed to send email"
             );
             AppError::ExternalServiceError {
                 message: format!("Failed to send email: {}", e),
             }
         })?;
 
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl EmailSenderPort for SmtpEmailSender {
+    #[instrument(skip(self, verification_token))]
+    async fn send_verification_email(
+        &self,
+        recipient_email: &str,
+        username: &str,
+        verification_token: &str,
+    ) -> AppResult<()> {
+        let verification_link = format!(
+            "{}/auth/verify-email?token={}",
+            self.base_url, verification_token
+        );
+
+        let email_body = EmailTemplate::verification_email_html(username, &verification_link);
+
+        self.send_html_email(
+            recipient_email,
+            "Welcome to FediPlace - Verify Your Email",
+            email_body,
+        )
+        .await?;
+
         info!(
             recipient = recipient_email,
             username = username,
@@ -120,4 +137,160 @@ impl EmailSenderPort for SmtpEmailSender {
 
         Ok(())
     }
+
+    #[instrument(skip(self, reset_token))]
+    async fn send_password_reset_email(
+        &self,
+        recipient_email: &str,
+        username: &str,
+        reset_token: &str,
+    ) -> AppResult<()> {
+        let reset_link = format!(
+            "{}/auth/reset-password?token={}",
+            self.base_url, reset_token
+        );
+
+        let email_body = EmailTemplate::password_reset_email_html(username, &reset_link);
+
+        self.send_html_email(
+            recipient_email,
+            "FediPlace - Reset your password",
+            email_body,
+        )
+        .await?;
+
+        info!(
+            recipient = recipient_email,
+            username = username,
+            "Password reset email sent successfully"
+        );
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, confirmation_token))]
+    async fn send_email_change_confirmation(
+        &self,
+        recipient_email: &str,
+        username: &str,
+        confirmation_token: &str,
+    ) -> AppResult<()> {
+        let confirmation_link = format!(
+            "{}/auth/email/confirm?token={}",
+            self.base_url, confirmation_token
+        );
+
+        let email_body =
+            EmailTemplate::email_change_confirmation_html(username, &confirmation_link);
+
+        self.send_html_email(
+            recipient_email,
+            "FediPlace - Confirm your new email address",
+            email_body,
+        )
+        .await?;
+
+        info!(
+            recipient = recipient_email,
+            username = username,
+            "Email change confirmation sent successfully"
+        );
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn send_email_changed_notice(
+        &self,
+        recipient_email: &str,
+        username: &str,
+        new_email: &str,
+    ) -> AppResult<()> {
+        let email_body = EmailTemplate::email_changed_notice_html(username, new_email);
+
+        self.send_html_email(
+            recipient_email,
+            "FediPlace - Your email address was changed",
+            email_body,
+        )
+        .await?;
+
+        info!(
+            recipient = recipient_email,
+            username = username,
+            "Email changed notice sent successfully"
+        );
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn send_ban_expired_email(&self, recipient_email: &str, username: &str) -> AppResult<()> {
+        let email_body = EmailTemplate::ban_expired_email_html(username);
+
+        self.send_html_email(
+            recipient_email,
+            "FediPlace - Your ban has expired",
+            email_body,
+        )
+        .await?;
+
+        info!(
+            recipient = recipient_email,
+            username = username,
+            "Ban expiry email sent successfully"
+        );
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn send_account_deletion_scheduled_email(
+        &self,
+        recipient_email: &str,
+        username: &str,
+        deletion_date: &str,
+    ) -> AppResult<()> {
+        let email_body = EmailTemplate::account_deletion_scheduled_html(username, deletion_date);
+
+        self.send_html_email(
+            recipient_email,
+            "FediPlace - Your account will be deleted",
+            email_body,
+        )
+        .await?;
+
+        info!(
+            recipient = recipient_email,
+            username = username,
+            "Account deletion notice sent successfully"
+        );
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn send_account_locked_email(
+        &self,
+        recipient_email: &str,
+        username: &str,
+        locked_until: &str,
+    ) -> AppResult<()> {
+        let email_body = EmailTemplate::account_locked_html(username, locked_until);
+
+        self.send_html_email(
+            recipient_email,
+            "FediPlace - Sign-in to your account was locked",
+            email_body,
+        )
+        .await?;
+
+        info!(
+            recipient = recipient_email,
+            username = username,
+            "Account lockout notice sent successfully"
+        );
+
+        Ok(())
+    }
 }
@@ -68,4 +68,391 @@ The FediPlace Team
             username, verification_link, verification_link
         )
     }
+
+    pub fn password_reset_email_console(
+        recipient_email: &str,
+        username: &str,
+        reset_link: &str,
+    ) -> String {
+        format!(
+            r"=== PASSWORD RESET ===
+To: {}
+Subject: FediPlace - Reset your password
+
+Hi {},
+
+Someone asked to reset the password of your FediPlace account.
+Click the link below to choose a new password. The link expires in 1 hour
+and can only be used once:
+{}
+
+Resetting your password signs you out everywhere.
+If you didn't ask for this, you can ignore this email.
+
+Thanks,
+The FediPlace Team
+=== END EMAIL ===",
+            recipient_email, username, reset_link
+        )
+    }
+
+    pub fn password_reset_email_html(username: &str, reset_link: &str) -> String {
+        format!(
+            r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="utf-8">
+    <title>FediPlace - Reset your password</title>
+    <style>
+        body {{ font-family: Arial, sans-serif; line-height: 1.6; color: #333; max-width: 600px; margin: 0 auto; padding: 20px; }}
+        .header {{ background-color: #f4f4f4; padding: 20px; text-align: center; border-radius: 5px; }}
+        .content {{ padding: 20px 0; }}
+        .button {{ display: inline-block; padding: 12px 24px; background-color: #007cba; color: white; text-decoration: none; border-radius: 5px; font-weight: bold; }}
+        .footer {{ margin-top: 30px; padding-top: 20px; border-top: 1px solid #eee; font-size: 0.9em; color: #666; }}
+    </style>
+</head>
+<body>
+    <div class="header">
+        <h1>Reset your password</h1>
+    </div>
+
+    <div class="content">
+        <p>Hi {},</p>
+
+        <p>Someone asked to reset the password of your FediPlace account. Click the button below to choose a new password:</p>
+
+        <p style="text-align: center; margin: 30px 0;">
+            <a href="{}" class="button">Reset Password</a>
+        </p>
+
+        <p>Or copy and paste this link into your browser:</p>
+        <p style="word-break: break-all; color: #007cba;">{}</p>
+
+        <p>The link expires in 1 hour and can only be used once. Resetting your password signs you out everywhere.</p>
+
+        <p>If you didn't ask for this, you can ignore this email.</p>
+    </div>
+
+    <div class="footer">
+        <p>Thanks,<br>The FediPlace Team</p>
+    </div>
+</body>
+</html>"#,
+            username, reset_link, reset_link
+        )
+    }
+
+    pub fn email_change_confirmation_console(
+        recipient_email: &str,
+        username: &str,
+        confirmation_link: &str,
+    ) -> String {
+        format!(
+            r"=== EMAIL CHANGE CONFIRMATION ===
+To: {}
+Subject: FediPlace - Confirm your new email address
+
+Hi {},
+
+Someone asked to use this address for the FediPlace account {}.
+Click the link below to confirm the change. The link expires in 24 hours:
+{}
+
+Confirming the change signs you out everywhere.
+If you didn't ask for this, you can ignore this email.
+
+Thanks,
+The FediPlace Team
+=== END EMAIL ===",
+            recipient_email, username, username, confirmation_link
+        )
+    }
+
+    pub fn email_change_confirmation_html(username: &str, confirmation_link: &str) -> String {
+        format!(
+            r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="utf-8">
+    <title>FediPlace - Confirm your new email address</title>
+    <style>
+        body {{ font-family: Arial, sans-serif; line-height: 1.6; color: #333; max-width: 600px; margin: 0 auto; padding: 20px; }}
+        .header {{ background-color: #f4f4f4; padding: 20px; text-align: center; border-radius: 5px; }}
+        .content {{ padding: 20px 0; }}
+        .button {{ display: inline-block; padding: 12px 24px; background-color: #007cba; color: white; text-decoration: none; border-radius: 5px; font-weight: bold; }}
+        .footer {{ margin-top: 30px; padding-top: 20px; border-top: 1px solid #eee; font-size: 0.9em; color: #666; }}
+    </style>
+</head>
+<body>
+    <div class="header">
+        <h1>Confirm your new email address</h1>
+    </div>
+
+    <div class="content">
+        <p>Hi {},</p>
+
+        <p>Someone asked to use this address for your FediPlace account. Click the button below to confirm the change:</p>
+
+        <p style="text-align: center; margin: 30px 0;">
+            <a href="{}" class="button">Confirm Email Address</a>
+        </p>
+
+        <p>Or copy and paste this link into your browser:</p>
+        <p style="word-break: break-all; color: #007cba;">{}</p>
+
+        <p>The link expires in 24 hours. Confirming the change signs you out everywhere.</p>
+
+        <p>If you didn't ask for this, you can ignore this email.</p>
+    </div>
+
+    <div class="footer">
+        <p>Thanks,<br>The FediPlace Team</p>
+    </div>
+</body>
+</html>"#,
+            username, confirmation_link, confirmation_link
+        )
+    }
+
+    pub fn email_changed_notice_console(
+        recipient_email: &str,
+        username: &str,
+        new_email: &str,
+    ) -> String {
+        format!(
+            r"=== EMAIL CHANGED ===
+To: {}
+Subject: FediPlace - Your email address was changed
+
+Hi {},
+
+The email address of your FediPlace account was changed to {}.
+This address will no longer receive emails about your account.
+
+If you didn't make this change, please contact the FediPlace team right away.
+
+Thanks,
+The FediPlace Team
+=== END EMAIL ===",
+            recipient_email, username, new_email
+        )
+    }
+
+    pub fn email_changed_notice_html(username: &str, new_email: &str) -> String {
+        format!(
+            r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="utf-8">
+    <title>FediPlace - Your email address was changed</title>
+    <style>
+        body {{ font-family: Arial, sans-serif; line-height: 1.6; color: #333; max-width: 600px; margin: 0 auto; padding: 20px; }}
+        .header {{ background-color: #f4f4f4; padding: 20px; text-align: center; border-radius: 5px; }}
+        .content {{ padding: 20px 0; }}
+        .footer {{ margin-top: 30px; padding-top: 20px; border-top: 1px solid #eee; font-size: 0.9em; color: #666; }}
+    </style>
+</head>
+<body>
+    <div class="header">
+        <h1>Your email address was changed</h1>
+    </div>
+
+    <div class="content">
+        <p>Hi {},</p>
+
+        <p>The email address of your FediPlace account was changed to <strong>{}</strong>.
+        This address will no longer receive emails about your account.</p>
+
+        <p>If you didn't make this change, please contact the FediPlace team right away.</p>
+    </div>
+
+    <div class="footer">
+        <p>Thanks,<br>The FediPlace Team</p>
+    </div>
+</body>
+</html>"#,
+            username, new_email
+        )
+    }
+
+    pub fn ban_expired_email_console(recipient_email: &str, username: &str) -> String {
+        format!(
+            r"=== BAN EXPIRED ===
+To: {}
+Subject: FediPlace - Your ban has expired
+
+Hi {},
+
+Your temporary ban on FediPlace has expired and your account is active again.
+Please keep the community rules in mind when painting.
+
+Thanks,
+The FediPlace Team
+=== END EMAIL ===",
+            recipient_email, username
+        )
+    }
+
+    pub fn ban_expired_email_html(username: &str) -> String {
+        format!(
+            r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="utf-8">
+    <title>FediPlace - Your ban has expired</title>
+    <style>
+        body {{ font-family: Arial, sans-serif; line-height: 1.6; color: #333; max-width: 600px; margin: 0 auto; padding: 20px; }}
+        .header {{ background-color: #f4f4f4; padding: 20px; text-align: center; border-radius: 5px; }}
+        .content {{ padding: 20px 0; }}
+        .footer {{ margin-top: 30px; padding-top: 20px; border-top: 1px solid #eee; font-size: 0.9em; color: #666; }}
+    </style>
+</head>
+<body>
+    <div class="header">
+        <h1>Your ban has expired</h1>
+    </div>
+
+    <div class="content">
+        <p>Hi {},</p>
+
+        <p>Your temporary ban on FediPlace has expired and your account is active again.</p>
+
+        <p>Please keep the community rules in mind when painting.</p>
+    </div>
+
+    <div class="footer">
+        <p>Thanks,<br>The FediPlace Team</p>
+    </div>
+</body>
+</html>"#,
+            username
+        )
+    }
+
+    pub fn account_deletion_scheduled_console(
+        recipient_email: &str,
+        username: &str,
+        deletion_date: &str,
+    ) -> String {
+        format!(
+            r"=== ACCOUNT DELETION SCHEDULED ===
+To: {}
+Subject: FediPlace - Your account will be deleted
+
+Hi {},
+
+Your FediPlace account is scheduled for deletion on {}.
+Until then you can cancel the deletion from your account settings.
+Afterwards your account and personal data are removed for good. Pixels you painted
+stay on the canvas without your name.
+
+If you didn't request this, sign in and cancel the deletion right away.
+
+Thanks,
+The FediPlace Team
+=== END EMAIL ===",
+            recipient_email, username, deletion_date
+        )
+    }
+
+    pub fn account_deletion_scheduled_html(username: &str, deletion_date: &str) -> String {
+        format!(
+            r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="utf-8">
+    <title>FediPlace - Your account will be deleted</title>
+    <style>
+        body {{ font-family: Arial, sans-serif; line-height: 1.6; color: #333; max-width: 600px; margin: 0 auto; padding: 20px; }}
+        .header {{ background-color: #f4f4f4; padding: 20px; text-align: center; border-radius: 5px; }}
+        .content {{ padding: 20px 0; }}
+        .footer {{ margin-top: 30px; padding-top: 20px; border-top: 1px solid #eee; font-size: 0.9em; color: #666; }}
+    </style>
+</head>
+<body>
+    <div class="header">
+        <h1>Your account will be deleted</h1>
+    </div>
+
+    <div class="content">
+        <p>Hi {},</p>
+
+        <p>Your FediPlace account is scheduled for deletion on <strong>{}</strong>.
+        Until then you can cancel the deletion from your account settings.</p>
+
+        <p>Afterwards your account and personal data are removed for good. Pixels you painted stay on the canvas without your name.</p>
+
+        <p>If you didn't request this, sign in and cancel the deletion right away.</p>
+    </div>
+
+    <div class="footer">
+        <p>Thanks,<br>The FediPlace Team</p>
+    </div>
+</body>
+</html>"#,
+            username, deletion_date
+        )
+    }
+
+    pub fn account_locked_console(
+        recipient_email: &str,
+        username: &str,
+        locked_until: &str,
+    ) -> String {
+        format!(
+            r"=== ACCOUNT LOCKED ===
+To: {}
+Subject: FediPlace - Sign-in to your account was locked
+
+Hi {},
+
+We saw many failed sign-in attempts on your FediPlace account, so password
+sign-in is locked until {}.
+
+If these attempts weren't you, someone may be guessing your password. Consider
+resetting it once the lock ends. If you simply forgot your password, you can
+reset it at any time.
+
+Thanks,
+The FediPlace Team
+=== END EMAIL ===",
+            recipient_email, username, locked_until
+        )
+    }
+
+    pub fn account_locked_html(username: &str, locked_until: &str) -> String {
+        format!(
+            r#"<!DOCTYPE html>
+<html>
+<head>
+    <meta charset="utf-8">
+    <title>FediPlace - Sign-in to your account was locked</title>
+    <style>
+        body {{ font-family: Arial, sans-serif; line-height: 1.6; color: #333; max-width: 600px; margin: 0 auto; padding: 20px; }}
+        .header {{ background-color: #f4f4f4; padding: 20px; text-align: center; border-radius: 5px; }}
+        .content {{ padding: 20px 0; }}
+        .footer {{ margin-top: 30px; padding-top: 20px; border-top: 1px solid #eee; font-size: 0.9em; color: #666; }}
+    </style>
+</head>
+<body>
+    <div class="header">
+        <h1>Sign-in to your account was locked</h1>
+    </div>
+
+    <div class="content">
+        <p>Hi {},</p>
+
+        <p>We saw many failed sign-in attempts on your FediPlace account, so password sign-in is locked until <strong>{}</strong>.</p>
+
+        <p>If these attempts weren't you, someone may be guessing your password. Consider resetting it once the lock ends. If you simply forgot your password, you can reset it at any time.</p>
+    </div>
+
+    <div class="footer">
+        <p>Thanks,<br>The FediPlace Team</p>
+    </div>
+</body>
+</html>"#,
+            username, locked_until
+        )
+    }
 }
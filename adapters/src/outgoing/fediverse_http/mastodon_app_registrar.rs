@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use reqwest::redirect::Policy;
+use serde::{Deserialize, Serialize};
+use tracing::instrument;
+
+use domain::fediverse::{FediverseApp, InstanceDomain};
+use fedi_wplace_application::{
+    error::{AppError, AppResult},
+    ports::outgoing::fediverse_app::FediverseAppRegistrarPort,
+};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Serialize)]
+struct RegisterAppRequest<'a> {
+    client_name: &'a str,
+    redirect_uris: &'a str,
+    scopes: &'a str,
+    website: &'a str,
+}
+
+#[derive(Deserialize)]
+struct RegisterAppResponse {
+    client_id: String,
+    client_secret: String,
+}
+
+// Registers OAuth apps through the Mastodon `POST /api/v1/apps` endpoint, which Pleroma,
+// Akkoma, GoToSocial and most other Mastodon-compatible servers also implement.
+pub struct MastodonAppRegistrar {
+    http_client: reqwest::Client,
+    client_name: String,
+    website: String,
+}
+
+impl MastodonAppRegistrar {
+    pub fn new(client_name: String, website: String) -> AppResult<Self> {
+        // Instances are user supplied, so never follow redirects to other hosts.
+        let http_client = reqwest::Client::builder()
+            .redirect(Policy::none())
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| AppError::ConfigError {
+                message: format!("Failed to build fediverse HTTP client: {}", e),
+            })?;
+
+        Ok(Self {
+            http_client,
+            client_name,
+            website,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl FediverseAppRegistrarPort for MastodonAppRegistrar {
+    #[instrument(skip(self))]
+    async fn register_app(
+        &self,
+        domain: &InstanceDomain,
+        redirect_url: &str,
+        scopes: &str,
+    ) -> AppResult<FediverseApp> {
+        let response = self
+            .http_client
+            .post(format!("https://{}/api/v1/apps", domain))
+            .json(&RegisterAppRequest {
+                client_name: &self.client_name,
+                redirect_uris: redirect_url,
+                scopes,
+                website: &self.website,
+            })
+            .send()
+            .await
+            .map_err(|e| AppError::ExternalServiceError {
+                message: format!("Failed to register app with {}: {}", domain, e),
+            })?;
+
+        if !response.status().is_success() {
+            return Err(AppError::ExternalServiceError {
+                message: format!(
+                    "App registration with {} returned {}",
+                    domain,
+                    response.status()
+                ),
+            });
+        }
+
+        let registered = response.json::<RegisterAppResponse>().await.map_err(|e| {
+            AppError::ExternalServiceError {
+                message: format!("Failed to parse app registration from {}: {}", domain, e),
+            }
+        })?;
+
+        Ok(FediverseApp {
+            domain: domain.clone(),
+            client_id: registered.client_id,
+            client_secret: registered.client_secret,
+            redirect_url: redirect_url.to_string(),
+        })
+    }
+}
@@ -0,0 +1 @@
+pub mod mastodon_app_registrar;
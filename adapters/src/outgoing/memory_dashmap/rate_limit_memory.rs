@@ -0,0 +1,101 @@
+use dashmap::DashMap;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::time::{MissedTickBehavior, interval};
+
+use fedi_wplace_application::{
+    error::AppResult,
+    ports::outgoing::rate_limit::{RateLimitDecision, RateLimitKey, RateLimitPort, RateLimitQuota},
+};
+
+const WINDOW: Duration = Duration::from_mins(1);
+
+#[derive(Debug, Clone)]
+struct RateLimitEntry {
+    requests: u32,
+    window_start: Instant,
+}
+
+#[derive(Debug, Clone)]
+pub struct InMemoryRateLimitAdapter {
+    store: Arc<DashMap<RateLimitKey, RateLimitEntry>>,
+}
+
+impl InMemoryRateLimitAdapter {
+    pub fn new() -> Self {
+        let store = Arc::new(DashMap::new());
+
+        let store_clone = Arc::clone(&store);
+        tokio::spawn(async move {
+            let mut cleanup_interval = interval(WINDOW);
+            cleanup_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            loop {
+                cleanup_interval.tick().await;
+                let now = Instant::now();
+                store_clone.retain(|_, entry: &mut RateLimitEntry| {
+                    now.duration_since(entry.window_start) < WINDOW
+                });
+            }
+        });
+
+        Self { store }
+    }
+}
+
+impl Default for InMemoryRateLimitAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait::async_trait]
+impl RateLimitPort for InMemoryRateLimitAdapter {
+    async fn check(
+        &self,
+        key: &RateLimitKey,
+        quota: RateLimitQuota,
+    ) -> AppResult<RateLimitDecision> {
+        let now = Instant::now();
+
+        let mut entry = self
+            .store
+            .entry(key.clone())
+            .or_insert_with(|| RateLimitEntry {
+                requests: 0,
+                window_start: now,
+            });
+
+        let window_expired = now.duration_since(entry.window_start) >= WINDOW;
+        if window_expired {
+            entry.window_start = now;
+            entry.requests = 0;
+        }
+
+        let remaining = quota.burst_size.saturating_sub(entry.requests);
+        let reset_after = (entry.window_start + WINDOW).saturating_duration_since(now);
+
+        let within_burst_limit = entry.requests < quota.burst_size;
+        if within_burst_limit {
+            entry.requests += 1;
+
+            Ok(RateLimitDecision {
+                allowed: true,
+                limit: quota.burst_size,
+                remaining: remaining.saturating_sub(1), // -1 because we just consumed one
+                reset_after,
+                retry_after: None,
+            })
+        } else {
+            Ok(RateLimitDecision {
+                allowed: false,
+                limit: quota.burst_size,
+                remaining: 0,
+                reset_after,
+                retry_after: Some(reset_after),
+            })
+        }
+    }
+}
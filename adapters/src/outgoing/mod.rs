@@ -1,6 +1,8 @@
 pub mod email_sender;
 pub mod events_broadcast;
+pub mod fediverse_http;
 pub mod image_rs;
+pub mod memory_dashmap;
 pub mod passwords;
 pub mod postgres_sqlx;
 pub mod redis_deadpool;
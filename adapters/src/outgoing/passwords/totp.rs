@@ -0,0 +1,65 @@
+use time::OffsetDateTime;
+use totp_rs::{Algorithm, Secret, TOTP};
+
+use domain::two_factor::{TOTP_DIGITS, TOTP_STEP_SECONDS};
+use fedi_wplace_application::error::{AppError, AppResult};
+use fedi_wplace_application::ports::outgoing::totp::TotpPort;
+
+// Codes from the previous and next step are accepted to tolerate clock drift.
+const ALLOWED_STEP_DRIFT: i64 = 1;
+
+pub struct TotpRsAuthenticator {
+    issuer: String,
+}
+
+impl TotpRsAuthenticator {
+    pub fn new(issuer: String) -> Self {
+        Self { issuer }
+    }
+
+    fn totp(&self, secret: &str, account_name: &str) -> AppResult<TOTP> {
+        let secret_bytes = Secret::Encoded(secret.to_string())
+            .to_bytes()
+            .map_err(|e| AppError::CodecError {
+                message: format!("Invalid TOTP secret: {}", e),
+            })?;
+
+        TOTP::new(
+            Algorithm::SHA1,
+            TOTP_DIGITS,
+            0,
+            TOTP_STEP_SECONDS as u64,
+            secret_bytes,
+            Some(self.issuer.clone()),
+            account_name.to_string(),
+        )
+        .map_err(|e| AppError::ValidationError {
+            message: format!("Invalid TOTP parameters: {}", e),
+        })
+    }
+}
+
+impl TotpPort for TotpRsAuthenticator {
+    fn generate_secret(&self) -> String {
+        Secret::generate_secret().to_encoded().to_string()
+    }
+
+    fn provisioning_url(&self, secret: &str, account_name: &str) -> AppResult<String> {
+        Ok(self.totp(secret, account_name)?.get_url())
+    }
+
+    fn matching_step(
+        &self,
+        secret: &str,
+        code: &str,
+        now: OffsetDateTime,
+    ) -> AppResult<Option<i64>> {
+        let totp = self.totp(secret, "")?;
+        let current_step = now.unix_timestamp() / TOTP_STEP_SECONDS;
+
+        Ok(
+            (current_step - ALLOWED_STEP_DRIFT..=current_step + ALLOWED_STEP_DRIFT)
+                .find(|step| totp.check(code, (step * TOTP_STEP_SECONDS) as u64)),
+        )
+    }
+}
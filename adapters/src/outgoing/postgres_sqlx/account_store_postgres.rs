@@ -0,0 +1,242 @@
+use sqlx::{PgPool, types::time::OffsetDateTime};
+use tracing::instrument;
+use uuid::Uuid;
+
+use fedi_wplace_application::{
+    error::{AppError, AppResult},
+    ports::outgoing::account_store::{AccountDeletion, AccountStorePort},
+};
+
+use super::utils::{PostgresExecutor, begin_transaction, commit_transaction};
+
+pub struct PostgresAccountStoreAdapter {
+    pool: PgPool,
+    executor: PostgresExecutor,
+}
+
+impl PostgresAccountStoreAdapter {
+    pub fn new(pool: PgPool, query_timeout_secs: u64) -> Self {
+        Self {
+            pool,
+            executor: PostgresExecutor::new(query_timeout_secs),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AccountStorePort for PostgresAccountStoreAdapter {
+    #[instrument(skip(self))]
+    async fn schedule_deletion(
+        &self,
+        user_id: Uuid,
+        scheduled_at: OffsetDateTime,
+    ) -> AppResult<bool> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    UPDATE users
+                    SET deletion_scheduled_at = $2
+                    WHERE id = $1 AND deletion_scheduled_at IS NULL
+                    "#,
+                        user_id,
+                        scheduled_at
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to schedule deletion of user {}", user_id),
+            )
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self))]
+    async fn cancel_deletion(&self, user_id: Uuid) -> AppResult<bool> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    UPDATE users
+                    SET deletion_scheduled_at = NULL
+                    WHERE id = $1 AND deletion_scheduled_at IS NOT NULL
+                    "#,
+                        user_id
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to cancel deletion of user {}", user_id),
+            )
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self))]
+    async fn find_deletion_scheduled_at(&self, user_id: Uuid) -> AppResult<Option<OffsetDateTime>> {
+        let scheduled_at = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_scalar!(
+                        r#"
+                    SELECT deletion_scheduled_at
+                    FROM users
+                    WHERE id = $1
+                    "#,
+                        user_id
+                    )
+                    .fetch_optional(&self.pool)
+                },
+                &format!("Failed to load deletion schedule of user {}", user_id),
+            )
+            .await?;
+
+        Ok(scheduled_at.flatten())
+    }
+
+    #[instrument(skip(self))]
+    async fn find_due_deletions(&self, now: OffsetDateTime, limit: u32) -> AppResult<Vec<Uuid>> {
+        let user_ids = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_scalar!(
+                        r#"
+                    SELECT id
+                    FROM users
+                    WHERE deletion_scheduled_at IS NOT NULL
+                      AND deletion_scheduled_at <= $1
+                      AND NOT EXISTS (
+                          SELECT 1 FROM banned_users
+                          WHERE banned_users.user_id = users.id
+                            AND revoked_at IS NULL
+                            AND (expires_at IS NULL OR expires_at > NOW())
+                      )
+                    ORDER BY deletion_scheduled_at
+                    LIMIT $2
+                    "#,
+                        now,
+                        i64::from(limit)
+                    )
+                    .fetch_all(&self.pool)
+                },
+                "Failed to load accounts due for deletion",
+            )
+            .await?;
+
+        Ok(user_ids)
+    }
+
+    #[instrument(skip(self))]
+    async fn is_last_admin(&self, user_id: Uuid) -> AppResult<bool> {
+        self.executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_scalar!(
+                        r#"
+                    SELECT EXISTS (
+                        SELECT 1 FROM user_roles
+                        JOIN roles ON roles.id = user_roles.role_id
+                        WHERE roles.name = 'admin' AND user_roles.user_id = $1
+                    ) AND NOT EXISTS (
+                        SELECT 1 FROM user_roles
+                        JOIN roles ON roles.id = user_roles.role_id
+                        WHERE roles.name = 'admin' AND user_roles.user_id <> $1
+                    ) AS "last_admin!"
+                    "#,
+                        user_id
+                    )
+                    .fetch_one(&self.pool)
+                },
+                &format!("Failed to check admin membership of user {}", user_id),
+            )
+            .await
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_account(
+        &self,
+        user_id: Uuid,
+        now: OffsetDateTime,
+    ) -> AppResult<AccountDeletion> {
+        let mut tx = begin_transaction(&self.pool).await?;
+
+        // Locking the admin role serializes this with role revocations, so the last
+        // admin cannot disappear through both paths at once.
+        sqlx::query!(
+            r#"
+            SELECT id FROM roles WHERE name = 'admin' FOR UPDATE
+            "#
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to lock admin role: {}", e),
+        })?;
+
+        let blockers = sqlx::query!(
+            r#"
+            SELECT
+                EXISTS (
+                    SELECT 1 FROM banned_users
+                    WHERE user_id = $1
+                      AND revoked_at IS NULL
+                      AND (expires_at IS NULL OR expires_at > NOW())
+                ) AS "banned!",
+                EXISTS (
+                    SELECT 1 FROM user_roles
+                    JOIN roles ON roles.id = user_roles.role_id
+                    WHERE roles.name = 'admin' AND user_roles.user_id = $1
+                ) AND NOT EXISTS (
+                    SELECT 1 FROM user_roles
+                    JOIN roles ON roles.id = user_roles.role_id
+                    WHERE roles.name = 'admin' AND user_roles.user_id <> $1
+                ) AS "last_admin!"
+            "#,
+            user_id
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!(
+                "Failed to check whether user {} can be deleted: {}",
+                user_id, e
+            ),
+        })?;
+
+        if blockers.banned {
+            return Ok(AccountDeletion::Banned);
+        }
+        if blockers.last_admin {
+            return Ok(AccountDeletion::LastAdmin);
+        }
+
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM users
+            WHERE id = $1
+              AND deletion_scheduled_at IS NOT NULL
+              AND deletion_scheduled_at <= $2
+            "#,
+            user_id,
+            now
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to delete user {}: {}", user_id, e),
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Ok(AccountDeletion::NotDue);
+        }
+
+        commit_transaction(tx).await?;
+        Ok(AccountDeletion::Deleted)
+    }
+}
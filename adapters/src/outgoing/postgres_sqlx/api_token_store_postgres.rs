@@ -0,0 +1,221 @@
+use sqlx::{PgPool, types::time::OffsetDateTime};
+use tracing::instrument;
+use uuid::Uuid;
+
+use domain::api_token::{ApiToken, ApiTokenScope};
+use fedi_wplace_application::{
+    error::{AppError, AppResult},
+    ports::outgoing::api_token_store::ApiTokenStorePort,
+};
+
+use super::utils::{PostgresExecutor, hash_token};
+
+pub struct PostgresApiTokenStoreAdapter {
+    pool: PgPool,
+    executor: PostgresExecutor,
+}
+
+impl PostgresApiTokenStoreAdapter {
+    pub fn new(pool: PgPool, query_timeout_secs: u64) -> Self {
+        Self {
+            pool,
+            executor: PostgresExecutor::new(query_timeout_secs),
+        }
+    }
+}
+
+struct ApiTokenRow {
+    id: Uuid,
+    user_id: Uuid,
+    name: String,
+    scopes: Vec<String>,
+    created_at: OffsetDateTime,
+    last_used_at: Option<OffsetDateTime>,
+    expires_at: Option<OffsetDateTime>,
+    revoked_at: Option<OffsetDateTime>,
+}
+
+impl ApiTokenRow {
+    fn into_token(self) -> AppResult<ApiToken> {
+        let scopes = self
+            .scopes
+            .iter()
+            .map(|scope| scope.parse::<ApiTokenScope>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| AppError::DatabaseError {
+                message: format!("API token {} has an invalid scope", self.id),
+            })?;
+
+        Ok(ApiToken {
+            id: self.id,
+            user_id: self.user_id,
+            name: self.name,
+            scopes,
+            created_at: self.created_at,
+            last_used_at: self.last_used_at,
+            expires_at: self.expires_at,
+            revoked_at: self.revoked_at,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl ApiTokenStorePort for PostgresApiTokenStoreAdapter {
+    #[instrument(skip(self, token, secret))]
+    async fn create_token(&self, token: &ApiToken, secret: &str) -> AppResult<()> {
+        let token_hash = hash_token(secret);
+        let scopes: Vec<String> = token
+            .scopes
+            .iter()
+            .map(|scope| scope.as_str().to_string())
+            .collect();
+
+        self.executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    INSERT INTO api_tokens (id, user_id, name, token_hash, scopes, created_at, expires_at)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7)
+                    "#,
+                        token.id,
+                        token.user_id,
+                        token.name,
+                        token_hash,
+                        &scopes[..],
+                        token.created_at,
+                        token.expires_at
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to create API token for user {}", token.user_id),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn list_tokens(&self, user_id: Uuid) -> AppResult<Vec<ApiToken>> {
+        let rows = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        ApiTokenRow,
+                        r#"
+                    SELECT id, user_id, name, scopes, created_at, last_used_at, expires_at, revoked_at
+                    FROM api_tokens
+                    WHERE user_id = $1 AND revoked_at IS NULL
+                    ORDER BY created_at DESC
+                    "#,
+                        user_id
+                    )
+                    .fetch_all(&self.pool)
+                },
+                &format!("Failed to list API tokens for user {}", user_id),
+            )
+            .await?;
+
+        rows.into_iter().map(ApiTokenRow::into_token).collect()
+    }
+
+    #[instrument(skip(self))]
+    async fn count_active_tokens(&self, user_id: Uuid, now: OffsetDateTime) -> AppResult<i64> {
+        let count = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_scalar!(
+                        r#"
+                    SELECT COUNT(*) AS "count!"
+                    FROM api_tokens
+                    WHERE user_id = $1
+                      AND revoked_at IS NULL
+                      AND (expires_at IS NULL OR expires_at > $2)
+                    "#,
+                        user_id,
+                        now
+                    )
+                    .fetch_one(&self.pool)
+                },
+                &format!("Failed to count API tokens for user {}", user_id),
+            )
+            .await?;
+
+        Ok(count)
+    }
+
+    #[instrument(skip(self, secret))]
+    async fn find_token_by_secret(&self, secret: &str) -> AppResult<Option<ApiToken>> {
+        let token_hash = hash_token(secret);
+
+        let row = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        ApiTokenRow,
+                        r#"
+                    SELECT id, user_id, name, scopes, created_at, last_used_at, expires_at, revoked_at
+                    FROM api_tokens
+                    WHERE token_hash = $1
+                    "#,
+                        token_hash
+                    )
+                    .fetch_optional(&self.pool)
+                },
+                "Failed to look up API token",
+            )
+            .await?;
+
+        row.map(ApiTokenRow::into_token).transpose()
+    }
+
+    #[instrument(skip(self))]
+    async fn record_token_use(&self, token_id: Uuid, used_before: OffsetDateTime) -> AppResult<()> {
+        self.executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    UPDATE api_tokens
+                    SET last_used_at = NOW()
+                    WHERE id = $1 AND (last_used_at IS NULL OR last_used_at < $2)
+                    "#,
+                        token_id,
+                        used_before
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to record use of API token {}", token_id),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn revoke_token(&self, user_id: Uuid, token_id: Uuid) -> AppResult<bool> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    UPDATE api_tokens
+                    SET revoked_at = NOW()
+                    WHERE id = $1 AND user_id = $2 AND revoked_at IS NULL
+                    "#,
+                        token_id,
+                        user_id
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to revoke API token {}", token_id),
+            )
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,133 @@
+use sqlx::{PgPool, types::ipnet::IpNet, types::time::OffsetDateTime};
+use tracing::instrument;
+
+use domain::{
+    audit::{AuditEntry, AuditEntryId},
+    auth::UserId,
+};
+use fedi_wplace_application::{
+    error::{AppError, AppResult},
+    ports::outgoing::audit_log::{AuditLogPort, AuditLogQuery},
+};
+
+use super::utils::PostgresExecutor;
+
+pub struct PostgresAuditLogAdapter {
+    pool: PgPool,
+    executor: PostgresExecutor,
+}
+
+impl PostgresAuditLogAdapter {
+    pub fn new(pool: PgPool, query_timeout_secs: u64) -> Self {
+        Self {
+            pool,
+            executor: PostgresExecutor::new(query_timeout_secs),
+        }
+    }
+}
+
+struct AuditEntryRow {
+    id: uuid::Uuid,
+    actor_user_id: Option<uuid::Uuid>,
+    action: String,
+    target_type: String,
+    target_id: Option<String>,
+    parameters: serde_json::Value,
+    ip_address: Option<IpNet>,
+    created_at: OffsetDateTime,
+}
+
+impl AuditEntryRow {
+    fn into_entry(self) -> AppResult<AuditEntry> {
+        let invalid = |field: &str| AppError::DatabaseError {
+            message: format!("Audit log entry {} has an invalid {}", self.id, field),
+        };
+
+        let action = self.action.parse().map_err(|_| invalid("action"))?;
+        let target_type = self
+            .target_type
+            .parse()
+            .map_err(|_| invalid("target type"))?;
+
+        Ok(AuditEntry {
+            id: AuditEntryId::from_uuid(self.id),
+            actor_user_id: self.actor_user_id.map(UserId::from_uuid),
+            action,
+            target_type,
+            target_id: self.target_id,
+            parameters: self.parameters,
+            ip_address: self.ip_address.map(|network| network.addr()),
+            created_at: self.created_at,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogPort for PostgresAuditLogAdapter {
+    #[instrument(skip(self, entry))]
+    async fn record(&self, entry: &AuditEntry) -> AppResult<()> {
+        self.executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    INSERT INTO audit_log (id, actor_user_id, action, target_type, target_id, parameters, ip_address, created_at)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                    "#,
+                        entry.id.as_uuid(),
+                        entry.actor_user_id.as_ref().map(UserId::as_uuid),
+                        entry.action.as_str(),
+                        entry.target_type.as_str(),
+                        entry.target_id,
+                        entry.parameters,
+                        entry.ip_address.map(IpNet::from),
+                        entry.created_at
+                    )
+                    .execute(&self.pool)
+                },
+                "Failed to record audit log entry",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn list_entries(&self, query: &AuditLogQuery) -> AppResult<Vec<AuditEntry>> {
+        let results = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        AuditEntryRow,
+                        r#"
+                    SELECT id, actor_user_id, action, target_type, target_id, parameters,
+                           ip_address as "ip_address: IpNet", created_at
+                    FROM audit_log
+                    WHERE ($1::UUID IS NULL OR actor_user_id = $1)
+                      AND ($2::TEXT IS NULL OR action = $2)
+                      AND ($3::TEXT IS NULL OR target_type = $3)
+                      AND ($4::TEXT IS NULL OR target_id = $4)
+                      AND ($5::TIMESTAMPTZ IS NULL OR created_at >= $5)
+                      AND ($6::TIMESTAMPTZ IS NULL OR created_at < $6)
+                    ORDER BY created_at DESC
+                    LIMIT $7 OFFSET $8
+                    "#,
+                        query.actor_user_id.as_ref().map(UserId::as_uuid),
+                        query.action.map(|action| action.as_str()),
+                        query.target_type.map(|target_type| target_type.as_str()),
+                        query.target_id,
+                        query.since,
+                        query.until,
+                        i64::from(query.limit),
+                        i64::from(query.offset)
+                    )
+                    .fetch_all(&self.pool)
+                },
+                "Failed to list audit log entries",
+            )
+            .await?;
+
+        results.into_iter().map(AuditEntryRow::into_entry).collect()
+    }
+}
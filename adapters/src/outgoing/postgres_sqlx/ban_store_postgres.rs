@@ -1,11 +1,15 @@
 use sqlx::{PgPool, types::time::OffsetDateTime};
 use tracing::instrument;
+use uuid::Uuid;
 
 use domain::{
     auth::UserId,
-    ban::{Ban, BanId},
+    ban::{Ban, BanError, BanId},
+};
+use fedi_wplace_application::{
+    error::{AppError, AppResult},
+    ports::outgoing::ban_store::BanStorePort,
 };
-use fedi_wplace_application::{error::AppResult, ports::outgoing::ban_store::BanStorePort};
 
 use super::utils::PostgresExecutor;
 
@@ -23,37 +27,72 @@ impl PostgresBanStoreAdapter {
     }
 }
 
+struct BanRow {
+    id: Uuid,
+    user_id: Uuid,
+    banned_by_user_id: Option<Uuid>,
+    reason: String,
+    banned_at: OffsetDateTime,
+    expires_at: Option<OffsetDateTime>,
+    created_at: OffsetDateTime,
+    revoked_at: Option<OffsetDateTime>,
+    revoked_by_user_id: Option<Uuid>,
+    revoke_reason: Option<String>,
+    shadow: bool,
+}
+
+impl From<BanRow> for Ban {
+    fn from(row: BanRow) -> Self {
+        Self {
+            id: BanId::from_uuid(row.id),
+            user_id: UserId::from_uuid(row.user_id),
+            banned_by_user_id: row.banned_by_user_id.map(UserId::from_uuid),
+            reason: row.reason,
+            banned_at: row.banned_at,
+            expires_at: row.expires_at,
+            created_at: row.created_at,
+            revoked_at: row.revoked_at,
+            revoked_by_user_id: row.revoked_by_user_id.map(UserId::from_uuid),
+            revoke_reason: row.revoke_reason,
+            shadow: row.shadow,
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl BanStorePort for PostgresBanStoreAdapter {
     #[instrument(skip(self, ban))]
     async fn create_ban(&self, ban: &Ban) -> AppResult<()> {
-        self.executor.execute_with_timeout(
-            || {
-                sqlx::query!(
-                    r#"
-                    INSERT INTO banned_users (id, user_id, banned_by_user_id, reason, banned_at, expires_at, created_at)
-                    VALUES ($1, $2, $3, $4, $5, $6, $7)
-                    ON CONFLICT (user_id) DO UPDATE SET
-                        banned_by_user_id = EXCLUDED.banned_by_user_id,
-                        reason = EXCLUDED.reason,
-                        banned_at = EXCLUDED.banned_at,
-                        expires_at = EXCLUDED.expires_at,
-                        created_at = EXCLUDED.created_at
-                    "#,
-                    ban.id.as_uuid(),
-                    ban.user_id.as_uuid(),
-                    ban.banned_by_user_id.as_ref().map(UserId::as_uuid),
-                    ban.reason,
-                    OffsetDateTime::from(ban.banned_at),
-                    ban.expires_at.map(OffsetDateTime::from),
-                    OffsetDateTime::from(ban.created_at)
-                )
-                .execute(&self.pool)
-            },
-            "Failed to create ban",
-
+        // Only one open ban per user is allowed, which the unique index enforces.
+        sqlx::query!(
+            r#"
+            INSERT INTO banned_users (id, user_id, banned_by_user_id, reason, banned_at, expires_at, created_at, shadow)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            ban.id.as_uuid(),
+            ban.user_id.as_uuid(),
+            ban.banned_by_user_id.as_ref().map(UserId::as_uuid),
+            ban.reason,
+            OffsetDateTime::from(ban.banned_at),
+            ban.expires_at.map(OffsetDateTime::from),
+            OffsetDateTime::from(ban.created_at),
+            ban.shadow
         )
-        .await?;
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            if e.as_database_error()
+                .is_some_and(|db_error| db_error.is_unique_violation())
+            {
+                AppError::ValidationError {
+                    message: BanError::UserAlreadyBanned.to_string(),
+                }
+            } else {
+                AppError::DatabaseError {
+                    message: format!("Failed to create ban: {}", e),
+                }
+            }
+        })?;
 
         Ok(())
     }
@@ -64,11 +103,17 @@ impl BanStorePort for PostgresBanStoreAdapter {
             .executor
             .execute_with_timeout(
                 || {
-                    sqlx::query!(
+                    sqlx::query_as!(
+                        BanRow,
                         r#"
-                    SELECT id, user_id, banned_by_user_id, reason, banned_at, expires_at, created_at
+                    SELECT id, user_id, banned_by_user_id, reason, banned_at, expires_at, created_at,
+                           revoked_at, revoked_by_user_id, revoke_reason, shadow
                     FROM banned_users
                     WHERE user_id = $1
+                      AND revoked_at IS NULL
+                      AND (expires_at IS NULL OR expires_at > NOW())
+                    ORDER BY banned_at DESC
+                    LIMIT 1
                     "#,
                         user_id.as_uuid()
                     )
@@ -78,38 +123,32 @@ impl BanStorePort for PostgresBanStoreAdapter {
             )
             .await?;
 
-        match result {
-            Some(row) => {
-                let ban = Ban {
-                    id: BanId::from_uuid(row.id),
-                    user_id: UserId::from_uuid(row.user_id),
-                    banned_by_user_id: row.banned_by_user_id.map(UserId::from_uuid),
-                    reason: row.reason,
-                    banned_at: row.banned_at,
-                    expires_at: row.expires_at,
-                    created_at: row.created_at,
-                };
-                Ok(Some(ban))
-            }
-            None => Ok(None),
-        }
+        Ok(result.map(Ban::from))
     }
 
     #[instrument(skip(self))]
-    async fn remove_ban_by_user_id(&self, user_id: &UserId) -> AppResult<()> {
+    async fn revoke_ban(
+        &self,
+        ban_id: &BanId,
+        revoked_by_user_id: &UserId,
+        revoke_reason: Option<&str>,
+    ) -> AppResult<()> {
         self.executor
             .execute_with_timeout(
                 || {
                     sqlx::query!(
                         r#"
-                    DELETE FROM banned_users
-                    WHERE user_id = $1
+                    UPDATE banned_users
+                    SET revoked_at = NOW(), revoked_by_user_id = $2, revoke_reason = $3
+                    WHERE id = $1 AND revoked_at IS NULL
                     "#,
-                        user_id.as_uuid()
+                        ban_id.as_uuid(),
+                        revoked_by_user_id.as_uuid(),
+                        revoke_reason
                     )
                     .execute(&self.pool)
                 },
-                &format!("Failed to remove ban for user {}", user_id.as_uuid()),
+                &format!("Failed to revoke ban {}", ban_id.as_uuid()),
             )
             .await?;
 
@@ -122,10 +161,14 @@ impl BanStorePort for PostgresBanStoreAdapter {
             .executor
             .execute_with_timeout(
                 || {
-                    sqlx::query!(
+                    sqlx::query_as!(
+                        BanRow,
                         r#"
-                    SELECT id, user_id, banned_by_user_id, reason, banned_at, expires_at, created_at
+                    SELECT id, user_id, banned_by_user_id, reason, banned_at, expires_at, created_at,
+                           revoked_at, revoked_by_user_id, revoke_reason, shadow
                     FROM banned_users
+                    WHERE revoked_at IS NULL
+                      AND (expires_at IS NULL OR expires_at > NOW())
                     ORDER BY banned_at DESC
                     "#
                     )
@@ -135,20 +178,97 @@ impl BanStorePort for PostgresBanStoreAdapter {
             )
             .await?;
 
-        let bans = results
-            .into_iter()
-            .map(|row| Ban {
-                id: BanId::from_uuid(row.id),
-                user_id: UserId::from_uuid(row.user_id),
-                banned_by_user_id: row.banned_by_user_id.map(UserId::from_uuid),
-                reason: row.reason,
-                banned_at: row.banned_at,
-                expires_at: row.expires_at,
-                created_at: row.created_at,
-            })
-            .collect();
-
-        Ok(bans)
+        Ok(results.into_iter().map(Ban::from).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn claim_expired_bans(&self, limit: u32) -> AppResult<Vec<Ban>> {
+        let results = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        BanRow,
+                        r#"
+                    UPDATE banned_users
+                    SET expiry_processed_at = NOW()
+                    WHERE id IN (
+                        SELECT id
+                        FROM banned_users
+                        WHERE expires_at <= NOW()
+                          AND revoked_at IS NULL
+                          AND expiry_processed_at IS NULL
+                        ORDER BY expires_at
+                        LIMIT $1
+                        FOR UPDATE SKIP LOCKED
+                    )
+                    RETURNING id, user_id, banned_by_user_id, reason, banned_at, expires_at, created_at,
+                              revoked_at, revoked_by_user_id, revoke_reason, shadow
+                    "#,
+                        i64::from(limit)
+                    )
+                    .fetch_all(&self.pool)
+                },
+                "Failed to claim expired bans",
+            )
+            .await?;
+
+        Ok(results.into_iter().map(Ban::from).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn claim_expired_bans_of_user(&self, user_id: &UserId) -> AppResult<Vec<Ban>> {
+        let results = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        BanRow,
+                        r#"
+                    UPDATE banned_users
+                    SET expiry_processed_at = NOW()
+                    WHERE user_id = $1
+                      AND expires_at <= NOW()
+                      AND revoked_at IS NULL
+                      AND expiry_processed_at IS NULL
+                    RETURNING id, user_id, banned_by_user_id, reason, banned_at, expires_at, created_at,
+                              revoked_at, revoked_by_user_id, revoke_reason, shadow
+                    "#,
+                        user_id.as_uuid()
+                    )
+                    .fetch_all(&self.pool)
+                },
+                &format!("Failed to claim expired bans of user {}", user_id.as_uuid()),
+            )
+            .await?;
+
+        Ok(results.into_iter().map(Ban::from).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_ban_history_by_user_id(&self, user_id: &UserId) -> AppResult<Vec<Ban>> {
+        let results = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        BanRow,
+                        r#"
+                    SELECT id, user_id, banned_by_user_id, reason, banned_at, expires_at, created_at,
+                           revoked_at, revoked_by_user_id, revoke_reason, shadow
+                    FROM banned_users
+                    WHERE user_id = $1
+                    ORDER BY banned_at DESC
+                    "#,
+                        user_id.as_uuid()
+                    )
+                    .fetch_all(&self.pool)
+                },
+                &format!("Failed to get ban history for user {}", user_id.as_uuid()),
+            )
+            .await?;
+
+        Ok(results.into_iter().map(Ban::from).collect())
     }
 
     #[instrument(skip(self))]
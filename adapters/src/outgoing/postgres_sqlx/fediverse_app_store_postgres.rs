@@ -0,0 +1,105 @@
+use sqlx::PgPool;
+use tracing::instrument;
+
+use domain::fediverse::{FediverseApp, InstanceDomain};
+use fedi_wplace_application::{
+    error::{AppError, AppResult},
+    ports::outgoing::fediverse_app::FediverseAppStorePort,
+};
+
+use super::utils::PostgresExecutor;
+
+pub struct PostgresFediverseAppStoreAdapter {
+    pool: PgPool,
+    executor: PostgresExecutor,
+}
+
+impl PostgresFediverseAppStoreAdapter {
+    pub fn new(pool: PgPool, query_timeout_secs: u64) -> Self {
+        Self {
+            pool,
+            executor: PostgresExecutor::new(query_timeout_secs),
+        }
+    }
+}
+
+struct FediverseAppRow {
+    domain: String,
+    client_id: String,
+    client_secret: String,
+    redirect_url: String,
+}
+
+impl TryFrom<FediverseAppRow> for FediverseApp {
+    type Error = AppError;
+
+    fn try_from(row: FediverseAppRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            domain: row
+                .domain
+                .parse::<InstanceDomain>()
+                .map_err(|e| AppError::DatabaseError {
+                    message: format!("Stored fediverse app has an invalid domain: {}", e),
+                })?,
+            client_id: row.client_id,
+            client_secret: row.client_secret,
+            redirect_url: row.redirect_url,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl FediverseAppStorePort for PostgresFediverseAppStoreAdapter {
+    #[instrument(skip(self))]
+    async fn find_app(&self, domain: &InstanceDomain) -> AppResult<Option<FediverseApp>> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        FediverseAppRow,
+                        r#"
+                    SELECT domain, client_id, client_secret, redirect_url
+                    FROM fediverse_apps
+                    WHERE domain = $1
+                    "#,
+                        domain.as_str()
+                    )
+                    .fetch_optional(&self.pool)
+                },
+                &format!("Failed to get fediverse app for {}", domain),
+            )
+            .await?;
+
+        result.map(FediverseApp::try_from).transpose()
+    }
+
+    #[instrument(skip(self, app))]
+    async fn save_app(&self, app: &FediverseApp) -> AppResult<()> {
+        self.executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    INSERT INTO fediverse_apps (domain, client_id, client_secret, redirect_url)
+                    VALUES ($1, $2, $3, $4)
+                    ON CONFLICT (domain) DO UPDATE
+                    SET client_id = EXCLUDED.client_id,
+                        client_secret = EXCLUDED.client_secret,
+                        redirect_url = EXCLUDED.redirect_url,
+                        created_at = NOW()
+                    "#,
+                        app.domain.as_str(),
+                        app.client_id,
+                        app.client_secret,
+                        app.redirect_url
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to save fediverse app for {}", app.domain),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
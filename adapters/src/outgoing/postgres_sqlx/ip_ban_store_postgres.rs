@@ -0,0 +1,201 @@
+use sqlx::{PgPool, types::ipnet::IpNet, types::time::OffsetDateTime};
+use std::net::IpAddr;
+use tracing::instrument;
+
+use domain::{
+    auth::UserId,
+    ban::{IpBan, IpBanId},
+};
+use fedi_wplace_application::{error::AppResult, ports::outgoing::ip_ban_store::IpBanStorePort};
+
+use super::utils::PostgresExecutor;
+
+pub struct PostgresIpBanStoreAdapter {
+    pool: PgPool,
+    executor: PostgresExecutor,
+}
+
+impl PostgresIpBanStoreAdapter {
+    pub fn new(pool: PgPool, query_timeout_secs: u64) -> Self {
+        Self {
+            pool,
+            executor: PostgresExecutor::new(query_timeout_secs),
+        }
+    }
+}
+
+struct IpBanRow {
+    id: uuid::Uuid,
+    network: IpNet,
+    banned_by_user_id: Option<uuid::Uuid>,
+    reason: String,
+    banned_at: OffsetDateTime,
+    expires_at: Option<OffsetDateTime>,
+    created_at: OffsetDateTime,
+}
+
+impl From<IpBanRow> for IpBan {
+    fn from(row: IpBanRow) -> Self {
+        Self {
+            id: IpBanId::from_uuid(row.id),
+            network: row.network,
+            banned_by_user_id: row.banned_by_user_id.map(UserId::from_uuid),
+            reason: row.reason,
+            banned_at: row.banned_at,
+            expires_at: row.expires_at,
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl IpBanStorePort for PostgresIpBanStoreAdapter {
+    #[instrument(skip(self, ip_ban))]
+    async fn create_ip_ban(&self, ip_ban: &IpBan) -> AppResult<()> {
+        self.executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    INSERT INTO ip_bans (id, network, banned_by_user_id, reason, banned_at, expires_at, created_at)
+                    VALUES ($1, $2, $3, $4, $5, $6, $7)
+                    "#,
+                        ip_ban.id.as_uuid(),
+                        ip_ban.network,
+                        ip_ban.banned_by_user_id.as_ref().map(UserId::as_uuid),
+                        ip_ban.reason,
+                        ip_ban.banned_at,
+                        ip_ban.expires_at,
+                        ip_ban.created_at
+                    )
+                    .execute(&self.pool)
+                },
+                "Failed to create IP ban",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_ip_ban_by_id(&self, ban_id: &IpBanId) -> AppResult<Option<IpBan>> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        IpBanRow,
+                        r#"
+                    SELECT id, network as "network: IpNet", banned_by_user_id, reason, banned_at, expires_at, created_at
+                    FROM ip_bans
+                    WHERE id = $1
+                    "#,
+                        ban_id.as_uuid()
+                    )
+                    .fetch_optional(&self.pool)
+                },
+                &format!("Failed to get IP ban {}", ban_id.as_uuid()),
+            )
+            .await?;
+
+        Ok(result.map(IpBan::from))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_active_ip_ban_by_network(&self, network: &IpNet) -> AppResult<Option<IpBan>> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        IpBanRow,
+                        r#"
+                    SELECT id, network as "network: IpNet", banned_by_user_id, reason, banned_at, expires_at, created_at
+                    FROM ip_bans
+                    WHERE network = $1
+                      AND (expires_at IS NULL OR expires_at > NOW())
+                    LIMIT 1
+                    "#,
+                        network
+                    )
+                    .fetch_optional(&self.pool)
+                },
+                &format!("Failed to get IP ban for network {}", network),
+            )
+            .await?;
+
+        Ok(result.map(IpBan::from))
+    }
+
+    #[instrument(skip(self))]
+    async fn find_active_ip_ban(&self, ip: IpAddr) -> AppResult<Option<IpBan>> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        IpBanRow,
+                        r#"
+                    SELECT id, network as "network: IpNet", banned_by_user_id, reason, banned_at, expires_at, created_at
+                    FROM ip_bans
+                    WHERE network >>= $1
+                      AND (expires_at IS NULL OR expires_at > NOW())
+                    ORDER BY masklen(network) DESC
+                    LIMIT 1
+                    "#,
+                        IpNet::from(ip)
+                    )
+                    .fetch_optional(&self.pool)
+                },
+                &format!("Failed to look up IP ban for {}", ip),
+            )
+            .await?;
+
+        Ok(result.map(IpBan::from))
+    }
+
+    #[instrument(skip(self))]
+    async fn remove_ip_ban(&self, ban_id: &IpBanId) -> AppResult<()> {
+        self.executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    DELETE FROM ip_bans
+                    WHERE id = $1
+                    "#,
+                        ban_id.as_uuid()
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to remove IP ban {}", ban_id.as_uuid()),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_all_active_ip_bans(&self) -> AppResult<Vec<IpBan>> {
+        let results = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        IpBanRow,
+                        r#"
+                    SELECT id, network as "network: IpNet", banned_by_user_id, reason, banned_at, expires_at, created_at
+                    FROM ip_bans
+                    WHERE expires_at IS NULL OR expires_at > NOW()
+                    ORDER BY banned_at DESC
+                    "#
+                    )
+                    .fetch_all(&self.pool)
+                },
+                "Failed to get active IP bans",
+            )
+            .await?;
+
+        Ok(results.into_iter().map(IpBan::from).collect())
+    }
+}
@@ -0,0 +1,221 @@
+use sqlx::{PgPool, types::time::OffsetDateTime};
+use tracing::instrument;
+use uuid::Uuid;
+
+use domain::{
+    auth::UserId,
+    login_protection::{LockedAccount, LoginFailures},
+};
+use fedi_wplace_application::{
+    error::{AppError, AppResult},
+    ports::outgoing::login_failure_store::LoginFailureStorePort,
+};
+
+use super::utils::PostgresExecutor;
+
+pub struct PostgresLoginFailureStoreAdapter {
+    pool: PgPool,
+    executor: PostgresExecutor,
+}
+
+impl PostgresLoginFailureStoreAdapter {
+    pub fn new(pool: PgPool, query_timeout_secs: u64) -> Self {
+        Self {
+            pool,
+            executor: PostgresExecutor::new(query_timeout_secs),
+        }
+    }
+}
+
+fn failed_attempts_from_row(user_id: Uuid, failed_attempts: i32) -> AppResult<u32> {
+    u32::try_from(failed_attempts).map_err(|_| AppError::DatabaseError {
+        message: format!("Login failures of user {} have a negative count", user_id),
+    })
+}
+
+struct LoginFailuresRow {
+    user_id: Uuid,
+    failed_attempts: i32,
+    last_failed_at: OffsetDateTime,
+    blocked_until: Option<OffsetDateTime>,
+    locked_until: Option<OffsetDateTime>,
+}
+
+impl LoginFailuresRow {
+    fn into_failures(self) -> AppResult<LoginFailures> {
+        Ok(LoginFailures {
+            failed_attempts: failed_attempts_from_row(self.user_id, self.failed_attempts)?,
+            user_id: UserId::from_uuid(self.user_id),
+            last_failed_at: self.last_failed_at,
+            blocked_until: self.blocked_until,
+            locked_until: self.locked_until,
+        })
+    }
+}
+
+struct LockedAccountRow {
+    user_id: Uuid,
+    username: String,
+    failed_attempts: i32,
+    last_failed_at: OffsetDateTime,
+    locked_until: OffsetDateTime,
+}
+
+impl LockedAccountRow {
+    fn into_locked_account(self) -> AppResult<LockedAccount> {
+        Ok(LockedAccount {
+            failed_attempts: failed_attempts_from_row(self.user_id, self.failed_attempts)?,
+            user_id: UserId::from_uuid(self.user_id),
+            username: self.username,
+            last_failed_at: self.last_failed_at,
+            locked_until: self.locked_until,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl LoginFailureStorePort for PostgresLoginFailureStoreAdapter {
+    #[instrument(skip(self))]
+    async fn find_failures(&self, user_id: Uuid) -> AppResult<Option<LoginFailures>> {
+        let row = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        LoginFailuresRow,
+                        r#"
+                    SELECT user_id, failed_attempts, last_failed_at, blocked_until, locked_until
+                    FROM login_failures
+                    WHERE user_id = $1
+                    "#,
+                        user_id
+                    )
+                    .fetch_optional(&self.pool)
+                },
+                &format!("Failed to load login failures of user {}", user_id),
+            )
+            .await?;
+
+        row.map(LoginFailuresRow::into_failures).transpose()
+    }
+
+    #[instrument(skip(self))]
+    async fn record_failure(
+        &self,
+        user_id: Uuid,
+        now: OffsetDateTime,
+        reset_before: OffsetDateTime,
+    ) -> AppResult<u32> {
+        let failed_attempts = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_scalar!(
+                        r#"
+                    INSERT INTO login_failures (user_id, failed_attempts, last_failed_at)
+                    VALUES ($1, 1, $2)
+                    ON CONFLICT (user_id) DO UPDATE SET
+                        failed_attempts = CASE
+                            WHEN login_failures.last_failed_at < $3 THEN 1
+                            ELSE login_failures.failed_attempts + 1
+                        END,
+                        last_failed_at = EXCLUDED.last_failed_at
+                    RETURNING failed_attempts
+                    "#,
+                        user_id,
+                        now,
+                        reset_before
+                    )
+                    .fetch_one(&self.pool)
+                },
+                &format!("Failed to record login failure of user {}", user_id),
+            )
+            .await?;
+
+        failed_attempts_from_row(user_id, failed_attempts)
+    }
+
+    #[instrument(skip(self))]
+    async fn block_until(
+        &self,
+        user_id: Uuid,
+        blocked_until: OffsetDateTime,
+        locked: bool,
+    ) -> AppResult<()> {
+        self.executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    UPDATE login_failures
+                    SET blocked_until = GREATEST(blocked_until, $2),
+                        locked_until = CASE
+                            WHEN $3 THEN GREATEST(locked_until, $2)
+                            ELSE locked_until
+                        END
+                    WHERE user_id = $1
+                    "#,
+                        user_id,
+                        blocked_until,
+                        locked
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to block login of user {}", user_id),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn clear_failures(&self, user_id: Uuid) -> AppResult<bool> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    DELETE FROM login_failures
+                    WHERE user_id = $1
+                    "#,
+                        user_id
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to clear login failures of user {}", user_id),
+            )
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self))]
+    async fn list_locked(&self, now: OffsetDateTime) -> AppResult<Vec<LockedAccount>> {
+        let rows = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        LockedAccountRow,
+                        r#"
+                    SELECT lf.user_id, u.username, lf.failed_attempts, lf.last_failed_at,
+                           lf.locked_until as "locked_until!"
+                    FROM login_failures lf
+                    JOIN users u ON u.id = lf.user_id
+                    WHERE lf.locked_until > $1
+                    ORDER BY lf.locked_until DESC
+                    "#,
+                        now
+                    )
+                    .fetch_all(&self.pool)
+                },
+                "Failed to list locked accounts",
+            )
+            .await?;
+
+        rows.into_iter()
+            .map(LockedAccountRow::into_locked_account)
+            .collect()
+    }
+}
@@ -1,6 +1,19 @@
 mod utils;
 
+pub mod account_store_postgres;
+pub mod api_token_store_postgres;
+pub mod audit_log_postgres;
 pub mod ban_store_postgres;
 pub mod credit_store_postgres;
+pub mod fediverse_app_store_postgres;
+pub mod ip_ban_store_postgres;
+pub mod login_failure_store_postgres;
 pub mod pixel_history_store_postgres;
+pub mod protected_region_store_postgres;
+pub mod quarantine_store_postgres;
+pub mod report_store_postgres;
+pub mod role_store_postgres;
+pub mod session_store_postgres;
+pub mod two_factor_store_postgres;
+pub mod user_directory_postgres;
 pub mod user_store_postgres;
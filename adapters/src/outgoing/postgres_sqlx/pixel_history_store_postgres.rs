@@ -1,6 +1,8 @@
 use domain::{
     action::PaintAction,
-    coords::{GlobalCoord, TileCoord},
+    auth::UserId,
+    color::ColorId,
+    coords::{GlobalCoord, GlobalRegion, TileCoord},
 };
 use sqlx::{PgPool, types::time::OffsetDateTime};
 use tracing::instrument;
@@ -42,6 +44,7 @@ impl PixelHistoryStorePort for PostgresPixelHistoryStoreAdapter {
         let mut global_ys: Vec<i32> = Vec::with_capacity(actions.len());
         let mut color_ids: Vec<i16> = Vec::with_capacity(actions.len());
         let mut timestamps: Vec<OffsetDateTime> = Vec::with_capacity(actions.len());
+        let mut automated: Vec<bool> = Vec::with_capacity(actions.len());
 
         for action in actions {
             user_ids.push(action.user_id.0);
@@ -49,25 +52,28 @@ impl PixelHistoryStorePort for PostgresPixelHistoryStoreAdapter {
             global_ys.push(action.global_coord.y);
             color_ids.push(i16::from(action.color_id.0));
             timestamps.push(action.timestamp);
+            automated.push(action.automated);
         }
 
         self.executor.execute_with_timeout(
             || {
                 sqlx::query!(
                     r#"
-                    INSERT INTO pixel_history (user_id, global_x, global_y, color_id, created_at)
-                    SELECT * FROM UNNEST($1::UUID[], $2::INTEGER[], $3::INTEGER[], $4::SMALLINT[], $5::TIMESTAMPTZ[])
+                    INSERT INTO pixel_history (user_id, global_x, global_y, color_id, created_at, automated)
+                    SELECT * FROM UNNEST($1::UUID[], $2::INTEGER[], $3::INTEGER[], $4::SMALLINT[], $5::TIMESTAMPTZ[], $6::BOOLEAN[])
                     ON CONFLICT (global_x, global_y)
                     DO UPDATE SET
                         user_id = EXCLUDED.user_id,
                         color_id = EXCLUDED.color_id,
-                        created_at = EXCLUDED.created_at
+                        created_at = EXCLUDED.created_at,
+                        automated = EXCLUDED.automated
                     "#,
                     &user_ids[..],
                     &global_xs[..],
                     &global_ys[..],
                     &color_ids[..],
-                    &timestamps[..]
+                    &timestamps[..],
+                    &automated[..]
                 )
                 .execute(&self.pool)
             },
@@ -94,14 +100,15 @@ impl PixelHistoryStorePort for PostgresPixelHistoryStoreAdapter {
                     sqlx::query!(
                         r#"
                     SELECT
-                        ph.user_id,
-                        u.username,
+                        ph.user_id as "user_id?",
+                        u.username as "username?",
                         ph.global_x,
                         ph.global_y,
                         ph.color_id,
-                        ph.created_at
+                        ph.created_at,
+                        ph.automated
                     FROM pixel_history ph
-                    JOIN users u ON ph.user_id = u.id
+                    LEFT JOIN users u ON ph.user_id = u.id
                     WHERE ph.global_x >= $1 AND ph.global_x <= $2
                       AND ph.global_y >= $3 AND ph.global_y <= $4
                     ORDER BY ph.created_at DESC
@@ -133,6 +140,7 @@ impl PixelHistoryStorePort for PostgresPixelHistoryStoreAdapter {
                     pixel_y,
                     color_id: row.color_id as u8,
                     timestamp: row.created_at,
+                    automated: row.automated,
                 }
             })
             .collect();
@@ -235,12 +243,13 @@ impl PixelHistoryStorePort for PostgresPixelHistoryStoreAdapter {
                     sqlx::query!(
                         r#"
                     SELECT
-                        ph.user_id,
-                        u.username,
+                        ph.user_id as "user_id?",
+                        u.username as "username?",
                         ph.color_id,
-                        ph.created_at
+                        ph.created_at,
+                        ph.automated
                     FROM pixel_history ph
-                    JOIN users u ON ph.user_id = u.id
+                    LEFT JOIN users u ON ph.user_id = u.id
                     WHERE ph.global_x = $1 AND ph.global_y = $2
                     "#,
                         coord.x,
@@ -260,6 +269,8 @@ impl PixelHistoryStorePort for PostgresPixelHistoryStoreAdapter {
             username: row.username,
             color_id: row.color_id as u8,
             timestamp: row.created_at,
+            automated: row.automated,
+            protection_remaining: None,
         });
 
         tracing::debug!(
@@ -270,4 +281,122 @@ impl PixelHistoryStorePort for PostgresPixelHistoryStoreAdapter {
         );
         Ok(result)
     }
+
+    #[instrument(skip(self, coords))]
+    async fn get_latest_paint_actions(
+        &self,
+        coords: &[GlobalCoord],
+    ) -> AppResult<Vec<PaintAction>> {
+        if coords.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let global_xs: Vec<i32> = coords.iter().map(|coord| coord.x).collect();
+        let global_ys: Vec<i32> = coords.iter().map(|coord| coord.y).collect();
+
+        let rows = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    SELECT ph.user_id as "user_id!", ph.global_x, ph.global_y, ph.color_id, ph.created_at, ph.automated
+                    FROM pixel_history ph
+                    JOIN UNNEST($1::INTEGER[], $2::INTEGER[]) AS c(x, y)
+                      ON ph.global_x = c.x AND ph.global_y = c.y
+                    WHERE ph.user_id IS NOT NULL
+                    "#,
+                        &global_xs[..],
+                        &global_ys[..]
+                    )
+                    .fetch_all(&self.pool)
+                },
+                &format!("Failed to load latest paints of {} pixels", coords.len()),
+            )
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| PaintAction {
+                user_id: UserId::from_uuid(row.user_id),
+                global_coord: GlobalCoord::new(row.global_x, row.global_y),
+                color_id: ColorId::new(u8::try_from(row.color_id).unwrap_or_default()),
+                timestamp: row.created_at,
+                automated: row.automated,
+            })
+            .collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_paint_actions_by_user(&self, user_id: &UserId) -> AppResult<Vec<PaintAction>> {
+        let rows = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    SELECT global_x, global_y, color_id, created_at, automated
+                    FROM pixel_history
+                    WHERE user_id = $1
+                    ORDER BY created_at DESC
+                    "#,
+                        user_id.as_uuid()
+                    )
+                    .fetch_all(&self.pool)
+                },
+                &format!("Failed to load paints of user {}", user_id.as_uuid()),
+            )
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| PaintAction {
+                user_id: user_id.clone(),
+                global_coord: GlobalCoord::new(row.global_x, row.global_y),
+                color_id: ColorId::new(u8::try_from(row.color_id).unwrap_or_default()),
+                timestamp: row.created_at,
+                automated: row.automated,
+            })
+            .collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_pixels_in_region(
+        &self,
+        region: GlobalRegion,
+        painted_since: Option<OffsetDateTime>,
+        painted_by: Option<&UserId>,
+    ) -> AppResult<Vec<GlobalCoord>> {
+        let rows = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    DELETE FROM pixel_history
+                    WHERE global_x BETWEEN $1 AND $3
+                      AND global_y BETWEEN $2 AND $4
+                      AND ($5::TIMESTAMPTZ IS NULL OR created_at >= $5)
+                      AND ($6::UUID IS NULL OR user_id = $6)
+                    RETURNING global_x, global_y
+                    "#,
+                        region.min_x,
+                        region.min_y,
+                        region.max_x,
+                        region.max_y,
+                        painted_since,
+                        painted_by.map(UserId::as_uuid)
+                    )
+                    .fetch_all(&self.pool)
+                },
+                &format!("Failed to delete pixels in region {}", region),
+            )
+            .await?;
+
+        tracing::debug!("Deleted {} pixels in region {}", rows.len(), region);
+        Ok(rows
+            .into_iter()
+            .map(|row| GlobalCoord::new(row.global_x, row.global_y))
+            .collect())
+    }
 }
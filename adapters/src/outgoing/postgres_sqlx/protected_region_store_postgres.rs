@@ -0,0 +1,339 @@
+use sqlx::{PgPool, types::time::OffsetDateTime};
+use tracing::instrument;
+use uuid::Uuid;
+
+use domain::{
+    auth::UserId,
+    coords::{GlobalCoord, GlobalRegion},
+    protected_region::{
+        PaintRestriction, PaintRestrictionKind, ProtectedArea, ProtectedRegion, ProtectedRegionId,
+    },
+};
+use fedi_wplace_application::{
+    error::{AppError, AppResult},
+    ports::outgoing::protected_region_store::ProtectedRegionStorePort,
+};
+
+use super::utils::PostgresExecutor;
+
+pub struct PostgresProtectedRegionStoreAdapter {
+    pool: PgPool,
+    executor: PostgresExecutor,
+}
+
+impl PostgresProtectedRegionStoreAdapter {
+    pub fn new(pool: PgPool, query_timeout_secs: u64) -> Self {
+        Self {
+            pool,
+            executor: PostgresExecutor::new(query_timeout_secs),
+        }
+    }
+}
+
+struct ProtectedRegionRow {
+    id: Uuid,
+    name: String,
+    description: Option<String>,
+    shape: String,
+    min_x: i32,
+    min_y: i32,
+    max_x: i32,
+    max_y: i32,
+    polygon_xs: Option<Vec<i32>>,
+    polygon_ys: Option<Vec<i32>>,
+    restriction: String,
+    allowed_roles: Vec<String>,
+    opens_at: Option<OffsetDateTime>,
+    closes_at: Option<OffsetDateTime>,
+    created_by: Option<Uuid>,
+    created_at: OffsetDateTime,
+    updated_at: OffsetDateTime,
+}
+
+impl ProtectedRegionRow {
+    fn into_region(self) -> AppResult<ProtectedRegion> {
+        let invalid = |field: &str| AppError::DatabaseError {
+            message: format!("Protected region {} has an invalid {}", self.id, field),
+        };
+
+        let area = match (self.shape.as_str(), &self.polygon_xs, &self.polygon_ys) {
+            ("rectangle", _, _) => ProtectedArea::Rectangle(GlobalRegion::new(
+                self.min_x, self.min_y, self.max_x, self.max_y,
+            )),
+            ("polygon", Some(xs), Some(ys)) if xs.len() == ys.len() => ProtectedArea::Polygon(
+                xs.iter()
+                    .zip(ys)
+                    .map(|(x, y)| GlobalCoord::new(*x, *y))
+                    .collect(),
+            ),
+            _ => return Err(invalid("shape")),
+        };
+
+        let kind: PaintRestrictionKind = self
+            .restriction
+            .parse()
+            .map_err(|_| invalid("restriction"))?;
+        let restriction = match kind {
+            PaintRestrictionKind::Locked => PaintRestriction::Locked,
+            PaintRestrictionKind::RolesOnly => PaintRestriction::RolesOnly {
+                role_names: self.allowed_roles,
+            },
+            PaintRestrictionKind::TimeWindow => PaintRestriction::TimeWindow {
+                opens_at: self.opens_at.ok_or_else(|| invalid("time window"))?,
+                closes_at: self.closes_at.ok_or_else(|| invalid("time window"))?,
+            },
+        };
+
+        Ok(ProtectedRegion {
+            id: ProtectedRegionId::from_uuid(self.id),
+            name: self.name,
+            description: self.description,
+            area,
+            restriction,
+            created_by: self.created_by.map(UserId::from_uuid),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        })
+    }
+}
+
+struct RegionColumns {
+    bounds: GlobalRegion,
+    polygon_xs: Option<Vec<i32>>,
+    polygon_ys: Option<Vec<i32>>,
+    allowed_roles: Vec<String>,
+    opens_at: Option<OffsetDateTime>,
+    closes_at: Option<OffsetDateTime>,
+}
+
+impl RegionColumns {
+    fn from_region(region: &ProtectedRegion) -> Self {
+        let (polygon_xs, polygon_ys) = match &region.area {
+            ProtectedArea::Rectangle(_) => (None, None),
+            ProtectedArea::Polygon(vertices) => (
+                Some(vertices.iter().map(|vertex| vertex.x).collect()),
+                Some(vertices.iter().map(|vertex| vertex.y).collect()),
+            ),
+        };
+        let (allowed_roles, opens_at, closes_at) = match &region.restriction {
+            PaintRestriction::Locked => (Vec::new(), None, None),
+            PaintRestriction::RolesOnly { role_names } => (role_names.clone(), None, None),
+            PaintRestriction::TimeWindow {
+                opens_at,
+                closes_at,
+            } => (Vec::new(), Some(*opens_at), Some(*closes_at)),
+        };
+
+        Self {
+            bounds: region.area.bounding_box(),
+            polygon_xs,
+            polygon_ys,
+            allowed_roles,
+            opens_at,
+            closes_at,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ProtectedRegionStorePort for PostgresProtectedRegionStoreAdapter {
+    #[instrument(skip(self))]
+    async fn list_protected_regions(&self) -> AppResult<Vec<ProtectedRegion>> {
+        let rows = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        ProtectedRegionRow,
+                        r#"
+                    SELECT id, name, description, shape, min_x, min_y, max_x, max_y,
+                           polygon_xs, polygon_ys, restriction, allowed_roles,
+                           opens_at, closes_at, created_by, created_at, updated_at
+                    FROM protected_regions
+                    ORDER BY created_at, id
+                    "#
+                    )
+                    .fetch_all(&self.pool)
+                },
+                "Failed to list protected regions",
+            )
+            .await?;
+
+        rows.into_iter()
+            .map(ProtectedRegionRow::into_region)
+            .collect()
+    }
+
+    #[instrument(skip(self))]
+    async fn list_protected_regions_in(
+        &self,
+        bounds: GlobalRegion,
+    ) -> AppResult<Vec<ProtectedRegion>> {
+        let rows = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        ProtectedRegionRow,
+                        r#"
+                    SELECT id, name, description, shape, min_x, min_y, max_x, max_y,
+                           polygon_xs, polygon_ys, restriction, allowed_roles,
+                           opens_at, closes_at, created_by, created_at, updated_at
+                    FROM protected_regions
+                    WHERE min_x <= $3 AND max_x >= $1
+                      AND min_y <= $4 AND max_y >= $2
+                    ORDER BY created_at, id
+                    "#,
+                        bounds.min_x,
+                        bounds.min_y,
+                        bounds.max_x,
+                        bounds.max_y
+                    )
+                    .fetch_all(&self.pool)
+                },
+                &format!("Failed to list protected regions in {}", bounds),
+            )
+            .await?;
+
+        rows.into_iter()
+            .map(ProtectedRegionRow::into_region)
+            .collect()
+    }
+
+    #[instrument(skip(self))]
+    async fn get_protected_region(
+        &self,
+        region_id: &ProtectedRegionId,
+    ) -> AppResult<Option<ProtectedRegion>> {
+        let row = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        ProtectedRegionRow,
+                        r#"
+                    SELECT id, name, description, shape, min_x, min_y, max_x, max_y,
+                           polygon_xs, polygon_ys, restriction, allowed_roles,
+                           opens_at, closes_at, created_by, created_at, updated_at
+                    FROM protected_regions
+                    WHERE id = $1
+                    "#,
+                        region_id.as_uuid()
+                    )
+                    .fetch_optional(&self.pool)
+                },
+                &format!("Failed to get protected region {}", region_id.as_uuid()),
+            )
+            .await?;
+
+        row.map(ProtectedRegionRow::into_region).transpose()
+    }
+
+    #[instrument(skip(self, region))]
+    async fn create_protected_region(&self, region: &ProtectedRegion) -> AppResult<()> {
+        let columns = RegionColumns::from_region(region);
+
+        self.executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    INSERT INTO protected_regions (
+                        id, name, description, shape, min_x, min_y, max_x, max_y,
+                        polygon_xs, polygon_ys, restriction, allowed_roles,
+                        opens_at, closes_at, created_by, created_at, updated_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+                    "#,
+                        region.id.as_uuid(),
+                        region.name,
+                        region.description,
+                        region.area.shape_str(),
+                        columns.bounds.min_x,
+                        columns.bounds.min_y,
+                        columns.bounds.max_x,
+                        columns.bounds.max_y,
+                        columns.polygon_xs.as_deref(),
+                        columns.polygon_ys.as_deref(),
+                        region.restriction.kind().as_str(),
+                        &columns.allowed_roles[..],
+                        columns.opens_at,
+                        columns.closes_at,
+                        region.created_by.as_ref().map(UserId::as_uuid),
+                        region.created_at,
+                        region.updated_at
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to create protected region {}", region.name),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, region))]
+    async fn update_protected_region(&self, region: &ProtectedRegion) -> AppResult<bool> {
+        let columns = RegionColumns::from_region(region);
+
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    UPDATE protected_regions
+                    SET name = $2, description = $3, shape = $4,
+                        min_x = $5, min_y = $6, max_x = $7, max_y = $8,
+                        polygon_xs = $9, polygon_ys = $10, restriction = $11,
+                        allowed_roles = $12, opens_at = $13, closes_at = $14,
+                        updated_at = $15
+                    WHERE id = $1
+                    "#,
+                        region.id.as_uuid(),
+                        region.name,
+                        region.description,
+                        region.area.shape_str(),
+                        columns.bounds.min_x,
+                        columns.bounds.min_y,
+                        columns.bounds.max_x,
+                        columns.bounds.max_y,
+                        columns.polygon_xs.as_deref(),
+                        columns.polygon_ys.as_deref(),
+                        region.restriction.kind().as_str(),
+                        &columns.allowed_roles[..],
+                        columns.opens_at,
+                        columns.closes_at,
+                        region.updated_at
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to update protected region {}", region.id.as_uuid()),
+            )
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_protected_region(&self, region_id: &ProtectedRegionId) -> AppResult<bool> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    DELETE FROM protected_regions
+                    WHERE id = $1
+                    "#,
+                        region_id.as_uuid()
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to delete protected region {}", region_id.as_uuid()),
+            )
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,197 @@
+use sqlx::{PgPool, types::time::OffsetDateTime};
+use tracing::instrument;
+use uuid::Uuid;
+
+use domain::{
+    action::PaintAction,
+    auth::UserId,
+    ban::{BanId, QuarantinedPixel},
+    color::ColorId,
+    coords::GlobalCoord,
+};
+use fedi_wplace_application::{
+    error::{AppError, AppResult},
+    ports::outgoing::quarantine_store::QuarantineStorePort,
+};
+
+use super::utils::PostgresExecutor;
+
+pub struct PostgresQuarantineStoreAdapter {
+    pool: PgPool,
+    executor: PostgresExecutor,
+}
+
+impl PostgresQuarantineStoreAdapter {
+    pub fn new(pool: PgPool, query_timeout_secs: u64) -> Self {
+        Self {
+            pool,
+            executor: PostgresExecutor::new(query_timeout_secs),
+        }
+    }
+}
+
+struct QuarantinedPixelRow {
+    id: i64,
+    user_id: Uuid,
+    ban_id: Uuid,
+    global_x: i32,
+    global_y: i32,
+    color_id: i16,
+    painted_at: OffsetDateTime,
+    automated: bool,
+}
+
+impl QuarantinedPixelRow {
+    fn into_pixel(self) -> AppResult<QuarantinedPixel> {
+        let color_id = u8::try_from(self.color_id).map_err(|_| AppError::DatabaseError {
+            message: format!("Quarantined pixel {} has an invalid color", self.id),
+        })?;
+
+        Ok(QuarantinedPixel {
+            id: self.id,
+            ban_id: BanId::from_uuid(self.ban_id),
+            action: PaintAction {
+                user_id: UserId::from_uuid(self.user_id),
+                global_coord: GlobalCoord::new(self.global_x, self.global_y),
+                color_id: ColorId::new(color_id),
+                timestamp: self.painted_at,
+                automated: self.automated,
+            },
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl QuarantineStorePort for PostgresQuarantineStoreAdapter {
+    #[instrument(skip(self, actions))]
+    async fn record_quarantined_pixels(
+        &self,
+        ban_id: &BanId,
+        actions: &[PaintAction],
+    ) -> AppResult<()> {
+        if actions.is_empty() {
+            return Ok(());
+        }
+
+        let mut user_ids: Vec<Uuid> = Vec::with_capacity(actions.len());
+        let mut global_xs: Vec<i32> = Vec::with_capacity(actions.len());
+        let mut global_ys: Vec<i32> = Vec::with_capacity(actions.len());
+        let mut color_ids: Vec<i16> = Vec::with_capacity(actions.len());
+        let mut timestamps: Vec<OffsetDateTime> = Vec::with_capacity(actions.len());
+        let mut automated: Vec<bool> = Vec::with_capacity(actions.len());
+
+        for action in actions {
+            user_ids.push(action.user_id.0);
+            global_xs.push(action.global_coord.x);
+            global_ys.push(action.global_coord.y);
+            color_ids.push(i16::from(action.color_id.0));
+            timestamps.push(action.timestamp);
+            automated.push(action.automated);
+        }
+
+        self.executor.execute_with_timeout(
+            || {
+                sqlx::query!(
+                    r#"
+                    INSERT INTO quarantined_pixels (ban_id, user_id, global_x, global_y, color_id, painted_at, automated)
+                    SELECT $1, * FROM UNNEST($2::UUID[], $3::INTEGER[], $4::INTEGER[], $5::SMALLINT[], $6::TIMESTAMPTZ[], $7::BOOLEAN[])
+                    "#,
+                    ban_id.as_uuid(),
+                    &user_ids[..],
+                    &global_xs[..],
+                    &global_ys[..],
+                    &color_ids[..],
+                    &timestamps[..],
+                    &automated[..]
+                )
+                .execute(&self.pool)
+            },
+            &format!("Failed to quarantine {} paint actions", actions.len()),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn list_quarantined_pixels(&self, user_id: &UserId) -> AppResult<Vec<QuarantinedPixel>> {
+        let results = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        QuarantinedPixelRow,
+                        r#"
+                    SELECT id, user_id, ban_id, global_x, global_y, color_id, painted_at, automated
+                    FROM quarantined_pixels
+                    WHERE user_id = $1
+                    ORDER BY painted_at, id
+                    "#,
+                        user_id.as_uuid()
+                    )
+                    .fetch_all(&self.pool)
+                },
+                &format!(
+                    "Failed to list quarantined pixels for user {}",
+                    user_id.as_uuid()
+                ),
+            )
+            .await?;
+
+        results
+            .into_iter()
+            .map(QuarantinedPixelRow::into_pixel)
+            .collect()
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_quarantined_pixels(&self, user_id: &UserId) -> AppResult<u64> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    DELETE FROM quarantined_pixels
+                    WHERE user_id = $1
+                    "#,
+                        user_id.as_uuid()
+                    )
+                    .execute(&self.pool)
+                },
+                &format!(
+                    "Failed to delete quarantined pixels for user {}",
+                    user_id.as_uuid()
+                ),
+            )
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip(self, pixel_ids))]
+    async fn delete_quarantined_pixels_by_id(&self, pixel_ids: &[i64]) -> AppResult<u64> {
+        if pixel_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    DELETE FROM quarantined_pixels
+                    WHERE id = ANY($1)
+                    "#,
+                        pixel_ids
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to delete {} quarantined pixels", pixel_ids.len()),
+            )
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
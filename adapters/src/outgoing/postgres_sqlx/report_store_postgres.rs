@@ -0,0 +1,400 @@
+use sqlx::{PgPool, types::time::OffsetDateTime};
+use tracing::instrument;
+
+use domain::{
+    auth::UserId,
+    coords::{GlobalCoord, GlobalRegion},
+    report::{Report, ReportId, ReportResolutionAction, ReportStatus, ReportTarget},
+};
+use fedi_wplace_application::{
+    error::{AppError, AppResult},
+    ports::outgoing::report_store::{ReportQuery, ReportStorePort},
+};
+
+use super::utils::PostgresExecutor;
+
+pub struct PostgresReportStoreAdapter {
+    pool: PgPool,
+    executor: PostgresExecutor,
+}
+
+impl PostgresReportStoreAdapter {
+    pub fn new(pool: PgPool, query_timeout_secs: u64) -> Self {
+        Self {
+            pool,
+            executor: PostgresExecutor::new(query_timeout_secs),
+        }
+    }
+}
+
+struct ReportRow {
+    id: uuid::Uuid,
+    reporter_user_id: uuid::Uuid,
+    target_type: String,
+    target_user_id: Option<uuid::Uuid>,
+    reported_user_id: Option<uuid::Uuid>,
+    region_min_x: Option<i32>,
+    region_min_y: Option<i32>,
+    region_max_x: Option<i32>,
+    region_max_y: Option<i32>,
+    category: String,
+    comment: String,
+    status: String,
+    claimed_by_user_id: Option<uuid::Uuid>,
+    claimed_at: Option<OffsetDateTime>,
+    resolved_by_user_id: Option<uuid::Uuid>,
+    resolved_at: Option<OffsetDateTime>,
+    resolution_action: Option<String>,
+    resolution_note: Option<String>,
+    created_at: OffsetDateTime,
+}
+
+impl ReportRow {
+    fn target(&self) -> Option<ReportTarget> {
+        if self.target_type == "user" {
+            return self
+                .reported_user_id
+                .map(|id| ReportTarget::User(UserId::from_uuid(id)));
+        }
+
+        let region = GlobalRegion::new(
+            self.region_min_x?,
+            self.region_min_y?,
+            self.region_max_x?,
+            self.region_max_y?,
+        );
+        match self.target_type.as_str() {
+            "pixel" => Some(ReportTarget::Pixel(GlobalCoord::new(
+                region.min_x,
+                region.min_y,
+            ))),
+            "region" => Some(ReportTarget::Region(region)),
+            _ => None,
+        }
+    }
+
+    fn into_report(self) -> AppResult<Report> {
+        let invalid = |field: &str| AppError::DatabaseError {
+            message: format!("Report {} has an invalid {}", self.id, field),
+        };
+
+        let target = self.target().ok_or_else(|| invalid("target"))?;
+        let category = self.category.parse().map_err(|_| invalid("category"))?;
+        let status = self.status.parse().map_err(|_| invalid("status"))?;
+        let resolution_action = self
+            .resolution_action
+            .as_deref()
+            .map(str::parse::<ReportResolutionAction>)
+            .transpose()
+            .map_err(|_| invalid("resolution action"))?;
+
+        Ok(Report {
+            id: ReportId::from_uuid(self.id),
+            reporter_user_id: UserId::from_uuid(self.reporter_user_id),
+            target,
+            target_user_id: self.target_user_id.map(UserId::from_uuid),
+            category,
+            comment: self.comment,
+            status,
+            claimed_by_user_id: self.claimed_by_user_id.map(UserId::from_uuid),
+            claimed_at: self.claimed_at,
+            resolved_by_user_id: self.resolved_by_user_id.map(UserId::from_uuid),
+            resolved_at: self.resolved_at,
+            resolution_action,
+            resolution_note: self.resolution_note,
+            created_at: self.created_at,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl ReportStorePort for PostgresReportStoreAdapter {
+    #[instrument(skip(self, report))]
+    async fn create_report(&self, report: &Report) -> AppResult<bool> {
+        let region = report.target.region();
+        let reported_user_id = match &report.target {
+            ReportTarget::User(user_id) => Some(*user_id.as_uuid()),
+            _ => None,
+        };
+
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    INSERT INTO reports (
+                        id, reporter_user_id, target_type, target_user_id, reported_user_id,
+                        region_min_x, region_min_y, region_max_x, region_max_y,
+                        dedup_key, category, comment, status, created_at
+                    )
+                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+                    ON CONFLICT (reporter_user_id, dedup_key) WHERE status IN ('open', 'claimed')
+                    DO NOTHING
+                    "#,
+                        report.id.as_uuid(),
+                        report.reporter_user_id.as_uuid(),
+                        report.target.type_str(),
+                        report.target_user_id.as_ref().map(UserId::as_uuid),
+                        reported_user_id,
+                        region.map(|r| r.min_x),
+                        region.map(|r| r.min_y),
+                        region.map(|r| r.max_x),
+                        region.map(|r| r.max_y),
+                        report.target.dedup_key(),
+                        report.category.as_str(),
+                        report.comment,
+                        report.status.as_str(),
+                        report.created_at
+                    )
+                    .execute(&self.pool)
+                },
+                "Failed to create report",
+            )
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self))]
+    async fn get_report_by_id(&self, report_id: &ReportId) -> AppResult<Option<Report>> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        ReportRow,
+                        r#"
+                    SELECT id, reporter_user_id, target_type, target_user_id, reported_user_id,
+                           region_min_x, region_min_y, region_max_x, region_max_y,
+                           category, comment, status, claimed_by_user_id, claimed_at,
+                           resolved_by_user_id, resolved_at, resolution_action, resolution_note,
+                           created_at
+                    FROM reports
+                    WHERE id = $1
+                    "#,
+                        report_id.as_uuid()
+                    )
+                    .fetch_optional(&self.pool)
+                },
+                &format!("Failed to get report {}", report_id.as_uuid()),
+            )
+            .await?;
+
+        result.map(ReportRow::into_report).transpose()
+    }
+
+    #[instrument(skip(self))]
+    async fn list_reports(&self, query: &ReportQuery) -> AppResult<Vec<Report>> {
+        let results = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        ReportRow,
+                        r#"
+                    SELECT id, reporter_user_id, target_type, target_user_id, reported_user_id,
+                           region_min_x, region_min_y, region_max_x, region_max_y,
+                           category, comment, status, claimed_by_user_id, claimed_at,
+                           resolved_by_user_id, resolved_at, resolution_action, resolution_note,
+                           created_at
+                    FROM reports
+                    WHERE ($1::TEXT IS NULL OR status = $1)
+                    ORDER BY created_at ASC
+                    LIMIT $2 OFFSET $3
+                    "#,
+                        query.status.map(|status| status.as_str()),
+                        i64::from(query.limit),
+                        i64::from(query.offset)
+                    )
+                    .fetch_all(&self.pool)
+                },
+                "Failed to list reports",
+            )
+            .await?;
+
+        results.into_iter().map(ReportRow::into_report).collect()
+    }
+
+    #[instrument(skip(self))]
+    async fn count_pending_reports_by_reporter(&self, reporter_user_id: &UserId) -> AppResult<u32> {
+        let count = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_scalar!(
+                        r#"
+                    SELECT COUNT(*) as "count!"
+                    FROM reports
+                    WHERE reporter_user_id = $1 AND status IN ('open', 'claimed')
+                    "#,
+                        reporter_user_id.as_uuid()
+                    )
+                    .fetch_one(&self.pool)
+                },
+                "Failed to count pending reports",
+            )
+            .await?;
+
+        Ok(u32::try_from(count).unwrap_or(u32::MAX))
+    }
+
+    #[instrument(skip(self))]
+    async fn claim_report(&self, report_id: &ReportId, moderator_id: &UserId) -> AppResult<bool> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    UPDATE reports
+                    SET status = 'claimed', claimed_by_user_id = $2, claimed_at = NOW()
+                    WHERE id = $1
+                      AND (status = 'open' OR (status = 'claimed' AND claimed_by_user_id = $2))
+                    "#,
+                        report_id.as_uuid(),
+                        moderator_id.as_uuid()
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to claim report {}", report_id.as_uuid()),
+            )
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self))]
+    async fn begin_resolution(
+        &self,
+        report_id: &ReportId,
+        moderator_id: &UserId,
+    ) -> AppResult<bool> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    UPDATE reports
+                    SET status = 'resolved', resolved_by_user_id = $2, resolved_at = NOW()
+                    WHERE id = $1
+                      AND (status = 'open' OR (status = 'claimed' AND claimed_by_user_id = $2))
+                    "#,
+                        report_id.as_uuid(),
+                        moderator_id.as_uuid()
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to begin resolving report {}", report_id.as_uuid()),
+            )
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self, resolution_note))]
+    async fn record_resolution(
+        &self,
+        report_id: &ReportId,
+        moderator_id: &UserId,
+        resolution_action: ReportResolutionAction,
+        resolution_note: Option<&str>,
+    ) -> AppResult<()> {
+        self.executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    UPDATE reports
+                    SET resolution_action = $3, resolution_note = $4
+                    WHERE id = $1 AND status = 'resolved' AND resolved_by_user_id = $2
+                    "#,
+                        report_id.as_uuid(),
+                        moderator_id.as_uuid(),
+                        resolution_action.as_str(),
+                        resolution_note
+                    )
+                    .execute(&self.pool)
+                },
+                &format!(
+                    "Failed to record resolution of report {}",
+                    report_id.as_uuid()
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn release_resolution(
+        &self,
+        report_id: &ReportId,
+        moderator_id: &UserId,
+    ) -> AppResult<()> {
+        self.executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    UPDATE reports
+                    SET status = CASE WHEN claimed_by_user_id IS NULL THEN 'open' ELSE 'claimed' END,
+                        resolved_by_user_id = NULL,
+                        resolved_at = NULL
+                    WHERE id = $1
+                      AND status = 'resolved'
+                      AND resolved_by_user_id = $2
+                      AND resolution_action IS NULL
+                    "#,
+                        report_id.as_uuid(),
+                        moderator_id.as_uuid()
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to release report {}", report_id.as_uuid()),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, resolution_note))]
+    async fn close_report(
+        &self,
+        report_id: &ReportId,
+        status: ReportStatus,
+        moderator_id: &UserId,
+        resolution_action: Option<ReportResolutionAction>,
+        resolution_note: Option<&str>,
+    ) -> AppResult<bool> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    UPDATE reports
+                    SET status = $2,
+                        resolved_by_user_id = $3,
+                        resolved_at = NOW(),
+                        resolution_action = $4,
+                        resolution_note = $5
+                    WHERE id = $1
+                      AND (status = 'open' OR (status = 'claimed' AND claimed_by_user_id = $3))
+                    "#,
+                        report_id.as_uuid(),
+                        status.as_str(),
+                        moderator_id.as_uuid(),
+                        resolution_action.map(|action| action.as_str()),
+                        resolution_note
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to close report {}", report_id.as_uuid()),
+            )
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,299 @@
+use sqlx::{PgPool, types::time::OffsetDateTime};
+use tracing::instrument;
+
+use domain::auth::{Permission, Role, RoleId, RoleMember, UserId, UserRole};
+use fedi_wplace_application::{
+    error::{AppError, AppResult},
+    ports::outgoing::role_store::{RoleRevocation, RoleStorePort},
+};
+
+use super::utils::{PostgresExecutor, begin_transaction, commit_transaction};
+
+pub struct PostgresRoleStoreAdapter {
+    pool: PgPool,
+    executor: PostgresExecutor,
+}
+
+impl PostgresRoleStoreAdapter {
+    pub fn new(pool: PgPool, query_timeout_secs: u64) -> Self {
+        Self {
+            pool,
+            executor: PostgresExecutor::new(query_timeout_secs),
+        }
+    }
+}
+
+struct RoleRow {
+    id: uuid::Uuid,
+    name: String,
+    description: Option<String>,
+    permissions: Vec<String>,
+    created_at: OffsetDateTime,
+    updated_at: OffsetDateTime,
+}
+
+impl From<RoleRow> for Role {
+    fn from(row: RoleRow) -> Self {
+        Self {
+            id: RoleId::from_uuid(row.id),
+            name: row.name,
+            description: row.description,
+            permissions: row
+                .permissions
+                .iter()
+                .filter_map(|permission| permission.parse::<Permission>().ok())
+                .collect(),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+fn permission_strings(permissions: &[Permission]) -> Vec<String> {
+    permissions
+        .iter()
+        .map(|permission| permission.as_str().to_string())
+        .collect()
+}
+
+#[async_trait::async_trait]
+impl RoleStorePort for PostgresRoleStoreAdapter {
+    #[instrument(skip(self))]
+    async fn list_roles(&self) -> AppResult<Vec<Role>> {
+        let results = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        RoleRow,
+                        r#"
+                    SELECT id, name, description, permissions, created_at, updated_at
+                    FROM roles
+                    ORDER BY name
+                    "#
+                    )
+                    .fetch_all(&self.pool)
+                },
+                "Failed to list roles",
+            )
+            .await?;
+
+        Ok(results.into_iter().map(Role::from).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_role_by_id(&self, role_id: &RoleId) -> AppResult<Option<Role>> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        RoleRow,
+                        r#"
+                    SELECT id, name, description, permissions, created_at, updated_at
+                    FROM roles
+                    WHERE id = $1
+                    "#,
+                        role_id.as_uuid()
+                    )
+                    .fetch_optional(&self.pool)
+                },
+                &format!("Failed to get role {}", role_id.as_uuid()),
+            )
+            .await?;
+
+        Ok(result.map(Role::from))
+    }
+
+    #[instrument(skip(self, role))]
+    async fn create_role(&self, role: &Role) -> AppResult<bool> {
+        let permissions = permission_strings(&role.permissions);
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    INSERT INTO roles (id, name, description, permissions, created_at, updated_at)
+                    VALUES ($1, $2, $3, $4, $5, $6)
+                    ON CONFLICT (name) DO NOTHING
+                    "#,
+                        role.id.as_uuid(),
+                        role.name,
+                        role.description,
+                        &permissions,
+                        role.created_at,
+                        role.updated_at
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to create role {}", role.name),
+            )
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self))]
+    async fn update_role(
+        &self,
+        role_id: &RoleId,
+        description: Option<&str>,
+        permissions: &[Permission],
+    ) -> AppResult<Option<Role>> {
+        let permissions = permission_strings(permissions);
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        RoleRow,
+                        r#"
+                    UPDATE roles
+                    SET description = $2, permissions = $3, updated_at = NOW()
+                    WHERE id = $1
+                    RETURNING id, name, description, permissions, created_at, updated_at
+                    "#,
+                        role_id.as_uuid(),
+                        description,
+                        &permissions
+                    )
+                    .fetch_optional(&self.pool)
+                },
+                &format!("Failed to update role {}", role_id.as_uuid()),
+            )
+            .await?;
+
+        Ok(result.map(Role::from))
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_role(&self, role_id: &RoleId) -> AppResult<bool> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    DELETE FROM roles
+                    WHERE id = $1
+                    "#,
+                        role_id.as_uuid()
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to delete role {}", role_id.as_uuid()),
+            )
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self))]
+    async fn list_role_members(&self, role_id: &RoleId) -> AppResult<Vec<RoleMember>> {
+        let rows = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    SELECT ur.user_id, u.username, ur.assigned_at, ur.assigned_by
+                    FROM user_roles ur
+                    JOIN users u ON u.id = ur.user_id
+                    WHERE ur.role_id = $1
+                    ORDER BY ur.assigned_at
+                    "#,
+                        role_id.as_uuid()
+                    )
+                    .fetch_all(&self.pool)
+                },
+                &format!("Failed to list members of role {}", role_id.as_uuid()),
+            )
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| RoleMember {
+                user_id: UserId::from_uuid(row.user_id),
+                username: row.username,
+                assigned_at: row.assigned_at,
+                assigned_by: row.assigned_by.map(UserId::from_uuid),
+            })
+            .collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn revoke_role_from_user(
+        &self,
+        user_id: &UserId,
+        role_id: &RoleId,
+        keep_last_member: bool,
+    ) -> AppResult<RoleRevocation> {
+        let mut tx = begin_transaction(&self.pool).await?;
+
+        // Locking the role row serializes concurrent revocations of the same role,
+        // so two of them cannot both see another member left.
+        sqlx::query!(
+            r#"
+            SELECT id FROM roles WHERE id = $1 FOR UPDATE
+            "#,
+            role_id.as_uuid()
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to lock role {}: {}", role_id.as_uuid(), e),
+        })?;
+
+        let revoked = sqlx::query!(
+            r#"
+            DELETE FROM user_roles
+            WHERE user_id = $1 AND role_id = $2
+            RETURNING user_id, role_id, assigned_at, assigned_by
+            "#,
+            user_id.as_uuid(),
+            role_id.as_uuid()
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to revoke role from user: {}", e),
+        })?;
+
+        let Some(row) = revoked else {
+            return Ok(RoleRevocation::NotAssigned);
+        };
+
+        if keep_last_member {
+            let remaining_members = sqlx::query_scalar!(
+                r#"
+                SELECT COUNT(*) as "count!" FROM user_roles WHERE role_id = $1
+                "#,
+                role_id.as_uuid()
+            )
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| AppError::DatabaseError {
+                message: format!(
+                    "Failed to count members of role {}: {}",
+                    role_id.as_uuid(),
+                    e
+                ),
+            })?;
+
+            // Dropping the transaction rolls the revocation back.
+            if remaining_members == 0 {
+                return Ok(RoleRevocation::LastMember);
+            }
+        }
+
+        commit_transaction(tx).await?;
+
+        Ok(RoleRevocation::Revoked(UserRole {
+            user_id: UserId::from_uuid(row.user_id),
+            role_id: RoleId::from_uuid(row.role_id),
+            assigned_at: row.assigned_at,
+            assigned_by: row.assigned_by.map(UserId::from_uuid),
+        }))
+    }
+}
@@ -0,0 +1,222 @@
+use sqlx::{PgPool, types::ipnet::IpNet, types::time::OffsetDateTime};
+use std::net::IpAddr;
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+use domain::session::UserSession;
+use fedi_wplace_application::{error::AppResult, ports::outgoing::session_store::SessionStorePort};
+
+use super::utils::PostgresExecutor;
+
+pub struct PostgresSessionStoreAdapter {
+    pool: PgPool,
+    executor: PostgresExecutor,
+}
+
+impl PostgresSessionStoreAdapter {
+    pub fn new(pool: PgPool, query_timeout_secs: u64) -> Self {
+        Self {
+            pool,
+            executor: PostgresExecutor::new(query_timeout_secs),
+        }
+    }
+}
+
+struct UserSessionRow {
+    id: Uuid,
+    user_id: Uuid,
+    user_agent: Option<String>,
+    ip_address: Option<IpNet>,
+    created_at: OffsetDateTime,
+    last_seen_at: OffsetDateTime,
+    revoked_at: Option<OffsetDateTime>,
+}
+
+impl From<UserSessionRow> for UserSession {
+    fn from(row: UserSessionRow) -> Self {
+        Self {
+            id: row.id,
+            user_id: row.user_id,
+            user_agent: row.user_agent,
+            ip_address: row.ip_address.map(|network| network.addr()),
+            created_at: row.created_at,
+            last_seen_at: row.last_seen_at,
+            revoked_at: row.revoked_at,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SessionStorePort for PostgresSessionStoreAdapter {
+    #[instrument(skip(self, session))]
+    async fn create_session(&self, session: &UserSession) -> AppResult<()> {
+        self.executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    INSERT INTO user_sessions (id, user_id, user_agent, ip_address, created_at, last_seen_at)
+                    VALUES ($1, $2, $3, $4, $5, $6)
+                    "#,
+                        session.id,
+                        session.user_id,
+                        session.user_agent,
+                        session.ip_address.map(IpNet::from),
+                        session.created_at,
+                        session.last_seen_at
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to record session for user {}", session.user_id),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn touch_session(
+        &self,
+        user_id: Uuid,
+        session_id: Uuid,
+        ip_address: Option<IpAddr>,
+        seen_before: OffsetDateTime,
+    ) -> AppResult<bool> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_scalar!(
+                        r#"
+                    WITH active AS (
+                        SELECT id, last_seen_at
+                        FROM user_sessions
+                        WHERE id = $1 AND user_id = $2 AND revoked_at IS NULL
+                    ), touched AS (
+                        UPDATE user_sessions
+                        SET last_seen_at = NOW(),
+                            ip_address = COALESCE($3, user_sessions.ip_address)
+                        FROM active
+                        WHERE user_sessions.id = active.id
+                          AND active.last_seen_at < $4
+                        RETURNING user_sessions.id
+                    )
+                    SELECT EXISTS(SELECT 1 FROM active) AS "active!"
+                    "#,
+                        session_id,
+                        user_id,
+                        ip_address.map(IpNet::from),
+                        seen_before
+                    )
+                    .fetch_one(&self.pool)
+                },
+                &format!("Failed to update session {}", session_id),
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(skip(self))]
+    async fn list_sessions(&self, user_id: Uuid) -> AppResult<Vec<UserSession>> {
+        let rows = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        UserSessionRow,
+                        r#"
+                    SELECT id, user_id, user_agent, ip_address as "ip_address: IpNet",
+                           created_at, last_seen_at, revoked_at
+                    FROM user_sessions
+                    WHERE user_id = $1 AND revoked_at IS NULL
+                    ORDER BY last_seen_at DESC
+                    "#,
+                        user_id
+                    )
+                    .fetch_all(&self.pool)
+                },
+                &format!("Failed to list sessions for user {}", user_id),
+            )
+            .await?;
+
+        Ok(rows.into_iter().map(UserSession::from).collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn revoke_session(&self, user_id: Uuid, session_id: Uuid) -> AppResult<bool> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    UPDATE user_sessions
+                    SET revoked_at = NOW()
+                    WHERE id = $1 AND user_id = $2 AND revoked_at IS NULL
+                    "#,
+                        session_id,
+                        user_id
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to revoke session {}", session_id),
+            )
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self))]
+    async fn revoke_all_sessions(&self, user_id: Uuid, except: Option<Uuid>) -> AppResult<u64> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    UPDATE user_sessions
+                    SET revoked_at = NOW()
+                    WHERE user_id = $1
+                      AND revoked_at IS NULL
+                      AND ($2::UUID IS NULL OR id <> $2)
+                    "#,
+                        user_id,
+                        except
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to revoke sessions for user {}", user_id),
+            )
+            .await?;
+
+        debug!(
+            "Revoked {} sessions for user {}",
+            result.rows_affected(),
+            user_id
+        );
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_stale_sessions(&self, last_seen_before: OffsetDateTime) -> AppResult<u64> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    DELETE FROM user_sessions
+                    WHERE revoked_at IS NOT NULL OR last_seen_at < $1
+                    "#,
+                        last_seen_before
+                    )
+                    .execute(&self.pool)
+                },
+                "Failed to delete stale sessions",
+            )
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+}
@@ -0,0 +1,268 @@
+use sqlx::{PgPool, Postgres, Transaction};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+use domain::two_factor::TotpCredential;
+use fedi_wplace_application::{
+    error::{AppError, AppResult},
+    ports::outgoing::two_factor_store::TwoFactorStorePort,
+};
+
+use super::utils::{PostgresExecutor, begin_transaction, commit_transaction, hash_token};
+
+pub struct PostgresTwoFactorStoreAdapter {
+    pool: PgPool,
+    executor: PostgresExecutor,
+}
+
+impl PostgresTwoFactorStoreAdapter {
+    pub fn new(pool: PgPool, query_timeout_secs: u64) -> Self {
+        Self {
+            pool,
+            executor: PostgresExecutor::new(query_timeout_secs),
+        }
+    }
+}
+
+// Swaps the user's recovery codes for new ones inside the caller's transaction.
+async fn store_recovery_codes(
+    tx: &mut Transaction<'_, Postgres>,
+    user_id: Uuid,
+    recovery_codes: &[String],
+) -> AppResult<()> {
+    let code_hashes: Vec<String> = recovery_codes.iter().map(|code| hash_token(code)).collect();
+
+    sqlx::query!(
+        r#"
+        DELETE FROM user_recovery_codes
+        WHERE user_id = $1
+        "#,
+        user_id
+    )
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| AppError::DatabaseError {
+        message: format!("Failed to clear recovery codes: {}", e),
+    })?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO user_recovery_codes (user_id, code_hash)
+        SELECT $1, code_hash FROM UNNEST($2::TEXT[]) AS code_hash
+        ON CONFLICT DO NOTHING
+        "#,
+        user_id,
+        &code_hashes
+    )
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| AppError::DatabaseError {
+        message: format!("Failed to store recovery codes for user {}: {}", user_id, e),
+    })?;
+
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl TwoFactorStorePort for PostgresTwoFactorStoreAdapter {
+    #[instrument(skip(self))]
+    async fn find_totp(&self, user_id: Uuid) -> AppResult<Option<TotpCredential>> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_as!(
+                        TotpCredential,
+                        r#"
+                    SELECT secret, enabled_at, last_used_step
+                    FROM user_totp
+                    WHERE user_id = $1
+                    "#,
+                        user_id
+                    )
+                    .fetch_optional(&self.pool)
+                },
+                &format!("Failed to get TOTP credential for user {}", user_id),
+            )
+            .await?;
+
+        Ok(result)
+    }
+
+    #[instrument(skip(self, secret))]
+    async fn save_pending_totp(&self, user_id: Uuid, secret: &str) -> AppResult<()> {
+        self.executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    INSERT INTO user_totp (user_id, secret)
+                    VALUES ($1, $2)
+                    ON CONFLICT (user_id) DO UPDATE
+                    SET secret = EXCLUDED.secret,
+                        last_used_step = NULL,
+                        created_at = NOW()
+                    WHERE user_totp.enabled_at IS NULL
+                    "#,
+                        user_id,
+                        secret
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to store TOTP secret for user {}", user_id),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, recovery_codes))]
+    async fn enable_totp(
+        &self,
+        user_id: Uuid,
+        step: i64,
+        recovery_codes: &[String],
+    ) -> AppResult<()> {
+        let mut tx = begin_transaction(&self.pool).await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE user_totp
+            SET enabled_at = NOW(), last_used_step = $2
+            WHERE user_id = $1
+            "#,
+            user_id,
+            step
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to enable TOTP for user {}: {}", user_id, e),
+        })?;
+
+        store_recovery_codes(&mut tx, user_id, recovery_codes).await?;
+
+        commit_transaction(tx).await?;
+
+        debug!("Enabled TOTP for user {}", user_id);
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn record_totp_step(&self, user_id: Uuid, step: i64) -> AppResult<bool> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    UPDATE user_totp
+                    SET last_used_step = $2
+                    WHERE user_id = $1
+                      AND (last_used_step IS NULL OR last_used_step < $2)
+                    "#,
+                        user_id,
+                        step
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to record TOTP use for user {}", user_id),
+            )
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_totp(&self, user_id: Uuid) -> AppResult<()> {
+        let mut tx = begin_transaction(&self.pool).await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM user_recovery_codes
+            WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to delete recovery codes: {}", e),
+        })?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM user_totp
+            WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to delete TOTP for user {}: {}", user_id, e),
+        })?;
+
+        commit_transaction(tx).await
+    }
+
+    #[instrument(skip(self, recovery_codes))]
+    async fn replace_recovery_codes(
+        &self,
+        user_id: Uuid,
+        recovery_codes: &[String],
+    ) -> AppResult<()> {
+        let mut tx = begin_transaction(&self.pool).await?;
+        store_recovery_codes(&mut tx, user_id, recovery_codes).await?;
+        commit_transaction(tx).await
+    }
+
+    #[instrument(skip(self, recovery_code))]
+    async fn use_recovery_code(&self, user_id: Uuid, recovery_code: &str) -> AppResult<bool> {
+        let code_hash = hash_token(recovery_code);
+
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    UPDATE user_recovery_codes
+                    SET used_at = NOW()
+                    WHERE user_id = $1 AND code_hash = $2 AND used_at IS NULL
+                    "#,
+                        user_id,
+                        code_hash
+                    )
+                    .execute(&self.pool)
+                },
+                &format!("Failed to use recovery code for user {}", user_id),
+            )
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self))]
+    async fn count_unused_recovery_codes(&self, user_id: Uuid) -> AppResult<i64> {
+        let count = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_scalar!(
+                        r#"
+                    SELECT COUNT(*) as "count!"
+                    FROM user_recovery_codes
+                    WHERE user_id = $1 AND used_at IS NULL
+                    "#,
+                        user_id
+                    )
+                    .fetch_one(&self.pool)
+                },
+                &format!("Failed to count recovery codes for user {}", user_id),
+            )
+            .await?;
+
+        Ok(count)
+    }
+}
@@ -0,0 +1,196 @@
+use sqlx::PgPool;
+use tracing::instrument;
+
+use domain::auth::{Identity, UserId, UserSummary};
+use fedi_wplace_application::{
+    error::AppResult,
+    ports::outgoing::user_directory::{UserAccountStats, UserDirectoryPort, UserDirectoryQuery},
+};
+
+use super::utils::PostgresExecutor;
+
+pub struct PostgresUserDirectoryAdapter {
+    pool: PgPool,
+    executor: PostgresExecutor,
+}
+
+impl PostgresUserDirectoryAdapter {
+    pub fn new(pool: PgPool, query_timeout_secs: u64) -> Self {
+        Self {
+            pool,
+            executor: PostgresExecutor::new(query_timeout_secs),
+        }
+    }
+}
+
+fn prefix_pattern(prefix: &str) -> String {
+    let escaped = prefix
+        .to_lowercase()
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("{escaped}%")
+}
+
+fn count_to_u64(count: i64) -> u64 {
+    u64::try_from(count).unwrap_or_default()
+}
+
+#[async_trait::async_trait]
+impl UserDirectoryPort for PostgresUserDirectoryAdapter {
+    #[instrument(skip(self))]
+    async fn list_users(&self, query: &UserDirectoryQuery) -> AppResult<Vec<UserSummary>> {
+        let search_pattern = query.search.as_deref().map(prefix_pattern);
+
+        let rows = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    SELECT u.id, u.email, u.username, u.email_verified_at, u.created_at,
+                           ARRAY(
+                               SELECT r.name
+                               FROM user_roles ur
+                               JOIN roles r ON r.id = ur.role_id
+                               WHERE ur.user_id = u.id
+                               ORDER BY r.name
+                           ) AS "role_names!",
+                           ban.banned AS "banned!"
+                    FROM users u
+                    CROSS JOIN LATERAL (
+                        SELECT EXISTS (
+                            SELECT 1
+                            FROM banned_users b
+                            WHERE b.user_id = u.id
+                              AND b.revoked_at IS NULL
+                              AND (b.expires_at IS NULL OR b.expires_at > NOW())
+                        ) AS banned
+                    ) ban
+                    WHERE ($1::TEXT IS NULL
+                           OR lower(u.username) LIKE $1 ESCAPE '\'
+                           OR lower(u.email) LIKE $1 ESCAPE '\')
+                      AND ($2::BOOLEAN IS NULL OR (u.email_verified_at IS NOT NULL) = $2)
+                      AND ($3::BOOLEAN IS NULL OR ban.banned = $3)
+                      AND ($4::TEXT IS NULL OR EXISTS (
+                          SELECT 1
+                          FROM user_roles ur
+                          JOIN roles r ON r.id = ur.role_id
+                          WHERE ur.user_id = u.id AND r.name = $4
+                      ))
+                      AND ($5::TIMESTAMPTZ IS NULL OR u.created_at >= $5)
+                      AND ($6::TIMESTAMPTZ IS NULL OR u.created_at < $6)
+                      AND ($9::UUID IS NULL OR EXISTS (
+                          SELECT 1
+                          FROM users c
+                          WHERE c.id = $9
+                            AND (
+                                ($7 = 'created_at' AND NOT $8 AND (u.created_at, u.id) > (c.created_at, c.id))
+                                OR ($7 = 'created_at' AND $8 AND (u.created_at, u.id) < (c.created_at, c.id))
+                                OR ($7 = 'username' AND NOT $8 AND (u.username, u.id) > (c.username, c.id))
+                                OR ($7 = 'username' AND $8 AND (u.username, u.id) < (c.username, c.id))
+                            )
+                      ))
+                    ORDER BY
+                        CASE WHEN $7 = 'created_at' AND NOT $8 THEN u.created_at END ASC,
+                        CASE WHEN $7 = 'created_at' AND $8 THEN u.created_at END DESC,
+                        CASE WHEN $7 = 'username' AND NOT $8 THEN u.username END ASC,
+                        CASE WHEN $7 = 'username' AND $8 THEN u.username END DESC,
+                        CASE WHEN NOT $8 THEN u.id END ASC,
+                        CASE WHEN $8 THEN u.id END DESC
+                    LIMIT $10
+                    "#,
+                        search_pattern,
+                        query.email_verified,
+                        query.banned,
+                        query.role_name,
+                        query.created_since,
+                        query.created_until,
+                        query.sort.as_str(),
+                        query.descending,
+                        query.after.as_ref().map(UserId::as_uuid),
+                        i64::from(query.limit)
+                    )
+                    .fetch_all(&self.pool)
+                },
+                "Failed to list users",
+            )
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| UserSummary {
+                id: UserId::from_uuid(row.id),
+                email: row.email,
+                username: row.username,
+                email_verified_at: row.email_verified_at,
+                created_at: row.created_at,
+                role_names: row.role_names,
+                banned: row.banned,
+            })
+            .collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn list_user_identities(&self, user_id: &UserId) -> AppResult<Vec<Identity>> {
+        let rows = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    SELECT id, provider, provider_user_id, created_at
+                    FROM user_identities
+                    WHERE user_id = $1
+                    ORDER BY created_at
+                    "#,
+                        user_id.as_uuid()
+                    )
+                    .fetch_all(&self.pool)
+                },
+                &format!("Failed to list identities of user {}", user_id.as_uuid()),
+            )
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Identity {
+                id: row.id,
+                provider: row.provider,
+                provider_user_id: row.provider_user_id,
+                created_at: row.created_at,
+            })
+            .collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn get_account_stats(&self, user_id: &UserId) -> AppResult<Option<UserAccountStats>> {
+        let row = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    SELECT u.created_at,
+                           (SELECT COUNT(*) FROM pixel_history ph WHERE ph.user_id = u.id)
+                               AS "pixels_on_canvas!",
+                           (SELECT COUNT(*) FROM quarantined_pixels qp WHERE qp.user_id = u.id)
+                               AS "pixels_quarantined!"
+                    FROM users u
+                    WHERE u.id = $1
+                    "#,
+                        user_id.as_uuid()
+                    )
+                    .fetch_optional(&self.pool)
+                },
+                &format!("Failed to load account stats of user {}", user_id.as_uuid()),
+            )
+            .await?;
+
+        Ok(row.map(|row| UserAccountStats {
+            created_at: row.created_at,
+            pixels_on_canvas: count_to_u64(row.pixels_on_canvas),
+            pixels_quarantined: count_to_u64(row.pixels_quarantined),
+        }))
+    }
+}
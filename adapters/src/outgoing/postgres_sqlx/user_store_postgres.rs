@@ -3,13 +3,13 @@ use time::OffsetDateTime;
 use tracing::{debug, instrument};
 use uuid::Uuid;
 
-use domain::auth::{Role, RoleId, UserId, UserPublic};
+use domain::auth::{Identity, Permission, Role, RoleId, UserId, UserPublic};
 use fedi_wplace_application::{
     error::{AppError, AppResult},
     ports::outgoing::user_store::UserStorePort,
 };
 
-use super::utils::{PostgresExecutor, begin_transaction, commit_transaction};
+use super::utils::{PostgresExecutor, begin_transaction, commit_transaction, hash_token};
 
 pub struct PostgresUserStoreAdapter {
     pool: PgPool,
@@ -31,7 +31,7 @@ impl PostgresUserStoreAdapter {
                 || {
                     sqlx::query!(
                         r#"
-                    SELECT r.id, r.name, r.description, r.created_at, r.updated_at
+                    SELECT r.id, r.name, r.description, r.permissions, r.created_at, r.updated_at
                     FROM roles r
                     JOIN user_roles ur ON r.id = ur.role_id
                     WHERE ur.user_id = $1
@@ -50,12 +50,18 @@ impl PostgresUserStoreAdapter {
                 id: RoleId::from_uuid(row.id),
                 name: row.name,
                 description: row.description,
+                permissions: row
+                    .permissions
+                    .iter()
+                    .filter_map(|permission| permission.parse::<Permission>().ok())
+                    .collect(),
                 created_at: row.created_at,
                 updated_at: row.updated_at,
             })
             .collect())
     }
 
+    #[allow(clippy::too_many_arguments)]
     async fn build_user_public(
         &self,
         id: Uuid,
@@ -64,6 +70,7 @@ impl PostgresUserStoreAdapter {
         email_verified_at: Option<OffsetDateTime>,
         available_charges: i32,
         charges_updated_at: OffsetDateTime,
+        session_version: i32,
     ) -> AppResult<UserPublic> {
         let roles = self.load_user_roles(id).await?;
         Ok(UserPublic {
@@ -73,6 +80,7 @@ impl PostgresUserStoreAdapter {
             email_verified_at,
             available_charges,
             charges_updated_at,
+            session_version,
             roles,
         })
     }
@@ -120,6 +128,7 @@ impl UserStorePort for PostgresUserStoreAdapter {
             None,
             30,
             time::OffsetDateTime::now_utc(),
+            0,
         )
         .await
     }
@@ -168,7 +177,7 @@ impl UserStorePort for PostgresUserStoreAdapter {
             || {
                 sqlx::query!(
                     r#"
-                    SELECT id, email, username, email_verified_at, available_charges, charges_updated_at
+                    SELECT id, email, username, email_verified_at, available_charges, charges_updated_at, session_version
                     FROM users
                     WHERE username = $1
                     "#,
@@ -191,6 +200,7 @@ impl UserStorePort for PostgresUserStoreAdapter {
                     record.email_verified_at,
                     record.available_charges,
                     record.charges_updated_at,
+                    record.session_version,
                 )
                 .await?,
             ))
@@ -206,7 +216,7 @@ impl UserStorePort for PostgresUserStoreAdapter {
             || {
                 sqlx::query!(
                     r#"
-                    SELECT id, email, username, email_verified_at, available_charges, charges_updated_at
+                    SELECT id, email, username, email_verified_at, available_charges, charges_updated_at, session_version
                     FROM users
                     WHERE id = $1
                     "#,
@@ -229,6 +239,7 @@ impl UserStorePort for PostgresUserStoreAdapter {
                     record.email_verified_at,
                     record.available_charges,
                     record.charges_updated_at,
+                    record.session_version,
                 )
                 .await?,
             ))
@@ -239,18 +250,36 @@ impl UserStorePort for PostgresUserStoreAdapter {
     }
 
     #[instrument(skip(self))]
-    async fn create_or_get_social_user(
+    async fn find_user_created_at(&self, id: Uuid) -> AppResult<Option<OffsetDateTime>> {
+        self.executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_scalar!(
+                        r#"
+                    SELECT created_at
+                    FROM users
+                    WHERE id = $1
+                    "#,
+                        id
+                    )
+                    .fetch_optional(&self.pool)
+                },
+                &format!("Failed to load creation time of user {}", id),
+            )
+            .await
+    }
+
+    #[instrument(skip(self))]
+    async fn find_user_by_identity(
         &self,
         provider: &str,
         provider_user_id: &str,
-        email: Option<&str>,
-        username: Option<&str>,
-    ) -> AppResult<UserPublic> {
-        let existing_identity = self.executor.execute_with_timeout(
+    ) -> AppResult<Option<UserPublic>> {
+        let identity_record = self.executor.execute_with_timeout(
             || {
                 sqlx::query!(
                     r#"
-                    SELECT ui.user_id, u.email, u.username, u.email_verified_at, u.available_charges, u.charges_updated_at
+                    SELECT ui.user_id, u.email, u.username, u.email_verified_at, u.available_charges, u.charges_updated_at, u.session_version
                     FROM user_identities ui
                     JOIN users u ON ui.user_id = u.id
                     WHERE ui.provider = $1 AND ui.provider_user_id = $2
@@ -268,113 +297,88 @@ impl UserStorePort for PostgresUserStoreAdapter {
         )
         .await?;
 
-        if let Some(identity_record) = existing_identity {
-            debug!(
-                "Found existing social user for provider {} user {} with id {}",
-                provider, provider_user_id, identity_record.user_id
-            );
-            return self
-                .build_user_public(
-                    identity_record.user_id,
-                    identity_record.email,
-                    identity_record.username,
-                    identity_record.email_verified_at,
-                    identity_record.available_charges,
-                    identity_record.charges_updated_at,
+        match identity_record {
+            Some(record) => Ok(Some(
+                self.build_user_public(
+                    record.user_id,
+                    record.email,
+                    record.username,
+                    record.email_verified_at,
+                    record.available_charges,
+                    record.charges_updated_at,
+                    record.session_version,
                 )
-                .await;
+                .await?,
+            )),
+            None => Ok(None),
         }
+    }
 
-        let user_id = if let Some(email) = email {
-            if let Some((existing_user_id, _, _, _, _)) = self.find_user_by_email(email).await? {
-                debug!("Found existing user by email {} for social login", email);
-                existing_user_id
-            } else {
-                let new_user_id = Uuid::new_v4();
-                let default_username = format!("user_{}", &new_user_id.to_string()[..8]);
-                let username = username.unwrap_or(&default_username);
-
-                self.executor
-                    .execute_with_timeout(
-                        || {
-                            sqlx::query!(
-                                r#"
-                            INSERT INTO users (id, email, username, email_verified_at)
-                            VALUES ($1, $2, $3, NOW())
-                            "#,
-                                new_user_id,
-                                email,
-                                username
-                            )
-                            .execute(&self.pool)
-                        },
-                        &format!("Failed to create social user with email {}", email),
-                    )
-                    .await?;
-
-                debug!(
-                    "Created new social user with email {} and id {}",
-                    email, new_user_id
-                );
-                new_user_id
-            }
-        } else {
-            let new_user_id = Uuid::new_v4();
-            let generated_email = format!("{}+{}@social.local", provider, provider_user_id);
-            let default_username = format!("user_{}", &new_user_id.to_string()[..8]);
-            let username = username.unwrap_or(&default_username);
-
-            self.executor
-                .execute_with_timeout(
-                    || {
-                        sqlx::query!(
-                            r#"
-                        INSERT INTO users (id, email, username, email_verified_at)
-                        VALUES ($1, $2, $3, NOW())
-                        "#,
-                            new_user_id,
-                            generated_email,
-                            username
-                        )
-                        .execute(&self.pool)
-                    },
-                    "Failed to create social user without email",
-                )
-                .await?;
-
-            debug!(
-                "Created new social user without email, generated {} with id {}",
-                generated_email, new_user_id
-            );
-            new_user_id
+    // Accounts without a provider email get an unverified placeholder address until the user
+    // adds a real one through an email change.
+    #[instrument(skip(self))]
+    async fn create_social_user(
+        &self,
+        provider: &str,
+        provider_user_id: &str,
+        email: Option<&str>,
+        email_verified: bool,
+        username: Option<&str>,
+    ) -> AppResult<UserPublic> {
+        let user_id = Uuid::new_v4();
+        let (email, email_verified_at) = match email {
+            Some(email) => (
+                email.to_string(),
+                email_verified.then(OffsetDateTime::now_utc),
+            ),
+            None => (UserId::from_uuid(user_id).placeholder_email(), None),
         };
+        let short_id: String = user_id.to_string().chars().take(8).collect();
+        let default_username = format!("user_{}", short_id);
+        let username = username.unwrap_or(&default_username);
 
-        let identity_id = Uuid::new_v4();
-        self.executor
-            .execute_with_timeout(
-                || {
-                    sqlx::query!(
-                        r#"
-                    INSERT INTO user_identities (id, user_id, provider, provider_user_id)
-                    VALUES ($1, $2, $3, $4)
-                    "#,
-                        identity_id,
-                        user_id,
-                        provider,
-                        provider_user_id
-                    )
-                    .execute(&self.pool)
-                },
-                &format!(
-                    "Failed to create identity for provider {} user {}",
-                    provider, provider_user_id
-                ),
-            )
-            .await?;
+        let mut tx = begin_transaction(&self.pool).await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO users (id, email, username, email_verified_at)
+            VALUES ($1, $2, $3, $4)
+            "#,
+            user_id,
+            email,
+            username,
+            email_verified_at
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to create social user with email {}: {}", email, e),
+        })?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO user_identities (id, user_id, provider, provider_user_id)
+            VALUES ($1, $2, $3, $4)
+            "#,
+            Uuid::new_v4(),
+            user_id,
+            provider,
+            provider_user_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!(
+                "Failed to create identity for provider {} user {}: {}",
+                provider, provider_user_id, e
+            ),
+        })?;
+
+        commit_transaction(tx).await?;
 
         debug!(
-            "Created identity for provider {} user {} linking to user {}",
-            provider, provider_user_id, user_id
+            "Created social user {} for provider {} user {}",
+            user_id, provider, provider_user_id
         );
 
         self.find_user_by_id(user_id)
@@ -385,30 +389,152 @@ impl UserStorePort for PostgresUserStoreAdapter {
     }
 
     #[instrument(skip(self))]
-    async fn store_verification_token(
+    async fn link_identity(
         &self,
         user_id: Uuid,
-        token: &str,
-        expires_at: OffsetDateTime,
-    ) -> AppResult<()> {
-        self.executor
+        provider: &str,
+        provider_user_id: &str,
+    ) -> AppResult<Uuid> {
+        let mut tx = begin_transaction(&self.pool).await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO user_identities (id, user_id, provider, provider_user_id)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (provider, provider_user_id) DO NOTHING
+            "#,
+            Uuid::new_v4(),
+            user_id,
+            provider,
+            provider_user_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!(
+                "Failed to link identity for provider {} user {}: {}",
+                provider, provider_user_id, e
+            ),
+        })?;
+
+        let owner_id = sqlx::query_scalar!(
+            r#"
+            SELECT user_id
+            FROM user_identities
+            WHERE provider = $1 AND provider_user_id = $2
+            "#,
+            provider,
+            provider_user_id
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to load identity owner: {}", e),
+        })?;
+
+        commit_transaction(tx).await?;
+
+        Ok(owner_id)
+    }
+
+    #[instrument(skip(self))]
+    async fn list_identities(&self, user_id: Uuid) -> AppResult<Vec<Identity>> {
+        let rows = self
+            .executor
             .execute_with_timeout(
                 || {
                     sqlx::query!(
                         r#"
-                    INSERT INTO email_verification_tokens (token, user_id, expires_at)
-                    VALUES ($1, $2, $3)
+                    SELECT id, provider, provider_user_id, created_at
+                    FROM user_identities
+                    WHERE user_id = $1
+                    ORDER BY created_at
                     "#,
-                        token,
-                        user_id,
-                        expires_at
+                        user_id
+                    )
+                    .fetch_all(&self.pool)
+                },
+                &format!("Failed to list identities of user {}", user_id),
+            )
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Identity {
+                id: row.id,
+                provider: row.provider,
+                provider_user_id: row.provider_user_id,
+                created_at: row.created_at,
+            })
+            .collect())
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_identity(&self, user_id: Uuid, identity_id: Uuid) -> AppResult<bool> {
+        let result = self
+            .executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query!(
+                        r#"
+                    DELETE FROM user_identities
+                    WHERE id = $1 AND user_id = $2
+                    "#,
+                        identity_id,
+                        user_id
                     )
                     .execute(&self.pool)
                 },
-                &format!("Failed to store verification token for user {}", user_id),
+                &format!("Failed to unlink identity {}", identity_id),
             )
             .await?;
 
+        Ok(result.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self))]
+    async fn store_verification_token(
+        &self,
+        user_id: Uuid,
+        token: &str,
+        expires_at: OffsetDateTime,
+    ) -> AppResult<()> {
+        // Resending a verification email retires the links sent before it.
+        let mut tx = begin_transaction(&self.pool).await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM email_verification_tokens
+            WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to clear verification tokens: {}", e),
+        })?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO email_verification_tokens (token, user_id, expires_at)
+            VALUES ($1, $2, $3)
+            "#,
+            token,
+            user_id,
+            expires_at
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!(
+                "Failed to store verification token for user {}: {}",
+                user_id, e
+            ),
+        })?;
+
+        commit_transaction(tx).await?;
+
         debug!(
             "Successfully stored verification token for user {}",
             user_id
@@ -482,6 +608,357 @@ impl UserStorePort for PostgresUserStoreAdapter {
             })
     }
 
+    #[instrument(skip(self))]
+    async fn find_latest_verification_token_issued_at(
+        &self,
+        user_id: Uuid,
+    ) -> AppResult<Option<OffsetDateTime>> {
+        self.executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_scalar!(
+                        r#"
+                    SELECT MAX(created_at)
+                    FROM email_verification_tokens
+                    WHERE user_id = $1
+                    "#,
+                        user_id
+                    )
+                    .fetch_one(&self.pool)
+                },
+                &format!("Failed to find verification token for user {}", user_id),
+            )
+            .await
+    }
+
+    #[instrument(skip(self))]
+    async fn find_latest_password_reset_token_issued_at(
+        &self,
+        user_id: Uuid,
+    ) -> AppResult<Option<OffsetDateTime>> {
+        self.executor
+            .execute_with_timeout(
+                || {
+                    sqlx::query_scalar!(
+                        r#"
+                    SELECT MAX(created_at)
+                    FROM password_reset_tokens
+                    WHERE user_id = $1
+                    "#,
+                        user_id
+                    )
+                    .fetch_one(&self.pool)
+                },
+                &format!("Failed to find password reset token for user {}", user_id),
+            )
+            .await
+    }
+
+    // Only the most recently requested reset link stays valid.
+    #[instrument(skip(self, token))]
+    async fn store_password_reset_token(
+        &self,
+        user_id: Uuid,
+        token: &str,
+        expires_at: OffsetDateTime,
+    ) -> AppResult<()> {
+        let token_hash = hash_token(token);
+        let mut tx = begin_transaction(&self.pool).await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM password_reset_tokens
+            WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to clear password reset tokens: {}", e),
+        })?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO password_reset_tokens (token_hash, user_id, expires_at)
+            VALUES ($1, $2, $3)
+            "#,
+            token_hash,
+            user_id,
+            expires_at
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to store password reset token: {}", e),
+        })?;
+
+        commit_transaction(tx).await?;
+
+        debug!("Stored password reset token for user {}", user_id);
+        Ok(())
+    }
+
+    // Bumps the session version together with the password so every existing session ends.
+    #[instrument(skip(self, token, password_hash))]
+    async fn reset_password_by_token(
+        &self,
+        token: &str,
+        password_hash: &str,
+    ) -> AppResult<UserPublic> {
+        let token_hash = hash_token(token);
+        let mut tx = begin_transaction(&self.pool).await?;
+
+        let token_record = sqlx::query!(
+            r#"
+            DELETE FROM password_reset_tokens
+            WHERE token_hash = $1
+            RETURNING user_id, expires_at
+            "#,
+            token_hash
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to consume password reset token: {}", e),
+        })?;
+
+        let token_record = token_record.ok_or(AppError::TokenNotFound)?;
+
+        if token_record.expires_at < OffsetDateTime::now_utc() {
+            commit_transaction(tx).await?;
+            return Err(AppError::TokenExpired);
+        }
+
+        sqlx::query!(
+            r#"
+            UPDATE users
+            SET password_hash = $1,
+                session_version = session_version + 1
+            WHERE id = $2
+            "#,
+            password_hash,
+            token_record.user_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to update password: {}", e),
+        })?;
+
+        commit_transaction(tx).await?;
+
+        debug!("Reset password of user {}", token_record.user_id);
+
+        self.find_user_by_id(token_record.user_id)
+            .await?
+            .ok_or_else(|| AppError::DatabaseError {
+                message: "Password was reset but user could not be retrieved".to_string(),
+            })
+    }
+
+    // Only the most recently requested email change stays pending.
+    #[instrument(skip(self, token))]
+    async fn store_email_change_token(
+        &self,
+        user_id: Uuid,
+        new_email: &str,
+        token: &str,
+        expires_at: OffsetDateTime,
+    ) -> AppResult<()> {
+        let token_hash = hash_token(token);
+        let mut tx = begin_transaction(&self.pool).await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM email_change_tokens
+            WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to clear email change tokens: {}", e),
+        })?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO email_change_tokens (token_hash, user_id, new_email, expires_at)
+            VALUES ($1, $2, $3, $4)
+            "#,
+            token_hash,
+            user_id,
+            new_email,
+            expires_at
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to store email change token: {}", e),
+        })?;
+
+        commit_transaction(tx).await?;
+
+        debug!("Stored email change token for user {}", user_id);
+        Ok(())
+    }
+
+    // The new address is verified by the link itself. The session version is bumped
+    // so sessions opened under the old address end.
+    #[allow(clippy::too_many_lines)]
+    #[instrument(skip(self, token))]
+    async fn change_email_by_token(&self, token: &str) -> AppResult<(UserPublic, String)> {
+        let token_hash = hash_token(token);
+        let mut tx = begin_transaction(&self.pool).await?;
+
+        let token_record = sqlx::query!(
+            r#"
+            DELETE FROM email_change_tokens
+            WHERE token_hash = $1
+            RETURNING user_id, new_email, expires_at
+            "#,
+            token_hash
+        )
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to consume email change token: {}", e),
+        })?;
+
+        let token_record = token_record.ok_or(AppError::TokenNotFound)?;
+
+        if token_record.expires_at < OffsetDateTime::now_utc() {
+            commit_transaction(tx).await?;
+            return Err(AppError::TokenExpired);
+        }
+
+        let previous_email = sqlx::query_scalar!(
+            r#"
+            SELECT email
+            FROM users
+            WHERE id = $1
+            FOR UPDATE
+            "#,
+            token_record.user_id
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to load current email: {}", e),
+        })?;
+
+        let email_taken = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS(SELECT 1 FROM users WHERE email = $1 AND id <> $2) AS "exists!"
+            "#,
+            token_record.new_email,
+            token_record.user_id
+        )
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to check email availability: {}", e),
+        })?;
+
+        if email_taken {
+            commit_transaction(tx).await?;
+            return Err(AppError::ValidationError {
+                message: "Email is already in use".to_string(),
+            });
+        }
+
+        sqlx::query!(
+            r#"
+            UPDATE users
+            SET email = $1,
+                email_verified_at = NOW(),
+                session_version = session_version + 1
+            WHERE id = $2
+            "#,
+            token_record.new_email,
+            token_record.user_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to update email: {}", e),
+        })?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM email_verification_tokens
+            WHERE user_id = $1
+            "#,
+            token_record.user_id
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to clear verification tokens: {}", e),
+        })?;
+
+        commit_transaction(tx).await?;
+
+        debug!("Changed email of user {}", token_record.user_id);
+
+        let user = self
+            .find_user_by_id(token_record.user_id)
+            .await?
+            .ok_or_else(|| AppError::DatabaseError {
+                message: "Email was changed but user could not be retrieved".to_string(),
+            })?;
+
+        Ok((user, previous_email))
+    }
+
+    #[instrument(skip(self))]
+    async fn delete_expired_tokens(&self) -> AppResult<u64> {
+        let mut tx = begin_transaction(&self.pool).await?;
+
+        let verification = sqlx::query!(
+            r#"
+            DELETE FROM email_verification_tokens
+            WHERE expires_at < NOW()
+            "#
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to delete expired verification tokens: {}", e),
+        })?;
+
+        let password_reset = sqlx::query!(
+            r#"
+            DELETE FROM password_reset_tokens
+            WHERE expires_at < NOW()
+            "#
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to delete expired password reset tokens: {}", e),
+        })?;
+
+        let email_change = sqlx::query!(
+            r#"
+            DELETE FROM email_change_tokens
+            WHERE expires_at < NOW()
+            "#
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::DatabaseError {
+            message: format!("Failed to delete expired email change tokens: {}", e),
+        })?;
+
+        commit_transaction(tx).await?;
+
+        Ok(verification.rows_affected()
+            + password_reset.rows_affected()
+            + email_change.rows_affected())
+    }
+
     #[instrument(skip(self))]
     async fn update_username(&self, user_id: Uuid, new_username: &str) -> AppResult<UserPublic> {
         self.executor
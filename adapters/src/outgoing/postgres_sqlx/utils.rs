@@ -1,4 +1,5 @@
 use fedi_wplace_application::error::{AppError, AppResult};
+use sha2::{Digest, Sha256};
 use sqlx::{PgPool, Postgres, Transaction};
 use std::{future::Future, time::Duration};
 use tokio::time::timeout;
@@ -43,3 +44,8 @@ pub async fn commit_transaction(tx: Transaction<'_, Postgres>) -> AppResult<()>
         message: format!("Failed to commit transaction: {}", e),
     })
 }
+
+// Single-use secrets are stored as SHA-256 digests so a database leak does not expose live tokens.
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
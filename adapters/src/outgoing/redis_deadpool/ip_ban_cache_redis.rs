@@ -0,0 +1,102 @@
+use deadpool_redis::{
+    Connection as RedisConnection, Pool as RedisPool,
+    redis::{AsyncCommands, cmd},
+};
+use std::{net::IpAddr, time::Duration};
+use tokio::time::timeout;
+use tracing::debug;
+
+use fedi_wplace_application::{
+    error::{AppError, AppResult},
+    ports::outgoing::ip_ban_cache::IpBanCachePort,
+};
+
+pub struct RedisIpBanCacheAdapter {
+    redis_pool: RedisPool,
+    namespace: String,
+}
+
+impl RedisIpBanCacheAdapter {
+    pub fn new(redis_pool: RedisPool, namespace_env: &str) -> Self {
+        Self {
+            redis_pool,
+            namespace: format!("fediplace:{}:ipban", namespace_env),
+        }
+    }
+
+    fn status_key(&self, ip: IpAddr) -> String {
+        format!("{}:{}", self.namespace, ip)
+    }
+
+    async fn get_redis_connection(&self) -> AppResult<RedisConnection> {
+        timeout(Duration::from_millis(500), self.redis_pool.get())
+            .await
+            .map_err(|_| AppError::CacheError {
+                message: "Redis connection timeout".to_string(),
+            })?
+            .map_err(|e| AppError::CacheError {
+                message: format!("Failed to get Redis connection: {}", e),
+            })
+    }
+}
+
+#[async_trait::async_trait]
+impl IpBanCachePort for RedisIpBanCacheAdapter {
+    async fn get_ban_status(&self, ip: IpAddr) -> AppResult<Option<bool>> {
+        let mut conn = self.get_redis_connection().await?;
+
+        conn.get(self.status_key(ip))
+            .await
+            .map_err(|e| AppError::CacheError {
+                message: format!("Failed to get IP ban status: {}", e),
+            })
+    }
+
+    async fn store_ban_status(&self, ip: IpAddr, banned: bool, ttl: Duration) -> AppResult<()> {
+        let mut conn = self.get_redis_connection().await?;
+        let ttl_ms = u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX).max(1);
+
+        conn.pset_ex::<_, _, ()>(self.status_key(ip), banned, ttl_ms)
+            .await
+            .map_err(|e| AppError::CacheError {
+                message: format!("Failed to store IP ban status: {}", e),
+            })
+    }
+
+    async fn clear_ban_statuses(&self) -> AppResult<()> {
+        let mut conn = self.get_redis_connection().await?;
+        let pattern = format!("{}:*", self.namespace);
+
+        let mut cursor: u64 = 0;
+        let mut cleared_keys = 0;
+
+        loop {
+            let (next, batch): (u64, Vec<String>) = cmd("SCAN")
+                .arg(cursor)
+                .arg("MATCH")
+                .arg(&pattern)
+                .query_async(&mut *conn)
+                .await
+                .map_err(|e| AppError::CacheError {
+                    message: format!("Failed to scan IP ban status keys: {}", e),
+                })?;
+
+            if !batch.is_empty() {
+                cleared_keys += batch.len();
+                conn.del::<_, ()>(&batch)
+                    .await
+                    .map_err(|e| AppError::CacheError {
+                        message: format!("Failed to clear IP ban statuses: {}", e),
+                    })?;
+            }
+
+            if next == 0 {
+                break;
+            }
+            cursor = next;
+        }
+
+        debug!("Cleared {} cached IP ban statuses", cleared_keys);
+        Ok(())
+    }
+}
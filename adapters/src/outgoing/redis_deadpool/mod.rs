@@ -1,3 +1,5 @@
+pub mod ip_ban_cache_redis;
 pub(crate) mod keys;
+pub mod rate_limit_redis;
 pub mod subscription_redis;
 pub mod tile_cache_redis;
@@ -0,0 +1,140 @@
+use std::{sync::LazyLock, time::Duration};
+
+use deadpool_redis::{
+    Pool as RedisPool,
+    redis::{ErrorKind, FromRedisValue, RedisError, RedisResult, Script, Value as RedisValue},
+};
+use tokio::time::timeout;
+use tracing::debug;
+
+use fedi_wplace_application::{
+    error::{AppError, AppResult},
+    ports::outgoing::rate_limit::{RateLimitDecision, RateLimitKey, RateLimitPort, RateLimitQuota},
+};
+
+// Generic cell rate algorithm: a single key per client holds the theoretical
+// arrival time (TAT), so the limit is shared by every replica and survives restarts.
+static GCRA_RATE_LIMIT_SCRIPT: LazyLock<Script> = LazyLock::new(|| {
+    Script::new(
+        r"
+        local rate_key = KEYS[1]
+        local emission_interval_ms = tonumber(ARGV[1])
+        local burst_size = tonumber(ARGV[2])
+
+        local redis_time = redis.call('TIME')
+        local now_ms = redis_time[1] * 1000 + math.floor(redis_time[2] / 1000)
+
+        local delay_tolerance_ms = emission_interval_ms * burst_size
+        local tat = tonumber(redis.call('GET', rate_key)) or now_ms
+        if tat < now_ms then
+            tat = now_ms
+        end
+
+        local new_tat = tat + emission_interval_ms
+        local allow_at = new_tat - delay_tolerance_ms
+
+        if allow_at > now_ms then
+            return {0, 0, tat - now_ms, allow_at - now_ms}
+        end
+
+        redis.call('SET', rate_key, new_tat, 'PX', new_tat - now_ms)
+        local remaining = math.floor((delay_tolerance_ms - (new_tat - now_ms)) / emission_interval_ms)
+        return {1, remaining, new_tat - now_ms, 0}
+        ",
+    )
+});
+
+#[derive(Debug)]
+struct GcraScriptResult {
+    allowed: bool,
+    remaining: u32,
+    reset_after_ms: u64,
+    retry_after_ms: u64,
+}
+
+impl FromRedisValue for GcraScriptResult {
+    fn from_redis_value(v: &RedisValue) -> RedisResult<Self> {
+        if let RedisValue::Array(values) = v {
+            if values.len() != 4 {
+                return Err((ErrorKind::TypeError, "Expected array of 4 elements").into());
+            }
+
+            let value_at = |index: usize| {
+                values.get(index).ok_or(RedisError::from((
+                    ErrorKind::TypeError,
+                    "Missing value in rate limit script result",
+                )))
+            };
+
+            Ok(GcraScriptResult {
+                allowed: i64::from_redis_value(value_at(0)?)? == 1,
+                remaining: i64::from_redis_value(value_at(1)?)?.max(0) as u32,
+                reset_after_ms: i64::from_redis_value(value_at(2)?)?.max(0) as u64,
+                retry_after_ms: i64::from_redis_value(value_at(3)?)?.max(0) as u64,
+            })
+        } else {
+            Err((ErrorKind::TypeError, "Expected array").into())
+        }
+    }
+}
+
+pub struct RedisRateLimitAdapter {
+    redis_pool: RedisPool,
+    namespace: String,
+}
+
+impl RedisRateLimitAdapter {
+    pub fn new(redis_pool: RedisPool, namespace_env: &str, limiter_name: &str) -> Self {
+        Self {
+            redis_pool,
+            namespace: format!("fediplace:{}:ratelimit:{}", namespace_env, limiter_name),
+        }
+    }
+
+    fn rate_key(&self, key: &RateLimitKey) -> String {
+        format!("{}:{}", self.namespace, key)
+    }
+}
+
+#[async_trait::async_trait]
+impl RateLimitPort for RedisRateLimitAdapter {
+    async fn check(
+        &self,
+        key: &RateLimitKey,
+        quota: RateLimitQuota,
+    ) -> AppResult<RateLimitDecision> {
+        let mut redis_conn = timeout(Duration::from_millis(500), self.redis_pool.get())
+            .await
+            .map_err(|_| AppError::CacheError {
+                message: "Redis connection timeout".to_string(),
+            })?
+            .map_err(|redis_error| AppError::CacheError {
+                message: format!("Failed to get Redis connection: {}", redis_error),
+            })?;
+
+        let rate_key = self.rate_key(key);
+        let emission_interval_ms = (60_000 / u64::from(quota.requests_per_minute.max(1))).max(1);
+        let result: GcraScriptResult = GCRA_RATE_LIMIT_SCRIPT
+            .key(&rate_key)
+            .arg(emission_interval_ms)
+            .arg(quota.burst_size)
+            .invoke_async(&mut redis_conn)
+            .await
+            .map_err(|redis_error| AppError::CacheError {
+                message: format!("Failed to execute rate limit script: {}", redis_error),
+            })?;
+
+        debug!(
+            "Rate limit check for {}: allowed={}, remaining={}",
+            rate_key, result.allowed, result.remaining
+        );
+
+        Ok(RateLimitDecision {
+            allowed: result.allowed,
+            limit: quota.burst_size,
+            remaining: result.remaining,
+            reset_after: Duration::from_millis(result.reset_after_ms),
+            retry_after: (!result.allowed).then(|| Duration::from_millis(result.retry_after_ms)),
+        })
+    }
+}
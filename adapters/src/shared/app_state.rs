@@ -6,18 +6,26 @@ use tokio::sync::broadcast;
 
 use fedi_wplace_application::infrastructure_config::Config;
 
-use crate::incoming::http_axum::middleware::rate_limit::RateLimiter;
+use crate::incoming::http_axum::auth::{mastodon, oidc::OidcProviders};
+use crate::incoming::http_axum::middleware::rate_limit::RateLimiters;
 use crate::incoming::ws_axum::WsAdapterPolicy;
 
 use domain::events::TileVersionEvent;
 use fedi_wplace_application::ports::incoming::{
+    account::AccountUseCase,
     admin::AdminUseCase,
-    auth::AuthUseCase,
-    ban::BanUseCase,
+    audit::AuditLogUseCase,
+    auth::{
+        ApiTokenUseCase, AuthUseCase, FediverseLoginUseCase, LoginProtectionUseCase,
+        SessionManagementUseCase, TwoFactorUseCase,
+    },
+    ban::{BanUseCase, IpBanUseCase, QuarantineUseCase},
+    protected_regions::ProtectedRegionUseCase,
+    reports::ReportUseCase,
     subscriptions::SubscriptionUseCase,
     tiles::{
-        MetricsQueryUseCase, PaintPixelsUseCase, PixelHistoryQueryUseCase, PixelInfoQueryUseCase,
-        TilesQueryUseCase,
+        MetricsQueryUseCase, PaintPixelsUseCase, PaintRulesQueryUseCase, PixelHistoryQueryUseCase,
+        PixelInfoQueryUseCase, TilesQueryUseCase,
     },
 };
 
@@ -30,12 +38,26 @@ pub struct AppState {
     pub metrics_query_service: Arc<dyn MetricsQueryUseCase + Send + Sync>,
     pub pixel_history_query_service: Arc<dyn PixelHistoryQueryUseCase + Send + Sync>,
     pub pixel_info_query_service: Arc<dyn PixelInfoQueryUseCase + Send + Sync>,
+    pub paint_rules_query_service: Arc<dyn PaintRulesQueryUseCase + Send + Sync>,
     pub subscription_service: Arc<dyn SubscriptionUseCase + Send + Sync>,
     pub auth_use_case: Arc<dyn AuthUseCase + Send + Sync>,
+    pub fediverse_login_use_case: Arc<dyn FediverseLoginUseCase + Send + Sync>,
+    pub two_factor_use_case: Arc<dyn TwoFactorUseCase + Send + Sync>,
+    pub session_management_use_case: Arc<dyn SessionManagementUseCase + Send + Sync>,
+    pub api_token_use_case: Arc<dyn ApiTokenUseCase + Send + Sync>,
+    pub account_use_case: Arc<dyn AccountUseCase + Send + Sync>,
+    pub login_protection_use_case: Arc<dyn LoginProtectionUseCase + Send + Sync>,
     pub admin_use_case: Arc<dyn AdminUseCase + Send + Sync>,
     pub ban_use_case: Arc<dyn BanUseCase + Send + Sync>,
+    pub ip_ban_use_case: Arc<dyn IpBanUseCase + Send + Sync>,
+    pub quarantine_use_case: Arc<dyn QuarantineUseCase + Send + Sync>,
+    pub report_use_case: Arc<dyn ReportUseCase + Send + Sync>,
+    pub protected_region_use_case: Arc<dyn ProtectedRegionUseCase + Send + Sync>,
+    pub audit_log_use_case: Arc<dyn AuditLogUseCase + Send + Sync>,
     pub ws_broadcast: broadcast::Sender<TileVersionEvent>,
-    pub websocket_rate_limiter: Option<Arc<RateLimiter>>,
+    pub rate_limiters: RateLimiters,
+    pub oidc_providers: Arc<OidcProviders>,
+    pub fediverse_http_client: reqwest::Client,
     pub active_websocket_connections: Arc<AtomicUsize>,
 }
 
@@ -49,14 +71,28 @@ impl AppState {
         metrics_query_service: Arc<dyn MetricsQueryUseCase + Send + Sync>,
         pixel_history_query_service: Arc<dyn PixelHistoryQueryUseCase + Send + Sync>,
         pixel_info_query_service: Arc<dyn PixelInfoQueryUseCase + Send + Sync>,
+        paint_rules_query_service: Arc<dyn PaintRulesQueryUseCase + Send + Sync>,
         subscription_service: Arc<dyn SubscriptionUseCase + Send + Sync>,
         auth_use_case: Arc<dyn AuthUseCase + Send + Sync>,
+        fediverse_login_use_case: Arc<dyn FediverseLoginUseCase + Send + Sync>,
+        two_factor_use_case: Arc<dyn TwoFactorUseCase + Send + Sync>,
+        session_management_use_case: Arc<dyn SessionManagementUseCase + Send + Sync>,
+        api_token_use_case: Arc<dyn ApiTokenUseCase + Send + Sync>,
+        account_use_case: Arc<dyn AccountUseCase + Send + Sync>,
+        login_protection_use_case: Arc<dyn LoginProtectionUseCase + Send + Sync>,
         admin_use_case: Arc<dyn AdminUseCase + Send + Sync>,
         ban_use_case: Arc<dyn BanUseCase + Send + Sync>,
+        ip_ban_use_case: Arc<dyn IpBanUseCase + Send + Sync>,
+        quarantine_use_case: Arc<dyn QuarantineUseCase + Send + Sync>,
+        report_use_case: Arc<dyn ReportUseCase + Send + Sync>,
+        protected_region_use_case: Arc<dyn ProtectedRegionUseCase + Send + Sync>,
+        audit_log_use_case: Arc<dyn AuditLogUseCase + Send + Sync>,
         ws_broadcast: broadcast::Sender<TileVersionEvent>,
-        websocket_rate_limiter: Option<Arc<RateLimiter>>,
+        rate_limiters: RateLimiters,
         active_websocket_connections: Arc<AtomicUsize>,
     ) -> Self {
+        let oidc_providers = Arc::new(OidcProviders::from_config(&config.auth));
+
         Self {
             config,
             ws_policy,
@@ -65,12 +101,26 @@ impl AppState {
             metrics_query_service,
             pixel_history_query_service,
             pixel_info_query_service,
+            paint_rules_query_service,
             subscription_service,
             auth_use_case,
+            fediverse_login_use_case,
+            two_factor_use_case,
+            session_management_use_case,
+            api_token_use_case,
+            account_use_case,
+            login_protection_use_case,
             admin_use_case,
             ban_use_case,
+            ip_ban_use_case,
+            quarantine_use_case,
+            report_use_case,
+            protected_region_use_case,
+            audit_log_use_case,
             ws_broadcast,
-            websocket_rate_limiter,
+            rate_limiters,
+            oidc_providers,
+            fediverse_http_client: mastodon::http_client(),
             active_websocket_connections,
         }
     }
@@ -0,0 +1,252 @@
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::audit::recorder::record_audit_entry;
+use crate::error::{AppError, AppResult};
+use crate::infrastructure_config::AccountDeletionConfig;
+use crate::ports::incoming::account::{AccountDeletionUseCase, AccountUseCase};
+use crate::ports::outgoing::{
+    account_store::{AccountDeletion, DynAccountStorePort},
+    audit_log::DynAuditLogPort,
+    ban_store::DynBanStorePort,
+    email_sender::DynEmailSenderPort,
+    password_hasher::DynPasswordHasherPort,
+    pixel_history_store::DynPixelHistoryStorePort,
+    session_store::DynSessionStorePort,
+    user_store::DynUserStorePort,
+};
+use domain::{
+    account::{AccountDataExport, AccountError},
+    audit::{AuditAction, AuditEntry, AuditTargetType},
+    auth::UserId,
+};
+
+const DELETION_BATCH_SIZE: u32 = 50;
+
+pub struct AccountServiceDeps {
+    pub account_store: DynAccountStorePort,
+    pub user_store: DynUserStorePort,
+    pub pixel_history_store: DynPixelHistoryStorePort,
+    pub ban_store: DynBanStorePort,
+    pub session_store: DynSessionStorePort,
+    pub password_hasher: DynPasswordHasherPort,
+    pub email_sender: DynEmailSenderPort,
+    pub audit_log: DynAuditLogPort,
+}
+
+pub struct AccountService {
+    account_store: DynAccountStorePort,
+    user_store: DynUserStorePort,
+    pixel_history_store: DynPixelHistoryStorePort,
+    ban_store: DynBanStorePort,
+    session_store: DynSessionStorePort,
+    password_hasher: DynPasswordHasherPort,
+    email_sender: DynEmailSenderPort,
+    audit_log: DynAuditLogPort,
+    grace_period: Duration,
+}
+
+impl AccountService {
+    pub fn new(deps: AccountServiceDeps, config: &AccountDeletionConfig) -> Self {
+        Self {
+            account_store: deps.account_store,
+            user_store: deps.user_store,
+            pixel_history_store: deps.pixel_history_store,
+            ban_store: deps.ban_store,
+            session_store: deps.session_store,
+            password_hasher: deps.password_hasher,
+            email_sender: deps.email_sender,
+            audit_log: deps.audit_log,
+            grace_period: Duration::days(i64::from(config.grace_period_days)),
+        }
+    }
+
+    fn validation_error(error: &AccountError) -> AppError {
+        AppError::ValidationError {
+            message: error.to_string(),
+        }
+    }
+
+    async fn delete_due_account(&self, user_id: Uuid, now: OffsetDateTime) -> AppResult<bool> {
+        match self.account_store.delete_account(user_id, now).await? {
+            AccountDeletion::Deleted => {}
+            AccountDeletion::NotDue => return Ok(false),
+            AccountDeletion::Banned => {
+                tracing::info!(user_id = %user_id, "Deferred deletion of banned account");
+                return Ok(false);
+            }
+            AccountDeletion::LastAdmin => {
+                self.account_store.cancel_deletion(user_id).await?;
+                tracing::warn!(user_id = %user_id, "Cancelled deletion of the last admin account");
+                return Ok(false);
+            }
+        }
+
+        // Only the id is kept; the rest of the account is gone for good.
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                None,
+                AuditAction::AccountDeleted,
+                AuditTargetType::User,
+                Some(user_id.to_string()),
+            ),
+        )
+        .await;
+
+        tracing::info!(user_id = %user_id, "Deleted account");
+        Ok(true)
+    }
+}
+
+#[async_trait::async_trait]
+impl AccountUseCase for AccountService {
+    async fn export_account_data(&self, user_id: Uuid) -> AppResult<AccountDataExport> {
+        let user = self
+            .user_store
+            .find_user_by_id(user_id)
+            .await?
+            .ok_or(AppError::Unauthorized)?;
+        let domain_user_id = UserId::from_uuid(user_id);
+
+        // Shadow bans stay hidden from their target while they are in force.
+        let bans = self
+            .ban_store
+            .get_ban_history_by_user_id(&domain_user_id)
+            .await?
+            .into_iter()
+            .filter(|ban| !ban.is_active_shadow())
+            .collect();
+
+        Ok(AccountDataExport {
+            exported_at: OffsetDateTime::now_utc(),
+            created_at: self.user_store.find_user_created_at(user_id).await?,
+            deletion_scheduled_at: self
+                .account_store
+                .find_deletion_scheduled_at(user_id)
+                .await?,
+            identities: self.user_store.list_identities(user_id).await?,
+            bans,
+            paints: self
+                .pixel_history_store
+                .get_paint_actions_by_user(&domain_user_id)
+                .await?,
+            sessions: self.session_store.list_sessions(user_id).await?,
+            user,
+        })
+    }
+
+    async fn deletion_scheduled_at(&self, user_id: Uuid) -> AppResult<Option<OffsetDateTime>> {
+        self.account_store.find_deletion_scheduled_at(user_id).await
+    }
+
+    async fn schedule_deletion(
+        &self,
+        user_id: Uuid,
+        current_password: Option<String>,
+    ) -> AppResult<OffsetDateTime> {
+        let user = self
+            .user_store
+            .find_user_by_id(user_id)
+            .await?
+            .ok_or(AppError::Unauthorized)?;
+
+        // Social-only accounts have no password to confirm; everyone else must re-enter theirs.
+        if let Some((_, _, _, Some(password_hash), _)) =
+            self.user_store.find_user_by_email(&user.email).await?
+        {
+            let password_matches = match current_password {
+                Some(password) => self.password_hasher.verify(&password, &password_hash)?,
+                None => false,
+            };
+            if !password_matches {
+                return Err(Self::validation_error(&AccountError::IncorrectPassword));
+            }
+        }
+
+        // Deleting the account must not be a way out of a ban. Shadow bans are not
+        // revealed here; the deletion simply waits for them to end.
+        if let Some(ban) = self
+            .ban_store
+            .get_active_ban_by_user_id(&UserId::from_uuid(user_id))
+            .await?
+            && !ban.shadow
+        {
+            return Err(Self::validation_error(&AccountError::Banned));
+        }
+
+        if user.is_admin() && self.account_store.is_last_admin(user_id).await? {
+            return Err(Self::validation_error(&AccountError::LastAdmin));
+        }
+
+        let scheduled_at = OffsetDateTime::now_utc() + self.grace_period;
+        if !self
+            .account_store
+            .schedule_deletion(user_id, scheduled_at)
+            .await?
+        {
+            return Err(Self::validation_error(
+                &AccountError::DeletionAlreadyScheduled,
+            ));
+        }
+
+        if let Err(e) = self
+            .email_sender
+            .send_account_deletion_scheduled_email(
+                &user.email,
+                &user.username,
+                &scheduled_at.date().to_string(),
+            )
+            .await
+        {
+            tracing::warn!(user_id = %user_id, "Failed to send account deletion notice: {}", e);
+        }
+
+        tracing::info!(user_id = %user_id, scheduled_at = %scheduled_at, "Scheduled account deletion");
+        Ok(scheduled_at)
+    }
+
+    async fn cancel_deletion(&self, user_id: Uuid) -> AppResult<()> {
+        if !self.account_store.cancel_deletion(user_id).await? {
+            return Err(Self::validation_error(&AccountError::DeletionNotScheduled));
+        }
+
+        tracing::info!(user_id = %user_id, "Cancelled account deletion");
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl AccountDeletionUseCase for AccountService {
+    async fn process_due_deletions(&self) -> AppResult<usize> {
+        let mut deleted = 0;
+
+        loop {
+            let now = OffsetDateTime::now_utc();
+            let due = self
+                .account_store
+                .find_due_deletions(now, DELETION_BATCH_SIZE)
+                .await?;
+
+            let mut batch_deleted = 0;
+            for user_id in &due {
+                match self.delete_due_account(*user_id, now).await {
+                    Ok(true) => batch_deleted += 1,
+                    Ok(false) => {}
+                    Err(e) => {
+                        tracing::error!(user_id = %user_id, "Failed to delete account: {}", e);
+                    }
+                }
+            }
+
+            deleted += batch_deleted;
+            // Stop on a short batch, or when nothing could be deleted so failures are not
+            // retried in a tight loop.
+            if due.len() < DELETION_BATCH_SIZE as usize || batch_deleted == 0 {
+                break;
+            }
+        }
+
+        Ok(deleted)
+    }
+}
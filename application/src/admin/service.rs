@@ -1,19 +1,95 @@
-use std::sync::Arc;
+use serde_json::json;
+use std::{net::IpAddr, sync::Arc};
 use uuid::Uuid;
 
 use crate::{
-    error::AppResult,
-    ports::{incoming::admin::AdminUseCase, outgoing::user_store::DynUserStorePort},
+    audit::recorder::{audit_timestamp, record_audit_entry},
+    auth::permissions::require_permission,
+    error::{AppError, AppResult},
+    ports::{
+        incoming::admin::AdminUseCase,
+        outgoing::{
+            audit_log::DynAuditLogPort,
+            ban_store::DynBanStorePort,
+            role_store::{DynRoleStorePort, RoleRevocation},
+            session_store::DynSessionStorePort,
+            user_directory::{DynUserDirectoryPort, UserDirectoryQuery},
+            user_store::DynUserStorePort,
+        },
+    },
+};
+use domain::{
+    audit::{AuditAction, AuditEntry, AuditTargetType},
+    auth::{
+        Permission, Role, RoleError, RoleId, RoleMember, RoleType, UserDetails, UserId, UserPublic,
+        UserSummary,
+    },
 };
-use domain::auth::UserPublic;
 
 pub struct AdminService {
     user_store: DynUserStorePort,
+    role_store: DynRoleStorePort,
+    user_directory: DynUserDirectoryPort,
+    ban_store: DynBanStorePort,
+    session_store: DynSessionStorePort,
+    audit_log: DynAuditLogPort,
 }
 
 impl AdminService {
-    pub fn new(user_store: DynUserStorePort) -> Self {
-        Self { user_store }
+    pub fn new(
+        user_store: DynUserStorePort,
+        role_store: DynRoleStorePort,
+        user_directory: DynUserDirectoryPort,
+        ban_store: DynBanStorePort,
+        session_store: DynSessionStorePort,
+        audit_log: DynAuditLogPort,
+    ) -> Self {
+        Self {
+            user_store,
+            role_store,
+            user_directory,
+            ban_store,
+            session_store,
+            audit_log,
+        }
+    }
+
+    async fn load_role(&self, role_id: Uuid) -> AppResult<Role> {
+        self.role_store
+            .get_role_by_id(&RoleId::from_uuid(role_id))
+            .await?
+            .ok_or_else(|| AppError::ValidationError {
+                message: RoleError::RoleNotFound.to_string(),
+            })
+    }
+
+    // Only admins may hand out or take away the admin role, otherwise any role
+    // manager could escalate to full access.
+    fn ensure_can_grant(actor: &UserPublic, role: &Role) -> AppResult<()> {
+        if role.role_type() == Some(RoleType::Admin) && !actor.is_admin() {
+            return Err(AppError::Forbidden);
+        }
+        Self::ensure_can_grant_permissions(actor, &role.permissions)
+    }
+
+    // A role manager who is not an admin can only pass on permissions they hold,
+    // so minting or widening a role cannot lift them above their own access.
+    fn ensure_can_grant_permissions(
+        actor: &UserPublic,
+        permissions: &[Permission],
+    ) -> AppResult<()> {
+        if actor.is_admin()
+            || permissions
+                .iter()
+                .all(|permission| actor.has_permission(*permission))
+        {
+            return Ok(());
+        }
+        Err(AppError::Forbidden)
+    }
+
+    fn permission_names(permissions: &[Permission]) -> Vec<&'static str> {
+        permissions.iter().map(Permission::as_str).collect()
     }
 }
 
@@ -24,11 +100,879 @@ impl AdminUseCase for AdminService {
         user_id: Uuid,
         role_id: Uuid,
         assigned_by: Uuid,
+        actor_ip: Option<IpAddr>,
     ) -> AppResult<UserPublic> {
-        self.user_store
+        let actor = require_permission(
+            self.user_store.as_ref(),
+            &UserId::from_uuid(assigned_by),
+            Permission::RolesAssign,
+        )
+        .await?;
+        let role = self.load_role(role_id).await?;
+        Self::ensure_can_grant(&actor, &role)?;
+
+        let user = self
+            .user_store
             .assign_role_to_user(user_id, role_id, assigned_by)
-            .await
+            .await?;
+
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                Some(actor.id),
+                AuditAction::RoleAssigned,
+                AuditTargetType::User,
+                Some(user_id.to_string()),
+            )
+            .with_parameters(json!({
+                "role_id": role_id,
+                "role_name": role.name,
+            }))
+            .with_ip_address(actor_ip),
+        )
+        .await;
+
+        Ok(user)
+    }
+
+    async fn revoke_role_from_user(
+        &self,
+        user_id: Uuid,
+        role_id: Uuid,
+        revoked_by: Uuid,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<UserPublic> {
+        let actor = require_permission(
+            self.user_store.as_ref(),
+            &UserId::from_uuid(revoked_by),
+            Permission::RolesAssign,
+        )
+        .await?;
+        let role = self.load_role(role_id).await?;
+        Self::ensure_can_grant(&actor, &role)?;
+
+        let is_admin_role = role.role_type() == Some(RoleType::Admin);
+        let revocation = self
+            .role_store
+            .revoke_role_from_user(&UserId::from_uuid(user_id), &role.id, is_admin_role)
+            .await?;
+
+        let user_role = match revocation {
+            RoleRevocation::Revoked(user_role) => user_role,
+            RoleRevocation::NotAssigned => {
+                return Err(AppError::ValidationError {
+                    message: RoleError::RoleNotAssigned.to_string(),
+                });
+            }
+            RoleRevocation::LastMember => {
+                return Err(AppError::ValidationError {
+                    message: RoleError::LastAdmin.to_string(),
+                });
+            }
+        };
+
+        // Losing a role ends the user's sessions so they sign in again with the roles left.
+        self.session_store
+            .revoke_all_sessions(user_id, None)
+            .await?;
+
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                Some(actor.id),
+                AuditAction::RoleRevoked,
+                AuditTargetType::User,
+                Some(user_id.to_string()),
+            )
+            .with_parameters(json!({
+                "role_id": role_id,
+                "role_name": role.name,
+                "assigned_by": user_role.assigned_by.as_ref().map(UserId::as_uuid),
+                "assigned_at": audit_timestamp(user_role.assigned_at),
+            }))
+            .with_ip_address(actor_ip),
+        )
+        .await;
+
+        tracing::info!(
+            user_id = %user_id,
+            role = %role.name,
+            revoked_by = %revoked_by,
+            "Role revoked"
+        );
+
+        self.user_store
+            .find_user_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::ValidationError {
+                message: format!("User with id {} not found", user_id),
+            })
+    }
+
+    async fn list_roles(&self, requesting_user_id: Uuid) -> AppResult<Vec<Role>> {
+        require_permission(
+            self.user_store.as_ref(),
+            &UserId::from_uuid(requesting_user_id),
+            Permission::RolesAssign,
+        )
+        .await?;
+        self.role_store.list_roles().await
+    }
+
+    async fn create_role(
+        &self,
+        name: String,
+        description: Option<String>,
+        permissions: Vec<Permission>,
+        created_by: Uuid,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<Role> {
+        let actor = require_permission(
+            self.user_store.as_ref(),
+            &UserId::from_uuid(created_by),
+            Permission::RolesAssign,
+        )
+        .await?;
+        Self::ensure_can_grant_permissions(&actor, &permissions)?;
+
+        let name = name.trim().to_string();
+        Role::validate_name(&name).map_err(|e| AppError::ValidationError {
+            message: e.to_string(),
+        })?;
+
+        let now = time::OffsetDateTime::now_utc();
+        let role = Role {
+            id: RoleId::new(),
+            name,
+            description,
+            permissions,
+            created_at: now,
+            updated_at: now,
+        };
+
+        if !self.role_store.create_role(&role).await? {
+            return Err(AppError::ValidationError {
+                message: RoleError::RoleNameTaken.to_string(),
+            });
+        }
+
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                Some(actor.id),
+                AuditAction::RoleCreated,
+                AuditTargetType::Role,
+                Some(role.id.as_uuid().to_string()),
+            )
+            .with_parameters(json!({
+                "name": role.name,
+                "permissions": Self::permission_names(&role.permissions),
+            }))
+            .with_ip_address(actor_ip),
+        )
+        .await;
+
+        Ok(role)
+    }
+
+    async fn update_role(
+        &self,
+        role_id: Uuid,
+        description: Option<String>,
+        permissions: Option<Vec<Permission>>,
+        updated_by: Uuid,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<Role> {
+        let actor = require_permission(
+            self.user_store.as_ref(),
+            &UserId::from_uuid(updated_by),
+            Permission::RolesAssign,
+        )
+        .await?;
+        let role = self.load_role(role_id).await?;
+
+        if role.role_type() == Some(RoleType::Admin) {
+            return Err(AppError::ValidationError {
+                message: RoleError::BuiltInRoleProtected.to_string(),
+            });
+        }
+
+        Self::ensure_can_grant(&actor, &role)?;
+
+        let description = description.or_else(|| role.description.clone());
+        let permissions = permissions.unwrap_or_else(|| role.permissions.clone());
+        Self::ensure_can_grant_permissions(&actor, &permissions)?;
+
+        let updated = self
+            .role_store
+            .update_role(&role.id, description.as_deref(), &permissions)
+            .await?
+            .ok_or_else(|| AppError::ValidationError {
+                message: RoleError::RoleNotFound.to_string(),
+            })?;
+
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                Some(actor.id),
+                AuditAction::RoleUpdated,
+                AuditTargetType::Role,
+                Some(role_id.to_string()),
+            )
+            .with_parameters(json!({
+                "name": role.name,
+                "previous_permissions": Self::permission_names(&role.permissions),
+                "permissions": Self::permission_names(&updated.permissions),
+            }))
+            .with_ip_address(actor_ip),
+        )
+        .await;
+
+        Ok(updated)
+    }
+
+    async fn delete_role(
+        &self,
+        role_id: Uuid,
+        deleted_by: Uuid,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<()> {
+        let actor = require_permission(
+            self.user_store.as_ref(),
+            &UserId::from_uuid(deleted_by),
+            Permission::RolesAssign,
+        )
+        .await?;
+        let role = self.load_role(role_id).await?;
+        Self::ensure_can_grant(&actor, &role)?;
+
+        if role.is_built_in() {
+            return Err(AppError::ValidationError {
+                message: RoleError::BuiltInRoleProtected.to_string(),
+            });
+        }
+
+        let members = self.role_store.list_role_members(&role.id).await?;
+        if !self.role_store.delete_role(&role.id).await? {
+            return Err(AppError::ValidationError {
+                message: RoleError::RoleNotFound.to_string(),
+            });
+        }
+
+        for member in &members {
+            self.session_store
+                .revoke_all_sessions(*member.user_id.as_uuid(), None)
+                .await?;
+        }
+
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                Some(actor.id),
+                AuditAction::RoleDeleted,
+                AuditTargetType::Role,
+                Some(role_id.to_string()),
+            )
+            .with_parameters(json!({
+                "name": role.name,
+                "permissions": Self::permission_names(&role.permissions),
+                "member_count": members.len(),
+            }))
+            .with_ip_address(actor_ip),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn list_users_by_role(
+        &self,
+        role_id: Uuid,
+        requesting_user_id: Uuid,
+    ) -> AppResult<Vec<RoleMember>> {
+        require_permission(
+            self.user_store.as_ref(),
+            &UserId::from_uuid(requesting_user_id),
+            Permission::RolesAssign,
+        )
+        .await?;
+        let role = self.load_role(role_id).await?;
+        self.role_store.list_role_members(&role.id).await
+    }
+
+    async fn list_users(
+        &self,
+        query: UserDirectoryQuery,
+        requesting_user_id: Uuid,
+    ) -> AppResult<Vec<UserSummary>> {
+        require_permission(
+            self.user_store.as_ref(),
+            &UserId::from_uuid(requesting_user_id),
+            Permission::UsersRead,
+        )
+        .await?;
+
+        if let (Some(since), Some(until)) = (query.created_since, query.created_until)
+            && since >= until
+        {
+            return Err(AppError::ValidationError {
+                message: "created_since must be before created_until".to_string(),
+            });
+        }
+
+        self.user_directory.list_users(&query).await
+    }
+
+    async fn get_user_details(
+        &self,
+        user_id: Uuid,
+        requesting_user_id: Uuid,
+    ) -> AppResult<UserDetails> {
+        require_permission(
+            self.user_store.as_ref(),
+            &UserId::from_uuid(requesting_user_id),
+            Permission::UsersRead,
+        )
+        .await?;
+
+        let user = self
+            .user_store
+            .find_user_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::ValidationError {
+                message: "User not found".to_string(),
+            })?;
+        let stats = self
+            .user_directory
+            .get_account_stats(&user.id)
+            .await?
+            .ok_or_else(|| AppError::ValidationError {
+                message: "User not found".to_string(),
+            })?;
+        let identities = self.user_directory.list_user_identities(&user.id).await?;
+        let active_ban = self.ban_store.get_active_ban_by_user_id(&user.id).await?;
+
+        Ok(UserDetails {
+            user,
+            created_at: stats.created_at,
+            identities,
+            active_ban,
+            pixels_on_canvas: stats.pixels_on_canvas,
+            pixels_quarantined: stats.pixels_quarantined,
+        })
     }
 }
 
 pub type DynAdminUseCase = Arc<dyn AdminUseCase>;
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+    use std::sync::Mutex;
+
+    use domain::{
+        audit::AuditEntry,
+        auth::{Identity, RoleMember},
+        ban::{Ban, BanId},
+        session::UserSession,
+    };
+    use time::OffsetDateTime;
+
+    use super::*;
+    use crate::ports::outgoing::{
+        audit_log::{AuditLogPort, AuditLogQuery},
+        ban_store::BanStorePort,
+        role_store::RoleStorePort,
+        session_store::SessionStorePort,
+        user_directory::{UserAccountStats, UserDirectoryPort},
+        user_store::UserStorePort,
+    };
+
+    type TestResult = Result<(), Box<dyn Error>>;
+
+    // Ports the role management paths do not touch answer with this error.
+    fn unused<T>() -> AppResult<T> {
+        Err(AppError::InternalServerError)
+    }
+
+    fn role(name: &str, permissions: Vec<Permission>) -> Role {
+        let now = OffsetDateTime::now_utc();
+        Role {
+            id: RoleId::new(),
+            name: name.to_string(),
+            description: None,
+            permissions,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn user(roles: Vec<Role>) -> UserPublic {
+        UserPublic {
+            id: UserId::new(),
+            email: "staff@example.com".to_string(),
+            username: "staff".to_string(),
+            email_verified_at: None,
+            available_charges: 0,
+            charges_updated_at: OffsetDateTime::now_utc(),
+            session_version: 0,
+            roles,
+        }
+    }
+
+    struct FakeUserStore {
+        users: Vec<UserPublic>,
+    }
+
+    #[async_trait::async_trait]
+    impl UserStorePort for FakeUserStore {
+        async fn create_user_with_password(
+            &self,
+            _email: &str,
+            _username: &str,
+            _password_hash: &str,
+        ) -> AppResult<UserPublic> {
+            unused()
+        }
+        async fn find_user_by_email(
+            &self,
+            _email: &str,
+        ) -> AppResult<Option<(Uuid, String, String, Option<String>, Option<OffsetDateTime>)>>
+        {
+            unused()
+        }
+        async fn find_user_by_username(&self, _username: &str) -> AppResult<Option<UserPublic>> {
+            unused()
+        }
+        async fn find_user_by_id(&self, id: Uuid) -> AppResult<Option<UserPublic>> {
+            Ok(self
+                .users
+                .iter()
+                .find(|user| *user.id.as_uuid() == id)
+                .cloned())
+        }
+        async fn find_user_created_at(&self, _id: Uuid) -> AppResult<Option<OffsetDateTime>> {
+            unused()
+        }
+        async fn find_user_by_identity(
+            &self,
+            _provider: &str,
+            _provider_user_id: &str,
+        ) -> AppResult<Option<UserPublic>> {
+            unused()
+        }
+        async fn create_social_user(
+            &self,
+            _provider: &str,
+            _provider_user_id: &str,
+            _email: Option<&str>,
+            _email_verified: bool,
+            _username: Option<&str>,
+        ) -> AppResult<UserPublic> {
+            unused()
+        }
+        async fn link_identity(
+            &self,
+            _user_id: Uuid,
+            _provider: &str,
+            _provider_user_id: &str,
+        ) -> AppResult<Uuid> {
+            unused()
+        }
+        async fn list_identities(&self, _user_id: Uuid) -> AppResult<Vec<Identity>> {
+            unused()
+        }
+        async fn delete_identity(&self, _user_id: Uuid, _identity_id: Uuid) -> AppResult<bool> {
+            unused()
+        }
+        async fn store_verification_token(
+            &self,
+            _user_id: Uuid,
+            _token: &str,
+            _expires_at: OffsetDateTime,
+        ) -> AppResult<()> {
+            unused()
+        }
+        async fn verify_user_by_token(&self, _token: &str) -> AppResult<UserPublic> {
+            unused()
+        }
+        async fn find_latest_verification_token_issued_at(
+            &self,
+            _user_id: Uuid,
+        ) -> AppResult<Option<OffsetDateTime>> {
+            unused()
+        }
+        async fn store_password_reset_token(
+            &self,
+            _user_id: Uuid,
+            _token: &str,
+            _expires_at: OffsetDateTime,
+        ) -> AppResult<()> {
+            unused()
+        }
+        async fn find_latest_password_reset_token_issued_at(
+            &self,
+            _user_id: Uuid,
+        ) -> AppResult<Option<OffsetDateTime>> {
+            unused()
+        }
+        async fn reset_password_by_token(
+            &self,
+            _token: &str,
+            _password_hash: &str,
+        ) -> AppResult<UserPublic> {
+            unused()
+        }
+        async fn store_email_change_token(
+            &self,
+            _user_id: Uuid,
+            _new_email: &str,
+            _token: &str,
+            _expires_at: OffsetDateTime,
+        ) -> AppResult<()> {
+            unused()
+        }
+        async fn change_email_by_token(&self, _token: &str) -> AppResult<(UserPublic, String)> {
+            unused()
+        }
+        async fn delete_expired_tokens(&self) -> AppResult<u64> {
+            unused()
+        }
+        async fn update_username(
+            &self,
+            _user_id: Uuid,
+            _new_username: &str,
+        ) -> AppResult<UserPublic> {
+            unused()
+        }
+        async fn assign_role_to_user(
+            &self,
+            _user_id: Uuid,
+            _role_id: Uuid,
+            _assigned_by: Uuid,
+        ) -> AppResult<UserPublic> {
+            unused()
+        }
+    }
+
+    struct FakeRoleStore {
+        roles: Mutex<Vec<Role>>,
+    }
+
+    impl FakeRoleStore {
+        fn snapshot(&self) -> AppResult<Vec<Role>> {
+            self.roles
+                .lock()
+                .map(|roles| roles.clone())
+                .map_err(|_| AppError::InternalServerError)
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl RoleStorePort for FakeRoleStore {
+        async fn list_roles(&self) -> AppResult<Vec<Role>> {
+            self.snapshot()
+        }
+
+        async fn get_role_by_id(&self, role_id: &RoleId) -> AppResult<Option<Role>> {
+            Ok(self
+                .snapshot()?
+                .into_iter()
+                .find(|role| role.id == *role_id))
+        }
+
+        async fn create_role(&self, role: &Role) -> AppResult<bool> {
+            let mut roles = self
+                .roles
+                .lock()
+                .map_err(|_| AppError::InternalServerError)?;
+            roles.push(role.clone());
+            Ok(true)
+        }
+
+        async fn update_role(
+            &self,
+            role_id: &RoleId,
+            description: Option<&str>,
+            permissions: &[Permission],
+        ) -> AppResult<Option<Role>> {
+            let mut roles = self
+                .roles
+                .lock()
+                .map_err(|_| AppError::InternalServerError)?;
+            Ok(roles
+                .iter_mut()
+                .find(|role| role.id == *role_id)
+                .map(|role| {
+                    role.description = description.map(str::to_string);
+                    role.permissions = permissions.to_vec();
+                    role.clone()
+                }))
+        }
+
+        async fn delete_role(&self, _role_id: &RoleId) -> AppResult<bool> {
+            unused()
+        }
+
+        async fn list_role_members(&self, _role_id: &RoleId) -> AppResult<Vec<RoleMember>> {
+            unused()
+        }
+
+        async fn revoke_role_from_user(
+            &self,
+            _user_id: &UserId,
+            _role_id: &RoleId,
+            _keep_last_member: bool,
+        ) -> AppResult<RoleRevocation> {
+            unused()
+        }
+    }
+
+    struct UnusedUserDirectory;
+
+    #[async_trait::async_trait]
+    impl UserDirectoryPort for UnusedUserDirectory {
+        async fn list_users(&self, _query: &UserDirectoryQuery) -> AppResult<Vec<UserSummary>> {
+            unused()
+        }
+
+        async fn list_user_identities(&self, _user_id: &UserId) -> AppResult<Vec<Identity>> {
+            unused()
+        }
+
+        async fn get_account_stats(
+            &self,
+            _user_id: &UserId,
+        ) -> AppResult<Option<UserAccountStats>> {
+            unused()
+        }
+    }
+
+    struct UnusedBanStore;
+
+    #[async_trait::async_trait]
+    impl BanStorePort for UnusedBanStore {
+        async fn create_ban(&self, _ban: &Ban) -> AppResult<()> {
+            unused()
+        }
+
+        async fn get_active_ban_by_user_id(&self, _user_id: &UserId) -> AppResult<Option<Ban>> {
+            unused()
+        }
+
+        async fn revoke_ban(
+            &self,
+            _ban_id: &BanId,
+            _revoked_by_user_id: &UserId,
+            _revoke_reason: Option<&str>,
+        ) -> AppResult<()> {
+            unused()
+        }
+
+        async fn get_all_active_bans(&self) -> AppResult<Vec<Ban>> {
+            unused()
+        }
+
+        async fn claim_expired_bans(&self, _limit: u32) -> AppResult<Vec<Ban>> {
+            unused()
+        }
+
+        async fn claim_expired_bans_of_user(&self, _user_id: &UserId) -> AppResult<Vec<Ban>> {
+            unused()
+        }
+
+        async fn get_ban_history_by_user_id(&self, _user_id: &UserId) -> AppResult<Vec<Ban>> {
+            unused()
+        }
+
+        async fn remove_user_pixels(&self, _user_id: &UserId) -> AppResult<u64> {
+            unused()
+        }
+    }
+
+    struct UnusedSessionStore;
+
+    #[async_trait::async_trait]
+    impl SessionStorePort for UnusedSessionStore {
+        async fn create_session(&self, _session: &UserSession) -> AppResult<()> {
+            unused()
+        }
+
+        async fn touch_session(
+            &self,
+            _user_id: Uuid,
+            _session_id: Uuid,
+            _ip_address: Option<IpAddr>,
+            _seen_before: OffsetDateTime,
+        ) -> AppResult<bool> {
+            unused()
+        }
+
+        async fn list_sessions(&self, _user_id: Uuid) -> AppResult<Vec<UserSession>> {
+            unused()
+        }
+
+        async fn revoke_session(&self, _user_id: Uuid, _session_id: Uuid) -> AppResult<bool> {
+            unused()
+        }
+
+        async fn revoke_all_sessions(
+            &self,
+            _user_id: Uuid,
+            _except: Option<Uuid>,
+        ) -> AppResult<u64> {
+            unused()
+        }
+
+        async fn delete_stale_sessions(&self, _last_seen_before: OffsetDateTime) -> AppResult<u64> {
+            unused()
+        }
+    }
+
+    struct DiscardAuditLog;
+
+    #[async_trait::async_trait]
+    impl AuditLogPort for DiscardAuditLog {
+        async fn record(&self, _entry: &AuditEntry) -> AppResult<()> {
+            Ok(())
+        }
+
+        async fn list_entries(&self, _query: &AuditLogQuery) -> AppResult<Vec<AuditEntry>> {
+            unused()
+        }
+    }
+
+    struct Fixture {
+        service: AdminService,
+        role_store: Arc<FakeRoleStore>,
+        admin: UserPublic,
+        moderator: UserPublic,
+        moderator_role: Role,
+    }
+
+    fn fixture() -> Fixture {
+        let admin_role = role("admin", Permission::ALL.to_vec());
+        let moderator_role = role(
+            "moderator",
+            vec![Permission::BanCreate, Permission::RolesAssign],
+        );
+        let admin = user(vec![admin_role.clone()]);
+        let moderator = user(vec![moderator_role.clone()]);
+
+        let role_store = Arc::new(FakeRoleStore {
+            roles: Mutex::new(vec![admin_role, moderator_role.clone()]),
+        });
+        let user_store = Arc::new(FakeUserStore {
+            users: vec![admin.clone(), moderator.clone()],
+        });
+        let service = AdminService::new(
+            user_store,
+            Arc::<FakeRoleStore>::clone(&role_store),
+            Arc::new(UnusedUserDirectory),
+            Arc::new(UnusedBanStore),
+            Arc::new(UnusedSessionStore),
+            Arc::new(DiscardAuditLog),
+        );
+
+        Fixture {
+            service,
+            role_store,
+            admin,
+            moderator,
+            moderator_role,
+        }
+    }
+
+    #[tokio::test]
+    async fn moderator_cannot_create_role_with_permissions_they_lack() -> TestResult {
+        let fixture = fixture();
+
+        let result = fixture
+            .service
+            .create_role(
+                "superuser".to_string(),
+                None,
+                Permission::ALL.to_vec(),
+                *fixture.moderator.id.as_uuid(),
+                None,
+            )
+            .await;
+
+        assert!(matches!(result, Err(AppError::Forbidden)));
+        assert!(
+            !fixture
+                .role_store
+                .snapshot()?
+                .iter()
+                .any(|role| role.name == "superuser")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn moderator_can_create_role_with_permissions_they_hold() -> TestResult {
+        let fixture = fixture();
+
+        let role = fixture
+            .service
+            .create_role(
+                "ban_helper".to_string(),
+                None,
+                vec![Permission::BanCreate],
+                *fixture.moderator.id.as_uuid(),
+                None,
+            )
+            .await?;
+
+        assert_eq!(role.permissions, vec![Permission::BanCreate]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn moderator_cannot_widen_their_own_role() -> TestResult {
+        let fixture = fixture();
+
+        let result = fixture
+            .service
+            .update_role(
+                *fixture.moderator_role.id.as_uuid(),
+                None,
+                Some(vec![
+                    Permission::BanCreate,
+                    Permission::RolesAssign,
+                    Permission::AuditRead,
+                ]),
+                *fixture.moderator.id.as_uuid(),
+                None,
+            )
+            .await;
+
+        assert!(matches!(result, Err(AppError::Forbidden)));
+        let stored = fixture
+            .role_store
+            .get_role_by_id(&fixture.moderator_role.id)
+            .await?
+            .ok_or("moderator role missing")?;
+        assert!(!stored.has_permission(Permission::AuditRead));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn admin_can_create_role_with_any_permission() -> TestResult {
+        let fixture = fixture();
+
+        let role = fixture
+            .service
+            .create_role(
+                "superuser".to_string(),
+                None,
+                Permission::ALL.to_vec(),
+                *fixture.admin.id.as_uuid(),
+                None,
+            )
+            .await?;
+
+        assert_eq!(role.permissions, Permission::ALL.to_vec());
+        Ok(())
+    }
+}
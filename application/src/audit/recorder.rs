@@ -0,0 +1,21 @@
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+
+use crate::ports::outgoing::audit_log::AuditLogPort;
+use domain::audit::AuditEntry;
+
+// A failed audit write must not undo an action that has already been applied,
+// so it is logged instead of being returned to the caller.
+pub(crate) async fn record_audit_entry(audit_log: &dyn AuditLogPort, entry: AuditEntry) {
+    if let Err(e) = audit_log.record(&entry).await {
+        tracing::error!(
+            action = entry.action.as_str(),
+            target_id = entry.target_id.as_deref(),
+            "Failed to record audit log entry: {}",
+            e
+        );
+    }
+}
+
+pub(crate) fn audit_timestamp(at: OffsetDateTime) -> Option<String> {
+    at.format(&Rfc3339).ok()
+}
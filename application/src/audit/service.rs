@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use crate::auth::permissions::require_permission;
+use crate::error::{AppError, AppResult};
+use crate::ports::incoming::audit::AuditLogUseCase;
+use crate::ports::outgoing::audit_log::{AuditLogPort, AuditLogQuery};
+use crate::ports::outgoing::user_store::UserStorePort;
+use domain::{
+    audit::AuditEntry,
+    auth::{Permission, UserId},
+};
+
+pub struct AuditLogService {
+    audit_log: Arc<dyn AuditLogPort>,
+    user_store: Arc<dyn UserStorePort>,
+}
+
+impl AuditLogService {
+    pub fn new(audit_log: Arc<dyn AuditLogPort>, user_store: Arc<dyn UserStorePort>) -> Self {
+        Self {
+            audit_log,
+            user_store,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogUseCase for AuditLogService {
+    async fn list_entries(
+        &self,
+        requesting_user_id: UserId,
+        query: AuditLogQuery,
+    ) -> AppResult<Vec<AuditEntry>> {
+        require_permission(
+            self.user_store.as_ref(),
+            &requesting_user_id,
+            Permission::AuditRead,
+        )
+        .await?;
+
+        if let (Some(since), Some(until)) = (query.since, query.until)
+            && since >= until
+        {
+            return Err(AppError::ValidationError {
+                message: "since must be before until".to_string(),
+            });
+        }
+
+        self.audit_log.list_entries(&query).await
+    }
+}
@@ -0,0 +1,166 @@
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::infrastructure_config::ApiTokenConfig;
+use crate::ports::incoming::auth::ApiTokenUseCase;
+use crate::ports::outgoing::api_token_store::DynApiTokenStorePort;
+use crate::ports::outgoing::user_store::DynUserStorePort;
+use domain::api_token::{
+    API_TOKEN_PREFIX, ApiToken, ApiTokenError, ApiTokenScope, CreatedApiToken,
+    MAX_API_TOKEN_NAME_LENGTH,
+};
+
+// Last-used timestamps are only refreshed this often, so busy tokens do not write on
+// every request.
+const LAST_USED_INTERVAL: Duration = Duration::seconds(60);
+
+pub struct ApiTokenService {
+    token_store: DynApiTokenStorePort,
+    user_store: DynUserStorePort,
+    config: ApiTokenConfig,
+}
+
+impl ApiTokenService {
+    pub fn new(
+        token_store: DynApiTokenStorePort,
+        user_store: DynUserStorePort,
+        config: ApiTokenConfig,
+    ) -> Self {
+        Self {
+            token_store,
+            user_store,
+            config,
+        }
+    }
+
+    fn ensure_enabled(&self) -> AppResult<()> {
+        if self.config.enabled {
+            Ok(())
+        } else {
+            Err(AppError::Forbidden)
+        }
+    }
+
+    fn validation_error(error: &ApiTokenError) -> AppError {
+        AppError::ValidationError {
+            message: error.to_string(),
+        }
+    }
+
+    fn generate_secret() -> String {
+        format!(
+            "{API_TOKEN_PREFIX}{}{}",
+            Uuid::new_v4().simple(),
+            Uuid::new_v4().simple()
+        )
+    }
+}
+
+#[async_trait::async_trait]
+impl ApiTokenUseCase for ApiTokenService {
+    async fn create_token(
+        &self,
+        user_id: Uuid,
+        name: String,
+        scopes: Vec<ApiTokenScope>,
+        expires_in_days: Option<u32>,
+    ) -> AppResult<CreatedApiToken> {
+        self.ensure_enabled()?;
+
+        let name = name.trim().to_string();
+        if name.is_empty() || name.chars().count() > MAX_API_TOKEN_NAME_LENGTH {
+            return Err(Self::validation_error(&ApiTokenError::InvalidName));
+        }
+
+        let mut scopes = scopes;
+        scopes.sort_by_key(ApiTokenScope::as_str);
+        scopes.dedup();
+        if scopes.is_empty() {
+            return Err(Self::validation_error(&ApiTokenError::NoScopes));
+        }
+
+        if scopes.contains(&ApiTokenScope::Admin) {
+            let user = self
+                .user_store
+                .find_user_by_id(user_id)
+                .await?
+                .ok_or_else(|| AppError::ValidationError {
+                    message: "User not found".to_string(),
+                })?;
+            if !user.is_privileged() {
+                return Err(Self::validation_error(&ApiTokenError::AdminScopeNotAllowed));
+            }
+        }
+
+        let lifetime_allowed = match (expires_in_days, self.config.max_lifetime_days) {
+            (Some(0), _) | (None, Some(_)) => false,
+            (Some(days), Some(max_days)) => days <= max_days,
+            (Some(_) | None, None) => true,
+        };
+        if !lifetime_allowed {
+            return Err(Self::validation_error(&ApiTokenError::LifetimeTooLong));
+        }
+
+        let now = OffsetDateTime::now_utc();
+        let active = self.token_store.count_active_tokens(user_id, now).await?;
+        if active >= i64::from(self.config.max_tokens_per_user) {
+            return Err(Self::validation_error(&ApiTokenError::TooManyTokens));
+        }
+
+        let token = ApiToken {
+            id: Uuid::new_v4(),
+            user_id,
+            name,
+            scopes,
+            created_at: now,
+            last_used_at: None,
+            expires_at: expires_in_days.map(|days| now + Duration::days(i64::from(days))),
+            revoked_at: None,
+        };
+        let secret = Self::generate_secret();
+
+        self.token_store.create_token(&token, &secret).await?;
+
+        tracing::info!(user_id = %user_id, token_id = %token.id, "Created API token");
+        Ok(CreatedApiToken { token, secret })
+    }
+
+    async fn list_tokens(&self, user_id: Uuid) -> AppResult<Vec<ApiToken>> {
+        self.token_store.list_tokens(user_id).await
+    }
+
+    async fn revoke_token(&self, user_id: Uuid, token_id: Uuid) -> AppResult<()> {
+        if !self.token_store.revoke_token(user_id, token_id).await? {
+            return Err(Self::validation_error(&ApiTokenError::TokenNotFound));
+        }
+
+        tracing::info!(user_id = %user_id, token_id = %token_id, "Revoked API token");
+        Ok(())
+    }
+
+    async fn authenticate(&self, secret: &str) -> AppResult<Option<ApiToken>> {
+        if !self.config.enabled || !secret.starts_with(API_TOKEN_PREFIX) {
+            return Ok(None);
+        }
+
+        let now = OffsetDateTime::now_utc();
+        let Some(token) = self.token_store.find_token_by_secret(secret).await? else {
+            return Ok(None);
+        };
+        if !token.is_active(now) {
+            return Ok(None);
+        }
+
+        if token
+            .last_used_at
+            .is_none_or(|last_used_at| last_used_at < now - LAST_USED_INTERVAL)
+        {
+            self.token_store
+                .record_token_use(token.id, now - LAST_USED_INTERVAL)
+                .await?;
+        }
+
+        Ok(Some(token))
+    }
+}
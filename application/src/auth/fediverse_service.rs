@@ -0,0 +1,74 @@
+use std::sync::Arc;
+use tracing::info;
+
+use crate::error::{AppError, AppResult};
+use crate::infrastructure_config::MastodonConfig;
+use crate::ports::incoming::auth::FediverseLoginUseCase;
+use crate::ports::outgoing::fediverse_app::{FediverseAppRegistrarPort, FediverseAppStorePort};
+use domain::fediverse::{FediverseApp, InstanceDomain, InstancePolicy};
+
+pub struct FediverseLoginService {
+    app_store: Arc<dyn FediverseAppStorePort>,
+    registrar: Arc<dyn FediverseAppRegistrarPort>,
+    policy: InstancePolicy,
+    redirect_url: String,
+    scopes: String,
+    enabled: bool,
+}
+
+impl FediverseLoginService {
+    pub fn new(
+        app_store: Arc<dyn FediverseAppStorePort>,
+        registrar: Arc<dyn FediverseAppRegistrarPort>,
+        config: &MastodonConfig,
+    ) -> AppResult<Self> {
+        Ok(Self {
+            app_store,
+            registrar,
+            policy: config.instance_policy()?,
+            redirect_url: config.redirect_url.clone(),
+            scopes: config.scopes.clone(),
+            enabled: config.enabled,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl FediverseLoginUseCase for FediverseLoginService {
+    async fn resolve_instance_app(&self, instance: &str) -> AppResult<FediverseApp> {
+        if !self.enabled {
+            return Err(AppError::ValidationError {
+                message: "Fediverse login is not enabled".to_string(),
+            });
+        }
+
+        let domain = instance
+            .parse::<InstanceDomain>()
+            .map_err(|e| AppError::ValidationError {
+                message: e.to_string(),
+            })?;
+
+        if !self.policy.permits(&domain) {
+            return Err(AppError::ValidationError {
+                message: format!("Logging in with {} is not allowed", domain),
+            });
+        }
+
+        // Apps registered under an older redirect URL would reject our callback.
+        if let Some(app) = self.app_store.find_app(&domain).await?
+            && app.redirect_url == self.redirect_url
+        {
+            return Ok(app);
+        }
+
+        let app = self
+            .registrar
+            .register_app(&domain, &self.redirect_url, &self.scopes)
+            .await?;
+        self.app_store.save_app(&app).await?;
+
+        info!("Registered OAuth app with fediverse instance {}", domain);
+
+        Ok(app)
+    }
+}
@@ -0,0 +1,222 @@
+use std::{net::IpAddr, sync::Arc};
+
+use serde_json::json;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::audit::recorder::{audit_timestamp, record_audit_entry};
+use crate::auth::permissions::require_permission;
+use crate::error::{AppError, AppResult};
+use crate::infrastructure_config::LoginProtectionConfig;
+use crate::ports::incoming::auth::LoginProtectionUseCase;
+use crate::ports::outgoing::audit_log::AuditLogPort;
+use crate::ports::outgoing::email_sender::DynEmailSenderPort;
+use crate::ports::outgoing::login_failure_store::LoginFailureStorePort;
+use crate::ports::outgoing::user_store::UserStorePort;
+use domain::{
+    audit::{AuditAction, AuditEntry, AuditTargetType},
+    auth::{Permission, UserId},
+    login_protection::{
+        LockedAccount, LoginFailurePenalty, LoginLockoutPolicy, LoginProtectionError,
+    },
+};
+
+pub struct LoginProtectionService {
+    failure_store: Arc<dyn LoginFailureStorePort>,
+    user_store: Arc<dyn UserStorePort>,
+    audit_log: Arc<dyn AuditLogPort>,
+    email_sender: Option<DynEmailSenderPort>,
+    enabled: bool,
+    policy: LoginLockoutPolicy,
+    failure_reset: Duration,
+}
+
+impl LoginProtectionService {
+    pub fn new(
+        failure_store: Arc<dyn LoginFailureStorePort>,
+        user_store: Arc<dyn UserStorePort>,
+        audit_log: Arc<dyn AuditLogPort>,
+        email_sender: Option<DynEmailSenderPort>,
+        config: &LoginProtectionConfig,
+    ) -> Self {
+        Self {
+            failure_store,
+            user_store,
+            audit_log,
+            email_sender,
+            enabled: config.enabled,
+            policy: LoginLockoutPolicy::new(
+                config.free_attempts,
+                config.backoff_base_secs,
+                config.backoff_max_secs,
+                config.lockout_threshold,
+                config.lockout_duration_secs,
+            ),
+            failure_reset: Duration::seconds(
+                i64::try_from(config.failure_reset_secs).unwrap_or(i64::MAX),
+            ),
+        }
+    }
+
+    async fn notify_lockout(
+        &self,
+        user_id: Uuid,
+        failed_attempts: u32,
+        locked_until: OffsetDateTime,
+    ) {
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                None,
+                AuditAction::AccountLocked,
+                AuditTargetType::User,
+                Some(user_id.to_string()),
+            )
+            .with_parameters(json!({
+                "failed_attempts": failed_attempts,
+                "locked_until": audit_timestamp(locked_until),
+            })),
+        )
+        .await;
+
+        let Some(email_sender) = &self.email_sender else {
+            return;
+        };
+        let user = match self.user_store.find_user_by_id(user_id).await {
+            Ok(Some(user)) => user,
+            Ok(None) => return,
+            Err(e) => {
+                tracing::warn!(user_id = %user_id, "Failed to load locked user for notification: {}", e);
+                return;
+            }
+        };
+        if let Err(e) = email_sender
+            .send_account_locked_email(
+                &user.email,
+                &user.username,
+                &audit_timestamp(locked_until).unwrap_or_default(),
+            )
+            .await
+        {
+            tracing::warn!(user_id = %user_id, "Failed to send account lockout notice: {}", e);
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl LoginProtectionUseCase for LoginProtectionService {
+    async fn is_login_allowed(&self, user_id: Uuid) -> AppResult<bool> {
+        if !self.enabled {
+            return Ok(true);
+        }
+
+        let failures = self.failure_store.find_failures(user_id).await?;
+        Ok(!failures.is_some_and(|failures| failures.is_blocked(OffsetDateTime::now_utc())))
+    }
+
+    async fn record_failed_login(&self, user_id: Uuid) -> AppResult<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        let now = OffsetDateTime::now_utc();
+        let failed_attempts = self
+            .failure_store
+            .record_failure(user_id, now, now - self.failure_reset)
+            .await?;
+
+        match self.policy.penalty(failed_attempts, now) {
+            LoginFailurePenalty::None => {}
+            LoginFailurePenalty::Backoff { until } => {
+                self.failure_store
+                    .block_until(user_id, until, false)
+                    .await?;
+            }
+            LoginFailurePenalty::Lockout { until } => {
+                self.failure_store.block_until(user_id, until, true).await?;
+                tracing::warn!(
+                    user_id = %user_id,
+                    failed_attempts,
+                    "Locked password login after repeated failures"
+                );
+                if self.policy.is_first_lockout(failed_attempts) {
+                    self.notify_lockout(user_id, failed_attempts, until).await;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn clear_failed_logins(&self, user_id: Uuid) -> AppResult<()> {
+        self.failure_store.clear_failures(user_id).await?;
+        Ok(())
+    }
+
+    async fn list_locked_accounts(
+        &self,
+        requesting_user_id: UserId,
+    ) -> AppResult<Vec<LockedAccount>> {
+        require_permission(
+            self.user_store.as_ref(),
+            &requesting_user_id,
+            Permission::UsersRead,
+        )
+        .await?;
+
+        self.failure_store
+            .list_locked(OffsetDateTime::now_utc())
+            .await
+    }
+
+    async fn unlock_account(
+        &self,
+        user_id: UserId,
+        unlocked_by: UserId,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<()> {
+        require_permission(
+            self.user_store.as_ref(),
+            &unlocked_by,
+            Permission::UsersManage,
+        )
+        .await?;
+
+        let now = OffsetDateTime::now_utc();
+        let locked_until = self
+            .failure_store
+            .find_failures(*user_id.as_uuid())
+            .await?
+            .filter(|failures| failures.is_locked(now))
+            .and_then(|failures| failures.locked_until)
+            .ok_or_else(|| AppError::ValidationError {
+                message: LoginProtectionError::NotLocked.to_string(),
+            })?;
+
+        self.failure_store
+            .clear_failures(*user_id.as_uuid())
+            .await?;
+
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                Some(unlocked_by.clone()),
+                AuditAction::AccountUnlocked,
+                AuditTargetType::User,
+                Some(user_id.as_uuid().to_string()),
+            )
+            .with_parameters(json!({
+                "locked_until": audit_timestamp(locked_until),
+            }))
+            .with_ip_address(actor_ip),
+        )
+        .await;
+
+        tracing::info!(
+            user_id = %user_id.as_uuid(),
+            unlocked_by = %unlocked_by.as_uuid(),
+            "Unlocked password login"
+        );
+        Ok(())
+    }
+}
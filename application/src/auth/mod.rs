@@ -1,2 +1,9 @@
+pub mod api_token_service;
+pub mod fediverse_service;
+pub mod login_protection_service;
 pub mod password_validator;
+pub mod permissions;
 pub mod service;
+pub mod session_service;
+pub mod token_cleanup_service;
+pub mod two_factor_service;
@@ -0,0 +1,22 @@
+use crate::error::{AppError, AppResult};
+use crate::ports::outgoing::user_store::UserStorePort;
+use domain::auth::{Permission, UserId, UserPublic};
+
+// Services check permissions themselves instead of trusting the caller, so an account
+// that has since lost the role is refused even on a still-open session.
+pub(crate) async fn require_permission(
+    user_store: &dyn UserStorePort,
+    user_id: &UserId,
+    permission: Permission,
+) -> AppResult<UserPublic> {
+    let user = user_store
+        .find_user_by_id(*user_id.as_uuid())
+        .await?
+        .ok_or(AppError::Unauthorized)?;
+
+    if !user.has_permission(permission) {
+        return Err(AppError::Forbidden);
+    }
+
+    Ok(user)
+}
@@ -4,16 +4,25 @@ use uuid::Uuid;
 
 use crate::auth::password_validator::PasswordValidator;
 use crate::error::{AppError, AppResult};
-use crate::ports::incoming::auth::AuthUseCase;
+use crate::ports::incoming::auth::{AuthUseCase, LoginProtectionUseCase};
 use crate::ports::outgoing::email_sender::DynEmailSenderPort;
 use crate::ports::outgoing::password_hasher::PasswordHasherPort;
+use crate::ports::outgoing::session_store::SessionStorePort;
 use crate::ports::outgoing::user_store::UserStorePort;
-use domain::auth::UserPublic;
+use domain::auth::{Identity, PLACEHOLDER_EMAIL_DOMAIN, UserPublic, is_placeholder_email};
+
+const VERIFICATION_TOKEN_TTL: Duration = Duration::hours(24);
+const VERIFICATION_RESEND_COOLDOWN: Duration = Duration::minutes(2);
+const PASSWORD_RESET_TOKEN_TTL: Duration = Duration::hours(1);
+const PASSWORD_RESET_RESEND_COOLDOWN: Duration = Duration::minutes(2);
+const EMAIL_CHANGE_TOKEN_TTL: Duration = Duration::hours(24);
 
 pub struct AuthService {
     user_store: Arc<dyn UserStorePort>,
     password_hasher: Arc<dyn PasswordHasherPort>,
     email_sender: DynEmailSenderPort,
+    session_store: Arc<dyn SessionStorePort>,
+    login_protection: Arc<dyn LoginProtectionUseCase>,
     password_validator: PasswordValidator,
 }
 
@@ -22,14 +31,48 @@ impl AuthService {
         user_store: Arc<dyn UserStorePort>,
         password_hasher: Arc<dyn PasswordHasherPort>,
         email_sender: DynEmailSenderPort,
+        session_store: Arc<dyn SessionStorePort>,
+        login_protection: Arc<dyn LoginProtectionUseCase>,
     ) -> Self {
         Self {
             user_store,
             password_hasher,
             email_sender,
+            session_store,
+            login_protection,
             password_validator: PasswordValidator::new(),
         }
     }
+
+    fn reject_placeholder_email(email: &str) -> AppResult<()> {
+        if is_placeholder_email(email) {
+            return Err(AppError::ValidationError {
+                message: format!(
+                    "Email addresses at {} are reserved",
+                    PLACEHOLDER_EMAIL_DOMAIN
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    async fn send_new_verification_token(
+        &self,
+        user_id: Uuid,
+        email: &str,
+        username: &str,
+    ) -> AppResult<()> {
+        let verification_token = Uuid::new_v4().to_string().replace('-', "");
+        let expires_at = OffsetDateTime::now_utc() + VERIFICATION_TOKEN_TTL;
+
+        self.user_store
+            .store_verification_token(user_id, &verification_token, expires_at)
+            .await?;
+
+        self.email_sender
+            .send_verification_email(email, username, &verification_token)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -41,6 +84,7 @@ impl AuthUseCase for AuthService {
         password: String,
     ) -> AppResult<UserPublic> {
         self.password_validator.validate(&password)?;
+        Self::reject_placeholder_email(&email)?;
 
         if (self.user_store.find_user_by_email(&email).await?).is_some() {
             return Err(AppError::ValidationError {
@@ -60,16 +104,7 @@ impl AuthUseCase for AuthService {
             .create_user_with_password(&email, &username, &password_hash)
             .await?;
 
-        let verification_token = Uuid::new_v4().to_string().replace('-', "");
-
-        let expires_at = OffsetDateTime::now_utc() + Duration::hours(24);
-
-        self.user_store
-            .store_verification_token(*user.id.as_uuid(), &verification_token, expires_at)
-            .await?;
-
-        self.email_sender
-            .send_verification_email(&email, &username, &verification_token)
+        self.send_new_verification_token(*user.id.as_uuid(), &email, &username)
             .await?;
 
         Ok(user)
@@ -79,34 +114,187 @@ impl AuthUseCase for AuthService {
         self.user_store.verify_user_by_token(&token).await
     }
 
-    async fn login_local(&self, email: String, password: String) -> AppResult<UserPublic> {
-        let user_data = self
+    async fn resend_verification_email(&self, user_id: Uuid) -> AppResult<()> {
+        let user = self.me(user_id).await?;
+
+        if user.email_verified_at.is_some() {
+            return Err(AppError::ValidationError {
+                message: "Email is already verified".to_string(),
+            });
+        }
+        if is_placeholder_email(&user.email) {
+            return Err(AppError::ValidationError {
+                message: "Add an email address to your account before verifying it".to_string(),
+            });
+        }
+
+        if let Some(issued_at) = self
             .user_store
-            .find_user_by_email(&email)
+            .find_latest_verification_token_issued_at(user_id)
             .await?
-            .ok_or_else(|| AppError::ValidationError {
-                message: "Invalid email or password".to_string(),
-            })?;
+        {
+            let retry_after = issued_at + VERIFICATION_RESEND_COOLDOWN - OffsetDateTime::now_utc();
+            if retry_after.is_positive() {
+                return Err(AppError::TooManyRequests {
+                    retry_after_seconds: retry_after.whole_seconds().max(1).unsigned_abs(),
+                });
+            }
+        }
 
-        let (user_id, _stored_email, _stored_username, password_hash, _email_verified_at) =
-            user_data;
-        let password_hash = password_hash.ok_or_else(|| AppError::ValidationError {
-            message: "User account does not support local login".to_string(),
-        })?;
+        self.send_new_verification_token(user_id, &user.email, &user.username)
+            .await
+    }
 
-        if !self.password_hasher.verify(&password, &password_hash)? {
+    async fn request_email_change(
+        &self,
+        user_id: Uuid,
+        new_email: String,
+        current_password: Option<String>,
+    ) -> AppResult<()> {
+        let user = self.me(user_id).await?;
+
+        if user.email.eq_ignore_ascii_case(&new_email) {
             return Err(AppError::ValidationError {
-                message: "Invalid email or password".to_string(),
+                message: "New email must differ from the current one".to_string(),
             });
         }
+        Self::reject_placeholder_email(&new_email)?;
+
+        // Social-only accounts have no password to confirm; everyone else must re-enter theirs.
+        if let Some((_, _, _, Some(password_hash), _)) =
+            self.user_store.find_user_by_email(&user.email).await?
+        {
+            let password_matches = match current_password {
+                Some(password) => self.password_hasher.verify(&password, &password_hash)?,
+                None => false,
+            };
+            if !password_matches {
+                return Err(AppError::ValidationError {
+                    message: "Current password is incorrect".to_string(),
+                });
+            }
+        }
 
-        let user_public = self
+        if self
             .user_store
-            .find_user_by_id(user_id)
+            .find_user_by_email(&new_email)
+            .await?
+            .is_some()
+        {
+            return Err(AppError::ValidationError {
+                message: "Email is already in use".to_string(),
+            });
+        }
+
+        let confirmation_token = Uuid::new_v4().to_string().replace('-', "");
+        let expires_at = OffsetDateTime::now_utc() + EMAIL_CHANGE_TOKEN_TTL;
+
+        self.user_store
+            .store_email_change_token(user_id, &new_email, &confirmation_token, expires_at)
+            .await?;
+
+        self.email_sender
+            .send_email_change_confirmation(&new_email, &user.username, &confirmation_token)
+            .await
+    }
+
+    async fn confirm_email_change(&self, token: String) -> AppResult<UserPublic> {
+        let (user, previous_email) = self
+            .user_store
+            .change_email_by_token(&token)
+            .await
+            .map_err(|error| match error {
+                AppError::TokenNotFound | AppError::TokenExpired => AppError::ValidationError {
+                    message: "Email change link is invalid or has expired".to_string(),
+                },
+                other => other,
+            })?;
+
+        self.session_store
+            .revoke_all_sessions(*user.id.as_uuid(), None)
+            .await?;
+
+        // The change is already committed, so a failed notice must not fail the request.
+        if let Err(e) = self
+            .email_sender
+            .send_email_changed_notice(&previous_email, &user.username, &user.email)
+            .await
+        {
+            tracing::warn!(
+                user_id = %user.id.as_uuid(),
+                "Failed to notify previous email address of change: {}",
+                e
+            );
+        }
+
+        Ok(user)
+    }
+
+    // Always succeeds so the endpoint cannot be used to find out which emails have accounts.
+    async fn request_password_reset(&self, email: String) -> AppResult<()> {
+        let Some((user_id, stored_email, username, password_hash, _email_verified_at)) =
+            self.user_store.find_user_by_email(&email).await?
+        else {
+            return Ok(());
+        };
+
+        if password_hash.is_none() {
+            return Ok(());
+        }
+
+        // The caller always sees success, so a cooldown or a failed send must not
+        // surface and reveal that the address belongs to an account.
+        if let Some(issued_at) = self
+            .user_store
+            .find_latest_password_reset_token_issued_at(user_id)
             .await?
-            .ok_or(AppError::InternalServerError)?;
+            && issued_at + PASSWORD_RESET_RESEND_COOLDOWN > OffsetDateTime::now_utc()
+        {
+            return Ok(());
+        }
+
+        let reset_token = Uuid::new_v4().to_string().replace('-', "");
+        let expires_at = OffsetDateTime::now_utc() + PASSWORD_RESET_TOKEN_TTL;
 
-        Ok(user_public)
+        self.user_store
+            .store_password_reset_token(user_id, &reset_token, expires_at)
+            .await?;
+
+        if let Err(e) = self
+            .email_sender
+            .send_password_reset_email(&stored_email, &username, &reset_token)
+            .await
+        {
+            tracing::warn!(user_id = %user_id, "Failed to send password reset email: {}", e);
+        }
+
+        Ok(())
+    }
+
+    async fn reset_password(&self, token: String, new_password: String) -> AppResult<UserPublic> {
+        self.password_validator.validate(&new_password)?;
+
+        let password_hash = self.password_hasher.hash(&new_password)?;
+
+        let user = self
+            .user_store
+            .reset_password_by_token(&token, &password_hash)
+            .await
+            .map_err(|error| match error {
+                AppError::TokenNotFound | AppError::TokenExpired => AppError::ValidationError {
+                    message: "Password reset link is invalid or has expired".to_string(),
+                },
+                other => other,
+            })?;
+
+        self.session_store
+            .revoke_all_sessions(*user.id.as_uuid(), None)
+            .await?;
+        self.login_protection
+            .clear_failed_logins(*user.id.as_uuid())
+            .await?;
+
+        Ok(user)
     }
 
     async fn logout(&self) -> AppResult<()> {
@@ -127,18 +315,116 @@ impl AuthUseCase for AuthService {
         provider: String,
         provider_user_id: String,
         email: Option<String>,
+        email_verified: bool,
         username: Option<String>,
     ) -> AppResult<UserPublic> {
+        if let Some(user) = self
+            .user_store
+            .find_user_by_identity(&provider, &provider_user_id)
+            .await?
+        {
+            return Ok(user);
+        }
+
+        // A provider cannot hand out an address on the placeholder domain and match it.
+        let email = email.filter(|email| !is_placeholder_email(email));
+
+        if let Some(email) = email.as_deref()
+            && let Some((existing_user_id, _, _, _, existing_verified_at)) =
+                self.user_store.find_user_by_email(email).await?
+        {
+            // Linking by email alone is only safe when both sides proved they own the address;
+            // otherwise whoever registered the address first could be taken over.
+            if !email_verified || existing_verified_at.is_none() {
+                return Err(AppError::AccountLinkRequired { provider });
+            }
+
+            self.link_social_identity(existing_user_id, provider, provider_user_id)
+                .await?;
+            return self.me(existing_user_id).await;
+        }
+
+        // Provider usernames are only unique per provider (or per instance on the fediverse),
+        // so a taken name falls back to a generated one that can be changed later.
+        let username = match username {
+            Some(username)
+                if self
+                    .user_store
+                    .find_user_by_username(&username)
+                    .await?
+                    .is_none() =>
+            {
+                Some(username)
+            }
+            _ => None,
+        };
+
         self.user_store
-            .create_or_get_social_user(
+            .create_social_user(
                 &provider,
                 &provider_user_id,
                 email.as_deref(),
+                email_verified,
                 username.as_deref(),
             )
             .await
     }
 
+    async fn link_social_identity(
+        &self,
+        user_id: Uuid,
+        provider: String,
+        provider_user_id: String,
+    ) -> AppResult<()> {
+        let owner_id = self
+            .user_store
+            .link_identity(user_id, &provider, &provider_user_id)
+            .await?;
+
+        if owner_id != user_id {
+            return Err(AppError::ValidationError {
+                message: format!(
+                    "This {} account is already linked to another user",
+                    provider
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn list_social_identities(&self, user_id: Uuid) -> AppResult<Vec<Identity>> {
+        self.user_store.list_identities(user_id).await
+    }
+
+    async fn unlink_social_identity(&self, user_id: Uuid, identity_id: Uuid) -> AppResult<()> {
+        let user = self.me(user_id).await?;
+        let identities = self.user_store.list_identities(user_id).await?;
+
+        if !identities.iter().any(|identity| identity.id == identity_id) {
+            return Err(AppError::ValidationError {
+                message: "Linked account not found".to_string(),
+            });
+        }
+
+        let has_password = matches!(
+            self.user_store.find_user_by_email(&user.email).await?,
+            Some((_, _, _, Some(_), _))
+        );
+        if !has_password && identities.len() <= 1 {
+            return Err(AppError::ValidationError {
+                message: "Set a password or link another account before removing your last sign-in method"
+                    .to_string(),
+            });
+        }
+
+        self.user_store
+            .delete_identity(user_id, identity_id)
+            .await?;
+
+        Ok(())
+    }
+
     async fn update_username(&self, user_id: Uuid, new_username: String) -> AppResult<UserPublic> {
         if new_username.trim().is_empty() {
             return Err(AppError::ValidationError {
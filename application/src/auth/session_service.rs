@@ -0,0 +1,94 @@
+use std::net::IpAddr;
+use time::{Duration, OffsetDateTime};
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::infrastructure_config::SessionTrackingConfig;
+use crate::ports::incoming::auth::SessionManagementUseCase;
+use crate::ports::outgoing::session_store::DynSessionStorePort;
+use domain::session::{UserSession, truncate_user_agent};
+
+pub struct SessionService {
+    session_store: DynSessionStorePort,
+    last_seen_interval: Duration,
+}
+
+impl SessionService {
+    pub fn new(session_store: DynSessionStorePort, config: &SessionTrackingConfig) -> Self {
+        Self {
+            session_store,
+            last_seen_interval: Duration::seconds(
+                i64::try_from(config.last_seen_interval_secs).unwrap_or(i64::MAX),
+            ),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl SessionManagementUseCase for SessionService {
+    async fn register_session(
+        &self,
+        user_id: Uuid,
+        user_agent: Option<String>,
+        ip_address: Option<IpAddr>,
+    ) -> AppResult<UserSession> {
+        let now = OffsetDateTime::now_utc();
+        let session = UserSession {
+            id: Uuid::new_v4(),
+            user_id,
+            user_agent: user_agent
+                .as_deref()
+                .map(truncate_user_agent)
+                .filter(|user_agent| !user_agent.is_empty()),
+            ip_address,
+            created_at: now,
+            last_seen_at: now,
+            revoked_at: None,
+        };
+
+        self.session_store.create_session(&session).await?;
+
+        Ok(session)
+    }
+
+    async fn touch_session(
+        &self,
+        user_id: Uuid,
+        session_id: Uuid,
+        ip_address: Option<IpAddr>,
+    ) -> AppResult<bool> {
+        let seen_before = OffsetDateTime::now_utc() - self.last_seen_interval;
+
+        self.session_store
+            .touch_session(user_id, session_id, ip_address, seen_before)
+            .await
+    }
+
+    async fn list_sessions(&self, user_id: Uuid) -> AppResult<Vec<UserSession>> {
+        self.session_store.list_sessions(user_id).await
+    }
+
+    async fn revoke_session(&self, user_id: Uuid, session_id: Uuid) -> AppResult<()> {
+        if !self
+            .session_store
+            .revoke_session(user_id, session_id)
+            .await?
+        {
+            return Err(AppError::ValidationError {
+                message: "Session not found".to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn revoke_all_sessions(&self, user_id: Uuid, except: Option<Uuid>) -> AppResult<u64> {
+        let revoked = self
+            .session_store
+            .revoke_all_sessions(user_id, except)
+            .await?;
+
+        tracing::info!(user_id = %user_id, revoked = revoked, "Revoked user sessions");
+        Ok(revoked)
+    }
+}
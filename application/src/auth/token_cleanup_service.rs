@@ -0,0 +1,41 @@
+use std::sync::Arc;
+use time::{Duration, OffsetDateTime};
+
+use crate::error::AppResult;
+use crate::infrastructure_config::SessionTrackingConfig;
+use crate::ports::incoming::auth::TokenCleanupUseCase;
+use crate::ports::outgoing::session_store::SessionStorePort;
+use crate::ports::outgoing::user_store::UserStorePort;
+
+pub struct TokenCleanupService {
+    user_store: Arc<dyn UserStorePort>,
+    session_store: Arc<dyn SessionStorePort>,
+    session_idle_expiry: Duration,
+}
+
+impl TokenCleanupService {
+    pub fn new(
+        user_store: Arc<dyn UserStorePort>,
+        session_store: Arc<dyn SessionStorePort>,
+        session_config: &SessionTrackingConfig,
+    ) -> Self {
+        Self {
+            user_store,
+            session_store,
+            session_idle_expiry: Duration::days(i64::from(session_config.idle_expiry_days)),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TokenCleanupUseCase for TokenCleanupService {
+    async fn purge_expired_tokens(&self) -> AppResult<u64> {
+        let tokens = self.user_store.delete_expired_tokens().await?;
+        let sessions = self
+            .session_store
+            .delete_stale_sessions(OffsetDateTime::now_utc() - self.session_idle_expiry)
+            .await?;
+
+        Ok(tokens + sessions)
+    }
+}
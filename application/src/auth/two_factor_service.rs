@@ -0,0 +1,255 @@
+use rand::seq::IndexedRandom;
+use time::OffsetDateTime;
+use tracing::info;
+use uuid::Uuid;
+
+use crate::error::{AppError, AppResult};
+use crate::infrastructure_config::TwoFactorConfig;
+use crate::ports::incoming::auth::TwoFactorUseCase;
+use crate::ports::outgoing::totp::DynTotpPort;
+use crate::ports::outgoing::two_factor_store::DynTwoFactorStorePort;
+use crate::ports::outgoing::user_store::DynUserStorePort;
+use domain::two_factor::{
+    TotpCredential, TotpEnrollment, TwoFactorStatus, is_totp_code, normalize_code,
+};
+
+// Lowercase letters and digits without the easily confused 0/o, 1/l/i.
+const RECOVERY_CODE_ALPHABET: &[u8] = b"abcdefghjkmnpqrstuvwxyz23456789";
+const RECOVERY_CODE_HALF_LENGTH: usize = 5;
+
+pub struct TwoFactorService {
+    two_factor_store: DynTwoFactorStorePort,
+    user_store: DynUserStorePort,
+    totp: DynTotpPort,
+    config: TwoFactorConfig,
+}
+
+impl TwoFactorService {
+    pub fn new(
+        two_factor_store: DynTwoFactorStorePort,
+        user_store: DynUserStorePort,
+        totp: DynTotpPort,
+        config: TwoFactorConfig,
+    ) -> Self {
+        Self {
+            two_factor_store,
+            user_store,
+            totp,
+            config,
+        }
+    }
+
+    // Codes are shown as `abcde-fghjk`; only the normalized form is handed to the store.
+    fn generate_recovery_codes(&self) -> (Vec<String>, Vec<String>) {
+        let mut rng = rand::rng();
+        let mut half = || -> String {
+            (0..RECOVERY_CODE_HALF_LENGTH)
+                .filter_map(|_| RECOVERY_CODE_ALPHABET.choose(&mut rng))
+                .map(|&byte| char::from(byte))
+                .collect()
+        };
+
+        (0..self.config.recovery_code_count)
+            .map(|_| {
+                let display = format!("{}-{}", half(), half());
+                let normalized = normalize_code(&display);
+                (display, normalized)
+            })
+            .unzip()
+    }
+
+    async fn enabled_credential(&self, user_id: Uuid) -> AppResult<TotpCredential> {
+        self.two_factor_store
+            .find_totp(user_id)
+            .await?
+            .filter(TotpCredential::is_enabled)
+            .ok_or_else(|| AppError::ValidationError {
+                message: "Two-factor authentication is not enabled".to_string(),
+            })
+    }
+
+    // Accepts a current TOTP code or an unused recovery code, consuming either.
+    async fn verify_code(
+        &self,
+        user_id: Uuid,
+        credential: &TotpCredential,
+        code: &str,
+    ) -> AppResult<bool> {
+        let code = normalize_code(code);
+
+        if is_totp_code(&code) {
+            let Some(step) =
+                self.totp
+                    .matching_step(&credential.secret, &code, OffsetDateTime::now_utc())?
+            else {
+                return Ok(false);
+            };
+            return self.two_factor_store.record_totp_step(user_id, step).await;
+        }
+
+        let used = self
+            .two_factor_store
+            .use_recovery_code(user_id, &code)
+            .await?;
+        if used {
+            info!("User {} used a two-factor recovery code", user_id);
+        }
+
+        Ok(used)
+    }
+
+    async fn require_valid_code(
+        &self,
+        user_id: Uuid,
+        credential: &TotpCredential,
+        code: &str,
+    ) -> AppResult<()> {
+        if self.verify_code(user_id, credential, code).await? {
+            Ok(())
+        } else {
+            Err(AppError::ValidationError {
+                message: "Invalid authentication code".to_string(),
+            })
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TwoFactorUseCase for TwoFactorService {
+    async fn status(&self, user_id: Uuid) -> AppResult<TwoFactorStatus> {
+        let enabled_at = self
+            .two_factor_store
+            .find_totp(user_id)
+            .await?
+            .and_then(|credential| credential.enabled_at);
+        let recovery_codes_remaining = if enabled_at.is_some() {
+            self.two_factor_store
+                .count_unused_recovery_codes(user_id)
+                .await?
+        } else {
+            0
+        };
+
+        Ok(TwoFactorStatus {
+            enabled_at,
+            recovery_codes_remaining,
+        })
+    }
+
+    async fn is_enabled(&self, user_id: Uuid) -> AppResult<bool> {
+        Ok(self
+            .two_factor_store
+            .find_totp(user_id)
+            .await?
+            .is_some_and(|credential| credential.is_enabled()))
+    }
+
+    async fn begin_totp_enrollment(&self, user_id: Uuid) -> AppResult<TotpEnrollment> {
+        if self.is_enabled(user_id).await? {
+            return Err(AppError::ValidationError {
+                message: "Two-factor authentication is already enabled".to_string(),
+            });
+        }
+
+        let user = self
+            .user_store
+            .find_user_by_id(user_id)
+            .await?
+            .ok_or_else(|| AppError::ValidationError {
+                message: "User not found".to_string(),
+            })?;
+
+        let secret = self.totp.generate_secret();
+        let otpauth_url = self.totp.provisioning_url(&secret, &user.email)?;
+
+        self.two_factor_store
+            .save_pending_totp(user_id, &secret)
+            .await?;
+
+        Ok(TotpEnrollment {
+            secret,
+            otpauth_url,
+        })
+    }
+
+    async fn confirm_totp_enrollment(&self, user_id: Uuid, code: String) -> AppResult<Vec<String>> {
+        let credential = self
+            .two_factor_store
+            .find_totp(user_id)
+            .await?
+            .filter(|credential| !credential.is_enabled())
+            .ok_or_else(|| AppError::ValidationError {
+                message: "No two-factor enrollment is in progress".to_string(),
+            })?;
+
+        let code = normalize_code(&code);
+        let step = if is_totp_code(&code) {
+            self.totp
+                .matching_step(&credential.secret, &code, OffsetDateTime::now_utc())?
+        } else {
+            None
+        };
+        let Some(step) = step else {
+            return Err(AppError::ValidationError {
+                message: "Invalid authentication code".to_string(),
+            });
+        };
+
+        let (display_codes, normalized_codes) = self.generate_recovery_codes();
+        self.two_factor_store
+            .enable_totp(user_id, step, &normalized_codes)
+            .await?;
+
+        info!("User {} enabled two-factor authentication", user_id);
+
+        Ok(display_codes)
+    }
+
+    async fn disable_totp(&self, user_id: Uuid, code: String) -> AppResult<()> {
+        let credential = self.enabled_credential(user_id).await?;
+        self.require_valid_code(user_id, &credential, &code).await?;
+
+        self.two_factor_store.delete_totp(user_id).await?;
+
+        info!("User {} disabled two-factor authentication", user_id);
+
+        Ok(())
+    }
+
+    async fn regenerate_recovery_codes(
+        &self,
+        user_id: Uuid,
+        code: String,
+    ) -> AppResult<Vec<String>> {
+        let credential = self.enabled_credential(user_id).await?;
+        self.require_valid_code(user_id, &credential, &code).await?;
+
+        let (display_codes, normalized_codes) = self.generate_recovery_codes();
+        self.two_factor_store
+            .replace_recovery_codes(user_id, &normalized_codes)
+            .await?;
+
+        Ok(display_codes)
+    }
+
+    async fn verify_login_code(&self, user_id: Uuid, code: Option<String>) -> AppResult<()> {
+        let Some(credential) = self
+            .two_factor_store
+            .find_totp(user_id)
+            .await?
+            .filter(TotpCredential::is_enabled)
+        else {
+            return Ok(());
+        };
+
+        let Some(code) = code.filter(|code| !code.trim().is_empty()) else {
+            return Err(AppError::TwoFactorRequired);
+        };
+
+        if self.verify_code(user_id, &credential, &code).await? {
+            Ok(())
+        } else {
+            Err(AppError::Unauthorized)
+        }
+    }
+}
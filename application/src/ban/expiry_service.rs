@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use serde_json::json;
+
+use crate::audit::recorder::{audit_timestamp, record_audit_entry};
+use crate::error::AppResult;
+use crate::ports::incoming::ban::BanExpiryUseCase;
+use crate::ports::outgoing::audit_log::AuditLogPort;
+use crate::ports::outgoing::ban_store::BanStorePort;
+use crate::ports::outgoing::email_sender::EmailSenderPort;
+use crate::ports::outgoing::user_store::UserStorePort;
+use domain::{
+    audit::{AuditAction, AuditEntry, AuditTargetType},
+    auth::UserId,
+    ban::Ban,
+};
+
+const EXPIRY_BATCH_SIZE: u32 = 100;
+
+pub struct BanExpiryService {
+    ban_store: Arc<dyn BanStorePort>,
+    user_store: Arc<dyn UserStorePort>,
+    audit_log: Arc<dyn AuditLogPort>,
+    email_sender: Option<Arc<dyn EmailSenderPort>>,
+}
+
+impl BanExpiryService {
+    pub fn new(
+        ban_store: Arc<dyn BanStorePort>,
+        user_store: Arc<dyn UserStorePort>,
+        audit_log: Arc<dyn AuditLogPort>,
+        email_sender: Option<Arc<dyn EmailSenderPort>>,
+    ) -> Self {
+        Self {
+            ban_store,
+            user_store,
+            audit_log,
+            email_sender,
+        }
+    }
+
+    async fn finalize_expired_ban(&self, ban: &Ban) {
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                None,
+                AuditAction::BanExpired,
+                AuditTargetType::Ban,
+                Some(ban.id.as_uuid().to_string()),
+            )
+            .with_parameters(json!({
+                "user_id": ban.user_id.as_uuid(),
+                "banned_by_user_id": ban.banned_by_user_id.as_ref().map(|id| *id.as_uuid()),
+                "reason": ban.reason,
+                "expires_at": ban.expires_at.and_then(audit_timestamp),
+                "shadow": ban.shadow,
+            })),
+        )
+        .await;
+
+        // Shadow-banned users were never told about their ban.
+        if let Some(email_sender) = &self.email_sender
+            && !ban.shadow
+        {
+            self.notify_user(email_sender.as_ref(), ban).await;
+        }
+
+        tracing::info!(
+            ban_id = %ban.id.as_uuid(),
+            user_id = %ban.user_id.as_uuid(),
+            "Temporary ban expired"
+        );
+    }
+
+    async fn notify_user(&self, email_sender: &dyn EmailSenderPort, ban: &Ban) {
+        let user = match self
+            .user_store
+            .find_user_by_id(*ban.user_id.as_uuid())
+            .await
+        {
+            Ok(Some(user)) => user,
+            Ok(None) => return,
+            Err(e) => {
+                tracing::warn!(
+                    user_id = %ban.user_id.as_uuid(),
+                    "Failed to load user for ban expiry notification: {}",
+                    e
+                );
+                return;
+            }
+        };
+
+        if let Err(e) = email_sender
+            .send_ban_expired_email(&user.email, &user.username)
+            .await
+        {
+            tracing::warn!(
+                user_id = %ban.user_id.as_uuid(),
+                "Failed to send ban expiry notification: {}",
+                e
+            );
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl BanExpiryUseCase for BanExpiryService {
+    async fn process_expired_bans(&self) -> AppResult<usize> {
+        let mut processed = 0;
+
+        loop {
+            let expired_bans = self.ban_store.claim_expired_bans(EXPIRY_BATCH_SIZE).await?;
+            let batch_len = expired_bans.len();
+
+            for ban in &expired_bans {
+                self.finalize_expired_ban(ban).await;
+            }
+
+            processed += batch_len;
+            if batch_len < EXPIRY_BATCH_SIZE as usize {
+                break;
+            }
+        }
+
+        Ok(processed)
+    }
+
+    async fn process_expired_bans_of_user(&self, user_id: &UserId) -> AppResult<usize> {
+        let expired_bans = self.ban_store.claim_expired_bans_of_user(user_id).await?;
+
+        for ban in &expired_bans {
+            self.finalize_expired_ban(ban).await;
+        }
+
+        Ok(expired_bans.len())
+    }
+}
@@ -0,0 +1,210 @@
+use serde_json::json;
+use std::{net::IpAddr, sync::Arc, time::Duration};
+use time::OffsetDateTime;
+
+use crate::audit::recorder::{audit_timestamp, record_audit_entry};
+use crate::auth::permissions::require_permission;
+use crate::error::{AppError, AppResult};
+use crate::ports::incoming::ban::IpBanUseCase;
+use crate::ports::outgoing::audit_log::AuditLogPort;
+use crate::ports::outgoing::ip_ban_cache::IpBanCachePort;
+use crate::ports::outgoing::ip_ban_store::IpBanStorePort;
+use crate::ports::outgoing::user_store::UserStorePort;
+use domain::{
+    audit::{AuditAction, AuditEntry, AuditTargetType},
+    auth::{Permission, UserId},
+    ban::{BanError, IpBan, IpBanId},
+};
+use ipnet::IpNet;
+
+const BAN_STATUS_CACHE_TTL: Duration = Duration::from_mins(1);
+
+pub struct IpBanService {
+    ip_ban_store: Arc<dyn IpBanStorePort>,
+    ip_ban_cache: Arc<dyn IpBanCachePort>,
+    user_store: Arc<dyn UserStorePort>,
+    audit_log: Arc<dyn AuditLogPort>,
+}
+
+impl IpBanService {
+    pub fn new(
+        ip_ban_store: Arc<dyn IpBanStorePort>,
+        ip_ban_cache: Arc<dyn IpBanCachePort>,
+        user_store: Arc<dyn UserStorePort>,
+        audit_log: Arc<dyn AuditLogPort>,
+    ) -> Self {
+        Self {
+            ip_ban_store,
+            ip_ban_cache,
+            user_store,
+            audit_log,
+        }
+    }
+
+    async fn invalidate_cached_statuses(&self) {
+        if let Err(e) = self.ip_ban_cache.clear_ban_statuses().await {
+            tracing::warn!("Failed to clear cached IP ban statuses: {}", e);
+        }
+    }
+
+    // Never cache a ban past its expiry, otherwise a lapsed ban would keep blocking.
+    fn status_ttl(ip_ban: Option<&IpBan>) -> Duration {
+        ip_ban
+            .and_then(|ban| ban.expires_at)
+            .map_or(BAN_STATUS_CACHE_TTL, |expires_at| {
+                let remaining = expires_at - OffsetDateTime::now_utc();
+                Duration::try_from(remaining)
+                    .unwrap_or(Duration::ZERO)
+                    .min(BAN_STATUS_CACHE_TTL)
+            })
+    }
+}
+
+#[async_trait::async_trait]
+impl IpBanUseCase for IpBanService {
+    async fn ban_network(
+        &self,
+        network: IpNet,
+        banned_by_user_id: UserId,
+        reason: String,
+        expires_at: Option<OffsetDateTime>,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<IpBan> {
+        require_permission(
+            self.user_store.as_ref(),
+            &banned_by_user_id,
+            Permission::BanCreate,
+        )
+        .await?;
+
+        if let Some(expires) = expires_at
+            && expires <= OffsetDateTime::now_utc()
+        {
+            return Err(AppError::ValidationError {
+                message: BanError::InvalidDuration.to_string(),
+            });
+        }
+
+        let ip_ban = IpBan::new(network, Some(banned_by_user_id.clone()), reason, expires_at);
+
+        if self
+            .ip_ban_store
+            .get_active_ip_ban_by_network(&ip_ban.network)
+            .await?
+            .is_some()
+        {
+            return Err(AppError::ValidationError {
+                message: BanError::NetworkAlreadyBanned.to_string(),
+            });
+        }
+
+        self.ip_ban_store.create_ip_ban(&ip_ban).await?;
+        self.invalidate_cached_statuses().await;
+
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                Some(banned_by_user_id.clone()),
+                AuditAction::IpBanCreated,
+                AuditTargetType::IpBan,
+                Some(ip_ban.id.as_uuid().to_string()),
+            )
+            .with_parameters(json!({
+                "network": ip_ban.network.to_string(),
+                "reason": ip_ban.reason,
+                "expires_at": ip_ban.expires_at.and_then(audit_timestamp),
+            }))
+            .with_ip_address(actor_ip),
+        )
+        .await;
+
+        tracing::info!(
+            network = %ip_ban.network,
+            banned_by = %banned_by_user_id.as_uuid(),
+            "Network banned"
+        );
+
+        Ok(ip_ban)
+    }
+
+    async fn unban_network(
+        &self,
+        ban_id: IpBanId,
+        unbanned_by: UserId,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<()> {
+        require_permission(
+            self.user_store.as_ref(),
+            &unbanned_by,
+            Permission::BanCreate,
+        )
+        .await?;
+
+        let ip_ban = self
+            .ip_ban_store
+            .get_ip_ban_by_id(&ban_id)
+            .await?
+            .filter(IpBan::is_active)
+            .ok_or_else(|| AppError::ValidationError {
+                message: BanError::BanNotFound.to_string(),
+            })?;
+
+        self.ip_ban_store.remove_ip_ban(&ban_id).await?;
+        self.invalidate_cached_statuses().await;
+
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                Some(unbanned_by.clone()),
+                AuditAction::IpBanRevoked,
+                AuditTargetType::IpBan,
+                Some(ban_id.as_uuid().to_string()),
+            )
+            .with_parameters(json!({
+                "network": ip_ban.network.to_string(),
+                "reason": ip_ban.reason,
+            }))
+            .with_ip_address(actor_ip),
+        )
+        .await;
+
+        tracing::info!(
+            network = %ip_ban.network,
+            unbanned_by = %unbanned_by.as_uuid(),
+            "Network unbanned"
+        );
+
+        Ok(())
+    }
+
+    async fn get_active_ip_bans(&self, requesting_user_id: UserId) -> AppResult<Vec<IpBan>> {
+        require_permission(
+            self.user_store.as_ref(),
+            &requesting_user_id,
+            Permission::BanCreate,
+        )
+        .await?;
+
+        self.ip_ban_store.get_all_active_ip_bans().await
+    }
+
+    async fn is_ip_banned(&self, ip: IpAddr) -> AppResult<bool> {
+        match self.ip_ban_cache.get_ban_status(ip).await {
+            Ok(Some(banned)) => return Ok(banned),
+            Ok(None) => {}
+            Err(e) => tracing::warn!("Failed to read cached IP ban status: {}", e),
+        }
+
+        let ip_ban = self.ip_ban_store.find_active_ip_ban(ip).await?;
+        let banned = ip_ban.is_some();
+
+        let ttl = Self::status_ttl(ip_ban.as_ref());
+        if !ttl.is_zero()
+            && let Err(e) = self.ip_ban_cache.store_ban_status(ip, banned, ttl).await
+        {
+            tracing::warn!("Failed to cache IP ban status: {}", e);
+        }
+
+        Ok(banned)
+    }
+}
@@ -1 +1,4 @@
+pub mod expiry_service;
+pub mod ip_service;
+pub mod quarantine_service;
 pub mod service;
@@ -0,0 +1,175 @@
+use serde_json::json;
+use std::{net::IpAddr, sync::Arc};
+
+use crate::audit::recorder::record_audit_entry;
+use crate::auth::permissions::require_permission;
+use crate::error::{AppError, AppResult};
+use crate::ports::incoming::ban::QuarantineUseCase;
+use crate::ports::incoming::tiles::PixelPromotionUseCase;
+use crate::ports::outgoing::audit_log::AuditLogPort;
+use crate::ports::outgoing::quarantine_store::QuarantineStorePort;
+use crate::ports::outgoing::user_store::UserStorePort;
+use crate::tiles::commands::PixelPromotionResult;
+use domain::{
+    action::PaintAction,
+    audit::{AuditAction, AuditEntry, AuditTargetType},
+    auth::{Permission, UserId},
+    ban::{BanError, QuarantinedPixel},
+};
+
+pub struct QuarantineService {
+    quarantine_store: Arc<dyn QuarantineStorePort>,
+    user_store: Arc<dyn UserStorePort>,
+    promotion_use_case: Arc<dyn PixelPromotionUseCase>,
+    audit_log: Arc<dyn AuditLogPort>,
+}
+
+impl QuarantineService {
+    pub fn new(
+        quarantine_store: Arc<dyn QuarantineStorePort>,
+        user_store: Arc<dyn UserStorePort>,
+        promotion_use_case: Arc<dyn PixelPromotionUseCase>,
+        audit_log: Arc<dyn AuditLogPort>,
+    ) -> Self {
+        Self {
+            quarantine_store,
+            user_store,
+            promotion_use_case,
+            audit_log,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl QuarantineUseCase for QuarantineService {
+    async fn list_quarantined_pixels(
+        &self,
+        user_id: UserId,
+        requesting_user_id: UserId,
+    ) -> AppResult<Vec<QuarantinedPixel>> {
+        require_permission(
+            self.user_store.as_ref(),
+            &requesting_user_id,
+            Permission::BanCreate,
+        )
+        .await?;
+
+        self.quarantine_store
+            .list_quarantined_pixels(&user_id)
+            .await
+    }
+
+    async fn discard_quarantined_pixels(
+        &self,
+        user_id: UserId,
+        moderator_id: UserId,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<u64> {
+        require_permission(
+            self.user_store.as_ref(),
+            &moderator_id,
+            Permission::BanCreate,
+        )
+        .await?;
+
+        let pixels_discarded = self
+            .quarantine_store
+            .delete_quarantined_pixels(&user_id)
+            .await?;
+
+        if pixels_discarded == 0 {
+            return Err(AppError::ValidationError {
+                message: BanError::NoQuarantinedPixels.to_string(),
+            });
+        }
+
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                Some(moderator_id.clone()),
+                AuditAction::QuarantineDiscarded,
+                AuditTargetType::User,
+                Some(user_id.as_uuid().to_string()),
+            )
+            .with_parameters(json!({
+                "pixels_discarded": pixels_discarded,
+            }))
+            .with_ip_address(actor_ip),
+        )
+        .await;
+
+        tracing::info!(
+            user_id = %user_id.as_uuid(),
+            moderator_id = %moderator_id.as_uuid(),
+            pixels_discarded = pixels_discarded,
+            "Quarantined pixels discarded"
+        );
+
+        Ok(pixels_discarded)
+    }
+
+    async fn promote_quarantined_pixels(
+        &self,
+        user_id: UserId,
+        moderator_id: UserId,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<PixelPromotionResult> {
+        require_permission(
+            self.user_store.as_ref(),
+            &moderator_id,
+            Permission::BanCreate,
+        )
+        .await?;
+
+        let quarantined = self
+            .quarantine_store
+            .list_quarantined_pixels(&user_id)
+            .await?;
+
+        if quarantined.is_empty() {
+            return Err(AppError::ValidationError {
+                message: BanError::NoQuarantinedPixels.to_string(),
+            });
+        }
+
+        let pixel_ids: Vec<i64> = quarantined.iter().map(|pixel| pixel.id).collect();
+        let paint_actions: Vec<PaintAction> =
+            quarantined.into_iter().map(|pixel| pixel.action).collect();
+
+        // Only the reviewed pixels are removed, so paints quarantined meanwhile stay queued.
+        let result = self
+            .promotion_use_case
+            .promote_paint_actions(&paint_actions)
+            .await?;
+        self.quarantine_store
+            .delete_quarantined_pixels_by_id(&pixel_ids)
+            .await?;
+
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                Some(moderator_id.clone()),
+                AuditAction::QuarantinePromoted,
+                AuditTargetType::User,
+                Some(user_id.as_uuid().to_string()),
+            )
+            .with_parameters(json!({
+                "pixels_quarantined": pixel_ids.len(),
+                "pixels_applied": result.pixels_applied,
+                "tiles_affected": result.tiles_affected,
+            }))
+            .with_ip_address(actor_ip),
+        )
+        .await;
+
+        tracing::info!(
+            user_id = %user_id.as_uuid(),
+            moderator_id = %moderator_id.as_uuid(),
+            pixels_applied = result.pixels_applied,
+            tiles_affected = result.tiles_affected,
+            "Quarantined pixels promoted"
+        );
+
+        Ok(result)
+    }
+}
@@ -1,44 +1,49 @@
-use std::sync::Arc;
+use serde_json::json;
+use std::{net::IpAddr, sync::Arc};
 use time::OffsetDateTime;
 
+use crate::audit::recorder::{audit_timestamp, record_audit_entry};
+use crate::auth::permissions::require_permission;
 use crate::error::{AppError, AppResult};
-use crate::ports::incoming::ban::BanUseCase;
+use crate::ports::incoming::ban::{BanExpiryUseCase, BanUseCase};
+use crate::ports::outgoing::audit_log::AuditLogPort;
 use crate::ports::outgoing::ban_store::BanStorePort;
+use crate::ports::outgoing::session_store::SessionStorePort;
 use crate::ports::outgoing::user_store::UserStorePort;
 use domain::{
-    auth::{RoleType, UserId},
-    ban::{Ban, BanError},
+    audit::{AuditAction, AuditEntry, AuditTargetType},
+    auth::{Permission, RoleType, UserId},
+    ban::{Ban, BanError, BanEscalationPolicy},
 };
 
 pub struct BanService {
     ban_store: Arc<dyn BanStorePort>,
     user_store: Arc<dyn UserStorePort>,
+    session_store: Arc<dyn SessionStorePort>,
+    audit_log: Arc<dyn AuditLogPort>,
+    ban_expiry: Arc<dyn BanExpiryUseCase>,
+    escalation_policy: BanEscalationPolicy,
 }
 
 impl BanService {
-    pub fn new(ban_store: Arc<dyn BanStorePort>, user_store: Arc<dyn UserStorePort>) -> Self {
+    pub fn new(
+        ban_store: Arc<dyn BanStorePort>,
+        user_store: Arc<dyn UserStorePort>,
+        session_store: Arc<dyn SessionStorePort>,
+        audit_log: Arc<dyn AuditLogPort>,
+        ban_expiry: Arc<dyn BanExpiryUseCase>,
+        escalation_policy: BanEscalationPolicy,
+    ) -> Self {
         Self {
             ban_store,
             user_store,
+            session_store,
+            audit_log,
+            ban_expiry,
+            escalation_policy,
         }
     }
 
-    async fn validate_ban_permissions(&self, banned_by_user_id: &UserId) -> AppResult<()> {
-        let user = self
-            .user_store
-            .find_user_by_id(*banned_by_user_id.as_uuid())
-            .await?
-            .ok_or_else(|| AppError::ValidationError {
-                message: "Admin user not found".to_string(),
-            })?;
-
-        if !user.has_role_type(RoleType::Admin) {
-            return Err(AppError::Forbidden);
-        }
-
-        Ok(())
-    }
-
     async fn validate_target_user(&self, user_id: &UserId) -> AppResult<()> {
         let user = self
             .user_store
@@ -64,16 +69,26 @@ impl BanUseCase for BanService {
         banned_by_user_id: UserId,
         reason: String,
         expires_at: Option<OffsetDateTime>,
+        shadow: bool,
+        actor_ip: Option<IpAddr>,
     ) -> AppResult<()> {
-        self.validate_ban_permissions(&banned_by_user_id).await?;
+        require_permission(
+            self.user_store.as_ref(),
+            &banned_by_user_id,
+            Permission::BanCreate,
+        )
+        .await?;
         self.validate_target_user(&user_id).await?;
 
-        if let Some(existing_ban) = self.ban_store.get_active_ban_by_user_id(&user_id).await? {
-            if existing_ban.is_active() {
-                return Err(AppError::ValidationError {
-                    message: BanError::UserAlreadyBanned.to_string(),
-                });
-            }
+        if self
+            .ban_store
+            .get_active_ban_by_user_id(&user_id)
+            .await?
+            .is_some()
+        {
+            return Err(AppError::ValidationError {
+                message: BanError::UserAlreadyBanned.to_string(),
+            });
         }
 
         if let Some(expires) = expires_at {
@@ -84,20 +99,77 @@ impl BanUseCase for BanService {
             }
         }
 
-        let ban = Ban::new(
+        let prior_bans = self
+            .ban_store
+            .get_ban_history_by_user_id(&user_id)
+            .await?
+            .len();
+        let prior_bans = u32::try_from(prior_bans).unwrap_or(u32::MAX);
+
+        let mut ban = Ban::new(
             user_id.clone(),
             Some(banned_by_user_id.clone()),
             reason,
             expires_at,
+            shadow,
         );
+        ban.expires_at = self
+            .escalation_policy
+            .escalate(ban.banned_at, ban.expires_at, prior_bans);
+
+        // A lapsed ban the expiry job has not reached yet still holds the user's open ban slot.
+        self.ban_expiry
+            .process_expired_bans_of_user(&user_id)
+            .await?;
 
         self.ban_store.create_ban(&ban).await?;
-        let pixels_removed = self.ban_store.remove_user_pixels(&user_id).await?;
+
+        // Wiping a shadow-banned user's pixels would give the ban away.
+        let pixels_removed = if shadow {
+            0
+        } else {
+            self.ban_store.remove_user_pixels(&user_id).await?
+        };
+
+        // Shadow-banned users keep their sessions for the same reason.
+        let sessions_revoked = if ban.blocks_login() {
+            self.session_store
+                .revoke_all_sessions(*user_id.as_uuid(), None)
+                .await?
+        } else {
+            0
+        };
+
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                Some(banned_by_user_id.clone()),
+                AuditAction::BanCreated,
+                AuditTargetType::Ban,
+                Some(ban.id.as_uuid().to_string()),
+            )
+            .with_parameters(json!({
+                "user_id": user_id.as_uuid(),
+                "reason": ban.reason,
+                "requested_expires_at": expires_at.and_then(audit_timestamp),
+                "expires_at": ban.expires_at.and_then(audit_timestamp),
+                "prior_bans": prior_bans,
+                "shadow": shadow,
+                "pixels_removed": pixels_removed,
+                "sessions_revoked": sessions_revoked,
+            }))
+            .with_ip_address(actor_ip),
+        )
+        .await;
 
         tracing::info!(
             user_id = %user_id.as_uuid(),
             banned_by = %banned_by_user_id.as_uuid(),
+            prior_bans = prior_bans,
+            escalated = ban.expires_at != expires_at,
+            shadow = shadow,
             pixels_removed = pixels_removed,
+            sessions_revoked = sessions_revoked,
             "User banned and pixels removed"
         );
 
@@ -105,28 +177,55 @@ impl BanUseCase for BanService {
     }
 
     async fn check_user_ban_status(&self, user_id: &UserId) -> AppResult<Option<Ban>> {
-        let ban = self.ban_store.get_active_ban_by_user_id(user_id).await?;
-
-        match ban {
-            Some(ban) if ban.is_active() => Ok(Some(ban)),
-            _ => Ok(None),
-        }
+        self.ban_store.get_active_ban_by_user_id(user_id).await
     }
 
-    async fn unban_user(&self, user_id: UserId, unbanned_by: UserId) -> AppResult<()> {
-        self.validate_ban_permissions(&unbanned_by).await?;
+    async fn unban_user(
+        &self,
+        user_id: UserId,
+        unbanned_by: UserId,
+        reason: Option<String>,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<()> {
+        require_permission(
+            self.user_store.as_ref(),
+            &unbanned_by,
+            Permission::BanCreate,
+        )
+        .await?;
 
-        let ban = self.ban_store.get_active_ban_by_user_id(&user_id).await?;
-        if ban.is_none() || !ban.as_ref().is_some_and(Ban::is_active) {
-            return Err(AppError::ValidationError {
+        let ban = self
+            .ban_store
+            .get_active_ban_by_user_id(&user_id)
+            .await?
+            .ok_or_else(|| AppError::ValidationError {
                 message: BanError::BanNotFound.to_string(),
-            });
-        }
+            })?;
 
-        self.ban_store.remove_ban_by_user_id(&user_id).await?;
+        self.ban_store
+            .revoke_ban(&ban.id, &unbanned_by, reason.as_deref())
+            .await?;
+
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                Some(unbanned_by.clone()),
+                AuditAction::BanRevoked,
+                AuditTargetType::Ban,
+                Some(ban.id.as_uuid().to_string()),
+            )
+            .with_parameters(json!({
+                "user_id": user_id.as_uuid(),
+                "ban_reason": ban.reason,
+                "revoke_reason": reason,
+            }))
+            .with_ip_address(actor_ip),
+        )
+        .await;
 
         tracing::info!(
             user_id = %user_id.as_uuid(),
+            ban_id = %ban.id.as_uuid(),
             unbanned_by = %unbanned_by.as_uuid(),
             "User unbanned"
         );
@@ -135,10 +234,28 @@ impl BanUseCase for BanService {
     }
 
     async fn get_active_bans(&self, requesting_user_id: UserId) -> AppResult<Vec<Ban>> {
-        self.validate_ban_permissions(&requesting_user_id).await?;
+        require_permission(
+            self.user_store.as_ref(),
+            &requesting_user_id,
+            Permission::BanCreate,
+        )
+        .await?;
+
+        self.ban_store.get_all_active_bans().await
+    }
 
-        let all_bans = self.ban_store.get_all_active_bans().await?;
+    async fn get_ban_history(
+        &self,
+        user_id: UserId,
+        requesting_user_id: UserId,
+    ) -> AppResult<Vec<Ban>> {
+        require_permission(
+            self.user_store.as_ref(),
+            &requesting_user_id,
+            Permission::BanCreate,
+        )
+        .await?;
 
-        Ok(all_bans.into_iter().filter(Ban::is_active).collect())
+        self.ban_store.get_ban_history_by_user_id(&user_id).await
     }
 }
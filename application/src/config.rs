@@ -10,3 +10,10 @@ pub struct TileSettings {
     pub transparency_color_id: u8,
     pub color_palette_config: Arc<ColorPaletteConfig>,
 }
+
+#[derive(Debug, Clone)]
+pub struct ReportSettings {
+    pub max_comment_length: usize,
+    pub max_pending_reports_per_user: u32,
+    pub max_region_area: u64,
+}
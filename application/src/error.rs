@@ -71,8 +71,31 @@ pub enum AppError {
     #[error("Verification token has expired")]
     TokenExpired,
 
+    #[error(
+        "An account with this email already exists, log in to it and link {provider} from your account settings"
+    )]
+    AccountLinkRequired { provider: String },
+
+    #[error("Two-factor authentication code required")]
+    TwoFactorRequired,
+
+    #[error("Two-factor authentication must be enabled for accounts with privileged roles")]
+    TwoFactorEnrollmentRequired,
+
+    #[error("Too many requests, try again in {retry_after_seconds} seconds")]
+    TooManyRequests { retry_after_seconds: u64 },
+
     #[error("Insufficient credits: {message}")]
     InsufficientCredits { message: String },
+
+    #[error("Protected region: {message}")]
+    ProtectedRegion { message: String },
+
+    #[error("Pixel protected: {message}")]
+    PixelProtected { message: String },
+
+    #[error("Rule {rule} violated: {message}")]
+    RuleViolation { rule: String, message: String },
 }
 
 pub type AppResult<T> = Result<T, AppError>;
@@ -1,9 +1,15 @@
+use ipnet::IpNet;
 use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use crate::error::{AppError, AppResult};
-use domain::color::RgbColor;
+use domain::{
+    color::RgbColor,
+    coords::GlobalRegion,
+    fediverse::{InstanceDomain, InstancePolicy},
+    rules::{PaintRule, PaintRuleSet},
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -19,6 +25,12 @@ pub struct Config {
     pub environment: EnvironmentConfig,
     pub color_palette: ColorPaletteConfig,
     pub auth: AuthConfig,
+    pub bans: BanConfig,
+    pub reports: ReportConfig,
+    #[serde(default)]
+    pub rules: RulesConfig,
+    #[serde(default)]
+    pub pixel_protection: PixelProtectionConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,8 +43,273 @@ pub struct AuthConfig {
     pub google_client_id: Option<String>,
     pub google_client_secret: Option<String>,
     pub google_redirect_url: Option<String>,
+    #[serde(default)]
+    pub oidc_providers: BTreeMap<String, OidcProviderConfig>,
+    #[serde(default)]
+    pub mastodon: MastodonConfig,
+    #[serde(default)]
+    pub two_factor: TwoFactorConfig,
+    #[serde(default)]
+    pub sessions: SessionTrackingConfig,
+    #[serde(default)]
+    pub api_tokens: ApiTokenConfig,
+    #[serde(default)]
+    pub account_deletion: AccountDeletionConfig,
+    #[serde(default)]
+    pub login_protection: LoginProtectionConfig,
     pub email: EmailConfig,
     pub argon2: Argon2Config,
+    pub token_cleanup_interval_secs: u64,
+}
+
+// Provider names become path segments (`/auth/{name}/start`), so they must not shadow
+// the fixed auth routes.
+const RESERVED_PROVIDER_NAMES: [&str; 15] = [
+    "register",
+    "login",
+    "logout",
+    "me",
+    "verify",
+    "username",
+    "email",
+    "password",
+    "identities",
+    "providers",
+    "mastodon",
+    "2fa",
+    "sessions",
+    "tokens",
+    "account",
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OidcProviderConfig {
+    pub display_name: Option<String>,
+    pub issuer_url: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub redirect_url: String,
+    #[serde(default = "OidcProviderConfig::default_scopes")]
+    pub scopes: Vec<String>,
+    #[serde(default)]
+    pub claims: OidcClaimMapping,
+}
+
+impl OidcProviderConfig {
+    fn default_scopes() -> Vec<String> {
+        vec![
+            "openid".to_string(),
+            "email".to_string(),
+            "profile".to_string(),
+        ]
+    }
+}
+
+// Names of the userinfo claims read for each piece of account data.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OidcClaimMapping {
+    pub subject: String,
+    pub email: String,
+    pub email_verified: String,
+    pub username: String,
+}
+
+impl Default for OidcClaimMapping {
+    fn default() -> Self {
+        Self {
+            subject: "sub".to_string(),
+            email: "email".to_string(),
+            email_verified: "email_verified".to_string(),
+            username: "preferred_username".to_string(),
+        }
+    }
+}
+
+impl AuthConfig {
+    // All configured OIDC providers. The `google_*` settings are kept as a shorthand for
+    // a `google` provider and are ignored once `oidc_providers.google` is set.
+    pub fn resolved_oidc_providers(&self) -> BTreeMap<String, OidcProviderConfig> {
+        let mut providers = self.oidc_providers.clone();
+
+        if let (Some(client_id), Some(client_secret), Some(redirect_url)) = (
+            &self.google_client_id,
+            &self.google_client_secret,
+            &self.google_redirect_url,
+        ) {
+            providers
+                .entry("google".to_string())
+                .or_insert_with(|| OidcProviderConfig {
+                    display_name: Some("Google".to_string()),
+                    issuer_url: "https://accounts.google.com".to_string(),
+                    client_id: client_id.clone(),
+                    client_secret: client_secret.clone(),
+                    redirect_url: redirect_url.clone(),
+                    scopes: OidcProviderConfig::default_scopes(),
+                    claims: OidcClaimMapping {
+                        username: "name".to_string(),
+                        ..OidcClaimMapping::default()
+                    },
+                });
+        }
+
+        providers
+    }
+}
+
+// Login through any Mastodon-compatible server. OAuth apps are registered with each
+// instance on first use, so there are no client credentials to configure here.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MastodonConfig {
+    pub enabled: bool,
+    pub redirect_url: String,
+    pub client_name: String,
+    pub scopes: String,
+    pub allowed_instances: Vec<String>,
+    pub denied_instances: Vec<String>,
+}
+
+impl MastodonConfig {
+    pub fn instance_policy(&self) -> AppResult<InstancePolicy> {
+        let parse = |entries: &[String]| {
+            entries
+                .iter()
+                .map(|entry| {
+                    entry
+                        .parse::<InstanceDomain>()
+                        .map_err(|e| AppError::ConfigError {
+                            message: format!("Mastodon instance list: {}", e),
+                        })
+                })
+                .collect::<AppResult<HashSet<_>>>()
+        };
+
+        Ok(InstancePolicy::new(
+            parse(&self.allowed_instances)?,
+            parse(&self.denied_instances)?,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TwoFactorConfig {
+    // Name shown next to the account in authenticator apps.
+    pub issuer: String,
+    pub recovery_code_count: usize,
+    // Users holding a role with any permission must enroll before using admin routes.
+    pub require_for_privileged_roles: bool,
+}
+
+impl Default for TwoFactorConfig {
+    fn default() -> Self {
+        Self {
+            issuer: "FediPlace".to_string(),
+            recovery_code_count: 10,
+            require_for_privileged_roles: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SessionTrackingConfig {
+    // Minimum time between last-seen updates of a session, so every request does not write.
+    pub last_seen_interval_secs: u64,
+    // Sessions unused for this long are ended and their records removed by the cleanup job.
+    pub idle_expiry_days: u32,
+}
+
+impl Default for SessionTrackingConfig {
+    fn default() -> Self {
+        Self {
+            last_seen_interval_secs: 60,
+            idle_expiry_days: 30,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApiTokenConfig {
+    pub enabled: bool,
+    pub max_tokens_per_user: u32,
+    // Tokens created without an expiry are only allowed when this is unset.
+    pub max_lifetime_days: Option<u32>,
+}
+
+impl Default for ApiTokenConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_tokens_per_user: 10,
+            max_lifetime_days: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccountDeletionConfig {
+    // Days between a deletion request and the account actually being removed, during
+    // which the user can still cancel it.
+    pub grace_period_days: u32,
+    pub check_interval_secs: u64,
+}
+
+impl Default for AccountDeletionConfig {
+    fn default() -> Self {
+        Self {
+            grace_period_days: 14,
+            check_interval_secs: 3600,
+        }
+    }
+}
+
+// Per-account throttling of password logins, on top of the per-IP auth rate limit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoginProtectionConfig {
+    pub enabled: bool,
+    // Failures allowed before each further one adds a doubling delay.
+    pub free_attempts: u32,
+    pub backoff_base_secs: u64,
+    pub backoff_max_secs: u64,
+    // Failures after which password login is locked for `lockout_duration_secs`.
+    pub lockout_threshold: u32,
+    pub lockout_duration_secs: u64,
+    // The failure count starts over after this long without a failure.
+    pub failure_reset_secs: u64,
+    pub notify_on_lockout: bool,
+}
+
+impl Default for LoginProtectionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            free_attempts: 5,
+            backoff_base_secs: 1,
+            backoff_max_secs: 300,
+            lockout_threshold: 20,
+            lockout_duration_secs: 1800,
+            failure_reset_secs: 86400,
+            notify_on_lockout: true,
+        }
+    }
+}
+
+impl Default for MastodonConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            redirect_url: "http://localhost:8000/auth/mastodon/callback".to_string(),
+            client_name: "FediPlace".to_string(),
+            scopes: "read:accounts".to_string(),
+            allowed_instances: Vec::new(),
+            denied_instances: Vec::new(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -73,6 +350,8 @@ pub struct ServerConfig {
     pub host: String,
     pub port: u16,
     pub cors_origin: Option<String>,
+    #[serde(default)]
+    pub trusted_proxies: Vec<IpNet>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -190,7 +469,148 @@ pub struct RateLimitConfig {
     pub global_requests_per_minute: u32,
     pub websocket_messages_per_minute: u32,
     pub auth_requests_per_minute: u32,
+    pub account_requests_per_minute: u32,
+    pub report_requests_per_minute: u32,
+    pub api_token_requests_per_minute: u32,
     pub burst_size_multiplier: u32,
+    // Signed-in requests also count against their IP at this multiple of the base rate.
+    pub shared_ip_multiplier: u32,
+    pub backends: RateLimitBackendsConfig,
+    #[serde(default)]
+    pub roles: HashMap<String, RoleRateLimitConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleRateLimitConfig {
+    pub paint_requests_per_minute: Option<u32>,
+    pub account_requests_per_minute: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitBackendsConfig {
+    pub paint: RateLimitBackend,
+    pub tile: RateLimitBackend,
+    pub global: RateLimitBackend,
+    pub websocket: RateLimitBackend,
+    pub auth: RateLimitBackend,
+    pub account: RateLimitBackend,
+    pub report: RateLimitBackend,
+    pub api_token: RateLimitBackend,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum RateLimitBackend {
+    #[serde(rename = "memory")]
+    Memory,
+    #[serde(rename = "redis")]
+    Redis,
+}
+
+impl RateLimitBackend {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RateLimitBackend::Memory => "memory",
+            RateLimitBackend::Redis => "redis",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BanConfig {
+    pub escalation_enabled: bool,
+    pub escalation_multiplier: u32,
+    pub permanent_after_prior_bans: Option<u32>,
+    pub expiry_check_interval_secs: u64,
+    pub notify_on_expiry: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportConfig {
+    pub max_comment_length: usize,
+    pub max_pending_reports_per_user: u32,
+    pub max_region_area: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RulesConfig {
+    pub min_account_age_secs: Option<u64>,
+    #[serde(default)]
+    pub require_verified_email: bool,
+    pub max_pixels_per_batch: Option<usize>,
+    pub pixel_cooldown_secs: Option<u64>,
+    #[serde(default)]
+    pub roles: HashMap<String, RoleRulesConfig>,
+    #[serde(default)]
+    pub color_zones: Vec<ColorZoneConfig>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleRulesConfig {
+    pub max_pixels_per_batch: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ColorZoneConfig {
+    pub name: String,
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub colors: Vec<u8>,
+}
+
+impl RulesConfig {
+    pub fn to_rule_set(&self) -> AppResult<PaintRuleSet> {
+        let mut rules = Vec::new();
+
+        if self.require_verified_email {
+            rules.push(PaintRule::EmailVerified);
+        }
+
+        if let Some(seconds) = self.min_account_age_secs {
+            rules.push(PaintRule::MinAccountAge { seconds });
+        }
+
+        // Role limits only raise the default, so without a default there is nothing to enforce.
+        if let Some(max_pixels) = self.max_pixels_per_batch {
+            let mut role_limits: Vec<(String, usize)> = self
+                .roles
+                .iter()
+                .filter_map(|(role_name, role_rules)| {
+                    role_rules
+                        .max_pixels_per_batch
+                        .map(|limit| (role_name.clone(), limit))
+                })
+                .collect();
+            role_limits.sort();
+            rules.push(PaintRule::MaxPixelsPerBatch {
+                max_pixels,
+                role_limits,
+            });
+        }
+
+        for zone in &self.color_zones {
+            rules.push(PaintRule::AllowedColors {
+                zone: zone.name.clone(),
+                area: GlobalRegion::from_origin_and_size(zone.x, zone.y, zone.width, zone.height)?,
+                color_ids: zone.colors.clone(),
+            });
+        }
+
+        if let Some(seconds) = self.pixel_cooldown_secs {
+            rules.push(PaintRule::PixelCooldown { seconds });
+        }
+
+        Ok(PaintRuleSet::new(rules))
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PixelProtectionConfig {
+    #[serde(default)]
+    pub window_secs: u64,
+    pub overwrite_surcharge: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -333,6 +753,21 @@ impl ColorPaletteConfig {
     }
 }
 
+impl Default for RateLimitBackendsConfig {
+    fn default() -> Self {
+        Self {
+            paint: RateLimitBackend::Memory,
+            tile: RateLimitBackend::Memory,
+            global: RateLimitBackend::Memory,
+            websocket: RateLimitBackend::Memory,
+            auth: RateLimitBackend::Memory,
+            account: RateLimitBackend::Memory,
+            report: RateLimitBackend::Memory,
+            api_token: RateLimitBackend::Memory,
+        }
+    }
+}
+
 impl Default for EmailConfig {
     fn default() -> Self {
         Self {
@@ -378,8 +813,16 @@ impl Default for AuthConfig {
             google_client_id: None,
             google_client_secret: None,
             google_redirect_url: None,
+            oidc_providers: BTreeMap::new(),
+            mastodon: MastodonConfig::default(),
+            two_factor: TwoFactorConfig::default(),
+            sessions: SessionTrackingConfig::default(),
+            api_tokens: ApiTokenConfig::default(),
+            account_deletion: AccountDeletionConfig::default(),
+            login_protection: LoginProtectionConfig::default(),
             email: EmailConfig::default(),
             argon2: Argon2Config::default(),
+            token_cleanup_interval_secs: 3600,
         }
     }
 }
@@ -417,6 +860,7 @@ impl Default for Config {
                 host: "0.0.0.0".to_string(),
                 port: 3000,
                 cors_origin: None,
+                trusted_proxies: Vec::new(),
             },
             tiles: TileConfig {
                 tile_size: 256,
@@ -458,7 +902,13 @@ impl Default for Config {
                 global_requests_per_minute: 1000,
                 websocket_messages_per_minute: 120,
                 auth_requests_per_minute: 30,
+                account_requests_per_minute: 20,
+                report_requests_per_minute: 5,
+                api_token_requests_per_minute: 120,
                 burst_size_multiplier: 2,
+                shared_ip_multiplier: 10,
+                backends: RateLimitBackendsConfig::default(),
+                roles: HashMap::new(),
             },
             credits: CreditConfig {
                 max_charges: 30,
@@ -475,6 +925,20 @@ impl Default for Config {
             },
             color_palette: ColorPaletteConfig::default(),
             auth: AuthConfig::default(),
+            bans: BanConfig {
+                escalation_enabled: true,
+                escalation_multiplier: 2,
+                permanent_after_prior_bans: None,
+                expiry_check_interval_secs: 60,
+                notify_on_expiry: false,
+            },
+            reports: ReportConfig {
+                max_comment_length: 1000,
+                max_pending_reports_per_user: 20,
+                max_region_area: 65536,
+            },
+            rules: RulesConfig::default(),
+            pixel_protection: PixelProtectionConfig::default(),
         }
     }
 }
@@ -582,6 +1046,9 @@ impl Config {
                 || self.rate_limit.global_requests_per_minute == 0
                 || self.rate_limit.websocket_messages_per_minute == 0
                 || self.rate_limit.auth_requests_per_minute == 0
+                || self.rate_limit.account_requests_per_minute == 0
+                || self.rate_limit.report_requests_per_minute == 0
+                || self.rate_limit.api_token_requests_per_minute == 0
             {
                 return Err(AppError::ConfigError {
                     message: "Rate limit values must be greater than 0 when enabled".to_string(),
@@ -593,6 +1060,24 @@ impl Config {
                     message: "burst_size_multiplier must be greater than 0".to_string(),
                 });
             }
+
+            if self.rate_limit.shared_ip_multiplier == 0 {
+                return Err(AppError::ConfigError {
+                    message: "shared_ip_multiplier must be greater than 0".to_string(),
+                });
+            }
+
+            for (role_name, role_limits) in &self.rate_limit.roles {
+                if role_limits.paint_requests_per_minute == Some(0)
+                    || role_limits.account_requests_per_minute == Some(0)
+                {
+                    return Err(AppError::ConfigError {
+                        message: format!(
+                            "Rate limit values for role '{role_name}' must be greater than 0"
+                        ),
+                    });
+                }
+            }
         }
 
         self.color_palette.validate()?;
@@ -621,6 +1106,188 @@ impl Config {
             });
         }
 
+        if self.bans.escalation_multiplier == 0 {
+            return Err(AppError::ConfigError {
+                message: "Ban escalation_multiplier must be greater than 0".to_string(),
+            });
+        }
+
+        if self.bans.expiry_check_interval_secs == 0 {
+            return Err(AppError::ConfigError {
+                message: "Ban expiry_check_interval_secs must be greater than 0".to_string(),
+            });
+        }
+
+        if self.bans.permanent_after_prior_bans == Some(0) {
+            return Err(AppError::ConfigError {
+                message: "Ban permanent_after_prior_bans must be greater than 0".to_string(),
+            });
+        }
+
+        if self.reports.max_pending_reports_per_user == 0 || self.reports.max_region_area == 0 {
+            return Err(AppError::ConfigError {
+                message:
+                    "Report max_pending_reports_per_user and max_region_area must be greater than 0"
+                        .to_string(),
+            });
+        }
+
+        if self.rules.max_pixels_per_batch == Some(0)
+            || self
+                .rules
+                .roles
+                .values()
+                .any(|role_rules| role_rules.max_pixels_per_batch == Some(0))
+        {
+            return Err(AppError::ConfigError {
+                message: "Rule max_pixels_per_batch must be greater than 0".to_string(),
+            });
+        }
+
+        let color_count = self.color_palette.colors.len() + self.color_palette.special_colors.len();
+        for zone in &self.rules.color_zones {
+            if zone.name.trim().is_empty() || zone.colors.is_empty() {
+                return Err(AppError::ConfigError {
+                    message: "Rule color zones need a name and at least one color".to_string(),
+                });
+            }
+
+            if let Some(color_id) = zone
+                .colors
+                .iter()
+                .find(|color_id| usize::from(**color_id) >= color_count)
+            {
+                return Err(AppError::ConfigError {
+                    message: format!(
+                        "Rule color zone '{}' uses color {} which is not in the palette",
+                        zone.name, color_id
+                    ),
+                });
+            }
+        }
+
+        self.rules.to_rule_set()?;
+
+        if self.pixel_protection.overwrite_surcharge == Some(0) {
+            return Err(AppError::ConfigError {
+                message: "Pixel protection overwrite_surcharge must be greater than 0".to_string(),
+            });
+        }
+
+        for (name, provider) in &self.auth.resolved_oidc_providers() {
+            if name.is_empty()
+                || !name
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+            {
+                return Err(AppError::ConfigError {
+                    message: format!(
+                        "OIDC provider name '{}' may only use lowercase letters, digits, '-' and '_'",
+                        name
+                    ),
+                });
+            }
+            if RESERVED_PROVIDER_NAMES.contains(&name.as_str()) {
+                return Err(AppError::ConfigError {
+                    message: format!("OIDC provider name '{}' is reserved", name),
+                });
+            }
+            if provider.issuer_url.is_empty()
+                || provider.client_id.is_empty()
+                || provider.redirect_url.is_empty()
+            {
+                return Err(AppError::ConfigError {
+                    message: format!(
+                        "OIDC provider '{}' needs issuer_url, client_id and redirect_url",
+                        name
+                    ),
+                });
+            }
+            if !provider.scopes.iter().any(|scope| scope == "openid") {
+                return Err(AppError::ConfigError {
+                    message: format!("OIDC provider '{}' must request the openid scope", name),
+                });
+            }
+        }
+
+        if self.auth.mastodon.enabled {
+            if self.auth.mastodon.redirect_url.is_empty()
+                || self.auth.mastodon.client_name.is_empty()
+                || self.auth.mastodon.scopes.is_empty()
+            {
+                return Err(AppError::ConfigError {
+                    message: "Mastodon login needs redirect_url, client_name and scopes"
+                        .to_string(),
+                });
+            }
+            self.auth.mastodon.instance_policy()?;
+        }
+
+        if self.auth.two_factor.issuer.is_empty() || self.auth.two_factor.issuer.contains(':') {
+            return Err(AppError::ConfigError {
+                message: "Two-factor issuer must be non-empty and may not contain ':'".to_string(),
+            });
+        }
+        if !(1..=50).contains(&self.auth.two_factor.recovery_code_count) {
+            return Err(AppError::ConfigError {
+                message: "Two-factor recovery_code_count must be between 1 and 50".to_string(),
+            });
+        }
+
+        if self.auth.sessions.idle_expiry_days == 0 {
+            return Err(AppError::ConfigError {
+                message: "Auth sessions idle_expiry_days must be greater than 0".to_string(),
+            });
+        }
+
+        if self.auth.api_tokens.max_tokens_per_user == 0 {
+            return Err(AppError::ConfigError {
+                message: "Auth api_tokens max_tokens_per_user must be greater than 0".to_string(),
+            });
+        }
+        if self.auth.api_tokens.max_lifetime_days == Some(0) {
+            return Err(AppError::ConfigError {
+                message: "Auth api_tokens max_lifetime_days must be greater than 0".to_string(),
+            });
+        }
+
+        if self.auth.account_deletion.check_interval_secs == 0 {
+            return Err(AppError::ConfigError {
+                message: "Auth account_deletion check_interval_secs must be greater than 0"
+                    .to_string(),
+            });
+        }
+
+        let login_protection = &self.auth.login_protection;
+        if login_protection.lockout_threshold <= login_protection.free_attempts {
+            return Err(AppError::ConfigError {
+                message:
+                    "Auth login_protection lockout_threshold must be greater than free_attempts"
+                        .to_string(),
+            });
+        }
+        if login_protection.backoff_base_secs == 0
+            || login_protection.backoff_base_secs > login_protection.backoff_max_secs
+        {
+            return Err(AppError::ConfigError {
+                message:
+                    "Auth login_protection backoff_base_secs must be between 1 and backoff_max_secs"
+                        .to_string(),
+            });
+        }
+        if login_protection.lockout_duration_secs == 0 || login_protection.failure_reset_secs == 0 {
+            return Err(AppError::ConfigError {
+                message: "Auth login_protection lockout_duration_secs and failure_reset_secs must be greater than 0"
+                    .to_string(),
+            });
+        }
+
+        if self.auth.token_cleanup_interval_secs == 0 {
+            return Err(AppError::ConfigError {
+                message: "Auth token_cleanup_interval_secs must be greater than 0".to_string(),
+            });
+        }
+
         if self.auth.argon2.memory_cost < 1024 {
             return Err(AppError::ConfigError {
                 message: "Argon2 memory_cost must be at least 1024 KiB".to_string(),
@@ -639,12 +1306,12 @@ impl Config {
             });
         }
 
-        if let Some(output_len) = self.auth.argon2.output_length {
-            if !(16..=512).contains(&output_len) {
-                return Err(AppError::ConfigError {
-                    message: "Argon2 output_length must be between 16 and 512 bytes".to_string(),
-                });
-            }
+        if let Some(output_len) = self.auth.argon2.output_length
+            && !(16..=512).contains(&output_len)
+        {
+            return Err(AppError::ConfigError {
+                message: "Argon2 output_length must be between 16 and 512 bytes".to_string(),
+            });
         }
 
         Ok(())
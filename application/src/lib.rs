@@ -7,7 +7,9 @@
 ))]
 compile_error!("application must not depend on adapters/framework crates");
 
+pub mod account;
 pub mod admin;
+pub mod audit;
 pub mod auth;
 pub mod ban;
 pub mod config;
@@ -15,5 +17,7 @@ pub mod contracts;
 pub mod error;
 pub mod infrastructure_config;
 pub mod ports;
+pub mod protected_regions;
+pub mod reports;
 pub mod subscriptions;
 pub mod tiles;
@@ -0,0 +1,23 @@
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use domain::account::AccountDataExport;
+
+#[async_trait::async_trait]
+pub trait AccountUseCase: Send + Sync {
+    async fn export_account_data(&self, user_id: Uuid) -> AppResult<AccountDataExport>;
+    async fn deletion_scheduled_at(&self, user_id: Uuid) -> AppResult<Option<OffsetDateTime>>;
+    // Returns when the account will be deleted. Accounts with a password must confirm it.
+    async fn schedule_deletion(
+        &self,
+        user_id: Uuid,
+        current_password: Option<String>,
+    ) -> AppResult<OffsetDateTime>;
+    async fn cancel_deletion(&self, user_id: Uuid) -> AppResult<()>;
+}
+
+#[async_trait::async_trait]
+pub trait AccountDeletionUseCase: Send + Sync {
+    async fn process_due_deletions(&self) -> AppResult<usize>;
+}
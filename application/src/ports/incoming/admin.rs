@@ -1,5 +1,7 @@
-use crate::error::AppResult;
-use domain::auth::UserPublic;
+use std::net::IpAddr;
+
+use crate::{error::AppResult, ports::outgoing::user_directory::UserDirectoryQuery};
+use domain::auth::{Permission, Role, RoleMember, UserDetails, UserPublic, UserSummary};
 use uuid::Uuid;
 
 #[async_trait::async_trait]
@@ -9,5 +11,59 @@ pub trait AdminUseCase: Send + Sync {
         user_id: Uuid,
         role_id: Uuid,
         assigned_by: Uuid,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<UserPublic>;
+
+    async fn revoke_role_from_user(
+        &self,
+        user_id: Uuid,
+        role_id: Uuid,
+        revoked_by: Uuid,
+        actor_ip: Option<IpAddr>,
     ) -> AppResult<UserPublic>;
+
+    async fn list_roles(&self, requesting_user_id: Uuid) -> AppResult<Vec<Role>>;
+
+    async fn create_role(
+        &self,
+        name: String,
+        description: Option<String>,
+        permissions: Vec<Permission>,
+        created_by: Uuid,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<Role>;
+
+    async fn update_role(
+        &self,
+        role_id: Uuid,
+        description: Option<String>,
+        permissions: Option<Vec<Permission>>,
+        updated_by: Uuid,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<Role>;
+
+    async fn delete_role(
+        &self,
+        role_id: Uuid,
+        deleted_by: Uuid,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<()>;
+
+    async fn list_users_by_role(
+        &self,
+        role_id: Uuid,
+        requesting_user_id: Uuid,
+    ) -> AppResult<Vec<RoleMember>>;
+
+    async fn list_users(
+        &self,
+        query: UserDirectoryQuery,
+        requesting_user_id: Uuid,
+    ) -> AppResult<Vec<UserSummary>>;
+
+    async fn get_user_details(
+        &self,
+        user_id: Uuid,
+        requesting_user_id: Uuid,
+    ) -> AppResult<UserDetails>;
 }
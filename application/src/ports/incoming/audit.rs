@@ -0,0 +1,11 @@
+use crate::{error::AppResult, ports::outgoing::audit_log::AuditLogQuery};
+use domain::{audit::AuditEntry, auth::UserId};
+
+#[async_trait::async_trait]
+pub trait AuditLogUseCase: Send + Sync {
+    async fn list_entries(
+        &self,
+        requesting_user_id: UserId,
+        query: AuditLogQuery,
+    ) -> AppResult<Vec<AuditEntry>>;
+}
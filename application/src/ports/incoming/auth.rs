@@ -1,5 +1,11 @@
 use crate::error::AppResult;
-use domain::auth::UserPublic;
+use domain::api_token::{ApiToken, ApiTokenScope, CreatedApiToken};
+use domain::auth::{Identity, UserId, UserPublic};
+use domain::fediverse::FediverseApp;
+use domain::login_protection::LockedAccount;
+use domain::session::UserSession;
+use domain::two_factor::{TotpEnrollment, TwoFactorStatus};
+use std::net::IpAddr;
 use uuid::Uuid;
 
 #[async_trait::async_trait]
@@ -11,7 +17,16 @@ pub trait AuthUseCase: Send + Sync {
         password: String,
     ) -> AppResult<UserPublic>;
     async fn verify_email(&self, token: String) -> AppResult<UserPublic>;
-    async fn login_local(&self, email: String, password: String) -> AppResult<UserPublic>;
+    async fn resend_verification_email(&self, user_id: Uuid) -> AppResult<()>;
+    async fn request_email_change(
+        &self,
+        user_id: Uuid,
+        new_email: String,
+        current_password: Option<String>,
+    ) -> AppResult<()>;
+    async fn confirm_email_change(&self, token: String) -> AppResult<UserPublic>;
+    async fn request_password_reset(&self, email: String) -> AppResult<()>;
+    async fn reset_password(&self, token: String, new_password: String) -> AppResult<UserPublic>;
     async fn logout(&self) -> AppResult<()>;
     async fn me(&self, user_id: Uuid) -> AppResult<UserPublic>;
     async fn upsert_social_identity(
@@ -19,7 +34,97 @@ pub trait AuthUseCase: Send + Sync {
         provider: String,
         provider_user_id: String,
         email: Option<String>,
+        email_verified: bool,
         username: Option<String>,
     ) -> AppResult<UserPublic>;
+    async fn link_social_identity(
+        &self,
+        user_id: Uuid,
+        provider: String,
+        provider_user_id: String,
+    ) -> AppResult<()>;
+    async fn list_social_identities(&self, user_id: Uuid) -> AppResult<Vec<Identity>>;
+    async fn unlink_social_identity(&self, user_id: Uuid, identity_id: Uuid) -> AppResult<()>;
     async fn update_username(&self, user_id: Uuid, new_username: String) -> AppResult<UserPublic>;
 }
+
+#[async_trait::async_trait]
+pub trait TokenCleanupUseCase: Send + Sync {
+    async fn purge_expired_tokens(&self) -> AppResult<u64>;
+}
+
+#[async_trait::async_trait]
+pub trait FediverseLoginUseCase: Send + Sync {
+    async fn resolve_instance_app(&self, instance: &str) -> AppResult<FediverseApp>;
+}
+
+#[async_trait::async_trait]
+pub trait TwoFactorUseCase: Send + Sync {
+    async fn status(&self, user_id: Uuid) -> AppResult<TwoFactorStatus>;
+    async fn is_enabled(&self, user_id: Uuid) -> AppResult<bool>;
+    async fn begin_totp_enrollment(&self, user_id: Uuid) -> AppResult<TotpEnrollment>;
+    async fn confirm_totp_enrollment(&self, user_id: Uuid, code: String) -> AppResult<Vec<String>>;
+    async fn disable_totp(&self, user_id: Uuid, code: String) -> AppResult<()>;
+    async fn regenerate_recovery_codes(
+        &self,
+        user_id: Uuid,
+        code: String,
+    ) -> AppResult<Vec<String>>;
+    // Second login step. Succeeds without a code for accounts that have no TOTP enabled.
+    async fn verify_login_code(&self, user_id: Uuid, code: Option<String>) -> AppResult<()>;
+}
+
+#[async_trait::async_trait]
+pub trait SessionManagementUseCase: Send + Sync {
+    async fn register_session(
+        &self,
+        user_id: Uuid,
+        user_agent: Option<String>,
+        ip_address: Option<IpAddr>,
+    ) -> AppResult<UserSession>;
+    // Returns false once the session was revoked, so the caller can end it.
+    async fn touch_session(
+        &self,
+        user_id: Uuid,
+        session_id: Uuid,
+        ip_address: Option<IpAddr>,
+    ) -> AppResult<bool>;
+    async fn list_sessions(&self, user_id: Uuid) -> AppResult<Vec<UserSession>>;
+    async fn revoke_session(&self, user_id: Uuid, session_id: Uuid) -> AppResult<()>;
+    async fn revoke_all_sessions(&self, user_id: Uuid, except: Option<Uuid>) -> AppResult<u64>;
+}
+
+#[async_trait::async_trait]
+pub trait ApiTokenUseCase: Send + Sync {
+    async fn create_token(
+        &self,
+        user_id: Uuid,
+        name: String,
+        scopes: Vec<ApiTokenScope>,
+        expires_in_days: Option<u32>,
+    ) -> AppResult<CreatedApiToken>;
+    async fn list_tokens(&self, user_id: Uuid) -> AppResult<Vec<ApiToken>>;
+    async fn revoke_token(&self, user_id: Uuid, token_id: Uuid) -> AppResult<()>;
+    // Returns the token when the secret belongs to an active token and records its use.
+    async fn authenticate(&self, secret: &str) -> AppResult<Option<ApiToken>>;
+}
+
+#[async_trait::async_trait]
+pub trait LoginProtectionUseCase: Send + Sync {
+    // False during a backoff delay or lockout. Callers must answer exactly as for a wrong
+    // password so the lock state does not leak.
+    async fn is_login_allowed(&self, user_id: Uuid) -> AppResult<bool>;
+    async fn record_failed_login(&self, user_id: Uuid) -> AppResult<()>;
+    // Called after a correct password or a completed password reset.
+    async fn clear_failed_logins(&self, user_id: Uuid) -> AppResult<()>;
+    async fn list_locked_accounts(
+        &self,
+        requesting_user_id: UserId,
+    ) -> AppResult<Vec<LockedAccount>>;
+    async fn unlock_account(
+        &self,
+        user_id: UserId,
+        unlocked_by: UserId,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<()>;
+}
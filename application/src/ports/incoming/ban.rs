@@ -1,5 +1,11 @@
-use crate::error::AppResult;
-use domain::{auth::UserId, ban::Ban};
+use std::net::IpAddr;
+
+use crate::{error::AppResult, tiles::commands::PixelPromotionResult};
+use domain::{
+    auth::UserId,
+    ban::{Ban, IpBan, IpBanId, QuarantinedPixel},
+};
+use ipnet::IpNet;
 use time::OffsetDateTime;
 
 #[async_trait::async_trait]
@@ -10,11 +16,79 @@ pub trait BanUseCase: Send + Sync {
         banned_by_user_id: UserId,
         reason: String,
         expires_at: Option<OffsetDateTime>,
+        shadow: bool,
+        actor_ip: Option<IpAddr>,
     ) -> AppResult<()>;
 
     async fn check_user_ban_status(&self, user_id: &UserId) -> AppResult<Option<Ban>>;
 
-    async fn unban_user(&self, user_id: UserId, unbanned_by: UserId) -> AppResult<()>;
+    async fn unban_user(
+        &self,
+        user_id: UserId,
+        unbanned_by: UserId,
+        reason: Option<String>,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<()>;
 
     async fn get_active_bans(&self, requesting_user_id: UserId) -> AppResult<Vec<Ban>>;
+
+    async fn get_ban_history(
+        &self,
+        user_id: UserId,
+        requesting_user_id: UserId,
+    ) -> AppResult<Vec<Ban>>;
+}
+
+#[async_trait::async_trait]
+pub trait BanExpiryUseCase: Send + Sync {
+    async fn process_expired_bans(&self) -> AppResult<usize>;
+
+    // Finalizes one user's lapsed bans right away instead of waiting for the next run.
+    async fn process_expired_bans_of_user(&self, user_id: &UserId) -> AppResult<usize>;
+}
+
+#[async_trait::async_trait]
+pub trait QuarantineUseCase: Send + Sync {
+    async fn list_quarantined_pixels(
+        &self,
+        user_id: UserId,
+        requesting_user_id: UserId,
+    ) -> AppResult<Vec<QuarantinedPixel>>;
+
+    async fn discard_quarantined_pixels(
+        &self,
+        user_id: UserId,
+        moderator_id: UserId,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<u64>;
+
+    async fn promote_quarantined_pixels(
+        &self,
+        user_id: UserId,
+        moderator_id: UserId,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<PixelPromotionResult>;
+}
+
+#[async_trait::async_trait]
+pub trait IpBanUseCase: Send + Sync {
+    async fn ban_network(
+        &self,
+        network: IpNet,
+        banned_by_user_id: UserId,
+        reason: String,
+        expires_at: Option<OffsetDateTime>,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<IpBan>;
+
+    async fn unban_network(
+        &self,
+        ban_id: IpBanId,
+        unbanned_by: UserId,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<()>;
+
+    async fn get_active_ip_bans(&self, requesting_user_id: UserId) -> AppResult<Vec<IpBan>>;
+
+    async fn is_ip_banned(&self, ip: IpAddr) -> AppResult<bool>;
 }
@@ -1,5 +1,9 @@
+pub mod account;
 pub mod admin;
+pub mod audit;
 pub mod auth;
 pub mod ban;
+pub mod protected_regions;
+pub mod reports;
 pub mod subscriptions;
 pub mod tiles;
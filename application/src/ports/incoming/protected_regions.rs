@@ -0,0 +1,42 @@
+use std::net::IpAddr;
+
+use crate::error::AppResult;
+use domain::{
+    auth::UserId,
+    protected_region::{PaintRestriction, ProtectedArea, ProtectedRegion, ProtectedRegionId},
+};
+
+#[derive(Debug, Clone)]
+pub struct ProtectedRegionDraft {
+    pub name: String,
+    pub description: Option<String>,
+    pub area: ProtectedArea,
+    pub restriction: PaintRestriction,
+}
+
+#[async_trait::async_trait]
+pub trait ProtectedRegionUseCase: Send + Sync {
+    async fn list_protected_regions(&self) -> AppResult<Vec<ProtectedRegion>>;
+
+    async fn create_protected_region(
+        &self,
+        draft: ProtectedRegionDraft,
+        created_by: UserId,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<ProtectedRegion>;
+
+    async fn update_protected_region(
+        &self,
+        region_id: ProtectedRegionId,
+        draft: ProtectedRegionDraft,
+        updated_by: UserId,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<ProtectedRegion>;
+
+    async fn delete_protected_region(
+        &self,
+        region_id: ProtectedRegionId,
+        deleted_by: UserId,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<()>;
+}
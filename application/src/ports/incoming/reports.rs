@@ -0,0 +1,57 @@
+use std::net::IpAddr;
+
+use crate::{error::AppResult, ports::outgoing::report_store::ReportQuery};
+use domain::{
+    auth::UserId,
+    report::{Report, ReportCategory, ReportId, ReportTarget},
+};
+use time::OffsetDateTime;
+
+#[derive(Debug, Clone)]
+pub enum ReportResolution {
+    NoAction,
+    BanUser {
+        reason: String,
+        expires_at: Option<OffsetDateTime>,
+    },
+    RollbackRegion {
+        painted_since: Option<OffsetDateTime>,
+        only_target_user: bool,
+    },
+}
+
+#[async_trait::async_trait]
+pub trait ReportUseCase: Send + Sync {
+    async fn submit_report(
+        &self,
+        reporter_user_id: UserId,
+        target: ReportTarget,
+        category: ReportCategory,
+        comment: String,
+    ) -> AppResult<Report>;
+
+    async fn list_reports(
+        &self,
+        requesting_user_id: UserId,
+        query: ReportQuery,
+    ) -> AppResult<Vec<Report>>;
+
+    async fn claim_report(&self, report_id: ReportId, moderator_id: UserId) -> AppResult<Report>;
+
+    async fn resolve_report(
+        &self,
+        report_id: ReportId,
+        moderator_id: UserId,
+        resolution: ReportResolution,
+        note: Option<String>,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<Report>;
+
+    async fn dismiss_report(
+        &self,
+        report_id: ReportId,
+        moderator_id: UserId,
+        note: Option<String>,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<Report>;
+}
@@ -1,12 +1,17 @@
 use crate::{
     error::AppResult,
     ports::outgoing::pixel_history_store::{PixelHistoryEntry, PixelInfo},
-    tiles::{commands::PaintingResult, gateway::TileVersionResult},
+    tiles::{
+        commands::{PaintingResult, PixelPromotionResult, RegionRollbackResult},
+        gateway::TileVersionResult,
+    },
 };
 use domain::{
+    action::PaintAction,
     auth::UserId,
     color::ColorId,
-    coords::{GlobalCoord, PixelCoord, TileCoord},
+    coords::{GlobalCoord, GlobalRegion, PixelCoord, TileCoord},
+    rules::PaintRule,
     tile::TileVersion,
 };
 
@@ -24,9 +29,16 @@ pub trait PaintPixelsUseCase: Send + Sync {
         user_id: UserId,
         tile: TileCoord,
         pixels: &[(PixelCoord, ColorId)],
+        // Set for requests authenticated with a personal API token.
+        automated: bool,
     ) -> AppResult<PaintingResult>;
 }
 
+#[async_trait::async_trait]
+pub trait PaintRulesQueryUseCase: Send + Sync {
+    async fn list_paint_rules(&self) -> AppResult<Vec<PaintRule>>;
+}
+
 #[async_trait::async_trait]
 pub trait MetricsQueryUseCase: Send + Sync {
     async fn get_metrics(&self) -> AppResult<serde_json::Value>;
@@ -41,3 +53,21 @@ pub trait PixelHistoryQueryUseCase: Send + Sync {
 pub trait PixelInfoQueryUseCase: Send + Sync {
     async fn get_pixel_info(&self, coord: GlobalCoord) -> AppResult<Option<PixelInfo>>;
 }
+
+#[async_trait::async_trait]
+pub trait RegionRollbackUseCase: Send + Sync {
+    async fn rollback_region(
+        &self,
+        region: GlobalRegion,
+        painted_since: Option<time::OffsetDateTime>,
+        painted_by: Option<UserId>,
+    ) -> AppResult<RegionRollbackResult>;
+}
+
+#[async_trait::async_trait]
+pub trait PixelPromotionUseCase: Send + Sync {
+    async fn promote_paint_actions(
+        &self,
+        paint_actions: &[PaintAction],
+    ) -> AppResult<PixelPromotionResult>;
+}
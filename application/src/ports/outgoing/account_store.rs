@@ -0,0 +1,38 @@
+use std::sync::Arc;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountDeletion {
+    Deleted,
+    NotDue,
+    // An active ban holds the deletion back until it ends.
+    Banned,
+    LastAdmin,
+}
+
+#[async_trait::async_trait]
+pub trait AccountStorePort: Send + Sync {
+    // Returns false when a deletion is already scheduled.
+    async fn schedule_deletion(
+        &self,
+        user_id: Uuid,
+        scheduled_at: OffsetDateTime,
+    ) -> AppResult<bool>;
+    async fn cancel_deletion(&self, user_id: Uuid) -> AppResult<bool>;
+    async fn find_deletion_scheduled_at(&self, user_id: Uuid) -> AppResult<Option<OffsetDateTime>>;
+    // Accounts under an active ban are left out until the ban ends.
+    async fn find_due_deletions(&self, now: OffsetDateTime, limit: u32) -> AppResult<Vec<Uuid>>;
+    async fn is_last_admin(&self, user_id: Uuid) -> AppResult<bool>;
+    // Deletes the user unless the deletion was cancelled in the meantime, the user is banned
+    // or they are the last admin. Their pixels stay on the canvas without attribution.
+    async fn delete_account(
+        &self,
+        user_id: Uuid,
+        now: OffsetDateTime,
+    ) -> AppResult<AccountDeletion>;
+}
+
+pub type DynAccountStorePort = Arc<dyn AccountStorePort>;
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use domain::api_token::ApiToken;
+
+#[async_trait::async_trait]
+pub trait ApiTokenStorePort: Send + Sync {
+    // Only a hash of `secret` is stored.
+    async fn create_token(&self, token: &ApiToken, secret: &str) -> AppResult<()>;
+
+    // Tokens that have not been revoked, newest first. Expired tokens are included.
+    async fn list_tokens(&self, user_id: Uuid) -> AppResult<Vec<ApiToken>>;
+
+    async fn count_active_tokens(&self, user_id: Uuid, now: OffsetDateTime) -> AppResult<i64>;
+
+    // Looks a token up by its secret, whether or not it is still usable.
+    async fn find_token_by_secret(&self, secret: &str) -> AppResult<Option<ApiToken>>;
+
+    // Only writes when the stored last-used time is older than `used_before`.
+    async fn record_token_use(&self, token_id: Uuid, used_before: OffsetDateTime) -> AppResult<()>;
+
+    async fn revoke_token(&self, user_id: Uuid, token_id: Uuid) -> AppResult<bool>;
+}
+
+pub type DynApiTokenStorePort = Arc<dyn ApiTokenStorePort>;
@@ -0,0 +1,29 @@
+use std::sync::Arc;
+
+use crate::error::AppResult;
+use domain::{
+    audit::{AuditAction, AuditEntry, AuditTargetType},
+    auth::UserId,
+};
+use time::OffsetDateTime;
+
+#[derive(Debug, Clone)]
+pub struct AuditLogQuery {
+    pub actor_user_id: Option<UserId>,
+    pub action: Option<AuditAction>,
+    pub target_type: Option<AuditTargetType>,
+    pub target_id: Option<String>,
+    pub since: Option<OffsetDateTime>,
+    pub until: Option<OffsetDateTime>,
+    pub limit: u32,
+    pub offset: u32,
+}
+
+#[async_trait::async_trait]
+pub trait AuditLogPort: Send + Sync {
+    async fn record(&self, entry: &AuditEntry) -> AppResult<()>;
+
+    async fn list_entries(&self, query: &AuditLogQuery) -> AppResult<Vec<AuditEntry>>;
+}
+
+pub type DynAuditLogPort = Arc<dyn AuditLogPort>;
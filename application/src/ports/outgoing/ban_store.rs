@@ -1,7 +1,10 @@
 use std::sync::Arc;
 
 use crate::error::AppResult;
-use domain::{auth::UserId, ban::Ban};
+use domain::{
+    auth::UserId,
+    ban::{Ban, BanId},
+};
 
 #[async_trait::async_trait]
 pub trait BanStorePort: Send + Sync {
@@ -9,10 +12,21 @@ pub trait BanStorePort: Send + Sync {
 
     async fn get_active_ban_by_user_id(&self, user_id: &UserId) -> AppResult<Option<Ban>>;
 
-    async fn remove_ban_by_user_id(&self, user_id: &UserId) -> AppResult<()>;
+    async fn revoke_ban(
+        &self,
+        ban_id: &BanId,
+        revoked_by_user_id: &UserId,
+        revoke_reason: Option<&str>,
+    ) -> AppResult<()>;
 
     async fn get_all_active_bans(&self) -> AppResult<Vec<Ban>>;
 
+    async fn claim_expired_bans(&self, limit: u32) -> AppResult<Vec<Ban>>;
+
+    async fn claim_expired_bans_of_user(&self, user_id: &UserId) -> AppResult<Vec<Ban>>;
+
+    async fn get_ban_history_by_user_id(&self, user_id: &UserId) -> AppResult<Vec<Ban>>;
+
     async fn remove_user_pixels(&self, user_id: &UserId) -> AppResult<u64>;
 }
 
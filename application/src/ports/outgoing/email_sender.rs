@@ -10,6 +10,43 @@ pub trait EmailSenderPort: Send + Sync {
         username: &str,
         verification_token: &str,
     ) -> AppResult<()>;
+
+    async fn send_password_reset_email(
+        &self,
+        recipient_email: &str,
+        username: &str,
+        reset_token: &str,
+    ) -> AppResult<()>;
+
+    async fn send_email_change_confirmation(
+        &self,
+        recipient_email: &str,
+        username: &str,
+        confirmation_token: &str,
+    ) -> AppResult<()>;
+
+    async fn send_email_changed_notice(
+        &self,
+        recipient_email: &str,
+        username: &str,
+        new_email: &str,
+    ) -> AppResult<()>;
+
+    async fn send_ban_expired_email(&self, recipient_email: &str, username: &str) -> AppResult<()>;
+
+    async fn send_account_deletion_scheduled_email(
+        &self,
+        recipient_email: &str,
+        username: &str,
+        deletion_date: &str,
+    ) -> AppResult<()>;
+
+    async fn send_account_locked_email(
+        &self,
+        recipient_email: &str,
+        username: &str,
+        locked_until: &str,
+    ) -> AppResult<()>;
 }
 
 pub type DynEmailSenderPort = Arc<dyn EmailSenderPort>;
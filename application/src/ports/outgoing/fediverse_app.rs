@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use crate::error::AppResult;
+use domain::fediverse::{FediverseApp, InstanceDomain};
+
+#[async_trait::async_trait]
+pub trait FediverseAppStorePort: Send + Sync {
+    async fn find_app(&self, domain: &InstanceDomain) -> AppResult<Option<FediverseApp>>;
+
+    async fn save_app(&self, app: &FediverseApp) -> AppResult<()>;
+}
+
+#[async_trait::async_trait]
+pub trait FediverseAppRegistrarPort: Send + Sync {
+    async fn register_app(
+        &self,
+        domain: &InstanceDomain,
+        redirect_url: &str,
+        scopes: &str,
+    ) -> AppResult<FediverseApp>;
+}
+
+pub type DynFediverseAppStorePort = Arc<dyn FediverseAppStorePort>;
+pub type DynFediverseAppRegistrarPort = Arc<dyn FediverseAppRegistrarPort>;
@@ -0,0 +1,14 @@
+use std::{net::IpAddr, sync::Arc, time::Duration};
+
+use crate::error::AppResult;
+
+#[async_trait::async_trait]
+pub trait IpBanCachePort: Send + Sync {
+    async fn get_ban_status(&self, ip: IpAddr) -> AppResult<Option<bool>>;
+
+    async fn store_ban_status(&self, ip: IpAddr, banned: bool, ttl: Duration) -> AppResult<()>;
+
+    async fn clear_ban_statuses(&self) -> AppResult<()>;
+}
+
+pub type DynIpBanCachePort = Arc<dyn IpBanCachePort>;
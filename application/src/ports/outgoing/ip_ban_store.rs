@@ -0,0 +1,22 @@
+use std::{net::IpAddr, sync::Arc};
+
+use crate::error::AppResult;
+use domain::ban::{IpBan, IpBanId};
+use ipnet::IpNet;
+
+#[async_trait::async_trait]
+pub trait IpBanStorePort: Send + Sync {
+    async fn create_ip_ban(&self, ip_ban: &IpBan) -> AppResult<()>;
+
+    async fn get_ip_ban_by_id(&self, ban_id: &IpBanId) -> AppResult<Option<IpBan>>;
+
+    async fn get_active_ip_ban_by_network(&self, network: &IpNet) -> AppResult<Option<IpBan>>;
+
+    async fn find_active_ip_ban(&self, ip: IpAddr) -> AppResult<Option<IpBan>>;
+
+    async fn remove_ip_ban(&self, ban_id: &IpBanId) -> AppResult<()>;
+
+    async fn get_all_active_ip_bans(&self) -> AppResult<Vec<IpBan>>;
+}
+
+pub type DynIpBanStorePort = Arc<dyn IpBanStorePort>;
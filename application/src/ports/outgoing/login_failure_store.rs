@@ -0,0 +1,30 @@
+use std::sync::Arc;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use domain::login_protection::{LockedAccount, LoginFailures};
+
+#[async_trait::async_trait]
+pub trait LoginFailureStorePort: Send + Sync {
+    async fn find_failures(&self, user_id: Uuid) -> AppResult<Option<LoginFailures>>;
+    // Counts one more failure and returns the new total. The count starts over when the
+    // previous failure happened before `reset_before`.
+    async fn record_failure(
+        &self,
+        user_id: Uuid,
+        now: OffsetDateTime,
+        reset_before: OffsetDateTime,
+    ) -> AppResult<u32>;
+    // Never shortens an existing block; `locked` also extends the lockout.
+    async fn block_until(
+        &self,
+        user_id: Uuid,
+        blocked_until: OffsetDateTime,
+        locked: bool,
+    ) -> AppResult<()>;
+    async fn clear_failures(&self, user_id: Uuid) -> AppResult<bool>;
+    async fn list_locked(&self, now: OffsetDateTime) -> AppResult<Vec<LockedAccount>>;
+}
+
+pub type DynLoginFailureStorePort = Arc<dyn LoginFailureStorePort>;
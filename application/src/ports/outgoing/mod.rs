@@ -1,14 +1,30 @@
+pub mod account_store;
+pub mod api_token_store;
+pub mod audit_log;
 pub mod ban_store;
 pub mod blocking_task;
 pub mod credit_store;
 pub mod email_sender;
 pub mod events;
+pub mod fediverse_app;
 pub mod image_codec;
+pub mod ip_ban_cache;
+pub mod ip_ban_store;
+pub mod login_failure_store;
 pub mod palette_compression;
 pub mod password_hasher;
 pub mod pixel_history_store;
+pub mod protected_region_store;
+pub mod quarantine_store;
+pub mod rate_limit;
+pub mod report_store;
+pub mod role_store;
+pub mod session_store;
 pub mod subscription_port;
 pub mod task_spawn;
 pub mod tile_cache;
 pub mod timeout;
+pub mod totp;
+pub mod two_factor_store;
+pub mod user_directory;
 pub mod user_store;
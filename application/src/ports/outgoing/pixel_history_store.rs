@@ -1,27 +1,32 @@
 use crate::error::AppResult;
 use domain::{
     action::PaintAction,
-    coords::{GlobalCoord, TileCoord},
+    auth::UserId,
+    coords::{GlobalCoord, GlobalRegion, TileCoord},
 };
 use std::sync::Arc;
 use uuid::Uuid;
 
+// `user_id` and `username` are None for pixels of deleted accounts.
 #[derive(Debug, Clone)]
 pub struct PixelHistoryEntry {
-    pub user_id: Uuid,
-    pub username: String,
+    pub user_id: Option<Uuid>,
+    pub username: Option<String>,
     pub pixel_x: usize,
     pub pixel_y: usize,
     pub color_id: u8,
     pub timestamp: time::OffsetDateTime,
+    pub automated: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct PixelInfo {
-    pub user_id: Uuid,
-    pub username: String,
+    pub user_id: Option<Uuid>,
+    pub username: Option<String>,
     pub color_id: u8,
     pub timestamp: time::OffsetDateTime,
+    pub automated: bool,
+    pub protection_remaining: Option<time::Duration>,
 }
 
 #[async_trait::async_trait]
@@ -31,6 +36,16 @@ pub trait PixelHistoryStorePort: Send + Sync {
     async fn get_current_tile_state(&self, coord: TileCoord) -> AppResult<Vec<(usize, usize, u8)>>;
     async fn get_distinct_tile_count(&self, tile_size: usize) -> AppResult<i64>;
     async fn get_pixel_info(&self, coord: GlobalCoord) -> AppResult<Option<PixelInfo>>;
+    // Pixels of deleted accounts have no owner and are left out.
+    async fn get_latest_paint_actions(&self, coords: &[GlobalCoord])
+    -> AppResult<Vec<PaintAction>>;
+    async fn get_paint_actions_by_user(&self, user_id: &UserId) -> AppResult<Vec<PaintAction>>;
+    async fn delete_pixels_in_region(
+        &self,
+        region: GlobalRegion,
+        painted_since: Option<time::OffsetDateTime>,
+        painted_by: Option<&UserId>,
+    ) -> AppResult<Vec<GlobalCoord>>;
 }
 
 pub type DynPixelHistoryStorePort = Arc<dyn PixelHistoryStorePort>;
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use crate::error::AppResult;
+use domain::{
+    coords::GlobalRegion,
+    protected_region::{ProtectedRegion, ProtectedRegionId},
+};
+
+#[async_trait::async_trait]
+pub trait ProtectedRegionStorePort: Send + Sync {
+    async fn list_protected_regions(&self) -> AppResult<Vec<ProtectedRegion>>;
+
+    async fn list_protected_regions_in(
+        &self,
+        bounds: GlobalRegion,
+    ) -> AppResult<Vec<ProtectedRegion>>;
+
+    async fn get_protected_region(
+        &self,
+        region_id: &ProtectedRegionId,
+    ) -> AppResult<Option<ProtectedRegion>>;
+
+    async fn create_protected_region(&self, region: &ProtectedRegion) -> AppResult<()>;
+
+    async fn update_protected_region(&self, region: &ProtectedRegion) -> AppResult<bool>;
+
+    async fn delete_protected_region(&self, region_id: &ProtectedRegionId) -> AppResult<bool>;
+}
+
+pub type DynProtectedRegionStorePort = Arc<dyn ProtectedRegionStorePort>;
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::error::AppResult;
+use domain::{
+    action::PaintAction,
+    auth::UserId,
+    ban::{BanId, QuarantinedPixel},
+};
+
+#[async_trait::async_trait]
+pub trait QuarantineStorePort: Send + Sync {
+    async fn record_quarantined_pixels(
+        &self,
+        ban_id: &BanId,
+        actions: &[PaintAction],
+    ) -> AppResult<()>;
+
+    async fn list_quarantined_pixels(&self, user_id: &UserId) -> AppResult<Vec<QuarantinedPixel>>;
+
+    async fn delete_quarantined_pixels(&self, user_id: &UserId) -> AppResult<u64>;
+
+    async fn delete_quarantined_pixels_by_id(&self, pixel_ids: &[i64]) -> AppResult<u64>;
+}
+
+pub type DynQuarantineStorePort = Arc<dyn QuarantineStorePort>;
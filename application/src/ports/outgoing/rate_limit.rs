@@ -0,0 +1,61 @@
+use std::{fmt, net::IpAddr, sync::Arc, time::Duration};
+
+use crate::error::AppResult;
+use domain::auth::UserId;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RateLimitKey {
+    Ip(IpAddr),
+    // Budget shared by every signed-in account behind one address.
+    SharedIp(IpAddr),
+    User(UserId),
+    ApiToken(Uuid),
+}
+
+impl fmt::Display for RateLimitKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RateLimitKey::Ip(ip) => write!(f, "ip:{ip}"),
+            RateLimitKey::SharedIp(ip) => write!(f, "shared_ip:{ip}"),
+            RateLimitKey::User(user_id) => write!(f, "user:{}", user_id.as_uuid()),
+            RateLimitKey::ApiToken(token_id) => write!(f, "token:{token_id}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitQuota {
+    pub requests_per_minute: u32,
+    pub burst_size: u32,
+}
+
+impl RateLimitQuota {
+    #[must_use]
+    pub fn new(requests_per_minute: u32, burst_size_multiplier: u32) -> Self {
+        Self {
+            requests_per_minute,
+            burst_size: requests_per_minute.saturating_mul(burst_size_multiplier),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitDecision {
+    pub allowed: bool,
+    pub limit: u32,
+    pub remaining: u32,
+    pub reset_after: Duration,
+    pub retry_after: Option<Duration>,
+}
+
+#[async_trait::async_trait]
+pub trait RateLimitPort: Send + Sync {
+    async fn check(
+        &self,
+        key: &RateLimitKey,
+        quota: RateLimitQuota,
+    ) -> AppResult<RateLimitDecision>;
+}
+
+pub type DynRateLimitPort = Arc<dyn RateLimitPort>;
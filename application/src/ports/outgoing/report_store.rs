@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use crate::error::AppResult;
+use domain::{
+    auth::UserId,
+    report::{Report, ReportId, ReportResolutionAction, ReportStatus},
+};
+
+#[derive(Debug, Clone)]
+pub struct ReportQuery {
+    pub status: Option<ReportStatus>,
+    pub limit: u32,
+    pub offset: u32,
+}
+
+#[async_trait::async_trait]
+pub trait ReportStorePort: Send + Sync {
+    async fn create_report(&self, report: &Report) -> AppResult<bool>;
+
+    async fn get_report_by_id(&self, report_id: &ReportId) -> AppResult<Option<Report>>;
+
+    async fn list_reports(&self, query: &ReportQuery) -> AppResult<Vec<Report>>;
+
+    async fn count_pending_reports_by_reporter(&self, reporter_user_id: &UserId) -> AppResult<u32>;
+
+    async fn claim_report(&self, report_id: &ReportId, moderator_id: &UserId) -> AppResult<bool>;
+
+    /// Atomically moves a pending report to `resolved` on behalf of the
+    /// moderator. Returns `false` when the report was already closed or is
+    /// claimed by someone else.
+    async fn begin_resolution(
+        &self,
+        report_id: &ReportId,
+        moderator_id: &UserId,
+    ) -> AppResult<bool>;
+
+    async fn record_resolution(
+        &self,
+        report_id: &ReportId,
+        moderator_id: &UserId,
+        resolution_action: ReportResolutionAction,
+        resolution_note: Option<&str>,
+    ) -> AppResult<()>;
+
+    /// Returns a report taken by `begin_resolution` to the pending queue when
+    /// its action could not be applied.
+    async fn release_resolution(
+        &self,
+        report_id: &ReportId,
+        moderator_id: &UserId,
+    ) -> AppResult<()>;
+
+    async fn close_report(
+        &self,
+        report_id: &ReportId,
+        status: ReportStatus,
+        moderator_id: &UserId,
+        resolution_action: Option<ReportResolutionAction>,
+        resolution_note: Option<&str>,
+    ) -> AppResult<bool>;
+}
+
+pub type DynReportStorePort = Arc<dyn ReportStorePort>;
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use crate::error::AppResult;
+use domain::auth::{Permission, Role, RoleId, RoleMember, UserId, UserRole};
+
+#[derive(Debug, Clone)]
+pub enum RoleRevocation {
+    Revoked(UserRole),
+    NotAssigned,
+    LastMember,
+}
+
+#[async_trait::async_trait]
+pub trait RoleStorePort: Send + Sync {
+    async fn list_roles(&self) -> AppResult<Vec<Role>>;
+
+    async fn get_role_by_id(&self, role_id: &RoleId) -> AppResult<Option<Role>>;
+
+    async fn create_role(&self, role: &Role) -> AppResult<bool>;
+
+    async fn update_role(
+        &self,
+        role_id: &RoleId,
+        description: Option<&str>,
+        permissions: &[Permission],
+    ) -> AppResult<Option<Role>>;
+
+    async fn delete_role(&self, role_id: &RoleId) -> AppResult<bool>;
+
+    async fn list_role_members(&self, role_id: &RoleId) -> AppResult<Vec<RoleMember>>;
+
+    async fn revoke_role_from_user(
+        &self,
+        user_id: &UserId,
+        role_id: &RoleId,
+        keep_last_member: bool,
+    ) -> AppResult<RoleRevocation>;
+}
+
+pub type DynRoleStorePort = Arc<dyn RoleStorePort>;
@@ -0,0 +1,33 @@
+use std::{net::IpAddr, sync::Arc};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use domain::session::UserSession;
+
+#[async_trait::async_trait]
+pub trait SessionStorePort: Send + Sync {
+    async fn create_session(&self, session: &UserSession) -> AppResult<()>;
+
+    // Returns false when the session was revoked or no longer exists. The last-seen time
+    // and address are only written when the stored time is older than `seen_before`.
+    async fn touch_session(
+        &self,
+        user_id: Uuid,
+        session_id: Uuid,
+        ip_address: Option<IpAddr>,
+        seen_before: OffsetDateTime,
+    ) -> AppResult<bool>;
+
+    // Sessions that have not been revoked, most recently used first.
+    async fn list_sessions(&self, user_id: Uuid) -> AppResult<Vec<UserSession>>;
+
+    async fn revoke_session(&self, user_id: Uuid, session_id: Uuid) -> AppResult<bool>;
+
+    async fn revoke_all_sessions(&self, user_id: Uuid, except: Option<Uuid>) -> AppResult<u64>;
+
+    // Removes revoked sessions and sessions last seen before `last_seen_before`.
+    async fn delete_stale_sessions(&self, last_seen_before: OffsetDateTime) -> AppResult<u64>;
+}
+
+pub type DynSessionStorePort = Arc<dyn SessionStorePort>;
@@ -0,0 +1,21 @@
+use std::sync::Arc;
+use time::OffsetDateTime;
+
+use crate::error::AppResult;
+
+pub trait TotpPort: Send + Sync {
+    // A new random secret, base32 encoded as authenticator apps expect it.
+    fn generate_secret(&self) -> String;
+
+    fn provisioning_url(&self, secret: &str, account_name: &str) -> AppResult<String>;
+
+    // The time step the code belongs to, allowing one step of clock drift either way.
+    fn matching_step(
+        &self,
+        secret: &str,
+        code: &str,
+        now: OffsetDateTime,
+    ) -> AppResult<Option<i64>>;
+}
+
+pub type DynTotpPort = Arc<dyn TotpPort>;
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::error::AppResult;
+use domain::two_factor::TotpCredential;
+
+#[async_trait::async_trait]
+pub trait TwoFactorStorePort: Send + Sync {
+    async fn find_totp(&self, user_id: Uuid) -> AppResult<Option<TotpCredential>>;
+
+    // Replaces any secret that was not enabled yet.
+    async fn save_pending_totp(&self, user_id: Uuid, secret: &str) -> AppResult<()>;
+
+    async fn enable_totp(
+        &self,
+        user_id: Uuid,
+        step: i64,
+        recovery_codes: &[String],
+    ) -> AppResult<()>;
+
+    // Returns false when a code from this or a later step was already accepted.
+    async fn record_totp_step(&self, user_id: Uuid, step: i64) -> AppResult<bool>;
+
+    async fn delete_totp(&self, user_id: Uuid) -> AppResult<()>;
+
+    async fn replace_recovery_codes(
+        &self,
+        user_id: Uuid,
+        recovery_codes: &[String],
+    ) -> AppResult<()>;
+
+    // Marks an unused recovery code as used, returning false if there was none.
+    async fn use_recovery_code(&self, user_id: Uuid, recovery_code: &str) -> AppResult<bool>;
+
+    async fn count_unused_recovery_codes(&self, user_id: Uuid) -> AppResult<i64>;
+}
+
+pub type DynTwoFactorStorePort = Arc<dyn TwoFactorStorePort>;
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use time::OffsetDateTime;
+
+use crate::error::AppResult;
+use domain::auth::{Identity, UserId, UserSummary};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UserSortField {
+    #[default]
+    CreatedAt,
+    Username,
+}
+
+impl UserSortField {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UserSortField::CreatedAt => "created_at",
+            UserSortField::Username => "username",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UserDirectoryQuery {
+    // Case-insensitive prefix of the username or email address.
+    pub search: Option<String>,
+    pub email_verified: Option<bool>,
+    pub banned: Option<bool>,
+    pub role_name: Option<String>,
+    pub created_since: Option<OffsetDateTime>,
+    pub created_until: Option<OffsetDateTime>,
+    pub sort: UserSortField,
+    pub descending: bool,
+    // Only return users ordered after this one.
+    pub after: Option<UserId>,
+    pub limit: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct UserAccountStats {
+    pub created_at: OffsetDateTime,
+    pub pixels_on_canvas: u64,
+    pub pixels_quarantined: u64,
+}
+
+#[async_trait::async_trait]
+pub trait UserDirectoryPort: Send + Sync {
+    async fn list_users(&self, query: &UserDirectoryQuery) -> AppResult<Vec<UserSummary>>;
+
+    async fn list_user_identities(&self, user_id: &UserId) -> AppResult<Vec<Identity>>;
+
+    async fn get_account_stats(&self, user_id: &UserId) -> AppResult<Option<UserAccountStats>>;
+}
+
+pub type DynUserDirectoryPort = Arc<dyn UserDirectoryPort>;
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use crate::error::AppResult;
-use domain::auth::UserPublic;
+use domain::auth::{Identity, UserPublic};
 use time::OffsetDateTime;
 use uuid::Uuid;
 
@@ -19,13 +19,29 @@ pub trait UserStorePort: Send + Sync {
     ) -> AppResult<Option<(Uuid, String, String, Option<String>, Option<OffsetDateTime>)>>;
     async fn find_user_by_username(&self, username: &str) -> AppResult<Option<UserPublic>>;
     async fn find_user_by_id(&self, id: Uuid) -> AppResult<Option<UserPublic>>;
-    async fn create_or_get_social_user(
+    async fn find_user_created_at(&self, id: Uuid) -> AppResult<Option<OffsetDateTime>>;
+    async fn find_user_by_identity(
+        &self,
+        provider: &str,
+        provider_user_id: &str,
+    ) -> AppResult<Option<UserPublic>>;
+    async fn create_social_user(
         &self,
         provider: &str,
         provider_user_id: &str,
         email: Option<&str>,
+        email_verified: bool,
         username: Option<&str>,
     ) -> AppResult<UserPublic>;
+    // Links the identity to `user_id` unless it already belongs to someone; returns its owner.
+    async fn link_identity(
+        &self,
+        user_id: Uuid,
+        provider: &str,
+        provider_user_id: &str,
+    ) -> AppResult<Uuid>;
+    async fn list_identities(&self, user_id: Uuid) -> AppResult<Vec<Identity>>;
+    async fn delete_identity(&self, user_id: Uuid, identity_id: Uuid) -> AppResult<bool>;
     async fn store_verification_token(
         &self,
         user_id: Uuid,
@@ -33,6 +49,35 @@ pub trait UserStorePort: Send + Sync {
         expires_at: OffsetDateTime,
     ) -> AppResult<()>;
     async fn verify_user_by_token(&self, token: &str) -> AppResult<UserPublic>;
+    async fn find_latest_verification_token_issued_at(
+        &self,
+        user_id: Uuid,
+    ) -> AppResult<Option<OffsetDateTime>>;
+    async fn store_password_reset_token(
+        &self,
+        user_id: Uuid,
+        token: &str,
+        expires_at: OffsetDateTime,
+    ) -> AppResult<()>;
+    async fn find_latest_password_reset_token_issued_at(
+        &self,
+        user_id: Uuid,
+    ) -> AppResult<Option<OffsetDateTime>>;
+    async fn reset_password_by_token(
+        &self,
+        token: &str,
+        password_hash: &str,
+    ) -> AppResult<UserPublic>;
+    async fn store_email_change_token(
+        &self,
+        user_id: Uuid,
+        new_email: &str,
+        token: &str,
+        expires_at: OffsetDateTime,
+    ) -> AppResult<()>;
+    // Returns the updated user together with the address it replaced.
+    async fn change_email_by_token(&self, token: &str) -> AppResult<(UserPublic, String)>;
+    async fn delete_expired_tokens(&self) -> AppResult<u64>;
     async fn update_username(&self, user_id: Uuid, new_username: &str) -> AppResult<UserPublic>;
     async fn assign_role_to_user(
         &self,
@@ -0,0 +1,253 @@
+use serde_json::json;
+use std::{net::IpAddr, sync::Arc};
+
+use crate::audit::recorder::{audit_timestamp, record_audit_entry};
+use crate::auth::permissions::require_permission;
+use crate::error::{AppError, AppResult};
+use crate::ports::incoming::protected_regions::{ProtectedRegionDraft, ProtectedRegionUseCase};
+use crate::ports::outgoing::audit_log::AuditLogPort;
+use crate::ports::outgoing::protected_region_store::ProtectedRegionStorePort;
+use crate::ports::outgoing::role_store::RoleStorePort;
+use crate::ports::outgoing::user_store::UserStorePort;
+use domain::{
+    audit::{AuditAction, AuditEntry, AuditTargetType},
+    auth::{Permission, UserId},
+    coords::GlobalCoord,
+    protected_region::{
+        PaintRestriction, ProtectedRegion, ProtectedRegionError, ProtectedRegionId,
+    },
+};
+
+pub struct ProtectedRegionService {
+    region_store: Arc<dyn ProtectedRegionStorePort>,
+    role_store: Arc<dyn RoleStorePort>,
+    user_store: Arc<dyn UserStorePort>,
+    audit_log: Arc<dyn AuditLogPort>,
+}
+
+impl ProtectedRegionService {
+    pub fn new(
+        region_store: Arc<dyn ProtectedRegionStorePort>,
+        role_store: Arc<dyn RoleStorePort>,
+        user_store: Arc<dyn UserStorePort>,
+        audit_log: Arc<dyn AuditLogPort>,
+    ) -> Self {
+        Self {
+            region_store,
+            role_store,
+            user_store,
+            audit_log,
+        }
+    }
+
+    async fn load_region(&self, region_id: &ProtectedRegionId) -> AppResult<ProtectedRegion> {
+        self.region_store
+            .get_protected_region(region_id)
+            .await?
+            .ok_or_else(|| AppError::ValidationError {
+                message: ProtectedRegionError::RegionNotFound.to_string(),
+            })
+    }
+
+    // A misspelled role would silently lock everyone out, so only existing roles are accepted.
+    async fn validate_region(&self, region: &ProtectedRegion) -> AppResult<()> {
+        region.validate().map_err(|e| AppError::ValidationError {
+            message: e.to_string(),
+        })?;
+
+        let bounds = region.area.bounding_box();
+        GlobalCoord::new(bounds.min_x, bounds.min_y).validate()?;
+        GlobalCoord::new(bounds.max_x, bounds.max_y).validate()?;
+
+        if let PaintRestriction::RolesOnly { role_names } = &region.restriction {
+            let roles = self.role_store.list_roles().await?;
+            if let Some(unknown) = role_names
+                .iter()
+                .find(|name| !roles.iter().any(|role| role.name == **name))
+            {
+                return Err(AppError::ValidationError {
+                    message: format!("Role {} does not exist", unknown),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn audit_parameters(region: &ProtectedRegion) -> serde_json::Value {
+        let (allowed_roles, opens_at, closes_at) = match &region.restriction {
+            PaintRestriction::Locked => (None, None, None),
+            PaintRestriction::RolesOnly { role_names } => (Some(role_names.clone()), None, None),
+            PaintRestriction::TimeWindow {
+                opens_at,
+                closes_at,
+            } => (
+                None,
+                audit_timestamp(*opens_at),
+                audit_timestamp(*closes_at),
+            ),
+        };
+        let bounds = region.area.bounding_box();
+
+        json!({
+            "name": region.name,
+            "shape": region.area.shape_str(),
+            "bounds": [bounds.min_x, bounds.min_y, bounds.max_x, bounds.max_y],
+            "restriction": region.restriction.kind().as_str(),
+            "allowed_roles": allowed_roles,
+            "opens_at": opens_at,
+            "closes_at": closes_at,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl ProtectedRegionUseCase for ProtectedRegionService {
+    async fn list_protected_regions(&self) -> AppResult<Vec<ProtectedRegion>> {
+        self.region_store.list_protected_regions().await
+    }
+
+    async fn create_protected_region(
+        &self,
+        draft: ProtectedRegionDraft,
+        created_by: UserId,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<ProtectedRegion> {
+        require_permission(
+            self.user_store.as_ref(),
+            &created_by,
+            Permission::RegionsManage,
+        )
+        .await?;
+
+        let region = ProtectedRegion::new(
+            draft.name.trim().to_string(),
+            draft.description,
+            draft.area,
+            draft.restriction,
+            created_by.clone(),
+        );
+        self.validate_region(&region).await?;
+
+        self.region_store.create_protected_region(&region).await?;
+
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                Some(created_by.clone()),
+                AuditAction::ProtectedRegionCreated,
+                AuditTargetType::ProtectedRegion,
+                Some(region.id.as_uuid().to_string()),
+            )
+            .with_parameters(Self::audit_parameters(&region))
+            .with_ip_address(actor_ip),
+        )
+        .await;
+
+        tracing::info!(
+            region_id = %region.id.as_uuid(),
+            name = %region.name,
+            created_by = %created_by.as_uuid(),
+            "Protected region created"
+        );
+
+        Ok(region)
+    }
+
+    async fn update_protected_region(
+        &self,
+        region_id: ProtectedRegionId,
+        draft: ProtectedRegionDraft,
+        updated_by: UserId,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<ProtectedRegion> {
+        require_permission(
+            self.user_store.as_ref(),
+            &updated_by,
+            Permission::RegionsManage,
+        )
+        .await?;
+        let previous = self.load_region(&region_id).await?;
+
+        let region = ProtectedRegion {
+            name: draft.name.trim().to_string(),
+            description: draft.description,
+            area: draft.area,
+            restriction: draft.restriction,
+            updated_at: time::OffsetDateTime::now_utc(),
+            ..previous.clone()
+        };
+        self.validate_region(&region).await?;
+
+        if !self.region_store.update_protected_region(&region).await? {
+            return Err(AppError::ValidationError {
+                message: ProtectedRegionError::RegionNotFound.to_string(),
+            });
+        }
+
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                Some(updated_by),
+                AuditAction::ProtectedRegionUpdated,
+                AuditTargetType::ProtectedRegion,
+                Some(region_id.as_uuid().to_string()),
+            )
+            .with_parameters(json!({
+                "previous": Self::audit_parameters(&previous),
+                "updated": Self::audit_parameters(&region),
+            }))
+            .with_ip_address(actor_ip),
+        )
+        .await;
+
+        Ok(region)
+    }
+
+    async fn delete_protected_region(
+        &self,
+        region_id: ProtectedRegionId,
+        deleted_by: UserId,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<()> {
+        require_permission(
+            self.user_store.as_ref(),
+            &deleted_by,
+            Permission::RegionsManage,
+        )
+        .await?;
+        let region = self.load_region(&region_id).await?;
+
+        if !self
+            .region_store
+            .delete_protected_region(&region_id)
+            .await?
+        {
+            return Err(AppError::ValidationError {
+                message: ProtectedRegionError::RegionNotFound.to_string(),
+            });
+        }
+
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                Some(deleted_by.clone()),
+                AuditAction::ProtectedRegionDeleted,
+                AuditTargetType::ProtectedRegion,
+                Some(region_id.as_uuid().to_string()),
+            )
+            .with_parameters(Self::audit_parameters(&region))
+            .with_ip_address(actor_ip),
+        )
+        .await;
+
+        tracing::info!(
+            region_id = %region_id.as_uuid(),
+            name = %region.name,
+            deleted_by = %deleted_by.as_uuid(),
+            "Protected region deleted"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,481 @@
+use serde_json::json;
+use std::{net::IpAddr, sync::Arc};
+
+use crate::audit::recorder::{audit_timestamp, record_audit_entry};
+use crate::auth::permissions::require_permission;
+use crate::config::ReportSettings;
+use crate::error::{AppError, AppResult};
+use crate::ports::incoming::ban::BanUseCase;
+use crate::ports::incoming::reports::{ReportResolution, ReportUseCase};
+use crate::ports::incoming::tiles::RegionRollbackUseCase;
+use crate::ports::outgoing::audit_log::AuditLogPort;
+use crate::ports::outgoing::pixel_history_store::PixelHistoryStorePort;
+use crate::ports::outgoing::report_store::{ReportQuery, ReportStorePort};
+use crate::ports::outgoing::user_store::UserStorePort;
+use domain::{
+    audit::{AuditAction, AuditEntry, AuditTargetType},
+    auth::{Permission, UserId},
+    coords::GlobalCoord,
+    report::{
+        Report, ReportCategory, ReportError, ReportId, ReportResolutionAction, ReportStatus,
+        ReportTarget,
+    },
+};
+
+pub struct ReportService {
+    report_store: Arc<dyn ReportStorePort>,
+    pixel_history_store: Arc<dyn PixelHistoryStorePort>,
+    user_store: Arc<dyn UserStorePort>,
+    ban_use_case: Arc<dyn BanUseCase>,
+    rollback_use_case: Arc<dyn RegionRollbackUseCase>,
+    audit_log: Arc<dyn AuditLogPort>,
+    settings: ReportSettings,
+}
+
+impl ReportService {
+    pub fn new(
+        report_store: Arc<dyn ReportStorePort>,
+        pixel_history_store: Arc<dyn PixelHistoryStorePort>,
+        user_store: Arc<dyn UserStorePort>,
+        ban_use_case: Arc<dyn BanUseCase>,
+        rollback_use_case: Arc<dyn RegionRollbackUseCase>,
+        audit_log: Arc<dyn AuditLogPort>,
+        settings: ReportSettings,
+    ) -> Self {
+        Self {
+            report_store,
+            pixel_history_store,
+            user_store,
+            ban_use_case,
+            rollback_use_case,
+            audit_log,
+            settings,
+        }
+    }
+
+    async fn resolve_target_user(&self, target: &ReportTarget) -> AppResult<Option<UserId>> {
+        match target {
+            ReportTarget::Pixel(coord) => {
+                coord.validate()?;
+                let pixel_info = self.pixel_history_store.get_pixel_info(*coord).await?;
+                Ok(pixel_info
+                    .and_then(|info| info.user_id)
+                    .map(UserId::from_uuid))
+            }
+            ReportTarget::Region(region) => {
+                GlobalCoord::new(region.min_x, region.min_y).validate()?;
+                GlobalCoord::new(region.max_x, region.max_y).validate()?;
+                region.validate_max_area(self.settings.max_region_area)?;
+                Ok(None)
+            }
+            ReportTarget::User(user_id) => {
+                let user = self
+                    .user_store
+                    .find_user_by_id(*user_id.as_uuid())
+                    .await?
+                    .ok_or_else(|| AppError::ValidationError {
+                        message: "Reported user not found".to_string(),
+                    })?;
+                Ok(Some(user.id))
+            }
+        }
+    }
+
+    async fn load_pending_report(
+        &self,
+        report_id: &ReportId,
+        moderator_id: &UserId,
+    ) -> AppResult<Report> {
+        let report = self
+            .report_store
+            .get_report_by_id(report_id)
+            .await?
+            .ok_or_else(|| AppError::ValidationError {
+                message: ReportError::ReportNotFound.to_string(),
+            })?;
+
+        if !report.status.is_pending() {
+            return Err(AppError::ValidationError {
+                message: ReportError::ReportClosed.to_string(),
+            });
+        }
+
+        if report.is_claimed_by_other(moderator_id) {
+            return Err(AppError::ValidationError {
+                message: ReportError::ClaimedByOtherModerator.to_string(),
+            });
+        }
+
+        Ok(report)
+    }
+
+    async fn apply_resolution(
+        &self,
+        report: &Report,
+        moderator_id: &UserId,
+        resolution: ReportResolution,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<ReportResolutionAction> {
+        match resolution {
+            ReportResolution::NoAction => Ok(ReportResolutionAction::NoAction),
+            ReportResolution::BanUser { reason, expires_at } => {
+                let target_user_id =
+                    report
+                        .target_user_id
+                        .clone()
+                        .ok_or_else(|| AppError::ValidationError {
+                            message: ReportError::NoTargetUser.to_string(),
+                        })?;
+
+                self.ban_use_case
+                    .ban_user(
+                        target_user_id,
+                        moderator_id.clone(),
+                        reason,
+                        expires_at,
+                        false,
+                        actor_ip,
+                    )
+                    .await?;
+                Ok(ReportResolutionAction::BanUser)
+            }
+            ReportResolution::RollbackRegion {
+                painted_since,
+                only_target_user,
+            } => {
+                require_permission(
+                    self.user_store.as_ref(),
+                    moderator_id,
+                    Permission::PixelsRollback,
+                )
+                .await?;
+
+                let region = report
+                    .target
+                    .region()
+                    .ok_or_else(|| AppError::ValidationError {
+                        message: ReportError::NoTargetRegion.to_string(),
+                    })?;
+
+                let painted_by = if only_target_user {
+                    Some(report.target_user_id.clone().ok_or_else(|| {
+                        AppError::ValidationError {
+                            message: ReportError::NoTargetUser.to_string(),
+                        }
+                    })?)
+                } else {
+                    None
+                };
+
+                let result = self
+                    .rollback_use_case
+                    .rollback_region(region, painted_since, painted_by.clone())
+                    .await?;
+
+                record_audit_entry(
+                    self.audit_log.as_ref(),
+                    AuditEntry::new(
+                        Some(moderator_id.clone()),
+                        AuditAction::RegionRolledBack,
+                        AuditTargetType::Region,
+                        Some(region.to_string()),
+                    )
+                    .with_parameters(json!({
+                        "report_id": report.id.as_uuid(),
+                        "painted_since": painted_since.and_then(audit_timestamp),
+                        "painted_by": painted_by.as_ref().map(UserId::as_uuid),
+                        "pixels_cleared": result.pixels_cleared,
+                        "tiles_affected": result.tiles_affected,
+                    }))
+                    .with_ip_address(actor_ip),
+                )
+                .await;
+
+                tracing::info!(
+                    report_id = %report.id.as_uuid(),
+                    region = %region,
+                    pixels_cleared = result.pixels_cleared,
+                    tiles_affected = result.tiles_affected,
+                    "Region rolled back from report"
+                );
+                Ok(ReportResolutionAction::RollbackRegion)
+            }
+        }
+    }
+
+    async fn close_report(
+        &self,
+        report_id: &ReportId,
+        status: ReportStatus,
+        moderator_id: &UserId,
+        resolution_action: Option<ReportResolutionAction>,
+        note: Option<String>,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<Report> {
+        let closed = self
+            .report_store
+            .close_report(
+                report_id,
+                status,
+                moderator_id,
+                resolution_action,
+                note.as_deref(),
+            )
+            .await?;
+
+        if !closed {
+            return Err(AppError::ValidationError {
+                message: ReportError::ReportClosed.to_string(),
+            });
+        }
+
+        self.finish_close(
+            report_id,
+            status,
+            moderator_id,
+            resolution_action,
+            note,
+            actor_ip,
+        )
+        .await
+    }
+
+    async fn finish_close(
+        &self,
+        report_id: &ReportId,
+        status: ReportStatus,
+        moderator_id: &UserId,
+        resolution_action: Option<ReportResolutionAction>,
+        note: Option<String>,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<Report> {
+        let action = match status {
+            ReportStatus::Dismissed => AuditAction::ReportDismissed,
+            _ => AuditAction::ReportResolved,
+        };
+        record_audit_entry(
+            self.audit_log.as_ref(),
+            AuditEntry::new(
+                Some(moderator_id.clone()),
+                action,
+                AuditTargetType::Report,
+                Some(report_id.as_uuid().to_string()),
+            )
+            .with_parameters(json!({
+                "resolution_action": resolution_action.map(|action| action.as_str()),
+                "note": note,
+            }))
+            .with_ip_address(actor_ip),
+        )
+        .await;
+
+        tracing::info!(
+            report_id = %report_id.as_uuid(),
+            moderator = %moderator_id.as_uuid(),
+            status = %status,
+            "Report closed"
+        );
+
+        self.report_store
+            .get_report_by_id(report_id)
+            .await?
+            .ok_or_else(|| AppError::ValidationError {
+                message: ReportError::ReportNotFound.to_string(),
+            })
+    }
+}
+
+#[async_trait::async_trait]
+impl ReportUseCase for ReportService {
+    async fn submit_report(
+        &self,
+        reporter_user_id: UserId,
+        target: ReportTarget,
+        category: ReportCategory,
+        comment: String,
+    ) -> AppResult<Report> {
+        let comment = comment.trim().to_string();
+        if comment.chars().count() > self.settings.max_comment_length {
+            return Err(AppError::ValidationError {
+                message: ReportError::CommentTooLong.to_string(),
+            });
+        }
+
+        let target_user_id = self.resolve_target_user(&target).await?;
+        if target_user_id.as_ref() == Some(&reporter_user_id)
+            && matches!(target, ReportTarget::User(_))
+        {
+            return Err(AppError::ValidationError {
+                message: ReportError::SelfReport.to_string(),
+            });
+        }
+
+        let pending = self
+            .report_store
+            .count_pending_reports_by_reporter(&reporter_user_id)
+            .await?;
+        if pending >= self.settings.max_pending_reports_per_user {
+            return Err(AppError::ValidationError {
+                message: format!(
+                    "You have too many open reports (maximum {})",
+                    self.settings.max_pending_reports_per_user
+                ),
+            });
+        }
+
+        let report = Report::new(
+            reporter_user_id.clone(),
+            target,
+            target_user_id,
+            category,
+            comment,
+        );
+
+        if !self.report_store.create_report(&report).await? {
+            return Err(AppError::ValidationError {
+                message: ReportError::DuplicateReport.to_string(),
+            });
+        }
+
+        tracing::info!(
+            report_id = %report.id.as_uuid(),
+            reporter = %reporter_user_id.as_uuid(),
+            target_type = report.target.type_str(),
+            category = report.category.as_str(),
+            "Report submitted"
+        );
+
+        Ok(report)
+    }
+
+    async fn list_reports(
+        &self,
+        requesting_user_id: UserId,
+        query: ReportQuery,
+    ) -> AppResult<Vec<Report>> {
+        require_permission(
+            self.user_store.as_ref(),
+            &requesting_user_id,
+            Permission::ReportsResolve,
+        )
+        .await?;
+        self.report_store.list_reports(&query).await
+    }
+
+    async fn claim_report(&self, report_id: ReportId, moderator_id: UserId) -> AppResult<Report> {
+        require_permission(
+            self.user_store.as_ref(),
+            &moderator_id,
+            Permission::ReportsResolve,
+        )
+        .await?;
+        self.load_pending_report(&report_id, &moderator_id).await?;
+
+        if !self
+            .report_store
+            .claim_report(&report_id, &moderator_id)
+            .await?
+        {
+            return Err(AppError::ValidationError {
+                message: ReportError::ClaimedByOtherModerator.to_string(),
+            });
+        }
+
+        self.report_store
+            .get_report_by_id(&report_id)
+            .await?
+            .ok_or_else(|| AppError::ValidationError {
+                message: ReportError::ReportNotFound.to_string(),
+            })
+    }
+
+    async fn resolve_report(
+        &self,
+        report_id: ReportId,
+        moderator_id: UserId,
+        resolution: ReportResolution,
+        note: Option<String>,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<Report> {
+        require_permission(
+            self.user_store.as_ref(),
+            &moderator_id,
+            Permission::ReportsResolve,
+        )
+        .await?;
+        let report = self.load_pending_report(&report_id, &moderator_id).await?;
+
+        // Take the report out of the pending queue before acting on it so a
+        // second moderator racing on the same report cannot apply the action twice.
+        if !self
+            .report_store
+            .begin_resolution(&report_id, &moderator_id)
+            .await?
+        {
+            return Err(AppError::ValidationError {
+                message: ReportError::ReportClosed.to_string(),
+            });
+        }
+
+        let action = match self
+            .apply_resolution(&report, &moderator_id, resolution, actor_ip)
+            .await
+        {
+            Ok(action) => action,
+            Err(error) => {
+                if let Err(release_error) = self
+                    .report_store
+                    .release_resolution(&report_id, &moderator_id)
+                    .await
+                {
+                    tracing::error!(
+                        report_id = %report_id.as_uuid(),
+                        error = %release_error,
+                        "Failed to reopen report after its resolution failed"
+                    );
+                }
+                return Err(error);
+            }
+        };
+
+        self.report_store
+            .record_resolution(&report_id, &moderator_id, action, note.as_deref())
+            .await?;
+
+        self.finish_close(
+            &report_id,
+            ReportStatus::Resolved,
+            &moderator_id,
+            Some(action),
+            note,
+            actor_ip,
+        )
+        .await
+    }
+
+    async fn dismiss_report(
+        &self,
+        report_id: ReportId,
+        moderator_id: UserId,
+        note: Option<String>,
+        actor_ip: Option<IpAddr>,
+    ) -> AppResult<Report> {
+        require_permission(
+            self.user_store.as_ref(),
+            &moderator_id,
+            Permission::ReportsResolve,
+        )
+        .await?;
+        self.load_pending_report(&report_id, &moderator_id).await?;
+
+        self.close_report(
+            &report_id,
+            ReportStatus::Dismissed,
+            &moderator_id,
+            None,
+            note,
+            actor_ip,
+        )
+        .await
+    }
+}
@@ -8,7 +8,7 @@ use crate::{config::TileSettings, error::AppResult};
 use domain::{
     color::ColorId,
     coords::{PixelCoord, TileCoord},
-    tile::Tile,
+    tile::{Tile, TileVersion},
 };
 
 pub struct PaintingResult {
@@ -16,6 +16,38 @@ pub struct PaintingResult {
     pub write_id: String,
 }
 
+pub struct RegionRollbackResult {
+    pub pixels_cleared: usize,
+    pub tiles_affected: usize,
+}
+
+pub struct PixelPromotionResult {
+    pub pixels_applied: usize,
+    pub tiles_affected: usize,
+}
+
+fn new_write_id(new_version: u64) -> String {
+    format!(
+        "{:x}-{:x}",
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+        new_version
+    )
+}
+
+// Mirrors a real paint response without touching the tile, so a shadow-banned
+// painter cannot tell their pixels were diverted.
+pub fn simulated_painting_result(current_version: TileVersion) -> PaintingResult {
+    let new_version = current_version.next().as_u64();
+
+    PaintingResult {
+        new_version,
+        write_id: new_write_id(new_version),
+    }
+}
+
 pub fn execute_batch_pixel_painting(
     tile_coord: TileCoord,
     pixels: &[(PixelCoord, ColorId)],
@@ -26,14 +58,7 @@ pub fn execute_batch_pixel_painting(
 
     let new_version = tile.paint_pixels_batch(pixels, pixel_size)?;
 
-    let write_id = format!(
-        "{:x}-{:x}",
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs(),
-        new_version
-    );
+    let write_id = new_write_id(new_version);
 
     debug!(
         "Painted {} pixels at tile {} -> v{} writeId={}",
@@ -1,42 +1,55 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 use tracing::{debug, instrument};
 
 use domain::{
     action::PaintAction,
     auth::UserId,
+    ban::Ban,
     color::ColorId,
-    coords::{GlobalCoord, PixelCoord, TileCoord},
+    coords::{GlobalCoord, GlobalRegion, PixelCoord, TileCoord},
     credits::CreditConfig,
     events::TileVersionEvent,
+    pixel_protection::PixelProtection,
+    rules::{PaintAttempt, PaintRule, PaintRuleSet, PainterFacts},
     tile::{PaletteBufferPool, Tile, TileVersion},
 };
 
 use crate::{
     config::TileSettings,
-    error::AppResult,
+    error::{AppError, AppResult},
     ports::{
         incoming::tiles::{
-            MetricsQueryUseCase, PaintPixelsUseCase, PixelHistoryQueryUseCase,
-            PixelInfoQueryUseCase, TilesQueryUseCase,
+            MetricsQueryUseCase, PaintPixelsUseCase, PaintRulesQueryUseCase,
+            PixelHistoryQueryUseCase, PixelInfoQueryUseCase, PixelPromotionUseCase,
+            RegionRollbackUseCase, TilesQueryUseCase,
         },
         outgoing::{
+            ban_store::DynBanStorePort,
             credit_store::DynCreditStorePort,
             events::DynEventsPort,
             image_codec::DynImageCodecPort,
             pixel_history_store::{DynPixelHistoryStorePort, PixelHistoryEntry, PixelInfo},
+            protected_region_store::DynProtectedRegionStorePort,
+            quarantine_store::DynQuarantineStorePort,
             task_spawn::DynTaskSpawnPort,
             tile_cache::DynTileCachePort,
             timeout::DynWebPTimeoutPort,
+            user_store::DynUserStorePort,
         },
     },
 };
 
 use super::{
-    commands::{PaintingResult, execute_batch_pixel_painting},
+    commands::{
+        PaintingResult, PixelPromotionResult, RegionRollbackResult, execute_batch_pixel_painting,
+        simulated_painting_result,
+    },
     gateway::TileGateway,
     util::validate_color_id,
 };
 
+const MAX_ROLLBACK_AREA: u64 = 1024 * 1024;
+
 pub type PaletteColorLookup = super::util::PaletteColorLookup;
 
 pub struct TileServiceDeps {
@@ -50,6 +63,12 @@ pub struct TileServiceDeps {
     pub pixel_history_store: DynPixelHistoryStorePort,
     pub credit_store: DynCreditStorePort,
     pub credit_config: CreditConfig,
+    pub ban_store: DynBanStorePort,
+    pub quarantine_store: DynQuarantineStorePort,
+    pub protected_region_store: DynProtectedRegionStorePort,
+    pub user_store: DynUserStorePort,
+    pub paint_rules: Arc<PaintRuleSet>,
+    pub pixel_protection: PixelProtection,
 }
 
 pub struct TileService {
@@ -59,6 +78,12 @@ pub struct TileService {
     pixel_history_store: DynPixelHistoryStorePort,
     credit_store: DynCreditStorePort,
     credit_config: CreditConfig,
+    ban_store: DynBanStorePort,
+    quarantine_store: DynQuarantineStorePort,
+    protected_region_store: DynProtectedRegionStorePort,
+    user_store: DynUserStorePort,
+    paint_rules: Arc<PaintRuleSet>,
+    pixel_protection: PixelProtection,
 }
 
 impl TileService {
@@ -79,6 +104,12 @@ impl TileService {
             pixel_history_store: deps.pixel_history_store,
             credit_store: deps.credit_store,
             credit_config: deps.credit_config,
+            ban_store: deps.ban_store,
+            quarantine_store: deps.quarantine_store,
+            protected_region_store: deps.protected_region_store,
+            user_store: deps.user_store,
+            paint_rules: deps.paint_rules,
+            pixel_protection: deps.pixel_protection,
         });
 
         Ok(service)
@@ -130,12 +161,155 @@ impl TileService {
             .await
     }
 
+    async fn quarantine_paint_actions(
+        &self,
+        ban: &Ban,
+        tile_coord: TileCoord,
+        paint_actions: &[PaintAction],
+    ) -> AppResult<PaintingResult> {
+        self.quarantine_store
+            .record_quarantined_pixels(&ban.id, paint_actions)
+            .await?;
+
+        let current_version = self.repository.get_tile_version(tile_coord).await?;
+
+        debug!(
+            "Quarantined {} pixels on tile {} from shadow-banned user",
+            paint_actions.len(),
+            tile_coord
+        );
+
+        Ok(simulated_painting_result(current_version))
+    }
+
+    // The latest paint of each target pixel, loaded only when a rule or the
+    // fresh pixel protection looks at it.
+    async fn load_last_paints(
+        &self,
+        global_pixels: &[(GlobalCoord, ColorId)],
+    ) -> AppResult<Vec<PaintAction>> {
+        if !self.paint_rules.needs_last_paints() && !self.pixel_protection.is_enabled() {
+            return Ok(Vec::new());
+        }
+
+        let coords: Vec<GlobalCoord> = global_pixels.iter().map(|(coord, _)| *coord).collect();
+        self.pixel_history_store
+            .get_latest_paint_actions(&coords)
+            .await
+    }
+
+    // Only the painter facts the configured rules ask for are loaded.
+    async fn enforce_paint_rules(
+        &self,
+        user_id: &UserId,
+        global_pixels: &[(GlobalCoord, ColorId)],
+        last_paints: &[PaintAction],
+    ) -> AppResult<()> {
+        if self.paint_rules.is_empty() {
+            return Ok(());
+        }
+
+        let user = self
+            .user_store
+            .find_user_by_id(*user_id.as_uuid())
+            .await?
+            .ok_or(AppError::Unauthorized)?;
+        let account_created_at = if self.paint_rules.needs_account_age() {
+            self.user_store
+                .find_user_created_at(*user_id.as_uuid())
+                .await?
+        } else {
+            None
+        };
+        let painter = PainterFacts {
+            email_verified: user.email_verified_at.is_some(),
+            account_created_at,
+            role_names: user.roles.into_iter().map(|role| role.name).collect(),
+        };
+
+        self.paint_rules
+            .evaluate(&PaintAttempt {
+                painter: &painter,
+                pixels: global_pixels,
+                last_paints,
+                now: time::OffsetDateTime::now_utc(),
+            })
+            .map_err(|violation| AppError::RuleViolation {
+                rule: violation.rule_kind().as_str().to_string(),
+                message: violation.to_string(),
+            })
+    }
+
+    fn overwrite_surcharge(&self, user_id: &UserId, last_paints: &[PaintAction]) -> AppResult<i32> {
+        let surcharge = self
+            .pixel_protection
+            .overwrite_surcharge(user_id, last_paints, time::OffsetDateTime::now_utc())
+            .map_err(|e| AppError::PixelProtected {
+                message: e.to_string(),
+            })?;
+
+        Ok(i32::try_from(surcharge).unwrap_or(i32::MAX))
+    }
+
+    // Runs before credits are spent so a batch touching a protected pixel costs nothing.
+    async fn enforce_protected_regions(
+        &self,
+        user_id: &UserId,
+        tile_coord: TileCoord,
+        pixels: &[(PixelCoord, ColorId)],
+    ) -> AppResult<()> {
+        let coords: Vec<GlobalCoord> = pixels
+            .iter()
+            .map(|(pixel_coord, _)| {
+                GlobalCoord::from_tile_and_pixel(tile_coord, *pixel_coord, self.config.tile_size)
+            })
+            .collect();
+        let Some(bounds) = GlobalRegion::bounding(&coords) else {
+            return Ok(());
+        };
+
+        let regions = self
+            .protected_region_store
+            .list_protected_regions_in(bounds)
+            .await?;
+        if regions.is_empty() {
+            return Ok(());
+        }
+
+        let role_names: Vec<String> = if regions
+            .iter()
+            .any(|region| region.restriction.requires_roles())
+        {
+            self.user_store
+                .find_user_by_id(*user_id.as_uuid())
+                .await?
+                .map(|user| user.roles.into_iter().map(|role| role.name).collect())
+                .unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+
+        let now = time::OffsetDateTime::now_utc();
+        for coord in &coords {
+            for region in &regions {
+                region.check_paint(*coord, &role_names, now).map_err(|e| {
+                    AppError::ProtectedRegion {
+                        message: e.to_string(),
+                    }
+                })?;
+            }
+        }
+
+        Ok(())
+    }
+
     #[instrument(skip(self, pixels))]
     pub async fn paint_pixels_batch(
         &self,
         user_id: UserId,
         tile_coord: TileCoord,
         pixels: &[(PixelCoord, ColorId)],
+        automated: bool,
     ) -> AppResult<PaintingResult> {
         tile_coord.validate_bounds()?;
 
@@ -144,12 +318,60 @@ impl TileService {
             validate_color_id(color_id.id(), &self.config.color_palette_config)?;
         }
 
+        let global_pixels: Vec<(GlobalCoord, ColorId)> = pixels
+            .iter()
+            .map(|(pixel_coord, color_id)| {
+                (
+                    GlobalCoord::from_tile_and_pixel(
+                        tile_coord,
+                        *pixel_coord,
+                        self.config.tile_size,
+                    ),
+                    *color_id,
+                )
+            })
+            .collect();
+        let last_paints = self.load_last_paints(&global_pixels).await?;
+
+        self.enforce_paint_rules(&user_id, &global_pixels, &last_paints)
+            .await?;
+        self.enforce_protected_regions(&user_id, tile_coord, pixels)
+            .await?;
+        let overwrite_surcharge = self.overwrite_surcharge(&user_id, &last_paints)?;
+
         #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
         let pixel_count = pixels.len() as i32;
         self.credit_store
-            .spend_user_credits(&user_id, pixel_count, &self.credit_config)
+            .spend_user_credits(
+                &user_id,
+                pixel_count.saturating_add(overwrite_surcharge),
+                &self.credit_config,
+            )
             .await?;
 
+        let paint_actions: Vec<PaintAction> = pixels
+            .iter()
+            .map(|(pixel_coord, color_id)| {
+                PaintAction::from_tile_and_pixel(
+                    user_id.clone(),
+                    tile_coord,
+                    *pixel_coord,
+                    *color_id,
+                    time::OffsetDateTime::now_utc(),
+                    self.config.tile_size,
+                    automated,
+                )
+            })
+            .collect();
+
+        if let Some(ban) = self.ban_store.get_active_ban_by_user_id(&user_id).await?
+            && ban.is_active_shadow()
+        {
+            return self
+                .quarantine_paint_actions(&ban, tile_coord, &paint_actions)
+                .await;
+        }
+
         debug!("Painting {} pixels on tile {}", pixels.len(), tile_coord);
 
         let tile_arc = self.load_tile_for_painting(tile_coord).await?;
@@ -167,20 +389,6 @@ impl TileService {
         self.update_cache_optimistically(tile_coord, painting_result.new_version, &tile_arc)
             .await?;
 
-        let paint_actions: Vec<PaintAction> = pixels
-            .iter()
-            .map(|(pixel_coord, color_id)| {
-                PaintAction::from_tile_and_pixel(
-                    user_id.clone(),
-                    tile_coord,
-                    *pixel_coord,
-                    *color_id,
-                    time::OffsetDateTime::now_utc(),
-                    self.config.tile_size,
-                )
-            })
-            .collect();
-
         self.pixel_history_store
             .record_paint_actions(&paint_actions)
             .await?;
@@ -200,6 +408,127 @@ impl TileService {
         Ok(painting_result)
     }
 
+    #[instrument(skip(self))]
+    pub async fn rollback_region(
+        &self,
+        region: GlobalRegion,
+        painted_since: Option<time::OffsetDateTime>,
+        painted_by: Option<UserId>,
+    ) -> AppResult<RegionRollbackResult> {
+        region.validate_max_area(MAX_ROLLBACK_AREA)?;
+
+        let cleared = self
+            .pixel_history_store
+            .delete_pixels_in_region(region, painted_since, painted_by.as_ref())
+            .await?;
+
+        let tile_size = self.config.tile_size;
+        let transparent = ColorId::new(self.config.transparency_color_id);
+        let mut pixels_by_tile: HashMap<TileCoord, Vec<(PixelCoord, ColorId)>> = HashMap::new();
+        for coord in &cleared {
+            pixels_by_tile
+                .entry(coord.to_tile_coord(tile_size))
+                .or_default()
+                .push((coord.to_pixel_coord(tile_size), transparent));
+        }
+
+        for (tile_coord, pixels) in &pixels_by_tile {
+            let tile_arc = self.load_tile_for_painting(*tile_coord).await?;
+            let painting_result =
+                execute_batch_pixel_painting(*tile_coord, pixels, &tile_arc, &self.config)?;
+
+            self.store_palette_in_redis(*tile_coord, painting_result.new_version, &tile_arc)
+                .await?;
+
+            self.update_cache_optimistically(*tile_coord, painting_result.new_version, &tile_arc)
+                .await?;
+
+            self.events_port
+                .broadcast_tile_version(TileVersionEvent {
+                    coord: *tile_coord,
+                    version: painting_result.new_version,
+                })
+                .ok();
+        }
+
+        debug!(
+            "Rolled back region {}: {} pixels across {} tiles",
+            region,
+            cleared.len(),
+            pixels_by_tile.len()
+        );
+
+        Ok(RegionRollbackResult {
+            pixels_cleared: cleared.len(),
+            tiles_affected: pixels_by_tile.len(),
+        })
+    }
+
+    // Promoted pixels land on the canvas now rather than when they were painted,
+    // so they cover whatever was painted over the same spots in the meantime.
+    #[instrument(skip(self, paint_actions))]
+    pub async fn promote_paint_actions(
+        &self,
+        paint_actions: &[PaintAction],
+    ) -> AppResult<PixelPromotionResult> {
+        let tile_size = self.config.tile_size;
+        let promoted_at = time::OffsetDateTime::now_utc();
+
+        let mut latest_by_coord: HashMap<GlobalCoord, PaintAction> = HashMap::new();
+        for action in paint_actions {
+            latest_by_coord.insert(
+                action.global_coord,
+                PaintAction {
+                    timestamp: promoted_at,
+                    ..action.clone()
+                },
+            );
+        }
+        let promoted: Vec<PaintAction> = latest_by_coord.into_values().collect();
+
+        self.pixel_history_store
+            .record_paint_actions(&promoted)
+            .await?;
+
+        let mut pixels_by_tile: HashMap<TileCoord, Vec<(PixelCoord, ColorId)>> = HashMap::new();
+        for action in &promoted {
+            pixels_by_tile
+                .entry(action.tile_coord(tile_size))
+                .or_default()
+                .push((action.pixel_coord(tile_size), action.color_id));
+        }
+
+        for (tile_coord, pixels) in &pixels_by_tile {
+            let tile_arc = self.load_tile_for_painting(*tile_coord).await?;
+            let painting_result =
+                execute_batch_pixel_painting(*tile_coord, pixels, &tile_arc, &self.config)?;
+
+            self.store_palette_in_redis(*tile_coord, painting_result.new_version, &tile_arc)
+                .await?;
+
+            self.update_cache_optimistically(*tile_coord, painting_result.new_version, &tile_arc)
+                .await?;
+
+            self.events_port
+                .broadcast_tile_version(TileVersionEvent {
+                    coord: *tile_coord,
+                    version: painting_result.new_version,
+                })
+                .ok();
+        }
+
+        debug!(
+            "Promoted {} quarantined pixels across {} tiles",
+            promoted.len(),
+            pixels_by_tile.len()
+        );
+
+        Ok(PixelPromotionResult {
+            pixels_applied: promoted.len(),
+            tiles_affected: pixels_by_tile.len(),
+        })
+    }
+
     #[instrument(skip(self))]
     pub async fn get_tile_version(&self, coord: TileCoord) -> AppResult<TileVersion> {
         self.repository.get_tile_version(coord).await
@@ -236,8 +565,17 @@ impl PaintPixelsUseCase for TileService {
         user_id: UserId,
         tile: TileCoord,
         pixels: &[(PixelCoord, ColorId)],
+        automated: bool,
     ) -> AppResult<PaintingResult> {
-        self.paint_pixels_batch(user_id, tile, pixels).await
+        self.paint_pixels_batch(user_id, tile, pixels, automated)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl PaintRulesQueryUseCase for TileService {
+    async fn list_paint_rules(&self) -> AppResult<Vec<PaintRule>> {
+        Ok(self.paint_rules.rules().to_vec())
     }
 }
 
@@ -260,6 +598,36 @@ impl PixelHistoryQueryUseCase for TileService {
 impl PixelInfoQueryUseCase for TileService {
     async fn get_pixel_info(&self, coord: GlobalCoord) -> AppResult<Option<PixelInfo>> {
         coord.validate()?;
-        self.pixel_history_store.get_pixel_info(coord).await
+        let pixel_info = self.pixel_history_store.get_pixel_info(coord).await?;
+
+        Ok(pixel_info.map(|info| PixelInfo {
+            protection_remaining: self
+                .pixel_protection
+                .remaining(info.timestamp, time::OffsetDateTime::now_utc()),
+            ..info
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl RegionRollbackUseCase for TileService {
+    async fn rollback_region(
+        &self,
+        region: GlobalRegion,
+        painted_since: Option<time::OffsetDateTime>,
+        painted_by: Option<UserId>,
+    ) -> AppResult<RegionRollbackResult> {
+        self.rollback_region(region, painted_since, painted_by)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl PixelPromotionUseCase for TileService {
+    async fn promote_paint_actions(
+        &self,
+        paint_actions: &[PaintAction],
+    ) -> AppResult<PixelPromotionResult> {
+        self.promote_paint_actions(paint_actions).await
     }
 }
@@ -0,0 +1,33 @@
+use time::OffsetDateTime;
+
+use crate::action::PaintAction;
+use crate::auth::{Identity, UserPublic};
+use crate::ban::Ban;
+use crate::session::UserSession;
+
+// Everything stored about a user, gathered for a personal data export.
+#[derive(Debug, Clone)]
+pub struct AccountDataExport {
+    pub exported_at: OffsetDateTime,
+    pub user: UserPublic,
+    pub created_at: Option<OffsetDateTime>,
+    pub deletion_scheduled_at: Option<OffsetDateTime>,
+    pub identities: Vec<Identity>,
+    pub bans: Vec<Ban>,
+    pub paints: Vec<PaintAction>,
+    pub sessions: Vec<UserSession>,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum AccountError {
+    #[error("Account deletion is already scheduled")]
+    DeletionAlreadyScheduled,
+    #[error("Account deletion is not scheduled")]
+    DeletionNotScheduled,
+    #[error("Current password is incorrect")]
+    IncorrectPassword,
+    #[error("Account cannot be deleted while it is banned")]
+    Banned,
+    #[error("The last admin cannot delete their account")]
+    LastAdmin,
+}
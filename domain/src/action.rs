@@ -9,6 +9,8 @@ pub struct PaintAction {
     pub global_coord: GlobalCoord,
     pub color_id: ColorId,
     pub timestamp: OffsetDateTime,
+    // Painted through a personal API token rather than a browser session.
+    pub automated: bool,
 }
 
 impl PaintAction {
@@ -30,12 +32,14 @@ impl PaintAction {
         color_id: ColorId,
         timestamp: OffsetDateTime,
         tile_size: usize,
+        automated: bool,
     ) -> Self {
         Self {
             user_id,
             global_coord: GlobalCoord::from_tile_and_pixel(tile_coord, pixel_coord, tile_size),
             color_id,
             timestamp,
+            automated,
         }
     }
 }
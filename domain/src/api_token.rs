@@ -0,0 +1,97 @@
+use std::{fmt, str::FromStr};
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+// Prepended to every token secret so leaked tokens are easy to recognise in logs and
+// secret scanners.
+pub const API_TOKEN_PREFIX: &str = "fpat_";
+pub const MAX_API_TOKEN_NAME_LENGTH: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ApiTokenScope {
+    Read,
+    Paint,
+    Admin,
+}
+
+impl ApiTokenScope {
+    pub const ALL: [ApiTokenScope; 3] = [
+        ApiTokenScope::Read,
+        ApiTokenScope::Paint,
+        ApiTokenScope::Admin,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiTokenScope::Read => "read",
+            ApiTokenScope::Paint => "paint",
+            ApiTokenScope::Admin => "admin",
+        }
+    }
+}
+
+impl fmt::Display for ApiTokenScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ApiTokenScope {
+    type Err = ApiTokenError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        ApiTokenScope::ALL
+            .into_iter()
+            .find(|scope| scope.as_str() == value)
+            .ok_or_else(|| ApiTokenError::UnknownScope(value.to_string()))
+    }
+}
+
+// A personal access token. Only a hash of the secret is stored, so the secret itself is
+// shown once when the token is created.
+#[derive(Debug, Clone)]
+pub struct ApiToken {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub scopes: Vec<ApiTokenScope>,
+    pub created_at: OffsetDateTime,
+    pub last_used_at: Option<OffsetDateTime>,
+    pub expires_at: Option<OffsetDateTime>,
+    pub revoked_at: Option<OffsetDateTime>,
+}
+
+impl ApiToken {
+    pub fn is_active(&self, now: OffsetDateTime) -> bool {
+        self.revoked_at.is_none() && self.expires_at.is_none_or(|expires_at| expires_at > now)
+    }
+
+    // Every scope includes read access.
+    pub fn grants(&self, scope: ApiTokenScope) -> bool {
+        self.scopes.contains(&scope) || (scope == ApiTokenScope::Read && !self.scopes.is_empty())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CreatedApiToken {
+    pub token: ApiToken,
+    pub secret: String,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ApiTokenError {
+    #[error("API token not found")]
+    TokenNotFound,
+    #[error("API token name must be between 1 and 64 characters")]
+    InvalidName,
+    #[error("Unknown API token scope '{0}'")]
+    UnknownScope(String),
+    #[error("An API token needs at least one scope")]
+    NoScopes,
+    #[error("Only users holding a role with permissions can create admin tokens")]
+    AdminScopeNotAllowed,
+    #[error("The API token lifetime exceeds the allowed maximum")]
+    LifetimeTooLong,
+    #[error("The maximum number of API tokens has been reached")]
+    TooManyTokens,
+}
@@ -0,0 +1,227 @@
+use std::{fmt, net::IpAddr, str::FromStr};
+
+use uuid::Uuid;
+
+use crate::auth::UserId;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AuditEntryId(pub Uuid);
+
+impl AuditEntryId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    pub fn from_uuid(id: Uuid) -> Self {
+        Self(id)
+    }
+
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for AuditEntryId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AuditAction {
+    BanCreated,
+    BanRevoked,
+    BanExpired,
+    IpBanCreated,
+    IpBanRevoked,
+    RegionRolledBack,
+    ReportResolved,
+    ReportDismissed,
+    RoleCreated,
+    RoleUpdated,
+    RoleDeleted,
+    RoleAssigned,
+    RoleRevoked,
+    QuarantineDiscarded,
+    QuarantinePromoted,
+    ProtectedRegionCreated,
+    ProtectedRegionUpdated,
+    ProtectedRegionDeleted,
+    AccountDeleted,
+    AccountLocked,
+    AccountUnlocked,
+}
+
+impl AuditAction {
+    pub const ALL: [AuditAction; 21] = [
+        AuditAction::BanCreated,
+        AuditAction::BanRevoked,
+        AuditAction::BanExpired,
+        AuditAction::IpBanCreated,
+        AuditAction::IpBanRevoked,
+        AuditAction::RegionRolledBack,
+        AuditAction::ReportResolved,
+        AuditAction::ReportDismissed,
+        AuditAction::RoleCreated,
+        AuditAction::RoleUpdated,
+        AuditAction::RoleDeleted,
+        AuditAction::RoleAssigned,
+        AuditAction::RoleRevoked,
+        AuditAction::QuarantineDiscarded,
+        AuditAction::QuarantinePromoted,
+        AuditAction::ProtectedRegionCreated,
+        AuditAction::ProtectedRegionUpdated,
+        AuditAction::ProtectedRegionDeleted,
+        AuditAction::AccountDeleted,
+        AuditAction::AccountLocked,
+        AuditAction::AccountUnlocked,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::BanCreated => "ban.created",
+            AuditAction::BanRevoked => "ban.revoked",
+            AuditAction::BanExpired => "ban.expired",
+            AuditAction::IpBanCreated => "ip_ban.created",
+            AuditAction::IpBanRevoked => "ip_ban.revoked",
+            AuditAction::RegionRolledBack => "region.rolled_back",
+            AuditAction::ReportResolved => "report.resolved",
+            AuditAction::ReportDismissed => "report.dismissed",
+            AuditAction::RoleCreated => "role.created",
+            AuditAction::RoleUpdated => "role.updated",
+            AuditAction::RoleDeleted => "role.deleted",
+            AuditAction::RoleAssigned => "role.assigned",
+            AuditAction::RoleRevoked => "role.revoked",
+            AuditAction::QuarantineDiscarded => "quarantine.discarded",
+            AuditAction::QuarantinePromoted => "quarantine.promoted",
+            AuditAction::ProtectedRegionCreated => "protected_region.created",
+            AuditAction::ProtectedRegionUpdated => "protected_region.updated",
+            AuditAction::ProtectedRegionDeleted => "protected_region.deleted",
+            AuditAction::AccountDeleted => "account.deleted",
+            AuditAction::AccountLocked => "account.locked",
+            AuditAction::AccountUnlocked => "account.unlocked",
+        }
+    }
+}
+
+impl FromStr for AuditAction {
+    type Err = AuditError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AuditAction::ALL
+            .into_iter()
+            .find(|action| action.as_str() == s)
+            .ok_or_else(|| AuditError::UnknownAction(s.to_string()))
+    }
+}
+
+impl fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AuditTargetType {
+    User,
+    Ban,
+    IpBan,
+    Region,
+    Report,
+    Role,
+    ProtectedRegion,
+}
+
+impl AuditTargetType {
+    pub const ALL: [AuditTargetType; 7] = [
+        AuditTargetType::User,
+        AuditTargetType::Ban,
+        AuditTargetType::IpBan,
+        AuditTargetType::Region,
+        AuditTargetType::Report,
+        AuditTargetType::Role,
+        AuditTargetType::ProtectedRegion,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditTargetType::User => "user",
+            AuditTargetType::Ban => "ban",
+            AuditTargetType::IpBan => "ip_ban",
+            AuditTargetType::Region => "region",
+            AuditTargetType::Report => "report",
+            AuditTargetType::Role => "role",
+            AuditTargetType::ProtectedRegion => "protected_region",
+        }
+    }
+}
+
+impl FromStr for AuditTargetType {
+    type Err = AuditError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AuditTargetType::ALL
+            .into_iter()
+            .find(|target_type| target_type.as_str() == s)
+            .ok_or_else(|| AuditError::UnknownTargetType(s.to_string()))
+    }
+}
+
+impl fmt::Display for AuditTargetType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum AuditError {
+    #[error("Unknown audit action: {0}")]
+    UnknownAction(String),
+    #[error("Unknown audit target type: {0}")]
+    UnknownTargetType(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    pub id: AuditEntryId,
+    pub actor_user_id: Option<UserId>,
+    pub action: AuditAction,
+    pub target_type: AuditTargetType,
+    pub target_id: Option<String>,
+    pub parameters: serde_json::Value,
+    pub ip_address: Option<IpAddr>,
+    pub created_at: time::OffsetDateTime,
+}
+
+impl AuditEntry {
+    // A `None` actor marks an action taken by the system itself, e.g. a scheduled job.
+    pub fn new(
+        actor_user_id: Option<UserId>,
+        action: AuditAction,
+        target_type: AuditTargetType,
+        target_id: Option<String>,
+    ) -> Self {
+        Self {
+            id: AuditEntryId::new(),
+            actor_user_id,
+            action,
+            target_type,
+            target_id,
+            parameters: serde_json::Value::Object(serde_json::Map::new()),
+            ip_address: None,
+            created_at: time::OffsetDateTime::now_utc(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_parameters(mut self, parameters: serde_json::Value) -> Self {
+        self.parameters = parameters;
+        self
+    }
+
+    #[must_use]
+    pub fn with_ip_address(mut self, ip_address: Option<IpAddr>) -> Self {
+        self.ip_address = ip_address;
+        self
+    }
+}
@@ -1,6 +1,20 @@
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 use uuid::Uuid;
 
+use crate::ban::Ban;
+
+// Accounts created through a provider that shares no email address get a placeholder on this
+// reserved, non-routable domain. Nobody can register or switch to an address on it.
+pub const PLACEHOLDER_EMAIL_DOMAIN: &str = "social.invalid";
+
+pub fn is_placeholder_email(email: &str) -> bool {
+    email.rsplit_once('@').is_some_and(|(_, domain)| {
+        domain
+            .trim_end_matches('.')
+            .eq_ignore_ascii_case(PLACEHOLDER_EMAIL_DOMAIN)
+    })
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct UserId(pub Uuid);
 
@@ -19,6 +33,11 @@ impl UserId {
     pub fn as_uuid(&self) -> &Uuid {
         &self.0
     }
+
+    // Derived from the user id, so the address cannot be taken before the account exists.
+    pub fn placeholder_email(&self) -> String {
+        format!("{}@{}", self.0, PLACEHOLDER_EMAIL_DOMAIN)
+    }
 }
 
 impl RoleId {
@@ -50,12 +69,14 @@ impl Default for RoleId {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum RoleType {
     Admin,
+    Moderator,
 }
 
 impl RoleType {
     pub fn as_str(&self) -> &'static str {
         match self {
             RoleType::Admin => "admin",
+            RoleType::Moderator => "moderator",
         }
     }
 }
@@ -66,16 +87,82 @@ impl FromStr for RoleType {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "admin" => Ok(RoleType::Admin),
+            "moderator" => Ok(RoleType::Moderator),
             _ => Err(()),
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Permission {
+    #[serde(rename = "ban:create")]
+    BanCreate,
+    #[serde(rename = "pixels:rollback")]
+    PixelsRollback,
+    #[serde(rename = "roles:assign")]
+    RolesAssign,
+    #[serde(rename = "reports:resolve")]
+    ReportsResolve,
+    #[serde(rename = "audit:read")]
+    AuditRead,
+    #[serde(rename = "users:read")]
+    UsersRead,
+    #[serde(rename = "regions:manage")]
+    RegionsManage,
+    #[serde(rename = "users:manage")]
+    UsersManage,
+}
+
+impl Permission {
+    pub const ALL: [Permission; 8] = [
+        Permission::BanCreate,
+        Permission::PixelsRollback,
+        Permission::RolesAssign,
+        Permission::ReportsResolve,
+        Permission::AuditRead,
+        Permission::UsersRead,
+        Permission::RegionsManage,
+        Permission::UsersManage,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Permission::BanCreate => "ban:create",
+            Permission::PixelsRollback => "pixels:rollback",
+            Permission::RolesAssign => "roles:assign",
+            Permission::ReportsResolve => "reports:resolve",
+            Permission::AuditRead => "audit:read",
+            Permission::UsersRead => "users:read",
+            Permission::RegionsManage => "regions:manage",
+            Permission::UsersManage => "users:manage",
+        }
+    }
+}
+
+impl FromStr for Permission {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Permission::ALL
+            .into_iter()
+            .find(|permission| permission.as_str() == s)
+            .ok_or(())
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Role {
     pub id: RoleId,
     pub name: String,
     pub description: Option<String>,
+    #[serde(default)]
+    pub permissions: Vec<Permission>,
     pub created_at: time::OffsetDateTime,
     pub updated_at: time::OffsetDateTime,
 }
@@ -84,6 +171,53 @@ impl Role {
     pub fn role_type(&self) -> Option<RoleType> {
         self.name.parse().ok()
     }
+
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.permissions.contains(&permission)
+    }
+
+    pub fn is_built_in(&self) -> bool {
+        self.role_type().is_some()
+    }
+
+    pub fn validate_name(name: &str) -> Result<(), RoleError> {
+        let valid_length = (2..=32).contains(&name.len());
+        let valid_chars = name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-');
+
+        if valid_length && valid_chars {
+            Ok(())
+        } else {
+            Err(RoleError::InvalidRoleName)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RoleMember {
+    pub user_id: UserId,
+    pub username: String,
+    pub assigned_at: time::OffsetDateTime,
+    pub assigned_by: Option<UserId>,
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum RoleError {
+    #[error("Role not found")]
+    RoleNotFound,
+    #[error("A role with this name already exists")]
+    RoleNameTaken,
+    #[error("Role names must be 2-32 characters of lowercase letters, digits, '_' or '-'")]
+    InvalidRoleName,
+    #[error("Unknown permission")]
+    InvalidPermission,
+    #[error("Built-in roles cannot be deleted and the admin role cannot be modified")]
+    BuiltInRoleProtected,
+    #[error("User does not have this role")]
+    RoleNotAssigned,
+    #[error("Cannot remove the last admin")]
+    LastAdmin,
 }
 
 #[derive(Debug, Clone)]
@@ -102,6 +236,7 @@ pub struct UserPublic {
     pub email_verified_at: Option<time::OffsetDateTime>,
     pub available_charges: i32,
     pub charges_updated_at: time::OffsetDateTime,
+    pub session_version: i32,
     pub roles: Vec<Role>,
 }
 
@@ -119,6 +254,38 @@ impl UserPublic {
     pub fn is_admin(&self) -> bool {
         self.has_role_type(RoleType::Admin)
     }
+
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.roles
+            .iter()
+            .any(|role| role.has_permission(permission))
+    }
+
+    // Any role that grants a permission counts, not only the built-in admin role.
+    pub fn is_privileged(&self) -> bool {
+        self.is_admin() || self.roles.iter().any(|role| !role.permissions.is_empty())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UserSummary {
+    pub id: UserId,
+    pub email: String,
+    pub username: String,
+    pub email_verified_at: Option<time::OffsetDateTime>,
+    pub created_at: time::OffsetDateTime,
+    pub role_names: Vec<String>,
+    pub banned: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct UserDetails {
+    pub user: UserPublic,
+    pub created_at: time::OffsetDateTime,
+    pub identities: Vec<Identity>,
+    pub active_ban: Option<Ban>,
+    pub pixels_on_canvas: u64,
+    pub pixels_quarantined: u64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -129,6 +296,8 @@ pub enum AuthProvider {
 
 #[derive(Debug, Clone)]
 pub struct Identity {
+    pub id: Uuid,
     pub provider: String,
     pub provider_user_id: String,
+    pub created_at: time::OffsetDateTime,
 }
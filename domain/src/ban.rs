@@ -1,5 +1,8 @@
+use ipnet::IpNet;
+use std::net::IpAddr;
 use uuid::Uuid;
 
+use crate::action::PaintAction;
 use crate::auth::UserId;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -34,6 +37,10 @@ pub struct Ban {
     pub banned_at: time::OffsetDateTime,
     pub expires_at: Option<time::OffsetDateTime>,
     pub created_at: time::OffsetDateTime,
+    pub revoked_at: Option<time::OffsetDateTime>,
+    pub revoked_by_user_id: Option<UserId>,
+    pub revoke_reason: Option<String>,
+    pub shadow: bool,
 }
 
 impl Ban {
@@ -42,6 +49,7 @@ impl Ban {
         banned_by_user_id: Option<UserId>,
         reason: String,
         expires_at: Option<time::OffsetDateTime>,
+        shadow: bool,
     ) -> Self {
         let now = time::OffsetDateTime::now_utc();
         Self {
@@ -52,6 +60,147 @@ impl Ban {
             banned_at: now,
             expires_at,
             created_at: now,
+            revoked_at: None,
+            revoked_by_user_id: None,
+            revoke_reason: None,
+            shadow,
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        if self.is_revoked() {
+            return false;
+        }
+
+        match self.expires_at {
+            Some(expires_at) => expires_at > time::OffsetDateTime::now_utc(),
+            None => true,
+        }
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+
+    pub fn is_permanent(&self) -> bool {
+        self.expires_at.is_none()
+    }
+
+    // Shadow bans are hidden from the banned user; only their paints are diverted.
+    pub fn blocks_login(&self) -> bool {
+        self.is_active() && !self.shadow
+    }
+
+    pub fn is_active_shadow(&self) -> bool {
+        self.is_active() && self.shadow
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct QuarantinedPixel {
+    pub id: i64,
+    pub ban_id: BanId,
+    pub action: PaintAction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BanEscalationPolicy {
+    pub multiplier: u32,
+    pub permanent_after_prior_bans: Option<u32>,
+}
+
+impl BanEscalationPolicy {
+    pub fn new(multiplier: u32, permanent_after_prior_bans: Option<u32>) -> Self {
+        Self {
+            multiplier,
+            permanent_after_prior_bans,
+        }
+    }
+
+    pub fn disabled() -> Self {
+        Self::new(1, None)
+    }
+
+    // Each prior ban multiplies the requested duration; `None` means permanent.
+    pub fn escalate(
+        &self,
+        banned_at: time::OffsetDateTime,
+        expires_at: Option<time::OffsetDateTime>,
+        prior_bans: u32,
+    ) -> Option<time::OffsetDateTime> {
+        let expires_at = expires_at?;
+        if prior_bans == 0 {
+            return Some(expires_at);
+        }
+
+        if let Some(threshold) = self.permanent_after_prior_bans
+            && prior_bans >= threshold
+        {
+            return None;
+        }
+
+        let factor = i32::try_from(self.multiplier.saturating_pow(prior_bans)).unwrap_or(i32::MAX);
+        let escalated_duration = (expires_at - banned_at).saturating_mul(factor);
+        banned_at.checked_add(escalated_duration)
+    }
+}
+
+impl Default for BanEscalationPolicy {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IpBanId(pub Uuid);
+
+impl IpBanId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    pub fn from_uuid(id: Uuid) -> Self {
+        Self(id)
+    }
+
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for IpBanId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IpBan {
+    pub id: IpBanId,
+    pub network: IpNet,
+    pub banned_by_user_id: Option<UserId>,
+    pub reason: String,
+    pub banned_at: time::OffsetDateTime,
+    pub expires_at: Option<time::OffsetDateTime>,
+    pub created_at: time::OffsetDateTime,
+}
+
+impl IpBan {
+    pub fn new(
+        network: IpNet,
+        banned_by_user_id: Option<UserId>,
+        reason: String,
+        expires_at: Option<time::OffsetDateTime>,
+    ) -> Self {
+        let now = time::OffsetDateTime::now_utc();
+        Self {
+            id: IpBanId::new(),
+            network: network.trunc(),
+            banned_by_user_id,
+            reason,
+            banned_at: now,
+            expires_at,
+            created_at: now,
         }
     }
 
@@ -65,6 +214,10 @@ impl Ban {
     pub fn is_permanent(&self) -> bool {
         self.expires_at.is_none()
     }
+
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        self.network.contains(&ip)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -86,4 +239,10 @@ pub enum BanError {
     InsufficientPermissions,
     #[error("Invalid ban duration")]
     InvalidDuration,
+    #[error("Network is already banned")]
+    NetworkAlreadyBanned,
+    #[error("Invalid IP address or network")]
+    InvalidNetwork,
+    #[error("User has no quarantined pixels")]
+    NoQuarantinedPixels,
 }
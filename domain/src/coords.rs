@@ -167,3 +167,113 @@ impl fmt::Display for GlobalCoord {
         write!(f, "({}, {})", self.x, self.y)
     }
 }
+
+#[cfg_attr(feature = "docs", derive(ToSchema))]
+#[cfg_attr(feature = "docs", schema(
+    description = "Rectangular region of the canvas in global pixel coordinates. Both corners are inclusive.",
+    example = json!({"min_x": 1024, "min_y": 768, "max_x": 1087, "max_y": 831})
+))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GlobalRegion {
+    #[cfg_attr(feature = "docs", schema(example = 1024))]
+    pub min_x: i32,
+    #[cfg_attr(feature = "docs", schema(example = 768))]
+    pub min_y: i32,
+    #[cfg_attr(feature = "docs", schema(example = 1087))]
+    pub max_x: i32,
+    #[cfg_attr(feature = "docs", schema(example = 831))]
+    pub max_y: i32,
+}
+
+impl GlobalRegion {
+    #[must_use]
+    pub fn new(x1: i32, y1: i32, x2: i32, y2: i32) -> Self {
+        Self {
+            min_x: x1.min(x2),
+            min_y: y1.min(y2),
+            max_x: x1.max(x2),
+            max_y: y1.max(y2),
+        }
+    }
+
+    #[must_use]
+    pub fn single_pixel(coord: GlobalCoord) -> Self {
+        Self::new(coord.x, coord.y, coord.x, coord.y)
+    }
+
+    // Smallest region containing every coordinate, or `None` when there are none.
+    #[must_use]
+    pub fn bounding(coords: &[GlobalCoord]) -> Option<Self> {
+        let first = Self::single_pixel(*coords.first()?);
+        Some(coords.iter().fold(first, |bounds, coord| {
+            Self::new(
+                bounds.min_x.min(coord.x),
+                bounds.min_y.min(coord.y),
+                bounds.max_x.max(coord.x),
+                bounds.max_y.max(coord.y),
+            )
+        }))
+    }
+
+    pub fn from_origin_and_size(x: i32, y: i32, width: u32, height: u32) -> DomainResult<Self> {
+        if width == 0 || height == 0 {
+            return Err(DomainError::InvalidCoordinates(
+                "Region width and height must be greater than 0".to_string(),
+            ));
+        }
+
+        let max_x = i32::try_from(width - 1)
+            .ok()
+            .and_then(|offset| x.checked_add(offset));
+        let max_y = i32::try_from(height - 1)
+            .ok()
+            .and_then(|offset| y.checked_add(offset));
+
+        match (max_x, max_y) {
+            (Some(max_x), Some(max_y)) => Ok(Self::new(x, y, max_x, max_y)),
+            _ => Err(DomainError::InvalidCoordinates(
+                "Region exceeds the canvas coordinate range".to_string(),
+            )),
+        }
+    }
+
+    #[must_use]
+    pub fn width(&self) -> u64 {
+        u64::from(self.max_x.abs_diff(self.min_x)) + 1
+    }
+
+    #[must_use]
+    pub fn height(&self) -> u64 {
+        u64::from(self.max_y.abs_diff(self.min_y)) + 1
+    }
+
+    #[must_use]
+    pub fn area(&self) -> u64 {
+        self.width() * self.height()
+    }
+
+    #[must_use]
+    pub fn contains(&self, coord: GlobalCoord) -> bool {
+        (self.min_x..=self.max_x).contains(&coord.x) && (self.min_y..=self.max_y).contains(&coord.y)
+    }
+
+    pub fn validate_max_area(&self, max_area: u64) -> DomainResult<()> {
+        if self.area() > max_area {
+            return Err(DomainError::InvalidCoordinates(format!(
+                "Region {self} covers {} pixels, exceeding the maximum of {max_area}",
+                self.area()
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for GlobalRegion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "({}, {})-({}, {})",
+            self.min_x, self.min_y, self.max_x, self.max_y
+        )
+    }
+}
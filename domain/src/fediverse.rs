@@ -0,0 +1,102 @@
+use std::{collections::HashSet, fmt, net::IpAddr, str::FromStr};
+
+// A fediverse server's host name, lowercased and without scheme, path or port.
+// IP literals and single-label hosts are rejected because the server makes requests to it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InstanceDomain(String);
+
+impl InstanceDomain {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    // True for the domain itself and any of its subdomains.
+    pub fn is_within(&self, domain: &InstanceDomain) -> bool {
+        self.0 == domain.0
+            || self
+                .0
+                .strip_suffix(&domain.0)
+                .is_some_and(|prefix| prefix.ends_with('.'))
+    }
+}
+
+impl FromStr for InstanceDomain {
+    type Err = InstanceDomainError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let trimmed = input.trim();
+        let without_scheme = trimmed
+            .strip_prefix("https://")
+            .or_else(|| trimmed.strip_prefix("http://"))
+            .unwrap_or(trimmed);
+        let host = without_scheme.trim_end_matches('/').to_ascii_lowercase();
+
+        // Accept `user@example.social` handles by keeping the part after the last '@'.
+        let host = host.rsplit('@').next().unwrap_or_default().to_string();
+
+        if host.is_empty() || host.len() > 253 {
+            return Err(InstanceDomainError::Invalid(input.to_string()));
+        }
+        if host.parse::<IpAddr>().is_ok() || host == "localhost" {
+            return Err(InstanceDomainError::NotPublic(host));
+        }
+
+        let labels_valid = host.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+        if !labels_valid || !host.contains('.') {
+            return Err(InstanceDomainError::Invalid(input.to_string()));
+        }
+
+        Ok(Self(host))
+    }
+}
+
+impl fmt::Display for InstanceDomain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum InstanceDomainError {
+    #[error("'{0}' is not a valid instance domain")]
+    Invalid(String),
+    #[error("'{0}' is not a public instance")]
+    NotPublic(String),
+}
+
+// Which instances may be used to log in. An empty allow list permits every instance
+// that is not denied; entries also cover their subdomains.
+#[derive(Debug, Clone, Default)]
+pub struct InstancePolicy {
+    allowed: HashSet<InstanceDomain>,
+    denied: HashSet<InstanceDomain>,
+}
+
+impl InstancePolicy {
+    pub fn new(allowed: HashSet<InstanceDomain>, denied: HashSet<InstanceDomain>) -> Self {
+        Self { allowed, denied }
+    }
+
+    pub fn permits(&self, domain: &InstanceDomain) -> bool {
+        if self.denied.iter().any(|denied| domain.is_within(denied)) {
+            return false;
+        }
+
+        self.allowed.is_empty() || self.allowed.iter().any(|allowed| domain.is_within(allowed))
+    }
+}
+
+// OAuth client credentials registered with an instance for this server.
+#[derive(Debug, Clone)]
+pub struct FediverseApp {
+    pub domain: InstanceDomain,
+    pub client_id: String,
+    pub client_secret: String,
+    pub redirect_url: String,
+}
@@ -1,4 +1,7 @@
+pub mod account;
 pub mod action;
+pub mod api_token;
+pub mod audit;
 pub mod auth;
 pub mod ban;
 pub mod color;
@@ -6,4 +9,12 @@ pub mod coords;
 pub mod credits;
 pub mod error;
 pub mod events;
+pub mod fediverse;
+pub mod login_protection;
+pub mod pixel_protection;
+pub mod protected_region;
+pub mod report;
+pub mod rules;
+pub mod session;
 pub mod tile;
+pub mod two_factor;
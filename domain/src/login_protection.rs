@@ -0,0 +1,106 @@
+use time::{Duration, OffsetDateTime};
+
+use crate::auth::UserId;
+
+// Failed password logins against one account, counted across all client addresses.
+#[derive(Debug, Clone)]
+pub struct LoginFailures {
+    pub user_id: UserId,
+    pub failed_attempts: u32,
+    pub last_failed_at: OffsetDateTime,
+    // Passwords are not checked before this, whether it comes from a backoff delay or a lockout.
+    pub blocked_until: Option<OffsetDateTime>,
+    pub locked_until: Option<OffsetDateTime>,
+}
+
+impl LoginFailures {
+    pub fn is_blocked(&self, now: OffsetDateTime) -> bool {
+        self.blocked_until.is_some_and(|until| until > now)
+    }
+
+    pub fn is_locked(&self, now: OffsetDateTime) -> bool {
+        self.locked_until.is_some_and(|until| until > now)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LockedAccount {
+    pub user_id: UserId,
+    pub username: String,
+    pub failed_attempts: u32,
+    pub last_failed_at: OffsetDateTime,
+    pub locked_until: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginFailurePenalty {
+    None,
+    Backoff { until: OffsetDateTime },
+    Lockout { until: OffsetDateTime },
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LoginLockoutPolicy {
+    free_attempts: u32,
+    backoff_base: Duration,
+    backoff_max: Duration,
+    lockout_threshold: u32,
+    lockout_duration: Duration,
+}
+
+fn seconds(secs: u64) -> Duration {
+    Duration::seconds(i64::try_from(secs).unwrap_or(i64::MAX))
+}
+
+impl LoginLockoutPolicy {
+    pub fn new(
+        free_attempts: u32,
+        backoff_base_secs: u64,
+        backoff_max_secs: u64,
+        lockout_threshold: u32,
+        lockout_duration_secs: u64,
+    ) -> Self {
+        Self {
+            free_attempts,
+            backoff_base: seconds(backoff_base_secs),
+            backoff_max: seconds(backoff_max_secs),
+            lockout_threshold,
+            lockout_duration: seconds(lockout_duration_secs),
+        }
+    }
+
+    // `failed_attempts` includes the failure being penalized. Past the free attempts each
+    // failure doubles the delay until the lockout threshold is reached.
+    pub fn penalty(&self, failed_attempts: u32, now: OffsetDateTime) -> LoginFailurePenalty {
+        if failed_attempts >= self.lockout_threshold {
+            return LoginFailurePenalty::Lockout {
+                until: now.saturating_add(self.lockout_duration),
+            };
+        }
+
+        if failed_attempts <= self.free_attempts {
+            return LoginFailurePenalty::None;
+        }
+
+        let doublings = failed_attempts - self.free_attempts - 1;
+        let factor = i32::try_from(2_u32.saturating_pow(doublings)).unwrap_or(i32::MAX);
+        let delay = self
+            .backoff_base
+            .saturating_mul(factor)
+            .min(self.backoff_max);
+        LoginFailurePenalty::Backoff {
+            until: now.saturating_add(delay),
+        }
+    }
+
+    // The owner is told once per streak of failures, when the first lockout starts.
+    pub fn is_first_lockout(&self, failed_attempts: u32) -> bool {
+        failed_attempts == self.lockout_threshold
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum LoginProtectionError {
+    #[error("Account is not locked")]
+    NotLocked,
+}
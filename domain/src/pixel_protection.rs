@@ -0,0 +1,91 @@
+use time::{Duration, OffsetDateTime};
+
+use crate::{action::PaintAction, auth::UserId, coords::GlobalCoord};
+
+// Keeps a freshly painted pixel from being overwritten by someone else for `window`.
+// With a surcharge the overwrite is allowed but each protected pixel costs extra credits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PixelProtection {
+    window: Duration,
+    overwrite_surcharge: Option<u32>,
+}
+
+impl PixelProtection {
+    pub fn new(window_secs: u64, overwrite_surcharge: Option<u32>) -> Self {
+        Self {
+            window: Duration::seconds(i64::try_from(window_secs).unwrap_or(i64::MAX)),
+            overwrite_surcharge,
+        }
+    }
+
+    pub fn disabled() -> Self {
+        Self::new(0, None)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.window.is_positive()
+    }
+
+    // Time left before anyone may paint over a pixel painted at `painted_at`,
+    // or `None` once it is unprotected.
+    pub fn remaining(&self, painted_at: OffsetDateTime, now: OffsetDateTime) -> Option<Duration> {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        let remaining = painted_at
+            .checked_add(self.window)
+            .map_or(Duration::MAX, |protected_until| protected_until - now);
+        remaining.is_positive().then_some(remaining)
+    }
+
+    // Returns the extra credits `painter` owes for overwriting protected pixels,
+    // or the first protected pixel when overwrites are not allowed at all.
+    pub fn overwrite_surcharge(
+        &self,
+        painter: &UserId,
+        last_paints: &[PaintAction],
+        now: OffsetDateTime,
+    ) -> Result<u32, PixelProtectionError> {
+        if !self.is_enabled() {
+            return Ok(0);
+        }
+
+        let mut protected_pixels: u32 = 0;
+        for last_paint in last_paints
+            .iter()
+            .filter(|last_paint| last_paint.user_id != *painter)
+        {
+            let Some(remaining) = self.remaining(last_paint.timestamp, now) else {
+                continue;
+            };
+
+            if self.overwrite_surcharge.is_none() {
+                return Err(PixelProtectionError::PixelProtected {
+                    coord: last_paint.global_coord,
+                    remaining_seconds: remaining.whole_seconds().max(1),
+                });
+            }
+            protected_pixels = protected_pixels.saturating_add(1);
+        }
+
+        Ok(protected_pixels.saturating_mul(self.overwrite_surcharge.unwrap_or_default()))
+    }
+}
+
+impl Default for PixelProtection {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum PixelProtectionError {
+    #[error(
+        "Pixel {coord} was just painted by someone else and is protected for another {remaining_seconds} seconds"
+    )]
+    PixelProtected {
+        coord: GlobalCoord,
+        remaining_seconds: i64,
+    },
+}
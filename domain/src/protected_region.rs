@@ -0,0 +1,294 @@
+use std::{fmt, str::FromStr};
+
+use uuid::Uuid;
+
+use crate::{
+    auth::UserId,
+    coords::{GlobalCoord, GlobalRegion},
+};
+
+pub const MAX_REGION_NAME_LENGTH: usize = 100;
+pub const MAX_POLYGON_VERTICES: usize = 256;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ProtectedRegionId(pub Uuid);
+
+impl ProtectedRegionId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    pub fn from_uuid(id: Uuid) -> Self {
+        Self(id)
+    }
+
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for ProtectedRegionId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProtectedArea {
+    Rectangle(GlobalRegion),
+    Polygon(Vec<GlobalCoord>),
+}
+
+impl ProtectedArea {
+    pub fn shape_str(&self) -> &'static str {
+        match self {
+            ProtectedArea::Rectangle(_) => "rectangle",
+            ProtectedArea::Polygon(_) => "polygon",
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ProtectedRegionError> {
+        match self {
+            ProtectedArea::Polygon(vertices) if vertices.len() < 3 => {
+                Err(ProtectedRegionError::TooFewVertices)
+            }
+            ProtectedArea::Polygon(vertices) if vertices.len() > MAX_POLYGON_VERTICES => {
+                Err(ProtectedRegionError::TooManyVertices)
+            }
+            ProtectedArea::Rectangle(_) | ProtectedArea::Polygon(_) => Ok(()),
+        }
+    }
+
+    pub fn bounding_box(&self) -> GlobalRegion {
+        match self {
+            ProtectedArea::Rectangle(region) => *region,
+            ProtectedArea::Polygon(vertices) => {
+                GlobalRegion::bounding(vertices).unwrap_or_else(|| GlobalRegion::new(0, 0, 0, 0))
+            }
+        }
+    }
+
+    // Polygon vertices sit on pixel corners, so a pixel is inside when its centre is.
+    pub fn contains(&self, coord: GlobalCoord) -> bool {
+        match self {
+            ProtectedArea::Rectangle(region) => region.contains(coord),
+            ProtectedArea::Polygon(vertices) => {
+                let px = f64::from(coord.x) + 0.5;
+                let py = f64::from(coord.y) + 0.5;
+
+                let previous_vertices = vertices.last().into_iter().chain(vertices.iter());
+                let mut inside = false;
+                for (vertex, previous) in vertices.iter().zip(previous_vertices) {
+                    let (x1, y1) = (f64::from(vertex.x), f64::from(vertex.y));
+                    let (x2, y2) = (f64::from(previous.x), f64::from(previous.y));
+                    if (y1 > py) != (y2 > py) && px < (x2 - x1) * (py - y1) / (y2 - y1) + x1 {
+                        inside = !inside;
+                    }
+                }
+                inside
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaintRestriction {
+    Locked,
+    RolesOnly {
+        role_names: Vec<String>,
+    },
+    TimeWindow {
+        opens_at: time::OffsetDateTime,
+        closes_at: time::OffsetDateTime,
+    },
+}
+
+impl PaintRestriction {
+    pub fn kind(&self) -> PaintRestrictionKind {
+        match self {
+            PaintRestriction::Locked => PaintRestrictionKind::Locked,
+            PaintRestriction::RolesOnly { .. } => PaintRestrictionKind::RolesOnly,
+            PaintRestriction::TimeWindow { .. } => PaintRestrictionKind::TimeWindow,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ProtectedRegionError> {
+        match self {
+            PaintRestriction::RolesOnly { role_names } if role_names.is_empty() => {
+                Err(ProtectedRegionError::NoAllowedRoles)
+            }
+            PaintRestriction::TimeWindow {
+                opens_at,
+                closes_at,
+            } if opens_at >= closes_at => Err(ProtectedRegionError::InvalidTimeWindow),
+            PaintRestriction::Locked
+            | PaintRestriction::RolesOnly { .. }
+            | PaintRestriction::TimeWindow { .. } => Ok(()),
+        }
+    }
+
+    pub fn requires_roles(&self) -> bool {
+        matches!(self, PaintRestriction::RolesOnly { .. })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PaintRestrictionKind {
+    Locked,
+    RolesOnly,
+    TimeWindow,
+}
+
+impl PaintRestrictionKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PaintRestrictionKind::Locked => "locked",
+            PaintRestrictionKind::RolesOnly => "roles_only",
+            PaintRestrictionKind::TimeWindow => "time_window",
+        }
+    }
+}
+
+impl FromStr for PaintRestrictionKind {
+    type Err = ProtectedRegionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "locked" => Ok(PaintRestrictionKind::Locked),
+            "roles_only" => Ok(PaintRestrictionKind::RolesOnly),
+            "time_window" => Ok(PaintRestrictionKind::TimeWindow),
+            _ => Err(ProtectedRegionError::InvalidRestriction),
+        }
+    }
+}
+
+impl fmt::Display for PaintRestrictionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ProtectedRegion {
+    pub id: ProtectedRegionId,
+    pub name: String,
+    pub description: Option<String>,
+    pub area: ProtectedArea,
+    pub restriction: PaintRestriction,
+    pub created_by: Option<UserId>,
+    pub created_at: time::OffsetDateTime,
+    pub updated_at: time::OffsetDateTime,
+}
+
+impl ProtectedRegion {
+    pub fn new(
+        name: String,
+        description: Option<String>,
+        area: ProtectedArea,
+        restriction: PaintRestriction,
+        created_by: UserId,
+    ) -> Self {
+        let now = time::OffsetDateTime::now_utc();
+        Self {
+            id: ProtectedRegionId::new(),
+            name,
+            description,
+            area,
+            restriction,
+            created_by: Some(created_by),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ProtectedRegionError> {
+        Self::validate_name(&self.name)?;
+        self.area.validate()?;
+        self.restriction.validate()
+    }
+
+    pub fn validate_name(name: &str) -> Result<(), ProtectedRegionError> {
+        if name.trim().is_empty() {
+            return Err(ProtectedRegionError::EmptyName);
+        }
+        if name.chars().count() > MAX_REGION_NAME_LENGTH {
+            return Err(ProtectedRegionError::NameTooLong);
+        }
+        Ok(())
+    }
+
+    // Returns why `coord` may not be painted by a user holding `role_names` at `now`,
+    // or `Ok` when the region does not cover the pixel or its rule allows the paint.
+    pub fn check_paint(
+        &self,
+        coord: GlobalCoord,
+        role_names: &[String],
+        now: time::OffsetDateTime,
+    ) -> Result<(), ProtectedRegionError> {
+        if !self.area.contains(coord) {
+            return Ok(());
+        }
+
+        match &self.restriction {
+            PaintRestriction::Locked => Err(ProtectedRegionError::Locked {
+                region: self.name.clone(),
+                coord,
+            }),
+            PaintRestriction::RolesOnly {
+                role_names: allowed,
+            } => {
+                if role_names.iter().any(|name| allowed.contains(name)) {
+                    Ok(())
+                } else {
+                    Err(ProtectedRegionError::RoleRequired {
+                        region: self.name.clone(),
+                        coord,
+                    })
+                }
+            }
+            PaintRestriction::TimeWindow {
+                opens_at,
+                closes_at,
+            } => {
+                if (*opens_at..*closes_at).contains(&now) {
+                    Ok(())
+                } else {
+                    Err(ProtectedRegionError::OutsideTimeWindow {
+                        region: self.name.clone(),
+                        coord,
+                    })
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ProtectedRegionError {
+    #[error("Protected region not found")]
+    RegionNotFound,
+    #[error("Protected region name cannot be empty")]
+    EmptyName,
+    #[error("Protected region name is too long")]
+    NameTooLong,
+    #[error("A polygon needs at least 3 vertices")]
+    TooFewVertices,
+    #[error("A polygon can have at most 256 vertices")]
+    TooManyVertices,
+    #[error("Invalid paint restriction")]
+    InvalidRestriction,
+    #[error("A roles_only restriction needs at least one role")]
+    NoAllowedRoles,
+    #[error("The time window must open before it closes")]
+    InvalidTimeWindow,
+    #[error("Pixel {coord} is in the protected region \"{region}\", which is locked")]
+    Locked { region: String, coord: GlobalCoord },
+    #[error(
+        "Pixel {coord} is in the protected region \"{region}\", which only certain roles may paint"
+    )]
+    RoleRequired { region: String, coord: GlobalCoord },
+    #[error(
+        "Pixel {coord} is in the protected region \"{region}\", which may not be painted at this time"
+    )]
+    OutsideTimeWindow { region: String, coord: GlobalCoord },
+}
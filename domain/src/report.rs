@@ -0,0 +1,256 @@
+use std::{fmt, str::FromStr};
+
+use uuid::Uuid;
+
+use crate::{
+    auth::UserId,
+    coords::{GlobalCoord, GlobalRegion},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReportId(pub Uuid);
+
+impl ReportId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    pub fn from_uuid(id: Uuid) -> Self {
+        Self(id)
+    }
+
+    pub fn as_uuid(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl Default for ReportId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReportTarget {
+    Pixel(GlobalCoord),
+    Region(GlobalRegion),
+    User(UserId),
+}
+
+impl ReportTarget {
+    pub fn type_str(&self) -> &'static str {
+        match self {
+            ReportTarget::Pixel(_) => "pixel",
+            ReportTarget::Region(_) => "region",
+            ReportTarget::User(_) => "user",
+        }
+    }
+
+    // Identifies the reported thing so the same reporter cannot file it twice while open.
+    pub fn dedup_key(&self) -> String {
+        match self {
+            ReportTarget::Pixel(coord) => format!("pixel:{}:{}", coord.x, coord.y),
+            ReportTarget::Region(region) => format!(
+                "region:{}:{}:{}:{}",
+                region.min_x, region.min_y, region.max_x, region.max_y
+            ),
+            ReportTarget::User(user_id) => format!("user:{}", user_id.as_uuid()),
+        }
+    }
+
+    pub fn region(&self) -> Option<GlobalRegion> {
+        match self {
+            ReportTarget::Pixel(coord) => Some(GlobalRegion::single_pixel(*coord)),
+            ReportTarget::Region(region) => Some(*region),
+            ReportTarget::User(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReportCategory {
+    Griefing,
+    Offensive,
+    Spam,
+    Botting,
+    Other,
+}
+
+impl ReportCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportCategory::Griefing => "griefing",
+            ReportCategory::Offensive => "offensive",
+            ReportCategory::Spam => "spam",
+            ReportCategory::Botting => "botting",
+            ReportCategory::Other => "other",
+        }
+    }
+}
+
+impl FromStr for ReportCategory {
+    type Err = ReportError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "griefing" => Ok(ReportCategory::Griefing),
+            "offensive" => Ok(ReportCategory::Offensive),
+            "spam" => Ok(ReportCategory::Spam),
+            "botting" => Ok(ReportCategory::Botting),
+            "other" => Ok(ReportCategory::Other),
+            _ => Err(ReportError::InvalidCategory),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReportStatus {
+    Open,
+    Claimed,
+    Resolved,
+    Dismissed,
+}
+
+impl ReportStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportStatus::Open => "open",
+            ReportStatus::Claimed => "claimed",
+            ReportStatus::Resolved => "resolved",
+            ReportStatus::Dismissed => "dismissed",
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        matches!(self, ReportStatus::Open | ReportStatus::Claimed)
+    }
+}
+
+impl FromStr for ReportStatus {
+    type Err = ReportError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "open" => Ok(ReportStatus::Open),
+            "claimed" => Ok(ReportStatus::Claimed),
+            "resolved" => Ok(ReportStatus::Resolved),
+            "dismissed" => Ok(ReportStatus::Dismissed),
+            _ => Err(ReportError::InvalidStatus),
+        }
+    }
+}
+
+impl fmt::Display for ReportStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReportResolutionAction {
+    NoAction,
+    BanUser,
+    RollbackRegion,
+}
+
+impl ReportResolutionAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportResolutionAction::NoAction => "none",
+            ReportResolutionAction::BanUser => "ban_user",
+            ReportResolutionAction::RollbackRegion => "rollback_region",
+        }
+    }
+}
+
+impl FromStr for ReportResolutionAction {
+    type Err = ReportError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(ReportResolutionAction::NoAction),
+            "ban_user" => Ok(ReportResolutionAction::BanUser),
+            "rollback_region" => Ok(ReportResolutionAction::RollbackRegion),
+            _ => Err(ReportError::InvalidResolutionAction),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub id: ReportId,
+    pub reporter_user_id: UserId,
+    pub target: ReportTarget,
+    pub target_user_id: Option<UserId>,
+    pub category: ReportCategory,
+    pub comment: String,
+    pub status: ReportStatus,
+    pub claimed_by_user_id: Option<UserId>,
+    pub claimed_at: Option<time::OffsetDateTime>,
+    pub resolved_by_user_id: Option<UserId>,
+    pub resolved_at: Option<time::OffsetDateTime>,
+    pub resolution_action: Option<ReportResolutionAction>,
+    pub resolution_note: Option<String>,
+    pub created_at: time::OffsetDateTime,
+}
+
+impl Report {
+    pub fn new(
+        reporter_user_id: UserId,
+        target: ReportTarget,
+        target_user_id: Option<UserId>,
+        category: ReportCategory,
+        comment: String,
+    ) -> Self {
+        Self {
+            id: ReportId::new(),
+            reporter_user_id,
+            target,
+            target_user_id,
+            category,
+            comment,
+            status: ReportStatus::Open,
+            claimed_by_user_id: None,
+            claimed_at: None,
+            resolved_by_user_id: None,
+            resolved_at: None,
+            resolution_action: None,
+            resolution_note: None,
+            created_at: time::OffsetDateTime::now_utc(),
+        }
+    }
+
+    pub fn is_claimed_by_other(&self, moderator_id: &UserId) -> bool {
+        self.status == ReportStatus::Claimed
+            && self
+                .claimed_by_user_id
+                .as_ref()
+                .is_some_and(|claimed_by| claimed_by != moderator_id)
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum ReportError {
+    #[error("Report not found")]
+    ReportNotFound,
+    #[error("You already have an open report for this target")]
+    DuplicateReport,
+    #[error("Invalid report category")]
+    InvalidCategory,
+    #[error("Invalid report status")]
+    InvalidStatus,
+    #[error("Invalid resolution action")]
+    InvalidResolutionAction,
+    #[error("Report has already been closed")]
+    ReportClosed,
+    #[error("Report is claimed by another moderator")]
+    ClaimedByOtherModerator,
+    #[error("Report has no user to ban")]
+    NoTargetUser,
+    #[error("Report has no region to roll back")]
+    NoTargetRegion,
+    #[error("Users cannot report themselves")]
+    SelfReport,
+    #[error("Report comment is too long")]
+    CommentTooLong,
+}
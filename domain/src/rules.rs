@@ -0,0 +1,305 @@
+use std::fmt;
+
+use time::Duration;
+
+use crate::{
+    action::PaintAction,
+    color::ColorId,
+    coords::{GlobalCoord, GlobalRegion},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaintRule {
+    MinAccountAge {
+        seconds: u64,
+    },
+    EmailVerified,
+    MaxPixelsPerBatch {
+        max_pixels: usize,
+        role_limits: Vec<(String, usize)>,
+    },
+    AllowedColors {
+        zone: String,
+        area: GlobalRegion,
+        color_ids: Vec<u8>,
+    },
+    PixelCooldown {
+        seconds: u64,
+    },
+}
+
+impl PaintRule {
+    pub fn kind(&self) -> PaintRuleKind {
+        match self {
+            PaintRule::MinAccountAge { .. } => PaintRuleKind::MinAccountAge,
+            PaintRule::EmailVerified => PaintRuleKind::EmailVerified,
+            PaintRule::MaxPixelsPerBatch { .. } => PaintRuleKind::MaxPixelsPerBatch,
+            PaintRule::AllowedColors { .. } => PaintRuleKind::AllowedColors,
+            PaintRule::PixelCooldown { .. } => PaintRuleKind::PixelCooldown,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            PaintRule::MinAccountAge { seconds } => format!(
+                "Accounts must be at least {} old to paint",
+                format_duration(*seconds)
+            ),
+            PaintRule::EmailVerified => {
+                "Accounts must have a verified email address to paint".to_string()
+            }
+            PaintRule::MaxPixelsPerBatch {
+                max_pixels,
+                role_limits,
+            } => {
+                let mut text = format!("At most {max_pixels} pixels may be painted per request");
+                if !role_limits.is_empty() {
+                    let exceptions: Vec<String> = role_limits
+                        .iter()
+                        .map(|(role, limit)| format!("{role}: {limit}"))
+                        .collect();
+                    text.push_str(" (");
+                    text.push_str(&exceptions.join(", "));
+                    text.push(')');
+                }
+                text
+            }
+            PaintRule::AllowedColors {
+                zone,
+                area,
+                color_ids,
+            } => {
+                let colors: Vec<String> = color_ids.iter().map(u8::to_string).collect();
+                format!(
+                    "Only colors {} may be used in \"{}\" {}",
+                    colors.join(", "),
+                    zone,
+                    area
+                )
+            }
+            PaintRule::PixelCooldown { seconds } => format!(
+                "A pixel cannot be painted again until {} after it was last painted",
+                format_duration(*seconds)
+            ),
+        }
+    }
+
+    fn evaluate(&self, attempt: &PaintAttempt<'_>) -> Result<(), RuleViolation> {
+        match self {
+            PaintRule::MinAccountAge { seconds } => {
+                let old_enough = attempt
+                    .painter
+                    .account_created_at
+                    .is_some_and(|created_at| {
+                        (attempt.now - created_at).whole_seconds()
+                            >= i64::try_from(*seconds).unwrap_or(i64::MAX)
+                    });
+                if old_enough {
+                    Ok(())
+                } else {
+                    Err(RuleViolation::AccountTooNew {
+                        min_age: format_duration(*seconds),
+                    })
+                }
+            }
+            PaintRule::EmailVerified => {
+                if attempt.painter.email_verified {
+                    Ok(())
+                } else {
+                    Err(RuleViolation::EmailNotVerified)
+                }
+            }
+            PaintRule::MaxPixelsPerBatch {
+                max_pixels,
+                role_limits,
+            } => {
+                // A user holding several roles gets the most generous of their limits.
+                let limit = role_limits
+                    .iter()
+                    .filter(|(role, _)| attempt.painter.role_names.contains(role))
+                    .map(|(_, limit)| *limit)
+                    .fold(*max_pixels, usize::max);
+                if attempt.pixels.len() <= limit {
+                    Ok(())
+                } else {
+                    Err(RuleViolation::BatchTooLarge {
+                        max_pixels: limit,
+                        requested: attempt.pixels.len(),
+                    })
+                }
+            }
+            PaintRule::AllowedColors {
+                zone,
+                area,
+                color_ids,
+            } => attempt
+                .pixels
+                .iter()
+                .find(|(coord, color_id)| {
+                    area.contains(*coord) && !color_ids.contains(&color_id.id())
+                })
+                .map_or(Ok(()), |(coord, color_id)| {
+                    Err(RuleViolation::ColorNotAllowed {
+                        zone: zone.clone(),
+                        coord: *coord,
+                        color_id: color_id.id(),
+                    })
+                }),
+            PaintRule::PixelCooldown { seconds } => {
+                let cooldown = Duration::seconds(i64::try_from(*seconds).unwrap_or(i64::MAX));
+                attempt
+                    .last_paints
+                    .iter()
+                    .filter(|paint| {
+                        attempt
+                            .pixels
+                            .iter()
+                            .any(|(coord, _)| *coord == paint.global_coord)
+                    })
+                    .find_map(|paint| {
+                        let remaining = paint
+                            .timestamp
+                            .checked_add(cooldown)
+                            .map_or(Duration::MAX, |ends_at| ends_at - attempt.now);
+                        remaining
+                            .is_positive()
+                            .then(|| RuleViolation::PixelCoolingDown {
+                                coord: paint.global_coord,
+                                remaining_seconds: remaining.whole_seconds().max(1),
+                            })
+                    })
+                    .map_or(Ok(()), Err)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PaintRuleKind {
+    MinAccountAge,
+    EmailVerified,
+    MaxPixelsPerBatch,
+    AllowedColors,
+    PixelCooldown,
+}
+
+impl PaintRuleKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PaintRuleKind::MinAccountAge => "min_account_age",
+            PaintRuleKind::EmailVerified => "email_verified",
+            PaintRuleKind::MaxPixelsPerBatch => "max_pixels_per_batch",
+            PaintRuleKind::AllowedColors => "allowed_colors",
+            PaintRuleKind::PixelCooldown => "pixel_cooldown",
+        }
+    }
+}
+
+impl fmt::Display for PaintRuleKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// What the rules need to know about the painter; fields a rule set does not
+// ask for (see `PaintRuleSet::needs_account_age`) may be left empty.
+#[derive(Debug, Clone, Default)]
+pub struct PainterFacts {
+    pub email_verified: bool,
+    pub account_created_at: Option<time::OffsetDateTime>,
+    pub role_names: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaintAttempt<'a> {
+    pub painter: &'a PainterFacts,
+    pub pixels: &'a [(GlobalCoord, ColorId)],
+    pub last_paints: &'a [PaintAction],
+    pub now: time::OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PaintRuleSet {
+    rules: Vec<PaintRule>,
+}
+
+impl PaintRuleSet {
+    pub fn new(rules: Vec<PaintRule>) -> Self {
+        Self { rules }
+    }
+
+    pub fn rules(&self) -> &[PaintRule] {
+        &self.rules
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    pub fn needs_account_age(&self) -> bool {
+        self.rules
+            .iter()
+            .any(|rule| matches!(rule, PaintRule::MinAccountAge { .. }))
+    }
+
+    pub fn needs_last_paints(&self) -> bool {
+        self.rules
+            .iter()
+            .any(|rule| matches!(rule, PaintRule::PixelCooldown { .. }))
+    }
+
+    // Rules are checked in the order they were configured and the first violation wins.
+    pub fn evaluate(&self, attempt: &PaintAttempt<'_>) -> Result<(), RuleViolation> {
+        self.rules
+            .iter()
+            .try_for_each(|rule| rule.evaluate(attempt))
+    }
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum RuleViolation {
+    #[error("Accounts must be at least {min_age} old to paint")]
+    AccountTooNew { min_age: String },
+    #[error("A verified email address is required to paint")]
+    EmailNotVerified,
+    #[error("At most {max_pixels} pixels may be painted per request, got {requested}")]
+    BatchTooLarge { max_pixels: usize, requested: usize },
+    #[error("Color {color_id} may not be used at {coord} in \"{zone}\"")]
+    ColorNotAllowed {
+        zone: String,
+        coord: GlobalCoord,
+        color_id: u8,
+    },
+    #[error("Pixel {coord} was painted too recently, try again in {remaining_seconds} seconds")]
+    PixelCoolingDown {
+        coord: GlobalCoord,
+        remaining_seconds: i64,
+    },
+}
+
+impl RuleViolation {
+    pub fn rule_kind(&self) -> PaintRuleKind {
+        match self {
+            RuleViolation::AccountTooNew { .. } => PaintRuleKind::MinAccountAge,
+            RuleViolation::EmailNotVerified => PaintRuleKind::EmailVerified,
+            RuleViolation::BatchTooLarge { .. } => PaintRuleKind::MaxPixelsPerBatch,
+            RuleViolation::ColorNotAllowed { .. } => PaintRuleKind::AllowedColors,
+            RuleViolation::PixelCoolingDown { .. } => PaintRuleKind::PixelCooldown,
+        }
+    }
+}
+
+fn format_duration(seconds: u64) -> String {
+    let (count, unit) = [(86_400, "day"), (3_600, "hour"), (60, "minute")]
+        .into_iter()
+        .find(|(unit_seconds, _)| seconds >= *unit_seconds && seconds.is_multiple_of(*unit_seconds))
+        .map_or((seconds, "second"), |(unit_seconds, unit)| {
+            (seconds / unit_seconds, unit)
+        });
+
+    if count == 1 {
+        format!("1 {unit}")
+    } else {
+        format!("{count} {unit}s")
+    }
+}
@@ -0,0 +1,34 @@
+use std::net::IpAddr;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+// Longest user agent kept for a session. Browsers stay well below this, so anything
+// longer is truncated rather than stored in full.
+pub const MAX_USER_AGENT_LENGTH: usize = 512;
+
+// A login session as the user sees it. The session data itself lives in the session
+// store; this record only indexes it per user so it can be listed and revoked.
+#[derive(Debug, Clone)]
+pub struct UserSession {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<IpAddr>,
+    pub created_at: OffsetDateTime,
+    pub last_seen_at: OffsetDateTime,
+    pub revoked_at: Option<OffsetDateTime>,
+}
+
+impl UserSession {
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+}
+
+pub fn truncate_user_agent(user_agent: &str) -> String {
+    user_agent
+        .trim()
+        .chars()
+        .take(MAX_USER_AGENT_LENGTH)
+        .collect()
+}
@@ -0,0 +1,46 @@
+use time::OffsetDateTime;
+
+// TOTP secrets use 30 second steps with 6 digit codes, which is what authenticator apps
+// assume when the otpauth URL does not say otherwise.
+pub const TOTP_STEP_SECONDS: i64 = 30;
+pub const TOTP_DIGITS: usize = 6;
+
+// A user's TOTP secret. It is only active once `enabled_at` is set, which happens after
+// the user proved their authenticator produces valid codes.
+#[derive(Debug, Clone)]
+pub struct TotpCredential {
+    pub secret: String,
+    pub enabled_at: Option<OffsetDateTime>,
+    // Time step of the last accepted code, so a code cannot be used twice.
+    pub last_used_step: Option<i64>,
+}
+
+impl TotpCredential {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled_at.is_some()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TotpEnrollment {
+    pub secret: String,
+    pub otpauth_url: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct TwoFactorStatus {
+    pub enabled_at: Option<OffsetDateTime>,
+    pub recovery_codes_remaining: i64,
+}
+
+// Authentication codes are typed by hand, so separators and case are ignored.
+pub fn normalize_code(code: &str) -> String {
+    code.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+pub fn is_totp_code(normalized_code: &str) -> bool {
+    normalized_code.len() == TOTP_DIGITS && normalized_code.chars().all(|c| c.is_ascii_digit())
+}
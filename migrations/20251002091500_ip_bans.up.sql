@@ -0,0 +1,13 @@
+CREATE TABLE ip_bans (
+    id UUID PRIMARY KEY,
+    network CIDR NOT NULL,
+    banned_by_user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    reason TEXT NOT NULL,
+    banned_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_ip_bans_network ON ip_bans USING GIST (network inet_ops);
+CREATE INDEX idx_ip_bans_banned_at ON ip_bans(banned_at DESC);
+CREATE INDEX idx_ip_bans_expires_at ON ip_bans(expires_at) WHERE expires_at IS NOT NULL;
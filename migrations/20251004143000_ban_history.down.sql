@@ -0,0 +1,15 @@
+DROP INDEX IF EXISTS idx_banned_users_unrevoked;
+
+DELETE FROM banned_users WHERE revoked_at IS NOT NULL;
+
+DELETE FROM banned_users older
+USING banned_users newer
+WHERE older.user_id = newer.user_id
+  AND older.banned_at < newer.banned_at;
+
+ALTER TABLE banned_users
+    DROP COLUMN revoke_reason,
+    DROP COLUMN revoked_by_user_id,
+    DROP COLUMN revoked_at;
+
+ALTER TABLE banned_users ADD CONSTRAINT banned_users_user_id_unique UNIQUE (user_id);
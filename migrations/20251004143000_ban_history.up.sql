@@ -0,0 +1,8 @@
+ALTER TABLE banned_users DROP CONSTRAINT banned_users_user_id_unique;
+
+ALTER TABLE banned_users
+    ADD COLUMN revoked_at TIMESTAMPTZ,
+    ADD COLUMN revoked_by_user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    ADD COLUMN revoke_reason TEXT;
+
+CREATE UNIQUE INDEX idx_banned_users_unrevoked ON banned_users(user_id) WHERE revoked_at IS NULL;
@@ -0,0 +1,10 @@
+DROP INDEX IF EXISTS idx_banned_users_open;
+
+-- The older index only allows one unrevoked ban per user, finalized ones included.
+UPDATE banned_users
+SET revoked_at = expires_at
+WHERE expiry_processed_at IS NOT NULL AND revoked_at IS NULL;
+
+CREATE UNIQUE INDEX idx_banned_users_unrevoked ON banned_users(user_id) WHERE revoked_at IS NULL;
+
+ALTER TABLE banned_users DROP COLUMN expiry_processed_at;
@@ -0,0 +1,10 @@
+ALTER TABLE banned_users ADD COLUMN expiry_processed_at TIMESTAMPTZ;
+
+UPDATE banned_users
+SET expiry_processed_at = NOW()
+WHERE expires_at <= NOW() AND revoked_at IS NULL;
+
+-- Bans the expiry job has finalized no longer hold the user's one open ban slot.
+DROP INDEX IF EXISTS idx_banned_users_unrevoked;
+CREATE UNIQUE INDEX idx_banned_users_open ON banned_users(user_id)
+    WHERE revoked_at IS NULL AND expiry_processed_at IS NULL;
@@ -0,0 +1,34 @@
+CREATE TABLE reports (
+    id UUID PRIMARY KEY,
+    reporter_user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    target_type TEXT NOT NULL CHECK (target_type IN ('pixel', 'region', 'user')),
+    target_user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    reported_user_id UUID,
+    region_min_x INTEGER,
+    region_min_y INTEGER,
+    region_max_x INTEGER,
+    region_max_y INTEGER,
+    dedup_key TEXT NOT NULL,
+    category TEXT NOT NULL,
+    comment TEXT NOT NULL DEFAULT '',
+    status TEXT NOT NULL DEFAULT 'open' CHECK (status IN ('open', 'claimed', 'resolved', 'dismissed')),
+    claimed_by_user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    claimed_at TIMESTAMPTZ,
+    resolved_by_user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    resolved_at TIMESTAMPTZ,
+    resolution_action TEXT,
+    resolution_note TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT reports_region_chk CHECK (
+        (target_type = 'user') = (region_min_x IS NULL)
+    ),
+    CONSTRAINT reports_reported_user_chk CHECK (
+        (target_type = 'user') = (reported_user_id IS NOT NULL)
+    )
+);
+
+CREATE UNIQUE INDEX idx_reports_pending_dedup ON reports(reporter_user_id, dedup_key)
+    WHERE status IN ('open', 'claimed');
+CREATE INDEX idx_reports_status_created_at ON reports(status, created_at);
+CREATE INDEX idx_reports_target_user_id ON reports(target_user_id);
+CREATE INDEX idx_reports_reporter_user_id ON reports(reporter_user_id);
@@ -0,0 +1,11 @@
+ALTER TABLE roles ADD COLUMN permissions TEXT[] NOT NULL DEFAULT '{}';
+
+UPDATE roles
+SET permissions = ARRAY['ban:create', 'pixels:rollback', 'roles:assign', 'reports:resolve'],
+    updated_at = NOW()
+WHERE name = 'admin';
+
+INSERT INTO roles (name, description, permissions) VALUES
+    ('moderator', 'Handles reports, bans and rollbacks without role management',
+     ARRAY['ban:create', 'pixels:rollback', 'reports:resolve'])
+ON CONFLICT (name) DO NOTHING;
@@ -0,0 +1,3 @@
+UPDATE roles
+SET permissions = array_remove(permissions, 'audit:read'),
+    updated_at = NOW();
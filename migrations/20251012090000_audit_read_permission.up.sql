@@ -0,0 +1,4 @@
+UPDATE roles
+SET permissions = array_append(permissions, 'audit:read'),
+    updated_at = NOW()
+WHERE name = 'admin' AND NOT ('audit:read' = ANY(permissions));
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS quarantined_pixels;
+
+ALTER TABLE banned_users DROP COLUMN IF EXISTS shadow;
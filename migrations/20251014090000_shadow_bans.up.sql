@@ -0,0 +1,13 @@
+ALTER TABLE banned_users ADD COLUMN shadow BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE quarantined_pixels (
+    id BIGSERIAL PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    ban_id UUID NOT NULL REFERENCES banned_users(id) ON DELETE CASCADE,
+    global_x INTEGER NOT NULL,
+    global_y INTEGER NOT NULL,
+    color_id SMALLINT NOT NULL,
+    painted_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_quarantined_pixels_user_painted_at ON quarantined_pixels(user_id, painted_at);
@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS idx_users_email_lower;
+DROP INDEX IF EXISTS idx_users_username_lower;
+DROP INDEX IF EXISTS idx_users_created_at;
+
+UPDATE roles
+SET permissions = array_remove(permissions, 'users:read'),
+    updated_at = NOW();
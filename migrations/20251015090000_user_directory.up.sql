@@ -0,0 +1,8 @@
+UPDATE roles
+SET permissions = array_append(permissions, 'users:read'),
+    updated_at = NOW()
+WHERE name IN ('admin', 'moderator') AND NOT ('users:read' = ANY(permissions));
+
+CREATE INDEX idx_users_created_at ON users(created_at, id);
+CREATE INDEX idx_users_username_lower ON users(lower(username) text_pattern_ops);
+CREATE INDEX idx_users_email_lower ON users(lower(email) text_pattern_ops);
@@ -0,0 +1,5 @@
+UPDATE roles
+SET permissions = array_remove(permissions, 'regions:manage'),
+    updated_at = NOW();
+
+DROP TABLE IF EXISTS protected_regions;
@@ -0,0 +1,32 @@
+CREATE TABLE protected_regions (
+    id UUID PRIMARY KEY,
+    name TEXT NOT NULL,
+    description TEXT,
+    shape TEXT NOT NULL CHECK (shape IN ('rectangle', 'polygon')),
+    min_x INTEGER NOT NULL,
+    min_y INTEGER NOT NULL,
+    max_x INTEGER NOT NULL,
+    max_y INTEGER NOT NULL,
+    polygon_xs INTEGER[],
+    polygon_ys INTEGER[],
+    restriction TEXT NOT NULL CHECK (restriction IN ('locked', 'roles_only', 'time_window')),
+    allowed_roles TEXT[] NOT NULL DEFAULT '{}',
+    opens_at TIMESTAMPTZ,
+    closes_at TIMESTAMPTZ,
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT protected_regions_polygon_chk CHECK (
+        (shape = 'polygon') = (polygon_xs IS NOT NULL AND polygon_ys IS NOT NULL)
+    ),
+    CONSTRAINT protected_regions_time_window_chk CHECK (
+        (restriction = 'time_window') = (opens_at IS NOT NULL AND closes_at IS NOT NULL)
+    )
+);
+
+CREATE INDEX idx_protected_regions_bounds ON protected_regions(min_x, max_x, min_y, max_y);
+
+UPDATE roles
+SET permissions = array_append(permissions, 'regions:manage'),
+    updated_at = NOW()
+WHERE name = 'admin' AND NOT ('regions:manage' = ANY(permissions));
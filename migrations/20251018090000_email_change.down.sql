@@ -0,0 +1,7 @@
+DROP TABLE IF EXISTS email_change_tokens;
+
+DROP INDEX IF EXISTS idx_password_reset_tokens_expires_at;
+DROP INDEX IF EXISTS idx_email_verification_tokens_expires_at;
+DROP INDEX IF EXISTS idx_email_verification_tokens_user_id;
+
+ALTER TABLE email_verification_tokens DROP COLUMN IF EXISTS created_at;
@@ -0,0 +1 @@
+DROP TABLE IF EXISTS fediverse_apps;
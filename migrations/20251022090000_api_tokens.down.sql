@@ -0,0 +1,3 @@
+ALTER TABLE quarantined_pixels DROP COLUMN IF EXISTS automated;
+ALTER TABLE pixel_history DROP COLUMN IF EXISTS automated;
+DROP TABLE IF EXISTS api_tokens;
@@ -0,0 +1,16 @@
+CREATE TABLE api_tokens (
+    id UUID PRIMARY KEY,
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    token_hash TEXT NOT NULL UNIQUE,
+    scopes TEXT[] NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_used_at TIMESTAMPTZ,
+    expires_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_api_tokens_user_id ON api_tokens(user_id);
+
+ALTER TABLE pixel_history ADD COLUMN automated BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE quarantined_pixels ADD COLUMN automated BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,12 @@
+ALTER TABLE user_roles DROP CONSTRAINT IF EXISTS user_roles_assigned_by_fkey;
+ALTER TABLE user_roles ADD CONSTRAINT user_roles_assigned_by_fkey
+    FOREIGN KEY (assigned_by) REFERENCES users(id);
+
+DELETE FROM pixel_history WHERE user_id IS NULL;
+ALTER TABLE pixel_history DROP CONSTRAINT IF EXISTS pixel_history_user_id_fkey;
+ALTER TABLE pixel_history ALTER COLUMN user_id SET NOT NULL;
+ALTER TABLE pixel_history ADD CONSTRAINT pixel_history_user_id_fkey
+    FOREIGN KEY (user_id) REFERENCES users(id);
+
+DROP INDEX IF EXISTS idx_users_deletion_scheduled_at;
+ALTER TABLE users DROP COLUMN IF EXISTS deletion_scheduled_at;
@@ -0,0 +1,14 @@
+ALTER TABLE users ADD COLUMN deletion_scheduled_at TIMESTAMPTZ;
+
+CREATE INDEX idx_users_deletion_scheduled_at ON users(deletion_scheduled_at)
+    WHERE deletion_scheduled_at IS NOT NULL;
+
+-- Pixels of deleted accounts stay on the canvas without attribution.
+ALTER TABLE pixel_history ALTER COLUMN user_id DROP NOT NULL;
+ALTER TABLE pixel_history DROP CONSTRAINT pixel_history_user_id_fkey;
+ALTER TABLE pixel_history ADD CONSTRAINT pixel_history_user_id_fkey
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE SET NULL;
+
+ALTER TABLE user_roles DROP CONSTRAINT user_roles_assigned_by_fkey;
+ALTER TABLE user_roles ADD CONSTRAINT user_roles_assigned_by_fkey
+    FOREIGN KEY (assigned_by) REFERENCES users(id) ON DELETE SET NULL;
@@ -0,0 +1,5 @@
+UPDATE roles
+SET permissions = array_remove(permissions, 'users:manage'),
+    updated_at = NOW();
+
+DROP TABLE IF EXISTS login_failures;
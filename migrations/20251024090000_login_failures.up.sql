@@ -0,0 +1,15 @@
+CREATE TABLE login_failures (
+    user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    failed_attempts INTEGER NOT NULL,
+    last_failed_at TIMESTAMPTZ NOT NULL,
+    blocked_until TIMESTAMPTZ,
+    locked_until TIMESTAMPTZ
+);
+
+CREATE INDEX idx_login_failures_locked_until ON login_failures(locked_until)
+    WHERE locked_until IS NOT NULL;
+
+UPDATE roles
+SET permissions = array_append(permissions, 'users:manage'),
+    updated_at = NOW()
+WHERE name IN ('admin', 'moderator') AND NOT ('users:manage' = ANY(permissions));
@@ -12,7 +12,10 @@ use fedi_wplace_adapters::shared::app_state::AppState as AdaptersAppState;
 use fedi_wplace_application::error::AppError;
 
 use fedi_wplace_adapters::incoming::http_axum::{
-    middleware::rate_limit::{create_general_rate_limiter, rate_limit_middleware},
+    middleware::{
+        client_ip::{ClientIpResolver, client_ip_middleware},
+        rate_limit::rate_limit_middleware,
+    },
     routes::build_application_router,
 };
 
@@ -23,17 +26,24 @@ pub async fn create_router(state: AppState) -> Result<Router, AppError> {
     let application_router =
         build_application_router(&adapters_state, user_store, password_hasher, ban_store).await?;
 
-    let router_with_rate_limiting = if adapters_state.config.rate_limit.enabled {
-        let global_rate_limiter = create_general_rate_limiter(&adapters_state.config.rate_limit);
-        application_router.layer(middleware::from_fn(move |conn_info, req, next| {
-            let limiter = Arc::clone(&global_rate_limiter);
-            rate_limit_middleware(limiter, conn_info, req, next)
-        }))
-    } else {
-        application_router
-    };
+    let router_with_rate_limiting =
+        if let Some(global_rate_limiter) = adapters_state.rate_limiters.general.clone() {
+            application_router.layer(middleware::from_fn(move |conn_info, req, next| {
+                let limiter = Arc::clone(&global_rate_limiter);
+                rate_limit_middleware(limiter, conn_info, req, next)
+            }))
+        } else {
+            application_router
+        };
 
-    Ok(router_with_rate_limiting
+    let client_ip_resolver =
+        ClientIpResolver::new(adapters_state.config.server.trusted_proxies.clone());
+    let router_with_client_ip =
+        router_with_rate_limiting.layer(middleware::from_fn(move |conn_info, req, next| {
+            client_ip_middleware(client_ip_resolver.clone(), conn_info, req, next)
+        }));
+
+    Ok(router_with_client_ip
         .layer(
             ServiceBuilder::new()
                 .layer(TraceLayer::new_for_http())
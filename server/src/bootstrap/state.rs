@@ -1,14 +1,24 @@
 use deadpool_redis::Pool as RedisPool;
 use sqlx::{PgPool, postgres::PgPoolOptions};
-use std::sync::{Arc, atomic::AtomicUsize};
+use std::{
+    collections::HashMap,
+    sync::{Arc, atomic::AtomicUsize},
+    time::Duration,
+};
 use tokio::sync::broadcast;
 
+use domain::ban::BanEscalationPolicy;
 use domain::credits::CreditConfig;
+use domain::pixel_protection::PixelProtection;
 use domain::{events::TileVersionEvent, tile::PaletteBufferPool};
 use fedi_wplace_adapters::shared::app_state::AppState as AdaptersAppState;
 use fedi_wplace_adapters::{
     incoming::{
-        http_axum::middleware::rate_limit::{RateLimiter, create_websocket_rate_limiter},
+        http_axum::middleware::rate_limit::{RateLimiter, RateLimiters},
+        scheduler::{
+            account_deletion::spawn_account_deletion_scheduler,
+            ban_expiry::spawn_ban_expiry_scheduler, token_cleanup::spawn_token_cleanup_scheduler,
+        },
         ws_axum::WsAdapterPolicy,
     },
     outgoing::{
@@ -17,40 +27,102 @@ use fedi_wplace_adapters::{
             smtp_email_sender::{SmtpEmailConfig, SmtpEmailSender},
         },
         events_broadcast::tokio_broadcast::TokioBroadcastEventsAdapter,
+        fediverse_http::mastodon_app_registrar::MastodonAppRegistrar,
         image_rs::webp_codec_image::{ImageWebpAdapter, ImageWebpConfig},
-        passwords::argon2::Argon2PasswordHasher,
+        memory_dashmap::rate_limit_memory::InMemoryRateLimitAdapter,
+        passwords::{argon2::Argon2PasswordHasher, totp::TotpRsAuthenticator},
         postgres_sqlx::{
+            account_store_postgres::PostgresAccountStoreAdapter,
+            api_token_store_postgres::PostgresApiTokenStoreAdapter,
+            audit_log_postgres::PostgresAuditLogAdapter,
             ban_store_postgres::PostgresBanStoreAdapter,
             credit_store_postgres::PostgresCreditStoreAdapter,
+            fediverse_app_store_postgres::PostgresFediverseAppStoreAdapter,
+            ip_ban_store_postgres::PostgresIpBanStoreAdapter,
+            login_failure_store_postgres::PostgresLoginFailureStoreAdapter,
             pixel_history_store_postgres::PostgresPixelHistoryStoreAdapter,
+            protected_region_store_postgres::PostgresProtectedRegionStoreAdapter,
+            quarantine_store_postgres::PostgresQuarantineStoreAdapter,
+            report_store_postgres::PostgresReportStoreAdapter,
+            role_store_postgres::PostgresRoleStoreAdapter,
+            session_store_postgres::PostgresSessionStoreAdapter,
+            two_factor_store_postgres::PostgresTwoFactorStoreAdapter,
+            user_directory_postgres::PostgresUserDirectoryAdapter,
             user_store_postgres::PostgresUserStoreAdapter,
         },
         redis_deadpool::{
+            ip_ban_cache_redis::RedisIpBanCacheAdapter, rate_limit_redis::RedisRateLimitAdapter,
             subscription_redis::RedisSubscriptionAdapter, tile_cache_redis::RedisTileCacheAdapter,
         },
         tokio_spawn::{TokioTaskSpawnAdapter, webp_timeout_tokio::TokioWebPTimeoutAdapter},
     },
 };
 use fedi_wplace_application::error::AppError;
-use fedi_wplace_application::infrastructure_config::{Config, EmailBackend};
+use fedi_wplace_application::infrastructure_config::{
+    Config, EmailBackend, RateLimitBackend, RoleRateLimitConfig,
+};
 use fedi_wplace_application::ports::incoming::tiles::{
-    MetricsQueryUseCase, PaintPixelsUseCase, PixelHistoryQueryUseCase, PixelInfoQueryUseCase,
-    TilesQueryUseCase,
+    MetricsQueryUseCase, PaintPixelsUseCase, PaintRulesQueryUseCase, PixelHistoryQueryUseCase,
+    PixelInfoQueryUseCase, PixelPromotionUseCase, RegionRollbackUseCase, TilesQueryUseCase,
 };
 use fedi_wplace_application::ports::outgoing::{
-    ban_store::BanStorePort, credit_store::CreditStorePort, email_sender::EmailSenderPort,
-    events::EventsPort, image_codec::ImageCodecPort, password_hasher::PasswordHasherPort,
-    pixel_history_store::PixelHistoryStorePort, subscription_port::SubscriptionPort,
-    tile_cache::TileCachePort, user_store::UserStorePort,
+    account_store::AccountStorePort,
+    api_token_store::ApiTokenStorePort,
+    audit_log::AuditLogPort,
+    ban_store::BanStorePort,
+    credit_store::CreditStorePort,
+    email_sender::EmailSenderPort,
+    events::EventsPort,
+    fediverse_app::{FediverseAppRegistrarPort, FediverseAppStorePort},
+    image_codec::ImageCodecPort,
+    ip_ban_cache::IpBanCachePort,
+    ip_ban_store::IpBanStorePort,
+    login_failure_store::LoginFailureStorePort,
+    password_hasher::PasswordHasherPort,
+    pixel_history_store::PixelHistoryStorePort,
+    protected_region_store::ProtectedRegionStorePort,
+    quarantine_store::QuarantineStorePort,
+    rate_limit::{DynRateLimitPort, RateLimitQuota},
+    report_store::ReportStorePort,
+    role_store::RoleStorePort,
+    session_store::SessionStorePort,
+    subscription_port::SubscriptionPort,
+    tile_cache::TileCachePort,
+    totp::TotpPort,
+    two_factor_store::TwoFactorStorePort,
+    user_directory::UserDirectoryPort,
+    user_store::UserStorePort,
 };
 use fedi_wplace_application::{
+    account::service::{AccountService, AccountServiceDeps},
     admin::service::AdminService,
-    auth::service::AuthService,
-    ban::service::BanService,
-    config::TileSettings,
+    audit::service::AuditLogService,
+    auth::{
+        api_token_service::ApiTokenService, fediverse_service::FediverseLoginService,
+        login_protection_service::LoginProtectionService, service::AuthService,
+        session_service::SessionService, token_cleanup_service::TokenCleanupService,
+        two_factor_service::TwoFactorService,
+    },
+    ban::{
+        expiry_service::BanExpiryService, ip_service::IpBanService,
+        quarantine_service::QuarantineService, service::BanService,
+    },
+    config::{ReportSettings, TileSettings},
     ports::incoming::{
-        admin::AdminUseCase, auth::AuthUseCase, ban::BanUseCase, subscriptions::SubscriptionUseCase,
+        account::{AccountDeletionUseCase, AccountUseCase},
+        admin::AdminUseCase,
+        audit::AuditLogUseCase,
+        auth::{
+            ApiTokenUseCase, AuthUseCase, FediverseLoginUseCase, LoginProtectionUseCase,
+            SessionManagementUseCase, TokenCleanupUseCase, TwoFactorUseCase,
+        },
+        ban::{BanExpiryUseCase, BanUseCase, IpBanUseCase, QuarantineUseCase},
+        protected_regions::ProtectedRegionUseCase,
+        reports::ReportUseCase,
+        subscriptions::SubscriptionUseCase,
     },
+    protected_regions::service::ProtectedRegionService,
+    reports::service::ReportService,
     subscriptions::service::SubscriptionService,
     tiles::service::PaletteColorLookup,
     tiles::service::{TileService, TileServiceDeps},
@@ -64,10 +136,24 @@ pub struct AppState {
     pub tile_service: Arc<TileService>,
     pub subscription_service: Arc<dyn SubscriptionUseCase>,
     pub auth_service: Arc<dyn AuthUseCase>,
+    pub fediverse_login_service: Arc<dyn FediverseLoginUseCase>,
+    pub two_factor_service: Arc<dyn TwoFactorUseCase>,
+    pub session_management_service: Arc<dyn SessionManagementUseCase>,
+    pub api_token_service: Arc<dyn ApiTokenUseCase>,
+    pub account_service: Arc<dyn AccountUseCase>,
+    pub account_deletion_service: Arc<dyn AccountDeletionUseCase>,
+    pub login_protection_service: Arc<dyn LoginProtectionUseCase>,
     pub admin_service: Arc<dyn AdminUseCase>,
     pub ban_service: Arc<dyn BanUseCase>,
+    pub ban_expiry_service: Arc<dyn BanExpiryUseCase>,
+    pub token_cleanup_service: Arc<dyn TokenCleanupUseCase>,
+    pub ip_ban_service: Arc<dyn IpBanUseCase>,
+    pub quarantine_service: Arc<dyn QuarantineUseCase>,
+    pub report_service: Arc<dyn ReportUseCase>,
+    pub protected_region_service: Arc<dyn ProtectedRegionUseCase>,
+    pub audit_log_service: Arc<dyn AuditLogUseCase>,
     pub ws_broadcast: broadcast::Sender<TileVersionEvent>,
-    pub websocket_rate_limiter: Option<Arc<RateLimiter>>,
+    pub rate_limiters: RateLimiters,
     pub active_websocket_connections: Arc<AtomicUsize>,
 }
 
@@ -89,15 +175,35 @@ impl AppState {
         )?;
 
         let subscription_service = Self::create_subscription_service(&config, &redis_pool);
-        let auth_service = Self::create_auth_service(&config, &db_pool)?;
+        let two_factor_service = Self::create_two_factor_service(&config, &db_pool);
+        let login_protection_service = Self::create_login_protection_service(&config, &db_pool)?;
+        let auth_service =
+            Self::create_auth_service(&config, &db_pool, Arc::clone(&login_protection_service))?;
+        let fediverse_login_service = Self::create_fediverse_login_service(&config, &db_pool)?;
+        let session_management_service = Self::create_session_management_service(&config, &db_pool);
+        let api_token_service = Self::create_api_token_service(&config, &db_pool);
+        let account_service = Self::create_account_service(&config, &db_pool)?;
         let admin_service = Self::create_admin_service(&config, &db_pool);
-        let ban_service = Self::create_ban_service(&config, &db_pool);
+        let ban_expiry_service = Self::create_ban_expiry_service(&config, &db_pool)?;
+        let ban_service =
+            Self::create_ban_service(&config, &db_pool, Arc::clone(&ban_expiry_service));
+        let token_cleanup_service = Self::create_token_cleanup_service(&config, &db_pool);
+        let ip_ban_service = Self::create_ip_ban_service(&config, &db_pool, &redis_pool);
+        let quarantine_service = Self::create_quarantine_service(
+            &config,
+            &db_pool,
+            Arc::clone(&tile_service) as Arc<dyn PixelPromotionUseCase>,
+        );
+        let report_service = Self::create_report_service(
+            &config,
+            &db_pool,
+            Arc::clone(&ban_service),
+            Arc::clone(&tile_service) as Arc<dyn RegionRollbackUseCase>,
+        );
+        let protected_region_service = Self::create_protected_region_service(&config, &db_pool);
+        let audit_log_service = Self::create_audit_log_service(&config, &db_pool);
 
-        let websocket_rate_limiter = if config.rate_limit.enabled {
-            Some(create_websocket_rate_limiter(&config.rate_limit))
-        } else {
-            None
-        };
+        let rate_limiters = Self::create_rate_limiters(&config, &redis_pool);
 
         Ok(Self {
             config,
@@ -106,10 +212,24 @@ impl AppState {
             tile_service,
             subscription_service,
             auth_service,
+            fediverse_login_service,
+            two_factor_service,
+            session_management_service,
+            api_token_service,
+            account_service: Arc::clone(&account_service) as Arc<dyn AccountUseCase>,
+            account_deletion_service: account_service,
+            login_protection_service,
             admin_service,
             ban_service,
+            ban_expiry_service,
+            token_cleanup_service,
+            ip_ban_service,
+            quarantine_service,
+            report_service,
+            protected_region_service,
+            audit_log_service,
             ws_broadcast,
-            websocket_rate_limiter,
+            rate_limiters,
             active_websocket_connections: Arc::new(AtomicUsize::new(0)),
         })
     }
@@ -176,6 +296,20 @@ impl AppState {
             db_pool.clone(),
             config.db.query_timeout_secs,
         ));
+        let ban_store: Arc<dyn BanStorePort> = Arc::new(PostgresBanStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let quarantine_store: Arc<dyn QuarantineStorePort> = Arc::new(
+            PostgresQuarantineStoreAdapter::new(db_pool.clone(), config.db.query_timeout_secs),
+        );
+        let protected_region_store: Arc<dyn ProtectedRegionStorePort> = Arc::new(
+            PostgresProtectedRegionStoreAdapter::new(db_pool.clone(), config.db.query_timeout_secs),
+        );
+        let user_store: Arc<dyn UserStorePort> = Arc::new(PostgresUserStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
         let codec_port: Arc<dyn ImageCodecPort> = Arc::new(ImageWebpAdapter::new(webp_config));
         let events_port: Arc<dyn EventsPort> =
             Arc::new(TokioBroadcastEventsAdapter::new(ws_broadcast.clone()));
@@ -207,6 +341,15 @@ impl AppState {
                     config.credits.max_charges,
                     config.credits.charge_cooldown_seconds,
                 ),
+                ban_store,
+                quarantine_store,
+                protected_region_store,
+                user_store,
+                paint_rules: Arc::new(config.rules.to_rule_set()?),
+                pixel_protection: PixelProtection::new(
+                    config.pixel_protection.window_secs,
+                    config.pixel_protection.overwrite_surcharge,
+                ),
             },
         )?;
 
@@ -228,6 +371,7 @@ impl AppState {
     fn create_auth_service(
         config: &Config,
         db_pool: &PgPool,
+        login_protection_service: Arc<dyn LoginProtectionUseCase>,
     ) -> Result<Arc<dyn AuthUseCase>, AppError> {
         let user_store_port: Arc<dyn UserStorePort> = Arc::new(PostgresUserStoreAdapter::new(
             db_pool.clone(),
@@ -237,10 +381,173 @@ impl AppState {
             Argon2PasswordHasher::from_config_or_default(&config.auth.argon2),
         );
 
-        let email_sender_port: Arc<dyn EmailSenderPort> = match config.auth.email.email_backend {
-            EmailBackend::Console => {
-                Arc::new(ConsoleEmailSender::new(config.auth.public_base_url.clone()))
-            }
+        let session_store_port: Arc<dyn SessionStorePort> = Arc::new(
+            PostgresSessionStoreAdapter::new(db_pool.clone(), config.db.query_timeout_secs),
+        );
+
+        let email_sender_port = Self::create_email_sender(config)?;
+
+        Ok(Arc::new(AuthService::new(
+            user_store_port,
+            password_hasher_port,
+            email_sender_port,
+            session_store_port,
+            login_protection_service,
+        )))
+    }
+
+    fn create_login_protection_service(
+        config: &Config,
+        db_pool: &PgPool,
+    ) -> Result<Arc<dyn LoginProtectionUseCase>, AppError> {
+        let login_failure_store_port: Arc<dyn LoginFailureStorePort> = Arc::new(
+            PostgresLoginFailureStoreAdapter::new(db_pool.clone(), config.db.query_timeout_secs),
+        );
+        let user_store_port: Arc<dyn UserStorePort> = Arc::new(PostgresUserStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let audit_log_port: Arc<dyn AuditLogPort> = Arc::new(PostgresAuditLogAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let email_sender_port = if config.auth.login_protection.notify_on_lockout {
+            Some(Self::create_email_sender(config)?)
+        } else {
+            None
+        };
+
+        Ok(Arc::new(LoginProtectionService::new(
+            login_failure_store_port,
+            user_store_port,
+            audit_log_port,
+            email_sender_port,
+            &config.auth.login_protection,
+        )))
+    }
+
+    fn create_session_management_service(
+        config: &Config,
+        db_pool: &PgPool,
+    ) -> Arc<dyn SessionManagementUseCase> {
+        let session_store_port: Arc<dyn SessionStorePort> = Arc::new(
+            PostgresSessionStoreAdapter::new(db_pool.clone(), config.db.query_timeout_secs),
+        );
+
+        Arc::new(SessionService::new(
+            session_store_port,
+            &config.auth.sessions,
+        ))
+    }
+
+    fn create_api_token_service(config: &Config, db_pool: &PgPool) -> Arc<dyn ApiTokenUseCase> {
+        let api_token_store_port: Arc<dyn ApiTokenStorePort> = Arc::new(
+            PostgresApiTokenStoreAdapter::new(db_pool.clone(), config.db.query_timeout_secs),
+        );
+        let user_store_port: Arc<dyn UserStorePort> = Arc::new(PostgresUserStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+
+        Arc::new(ApiTokenService::new(
+            api_token_store_port,
+            user_store_port,
+            config.auth.api_tokens.clone(),
+        ))
+    }
+
+    fn create_account_service(
+        config: &Config,
+        db_pool: &PgPool,
+    ) -> Result<Arc<AccountService>, AppError> {
+        let account_store_port: Arc<dyn AccountStorePort> = Arc::new(
+            PostgresAccountStoreAdapter::new(db_pool.clone(), config.db.query_timeout_secs),
+        );
+        let user_store_port: Arc<dyn UserStorePort> = Arc::new(PostgresUserStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let pixel_history_store_port: Arc<dyn PixelHistoryStorePort> =
+            Arc::new(PostgresPixelHistoryStoreAdapter::new(
+                db_pool.clone(),
+                config.tiles.tile_size,
+                config.db.query_timeout_secs,
+            ));
+        let ban_store_port: Arc<dyn BanStorePort> = Arc::new(PostgresBanStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let session_store_port: Arc<dyn SessionStorePort> = Arc::new(
+            PostgresSessionStoreAdapter::new(db_pool.clone(), config.db.query_timeout_secs),
+        );
+        let password_hasher_port: Arc<dyn PasswordHasherPort> = Arc::new(
+            Argon2PasswordHasher::from_config_or_default(&config.auth.argon2),
+        );
+        let audit_log_port: Arc<dyn AuditLogPort> = Arc::new(PostgresAuditLogAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+
+        Ok(Arc::new(AccountService::new(
+            AccountServiceDeps {
+                account_store: account_store_port,
+                user_store: user_store_port,
+                pixel_history_store: pixel_history_store_port,
+                ban_store: ban_store_port,
+                session_store: session_store_port,
+                password_hasher: password_hasher_port,
+                email_sender: Self::create_email_sender(config)?,
+                audit_log: audit_log_port,
+            },
+            &config.auth.account_deletion,
+        )))
+    }
+
+    fn create_two_factor_service(config: &Config, db_pool: &PgPool) -> Arc<dyn TwoFactorUseCase> {
+        let two_factor_store_port: Arc<dyn TwoFactorStorePort> = Arc::new(
+            PostgresTwoFactorStoreAdapter::new(db_pool.clone(), config.db.query_timeout_secs),
+        );
+        let user_store_port: Arc<dyn UserStorePort> = Arc::new(PostgresUserStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let totp_port: Arc<dyn TotpPort> = Arc::new(TotpRsAuthenticator::new(
+            config.auth.two_factor.issuer.clone(),
+        ));
+
+        Arc::new(TwoFactorService::new(
+            two_factor_store_port,
+            user_store_port,
+            totp_port,
+            config.auth.two_factor.clone(),
+        ))
+    }
+
+    fn create_fediverse_login_service(
+        config: &Config,
+        db_pool: &PgPool,
+    ) -> Result<Arc<dyn FediverseLoginUseCase>, AppError> {
+        let app_store_port: Arc<dyn FediverseAppStorePort> = Arc::new(
+            PostgresFediverseAppStoreAdapter::new(db_pool.clone(), config.db.query_timeout_secs),
+        );
+        let registrar_port: Arc<dyn FediverseAppRegistrarPort> =
+            Arc::new(MastodonAppRegistrar::new(
+                config.auth.mastodon.client_name.clone(),
+                config.auth.public_base_url.clone(),
+            )?);
+
+        Ok(Arc::new(FediverseLoginService::new(
+            app_store_port,
+            registrar_port,
+            &config.auth.mastodon,
+        )?))
+    }
+
+    fn create_email_sender(config: &Config) -> Result<Arc<dyn EmailSenderPort>, AppError> {
+        match config.auth.email.email_backend {
+            EmailBackend::Console => Ok(Arc::new(ConsoleEmailSender::new(
+                config.auth.public_base_url.clone(),
+            ))),
             EmailBackend::Smtp => {
                 let smtp_config = &config.auth.email.smtp;
                 let email_config = SmtpEmailConfig {
@@ -254,35 +561,369 @@ impl AppState {
                     use_tls: smtp_config.use_tls,
                 };
                 let smtp_sender = SmtpEmailSender::new(email_config)?;
-                Arc::new(smtp_sender)
+                Ok(Arc::new(smtp_sender))
             }
+        }
+    }
+
+    fn create_admin_service(config: &Config, db_pool: &PgPool) -> Arc<dyn AdminUseCase> {
+        let user_store_port: Arc<dyn UserStorePort> = Arc::new(PostgresUserStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let role_store_port: Arc<dyn RoleStorePort> = Arc::new(PostgresRoleStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let user_directory_port: Arc<dyn UserDirectoryPort> = Arc::new(
+            PostgresUserDirectoryAdapter::new(db_pool.clone(), config.db.query_timeout_secs),
+        );
+        let ban_store_port: Arc<dyn BanStorePort> = Arc::new(PostgresBanStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let session_store_port: Arc<dyn SessionStorePort> = Arc::new(
+            PostgresSessionStoreAdapter::new(db_pool.clone(), config.db.query_timeout_secs),
+        );
+        let audit_log_port: Arc<dyn AuditLogPort> = Arc::new(PostgresAuditLogAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        Arc::new(AdminService::new(
+            user_store_port,
+            role_store_port,
+            user_directory_port,
+            ban_store_port,
+            session_store_port,
+            audit_log_port,
+        ))
+    }
+
+    fn create_ban_service(
+        config: &Config,
+        db_pool: &PgPool,
+        ban_expiry_service: Arc<dyn BanExpiryUseCase>,
+    ) -> Arc<dyn BanUseCase> {
+        let ban_store_port: Arc<dyn BanStorePort> = Arc::new(PostgresBanStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let user_store_port: Arc<dyn UserStorePort> = Arc::new(PostgresUserStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let escalation_policy = if config.bans.escalation_enabled {
+            BanEscalationPolicy::new(
+                config.bans.escalation_multiplier,
+                config.bans.permanent_after_prior_bans,
+            )
+        } else {
+            BanEscalationPolicy::disabled()
         };
+        let session_store_port: Arc<dyn SessionStorePort> = Arc::new(
+            PostgresSessionStoreAdapter::new(db_pool.clone(), config.db.query_timeout_secs),
+        );
+        let audit_log_port: Arc<dyn AuditLogPort> = Arc::new(PostgresAuditLogAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        Arc::new(BanService::new(
+            ban_store_port,
+            user_store_port,
+            session_store_port,
+            audit_log_port,
+            ban_expiry_service,
+            escalation_policy,
+        ))
+    }
 
-        Ok(Arc::new(AuthService::new(
+    fn create_token_cleanup_service(
+        config: &Config,
+        db_pool: &PgPool,
+    ) -> Arc<dyn TokenCleanupUseCase> {
+        let user_store_port: Arc<dyn UserStorePort> = Arc::new(PostgresUserStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let session_store_port: Arc<dyn SessionStorePort> = Arc::new(
+            PostgresSessionStoreAdapter::new(db_pool.clone(), config.db.query_timeout_secs),
+        );
+
+        Arc::new(TokenCleanupService::new(
             user_store_port,
-            password_hasher_port,
+            session_store_port,
+            &config.auth.sessions,
+        ))
+    }
+
+    fn create_ban_expiry_service(
+        config: &Config,
+        db_pool: &PgPool,
+    ) -> Result<Arc<dyn BanExpiryUseCase>, AppError> {
+        let ban_store_port: Arc<dyn BanStorePort> = Arc::new(PostgresBanStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let user_store_port: Arc<dyn UserStorePort> = Arc::new(PostgresUserStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let audit_log_port: Arc<dyn AuditLogPort> = Arc::new(PostgresAuditLogAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let email_sender_port = if config.bans.notify_on_expiry {
+            Some(Self::create_email_sender(config)?)
+        } else {
+            None
+        };
+
+        Ok(Arc::new(BanExpiryService::new(
+            ban_store_port,
+            user_store_port,
+            audit_log_port,
             email_sender_port,
         )))
     }
 
-    fn create_admin_service(config: &Config, db_pool: &PgPool) -> Arc<dyn AdminUseCase> {
+    fn create_ip_ban_service(
+        config: &Config,
+        db_pool: &PgPool,
+        redis_pool: &RedisPool,
+    ) -> Arc<dyn IpBanUseCase> {
+        let ip_ban_store_port: Arc<dyn IpBanStorePort> = Arc::new(PostgresIpBanStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let ip_ban_cache_port: Arc<dyn IpBanCachePort> = Arc::new(RedisIpBanCacheAdapter::new(
+            redis_pool.clone(),
+            &config.environment.env,
+        ));
         let user_store_port: Arc<dyn UserStorePort> = Arc::new(PostgresUserStoreAdapter::new(
             db_pool.clone(),
             config.db.query_timeout_secs,
         ));
-        Arc::new(AdminService::new(user_store_port))
+        let audit_log_port: Arc<dyn AuditLogPort> = Arc::new(PostgresAuditLogAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        Arc::new(IpBanService::new(
+            ip_ban_store_port,
+            ip_ban_cache_port,
+            user_store_port,
+            audit_log_port,
+        ))
     }
 
-    fn create_ban_service(config: &Config, db_pool: &PgPool) -> Arc<dyn BanUseCase> {
-        let ban_store_port: Arc<dyn BanStorePort> = Arc::new(PostgresBanStoreAdapter::new(
+    fn create_quarantine_service(
+        config: &Config,
+        db_pool: &PgPool,
+        promotion_service: Arc<dyn PixelPromotionUseCase>,
+    ) -> Arc<dyn QuarantineUseCase> {
+        let quarantine_store_port: Arc<dyn QuarantineStorePort> = Arc::new(
+            PostgresQuarantineStoreAdapter::new(db_pool.clone(), config.db.query_timeout_secs),
+        );
+        let user_store_port: Arc<dyn UserStorePort> = Arc::new(PostgresUserStoreAdapter::new(
             db_pool.clone(),
             config.db.query_timeout_secs,
         ));
+        let audit_log_port: Arc<dyn AuditLogPort> = Arc::new(PostgresAuditLogAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        Arc::new(QuarantineService::new(
+            quarantine_store_port,
+            user_store_port,
+            promotion_service,
+            audit_log_port,
+        ))
+    }
+
+    fn create_report_service(
+        config: &Config,
+        db_pool: &PgPool,
+        ban_service: Arc<dyn BanUseCase>,
+        rollback_service: Arc<dyn RegionRollbackUseCase>,
+    ) -> Arc<dyn ReportUseCase> {
+        let report_store_port: Arc<dyn ReportStorePort> = Arc::new(
+            PostgresReportStoreAdapter::new(db_pool.clone(), config.db.query_timeout_secs),
+        );
+        let pixel_history_store_port: Arc<dyn PixelHistoryStorePort> =
+            Arc::new(PostgresPixelHistoryStoreAdapter::new(
+                db_pool.clone(),
+                config.tiles.tile_size,
+                config.db.query_timeout_secs,
+            ));
         let user_store_port: Arc<dyn UserStorePort> = Arc::new(PostgresUserStoreAdapter::new(
             db_pool.clone(),
             config.db.query_timeout_secs,
         ));
-        Arc::new(BanService::new(ban_store_port, user_store_port))
+        let settings = ReportSettings {
+            max_comment_length: config.reports.max_comment_length,
+            max_pending_reports_per_user: config.reports.max_pending_reports_per_user,
+            max_region_area: config.reports.max_region_area,
+        };
+        let audit_log_port: Arc<dyn AuditLogPort> = Arc::new(PostgresAuditLogAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        Arc::new(ReportService::new(
+            report_store_port,
+            pixel_history_store_port,
+            user_store_port,
+            ban_service,
+            rollback_service,
+            audit_log_port,
+            settings,
+        ))
+    }
+
+    fn create_protected_region_service(
+        config: &Config,
+        db_pool: &PgPool,
+    ) -> Arc<dyn ProtectedRegionUseCase> {
+        let region_store_port: Arc<dyn ProtectedRegionStorePort> = Arc::new(
+            PostgresProtectedRegionStoreAdapter::new(db_pool.clone(), config.db.query_timeout_secs),
+        );
+        let role_store_port: Arc<dyn RoleStorePort> = Arc::new(PostgresRoleStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let user_store_port: Arc<dyn UserStorePort> = Arc::new(PostgresUserStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let audit_log_port: Arc<dyn AuditLogPort> = Arc::new(PostgresAuditLogAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        Arc::new(ProtectedRegionService::new(
+            region_store_port,
+            role_store_port,
+            user_store_port,
+            audit_log_port,
+        ))
+    }
+
+    fn create_audit_log_service(config: &Config, db_pool: &PgPool) -> Arc<dyn AuditLogUseCase> {
+        let audit_log_port: Arc<dyn AuditLogPort> = Arc::new(PostgresAuditLogAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        let user_store_port: Arc<dyn UserStorePort> = Arc::new(PostgresUserStoreAdapter::new(
+            db_pool.clone(),
+            config.db.query_timeout_secs,
+        ));
+        Arc::new(AuditLogService::new(audit_log_port, user_store_port))
+    }
+
+    fn create_rate_limiters(config: &Config, redis_pool: &RedisPool) -> RateLimiters {
+        let rate_limit = &config.rate_limit;
+        if !rate_limit.enabled {
+            return RateLimiters::default();
+        }
+
+        let create_limiter = |name: &str, backend: RateLimitBackend, requests_per_minute: u32| {
+            let rate_limit_port: DynRateLimitPort = match backend {
+                RateLimitBackend::Memory => Arc::new(InMemoryRateLimitAdapter::new()),
+                RateLimitBackend::Redis => Arc::new(RedisRateLimitAdapter::new(
+                    redis_pool.clone(),
+                    &config.environment.env,
+                    name,
+                )),
+            };
+            RateLimiter::new(
+                rate_limit_port,
+                RateLimitQuota::new(requests_per_minute, rate_limit.burst_size_multiplier),
+            )
+            .with_shared_ip_multiplier(rate_limit.shared_ip_multiplier)
+        };
+
+        let role_quotas = |select: fn(&RoleRateLimitConfig) -> Option<u32>| {
+            rate_limit
+                .roles
+                .iter()
+                .filter_map(|(role_name, role_limits)| {
+                    select(role_limits).map(|requests_per_minute| {
+                        (
+                            role_name.clone(),
+                            RateLimitQuota::new(
+                                requests_per_minute,
+                                rate_limit.burst_size_multiplier,
+                            ),
+                        )
+                    })
+                })
+                .collect::<HashMap<_, _>>()
+        };
+
+        RateLimiters {
+            paint: Some(Arc::new(
+                create_limiter(
+                    "paint",
+                    rate_limit.backends.paint,
+                    rate_limit.paint_requests_per_minute,
+                )
+                .with_role_quotas(role_quotas(|role_limits| {
+                    role_limits.paint_requests_per_minute
+                })),
+            )),
+            tile: Some(Arc::new(create_limiter(
+                "tile",
+                rate_limit.backends.tile,
+                rate_limit.tile_requests_per_minute,
+            ))),
+            general: Some(Arc::new(create_limiter(
+                "global",
+                rate_limit.backends.global,
+                rate_limit.global_requests_per_minute,
+            ))),
+            websocket: Some(Arc::new(create_limiter(
+                "websocket",
+                rate_limit.backends.websocket,
+                rate_limit.websocket_messages_per_minute,
+            ))),
+            auth: Some(Arc::new(create_limiter(
+                "auth",
+                rate_limit.backends.auth,
+                rate_limit.auth_requests_per_minute,
+            ))),
+            account: Some(Arc::new(
+                create_limiter(
+                    "account",
+                    rate_limit.backends.account,
+                    rate_limit.account_requests_per_minute,
+                )
+                .with_role_quotas(role_quotas(|role_limits| {
+                    role_limits.account_requests_per_minute
+                })),
+            )),
+            report: Some(Arc::new(create_limiter(
+                "report",
+                rate_limit.backends.report,
+                rate_limit.report_requests_per_minute,
+            ))),
+            api_token: Some(Arc::new(create_limiter(
+                "api_token",
+                rate_limit.backends.api_token,
+                rate_limit.api_token_requests_per_minute,
+            ))),
+        }
+    }
+
+    pub fn spawn_background_jobs(&self) {
+        spawn_ban_expiry_scheduler(
+            Arc::clone(&self.ban_expiry_service),
+            Duration::from_secs(self.config.bans.expiry_check_interval_secs),
+        );
+        spawn_token_cleanup_scheduler(
+            Arc::clone(&self.token_cleanup_service),
+            Duration::from_secs(self.config.auth.token_cleanup_interval_secs),
+        );
+        spawn_account_deletion_scheduler(
+            Arc::clone(&self.account_deletion_service),
+            Duration::from_secs(self.config.auth.account_deletion.check_interval_secs),
+        );
     }
 
     pub fn db_pool(&self) -> &PgPool {
@@ -319,7 +960,6 @@ impl AppState {
             self.db_pool.clone(),
             self.config.db.query_timeout_secs,
         ));
-        let admin_service = Arc::new(AdminService::new(Arc::clone(&user_store_port)));
 
         let adapters_state = AdaptersAppState::new(
             self.config,
@@ -329,12 +969,24 @@ impl AppState {
             Arc::clone(&self.tile_service) as Arc<dyn MetricsQueryUseCase + Send + Sync>,
             Arc::clone(&self.tile_service) as Arc<dyn PixelHistoryQueryUseCase + Send + Sync>,
             Arc::clone(&self.tile_service) as Arc<dyn PixelInfoQueryUseCase + Send + Sync>,
+            Arc::clone(&self.tile_service) as Arc<dyn PaintRulesQueryUseCase + Send + Sync>,
             self.subscription_service,
             self.auth_service,
-            admin_service,
+            self.fediverse_login_service,
+            self.two_factor_service,
+            self.session_management_service,
+            self.api_token_service,
+            self.account_service,
+            self.login_protection_service,
+            self.admin_service,
             self.ban_service,
+            self.ip_ban_service,
+            self.quarantine_service,
+            self.report_service,
+            self.protected_region_service,
+            self.audit_log_service,
             self.ws_broadcast,
-            self.websocket_rate_limiter,
+            self.rate_limiters,
             self.active_websocket_connections,
         );
 
@@ -23,6 +23,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     info!("Database URL: {}", config.db.redacted_url());
 
     let state = AppState::new(config.clone()).await?;
+    state.spawn_background_jobs();
 
     let app = create_router(state.clone())
         .await?
@@ -69,23 +69,46 @@ fn print_rate_limiting_info(config: &Config) {
 #[allow(clippy::cognitive_complexity)]
 fn print_rate_limits(rate_limit: &RateLimitConfig) {
     info!(
-        "    • Paint: {}/min per IP (burst: {})",
+        "    • Paint: {}/min per user (burst: {}, backend: {})",
         rate_limit.paint_requests_per_minute,
-        rate_limit.paint_requests_per_minute * rate_limit.burst_size_multiplier
+        rate_limit.paint_requests_per_minute * rate_limit.burst_size_multiplier,
+        rate_limit.backends.paint.as_str()
     );
     info!(
-        "    • Tiles: {}/min per IP (burst: {})",
+        "    • Tiles: {}/min per IP (burst: {}, backend: {})",
         rate_limit.tile_requests_per_minute,
-        rate_limit.tile_requests_per_minute * rate_limit.burst_size_multiplier
+        rate_limit.tile_requests_per_minute * rate_limit.burst_size_multiplier,
+        rate_limit.backends.tile.as_str()
     );
     info!(
-        "    • Global: {}/min per IP (burst: {})",
+        "    • Global: {}/min per IP (burst: {}, backend: {})",
         rate_limit.global_requests_per_minute,
-        rate_limit.global_requests_per_minute * rate_limit.burst_size_multiplier
+        rate_limit.global_requests_per_minute * rate_limit.burst_size_multiplier,
+        rate_limit.backends.global.as_str()
     );
     info!(
-        "    • WebSocket: {}/min per IP (burst: {})",
+        "    • WebSocket: {}/min per IP (burst: {}, backend: {})",
         rate_limit.websocket_messages_per_minute,
-        rate_limit.websocket_messages_per_minute * rate_limit.burst_size_multiplier
+        rate_limit.websocket_messages_per_minute * rate_limit.burst_size_multiplier,
+        rate_limit.backends.websocket.as_str()
+    );
+    info!(
+        "    • Account: {}/min per user (burst: {}, backend: {}, role overrides: {})",
+        rate_limit.account_requests_per_minute,
+        rate_limit.account_requests_per_minute * rate_limit.burst_size_multiplier,
+        rate_limit.backends.account.as_str(),
+        rate_limit.roles.len()
+    );
+    info!(
+        "    • Reports: {}/min per user (burst: {}, backend: {})",
+        rate_limit.report_requests_per_minute,
+        rate_limit.report_requests_per_minute * rate_limit.burst_size_multiplier,
+        rate_limit.backends.report.as_str()
+    );
+    info!(
+        "    • API tokens: {}/min per token (burst: {}, backend: {})",
+        rate_limit.api_token_requests_per_minute,
+        rate_limit.api_token_requests_per_minute * rate_limit.burst_size_multiplier,
+        rate_limit.backends.api_token.as_str()
     );
 }